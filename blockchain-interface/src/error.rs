@@ -17,7 +17,10 @@ pub enum ForkEvent {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BlockchainEvent {
     Extended(Blake2bHash),
-    HistoryAdopted(Blake2bHash),
+    /// The history of the given macro block was adopted during history sync. The micro blocks
+    /// that were reverted because they diverge from the adopted history are listed in ascending
+    /// order.
+    HistoryAdopted(Blake2bHash, Vec<(Blake2bHash, Block)>),
     Rebranched(Vec<(Blake2bHash, Block)>, Vec<(Blake2bHash, Block)>),
     /// Given Block was stored in the chain store but was not adopted as new head block.
     /// I.e. forked blocks and inferior chain blocks.
@@ -30,9 +33,9 @@ pub enum BlockchainEvent {
 pub enum BlockchainError {
     #[error("Invalid genesis block stored. Verify you are on the correct network or reset your consensus database.")]
     InvalidGenesisBlock,
-    #[error("Failed to load the main chain. Reset your consensus database.")]
+    #[error("Failed to load the main chain. Run `nimiq-client check-db --repair` or reset your consensus database.")]
     FailedLoadingMainChain,
-    #[error("Inconsistent chain/accounts state. Reset your consensus database.")]
+    #[error("Inconsistent chain/accounts state. Run `nimiq-client check-db --repair` or reset your consensus database.")]
     InconsistentState,
     #[error("No network for: {:?}", _0)]
     NoNetwork(NetworkId),
@@ -0,0 +1,261 @@
+use std::{
+    io::{self, Read, Write},
+    sync::Arc,
+    time::Instant,
+};
+
+use nimiq_block::{Block, BlockError};
+use nimiq_blockchain_interface::{AbstractBlockchain, PushError, PushResult};
+use nimiq_primitives::{networks::NetworkId, policy::Policy};
+use nimiq_serde::{Deserialize, DeserializeError, Serialize};
+use parking_lot::RwLock;
+use thiserror::Error;
+
+use crate::Blockchain;
+
+/// Magic bytes at the start of every block file.
+pub const BLOCK_FILE_MAGIC: [u8; 4] = *b"NQBF";
+
+/// The current version of the block file format.
+pub const BLOCK_FILE_VERSION: u8 = 1;
+
+/// Maximum size of a single serialized block accepted when reading a block file.
+const MAX_BLOCK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Number of blocks after which the export and import progress is logged.
+const PROGRESS_INTERVAL: u32 = 1000;
+
+#[derive(Debug, Error)]
+pub enum BlockFileError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Not a block file")]
+    InvalidMagic,
+    #[error("Unsupported block file version {0}")]
+    UnsupportedVersion(u8),
+    #[error("Unknown network id {0}")]
+    UnknownNetwork(u8),
+    #[error("The block file contains blocks of network {actual}, expected {expected}")]
+    WrongNetwork {
+        expected: NetworkId,
+        actual: NetworkId,
+    },
+    #[error("Block of {0} bytes exceeds the maximum block size")]
+    BlockTooLarge(usize),
+    #[error("Failed to deserialize block: {0}")]
+    Deserialize(#[from] DeserializeError),
+    #[error("Block #{0} is not stored")]
+    MissingBlock(u32),
+    #[error("Block #{block_number} can't be exported: {error}")]
+    IncompleteBlock {
+        block_number: u32,
+        error: BlockError,
+    },
+    #[error("Failed to push block #{block_number}: {error}")]
+    Push { block_number: u32, error: PushError },
+}
+
+/// Writes blocks to a block file.
+///
+/// A block file starts with the magic bytes, the format version and the network id. It is
+/// followed by the blocks in ascending order, each prefixed by its serialized size as a
+/// big-endian `u32`. Blocks include their bodies and justifications, so that they can be fully
+/// verified when they are imported again.
+pub struct BlockFileWriter<W: Write> {
+    writer: W,
+    num_blocks: u32,
+}
+
+impl<W: Write> BlockFileWriter<W> {
+    /// Creates a new block file for blocks of the given network and writes its header.
+    pub fn new(mut writer: W, network_id: NetworkId) -> io::Result<Self> {
+        writer.write_all(&BLOCK_FILE_MAGIC)?;
+        writer.write_all(&[BLOCK_FILE_VERSION, network_id as u8])?;
+        Ok(Self {
+            writer,
+            num_blocks: 0,
+        })
+    }
+
+    /// Appends the given block to the file.
+    pub fn write_block(&mut self, block: &Block) -> io::Result<()> {
+        let bytes = block.serialize_to_vec();
+        self.writer.write_all(&(bytes.len() as u32).to_be_bytes())?;
+        self.writer.write_all(&bytes)?;
+        self.num_blocks += 1;
+        Ok(())
+    }
+
+    /// Returns the number of blocks written so far.
+    pub fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Flushes the file and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads the blocks of a block file one by one, see `BlockFileWriter` for the format.
+pub struct BlockFileReader<R: Read> {
+    reader: R,
+    network_id: NetworkId,
+}
+
+impl<R: Read> BlockFileReader<R> {
+    /// Opens a block file and reads its header.
+    pub fn new(mut reader: R) -> Result<Self, BlockFileError> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != BLOCK_FILE_MAGIC {
+            return Err(BlockFileError::InvalidMagic);
+        }
+
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header)?;
+        let [version, network_id] = header;
+        if version != BLOCK_FILE_VERSION {
+            return Err(BlockFileError::UnsupportedVersion(version));
+        }
+        let network_id = NetworkId::try_from(network_id)
+            .map_err(|_| BlockFileError::UnknownNetwork(network_id))?;
+
+        Ok(Self { reader, network_id })
+    }
+
+    /// The network of the blocks in this file.
+    pub fn network_id(&self) -> NetworkId {
+        self.network_id
+    }
+
+    /// Reads the next block. Returns `None` at the end of the file.
+    pub fn read_block(&mut self) -> Result<Option<Block>, BlockFileError> {
+        // The file may only end before the size prefix of a block.
+        let mut len = [0u8; 4];
+        if self.reader.read(&mut len[..1])? == 0 {
+            return Ok(None);
+        }
+        self.reader.read_exact(&mut len[1..])?;
+
+        let len = u32::from_be_bytes(len) as usize;
+        if len > MAX_BLOCK_SIZE {
+            return Err(BlockFileError::BlockTooLarge(len));
+        }
+
+        let mut bytes = vec![0u8; len];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(Some(Block::deserialize_from_vec(&bytes)?))
+    }
+}
+
+impl<R: Read> Iterator for BlockFileReader<R> {
+    type Item = Result<Block, BlockFileError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_block().transpose()
+    }
+}
+
+/// The outcome of a block import.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportStats {
+    /// Number of blocks that were pushed successfully.
+    pub imported: u32,
+    /// Number of blocks that were already known.
+    pub known: u32,
+}
+
+/// Writes the main chain blocks from `from` to `to` (inclusive) to the given block file. Returns
+/// the number of blocks written.
+///
+/// All blocks in the range must be stored with their bodies, i.e. the range must lie within the
+/// history kept by this node. The genesis block is never exported since every node has it.
+pub fn export_blocks<W: Write>(
+    blockchain: &Blockchain,
+    writer: &mut BlockFileWriter<W>,
+    from: u32,
+    to: u32,
+) -> Result<u32, BlockFileError> {
+    let from = from.max(Policy::genesis_block_number() + 1);
+    let to = to.min(blockchain.block_number());
+    let start = Instant::now();
+    let mut num_blocks = 0;
+
+    for block_number in from..=to {
+        let block = blockchain
+            .chain_store
+            .get_block_at(block_number, true, None)
+            .map_err(|_| BlockFileError::MissingBlock(block_number))?;
+
+        // Bodies are reconstructed from the history store. If the history was pruned, the body
+        // doesn't match the body root of the block anymore.
+        block
+            .verify(blockchain.network_id())
+            .map_err(|error| BlockFileError::IncompleteBlock {
+                block_number,
+                error,
+            })?;
+
+        writer.write_block(&block)?;
+        num_blocks += 1;
+
+        if num_blocks % PROGRESS_INTERVAL == 0 {
+            info!(
+                block_number,
+                remaining = to - block_number,
+                elapsed = ?start.elapsed(),
+                "Exporting blocks",
+            );
+        }
+    }
+
+    Ok(num_blocks)
+}
+
+/// Pushes all blocks of the given block file to the blockchain. Every block is fully verified.
+/// Blocks that are already known are skipped, the import stops at the first invalid block.
+pub fn import_blocks<R: Read>(
+    blockchain: &Arc<RwLock<Blockchain>>,
+    reader: BlockFileReader<R>,
+) -> Result<ImportStats, BlockFileError> {
+    let network_id = blockchain.read().network_id();
+    if reader.network_id() != network_id {
+        return Err(BlockFileError::WrongNetwork {
+            expected: network_id,
+            actual: reader.network_id(),
+        });
+    }
+
+    let start = Instant::now();
+    let mut stats = ImportStats::default();
+
+    for block in reader {
+        let block = block?;
+        let block_number = block.block_number();
+
+        match Blockchain::push(blockchain.upgradable_read(), block) {
+            Ok(PushResult::Known) => stats.known += 1,
+            Ok(_) => stats.imported += 1,
+            Err(error) => {
+                return Err(BlockFileError::Push {
+                    block_number,
+                    error,
+                })
+            }
+        }
+
+        if (stats.imported + stats.known) % PROGRESS_INTERVAL == 0 {
+            let elapsed = start.elapsed();
+            info!(
+                block_number,
+                imported = stats.imported,
+                blocks_per_second = stats.imported as f64 / elapsed.as_secs_f64(),
+                "Importing blocks",
+            );
+        }
+    }
+
+    Ok(stats)
+}
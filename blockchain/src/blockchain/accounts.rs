@@ -1,18 +1,33 @@
+use std::{cmp, collections::HashMap};
+
 use nimiq_account::{
-    Account, Accounts, BlockLogger, BlockState, RevertInfo, TransactionOperationReceipt,
+    Account, Accounts, BlockLogger, BlockState, InherentOperationReceipt, RevertInfo, Staker,
+    StakingContractStore, TransactionOperationReceipt, Validator,
 };
 use nimiq_block::{Block, BlockError, SkipBlockInfo};
 use nimiq_blockchain_interface::PushError;
 use nimiq_database::{mdbx::MdbxReadTransaction, traits::Database};
 use nimiq_keys::Address;
 use nimiq_primitives::{
+    coin::Coin,
     key_nibbles::KeyNibbles,
-    trie::{error::IncompleteTrie, trie_diff::TrieDiff, trie_proof::TrieProof},
+    policy::Policy,
+    trie::{
+        error::IncompleteTrie, trie_chunk::TrieItem, trie_diff::TrieDiff, trie_proof::TrieProof,
+    },
 };
 use nimiq_serde::Deserialize;
+use nimiq_transaction::inherent::Inherent;
 use nimiq_trie::WriteTransactionProxy;
 
-use crate::{interface::HistoryInterface, Blockchain};
+use crate::{
+    chain_store::{BurnedSupply, StakeSnapshot},
+    interface::HistoryInterface,
+    Blockchain,
+};
+
+/// The number of items of the accounts tree that are read at once when iterating over a page.
+const ACCOUNTS_PAGE_CHUNK_SIZE: usize = 1000;
 
 /// Subset of the accounts in the accounts tree
 pub struct AccountsChunk {
@@ -23,6 +38,53 @@ pub struct AccountsChunk {
     pub accounts: Vec<(Address, Account)>,
 }
 
+/// An item of the accounts tree: either an account or an entry of the staking contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountsTreeItem {
+    Account(Address, Account),
+    Validator(Validator),
+    Staker(Staker),
+}
+
+impl AccountsTreeItem {
+    /// Decodes an item of the accounts tree. Returns `None` for the entries of the staking
+    /// contract that are neither validators nor stakers, e.g. tombstones.
+    fn from_trie_item(item: &TrieItem) -> Option<Self> {
+        if let Some(address) = item.key.to_address() {
+            let account = Account::deserialize_from_vec(&item.value).unwrap();
+            return Some(AccountsTreeItem::Account(address, account));
+        }
+
+        // The entries of the staking contract are stored below its address, with a one byte
+        // prefix for the type of the entry followed by the address of the validator or staker.
+        let contract_key = KeyNibbles::from(&Policy::STAKING_CONTRACT_ADDRESS);
+        if !contract_key.is_prefix_of(&item.key) {
+            return None;
+        }
+        let store_key = item.key.suffix(contract_key.len() as u8);
+        let address = store_key.suffix(2).to_address()?;
+        if store_key == StakingContractStore::validator_key(&address) {
+            Some(AccountsTreeItem::Validator(
+                Validator::deserialize_from_vec(&item.value).unwrap(),
+            ))
+        } else if store_key == StakingContractStore::staker_key(&address) {
+            Some(AccountsTreeItem::Staker(
+                Staker::deserialize_from_vec(&item.value).unwrap(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// A page of the items in the accounts tree.
+pub struct AccountsPage {
+    /// The key to continue from. When set to None it means that the end of the tree was reached.
+    pub end_key: Option<KeyNibbles>,
+    /// The items of the page.
+    pub items: Vec<AccountsTreeItem>,
+}
+
 /// Implements methods to handle the accounts.
 impl Blockchain {
     /// Updates the accounts given a block.
@@ -46,7 +108,29 @@ impl Blockchain {
 
                 // Commit block to AccountsTree.
                 if accounts.is_complete(Some(txn)) {
-                    accounts.commit(txn, &[], &inherents, &block_state, block_logger)?;
+                    // The punished slots are updated by the block, so the reward withheld from
+                    // them is computed beforehand.
+                    let penalized = match accounts.get(&Policy::STAKING_CONTRACT_ADDRESS, Some(txn))
+                    {
+                        Ok(Account::Staking(staking_contract)) => {
+                            self.penalized_reward(&macro_block.header, &staking_contract)
+                        }
+                        _ => Coin::ZERO,
+                    };
+
+                    let receipts =
+                        accounts.commit(txn, &[], &inherents, &block_state, block_logger)?;
+
+                    self.put_burned_supply(
+                        txn,
+                        macro_block.block_number(),
+                        &inherents,
+                        &receipts.inherents,
+                        penalized,
+                    );
+                    if macro_block.is_election() {
+                        self.put_stake_snapshots(txn, macro_block.block_number());
+                    }
                 } else if let Some(diff) = diff {
                     accounts.commit_incomplete(txn, diff)?;
                 } else {
@@ -133,6 +217,110 @@ impl Blockchain {
         }
     }
 
+    /// Records the coins burned since genesis up to the given macro block by adding the rewards
+    /// burned by its inherents to the amount recorded at the previous macro block. Nothing is
+    /// recorded if the amount at the previous macro block is unknown, e.g. because this node
+    /// synced the accounts tree from its peers.
+    fn put_burned_supply(
+        &self,
+        txn: &mut WriteTransactionProxy,
+        block_number: u32,
+        inherents: &[Inherent],
+        receipts: &[InherentOperationReceipt],
+        penalized: Coin,
+    ) {
+        let previous_block_number = Policy::macro_block_before(block_number);
+        let previous = if previous_block_number == Policy::genesis_block_number() {
+            Some(BurnedSupply::default())
+        } else {
+            self.chain_store
+                .get_burned_supply(previous_block_number, Some(txn))
+        };
+        let Some(mut burned_supply) = previous else {
+            return;
+        };
+
+        for (inherent, receipt) in inherents.iter().zip(receipts) {
+            if let Inherent::Reward { target, value, .. } = inherent {
+                // Staker rewards that couldn't be distributed are burned as well.
+                let failed_staker_reward = *target == Policy::STAKING_CONTRACT_ADDRESS
+                    && matches!(receipt, InherentOperationReceipt::Err(..));
+                if *target == Address::burn_address() || failed_staker_reward {
+                    burned_supply.burned += *value;
+                }
+            }
+        }
+        burned_supply.penalized += penalized;
+
+        self.chain_store
+            .put_burned_supply(txn.raw(), block_number, &burned_supply);
+    }
+
+    /// Returns the coins burned since genesis up to and including the given macro block. Returns
+    /// `None` if they are unknown because this node didn't apply all macro blocks since genesis.
+    pub fn get_burned_supply(&self, block_number: u32) -> Option<BurnedSupply> {
+        if block_number == Policy::genesis_block_number() {
+            return Some(BurnedSupply::default());
+        }
+        self.chain_store.get_burned_supply(block_number, None)
+    }
+
+    /// Records the stake of all validators and their stakers at the given election block, since
+    /// the staking contract of past blocks isn't available otherwise.
+    fn put_stake_snapshots(&self, txn: &mut WriteTransactionProxy, block_number: u32) {
+        let Ok(Account::Staking(staking_contract)) = self
+            .state
+            .accounts
+            .get(&Policy::STAKING_CONTRACT_ADDRESS, Some(txn))
+        else {
+            return;
+        };
+        let data_store = self.get_staking_contract_store();
+        let data_store = data_store.read(txn);
+
+        let mut stakers: HashMap<Address, Vec<Staker>> = HashMap::new();
+        for staker in staking_contract.get_stakers(&data_store) {
+            if let Some(ref validator_address) = staker.delegation {
+                stakers
+                    .entry(validator_address.clone())
+                    .or_default()
+                    .push(staker);
+            }
+        }
+        let snapshots: Vec<_> = staking_contract
+            .get_validators(&data_store)
+            .into_iter()
+            .map(|validator| StakeSnapshot {
+                stakers: stakers.remove(&validator.address).unwrap_or_default(),
+                validator,
+            })
+            .collect();
+
+        self.chain_store
+            .put_stake_snapshots(txn.raw(), block_number, &snapshots);
+    }
+
+    /// Returns the stake of a validator and its stakers at the given election block. Returns
+    /// `None` if the validator didn't exist at the election block or if no stake snapshots are
+    /// stored for the election block, see [`Blockchain::has_stake_snapshots`].
+    pub fn get_stake_snapshot(
+        &self,
+        election_block_number: u32,
+        validator_address: &Address,
+    ) -> Option<StakeSnapshot> {
+        self.chain_store
+            .get_stake_snapshot(election_block_number, validator_address, None)
+    }
+
+    /// Returns whether the stake snapshots of the given election block are stored. The snapshots
+    /// are recorded for election blocks for which this node had the complete accounts tree. Nodes
+    /// that keep the full history retain them indefinitely, other nodes only keep the snapshots of
+    /// the last two election blocks.
+    pub fn has_stake_snapshots(&self, election_block_number: u32) -> bool {
+        self.chain_store
+            .has_stake_snapshots(election_block_number, None)
+    }
+
     /// Reverts the accounts given a block. This only applies to micro blocks and skip blocks, since
     /// macro blocks are final and can't be reverted.
     pub(crate) fn revert_accounts(
@@ -233,4 +421,69 @@ impl Blockchain {
             .collect();
         AccountsChunk { end_key, accounts }
     }
+
+    /// Gets a page of up to `limit` items of the accounts tree that satisfy the given filter,
+    /// starting at the given key. Besides the accounts, the page includes the validators and
+    /// stakers of the staking contract. At most `scan_limit` items of the accounts tree are
+    /// scanned, so the page can contain fewer items even if there are more items satisfying the
+    /// filter.
+    pub fn get_accounts_page<F: Fn(&AccountsTreeItem) -> bool>(
+        &self,
+        txn: &MdbxReadTransaction,
+        start: KeyNibbles,
+        limit: usize,
+        scan_limit: usize,
+        filter: F,
+    ) -> AccountsPage {
+        let mut items = vec![];
+        let mut next_key = Some(start);
+        let mut scanned = 0;
+
+        // The commission of a validator is stored in a separate entry of the staking contract,
+        // which can only be read if the tree is complete.
+        let staking_contract = self
+            .state
+            .accounts
+            .is_complete(Some(txn))
+            .then(|| self.get_staking_contract_if_complete(Some(txn)))
+            .flatten();
+        let data_store = self.get_staking_contract_store();
+
+        while let Some(start) = next_key.take() {
+            if items.len() >= limit || scanned >= scan_limit {
+                next_key = Some(start);
+                break;
+            }
+
+            let chunk_size = cmp::min(scan_limit - scanned, ACCOUNTS_PAGE_CHUNK_SIZE);
+            let chunk = self.state.accounts.get_chunk(start, chunk_size, Some(txn));
+            scanned += chunk_size;
+            next_key = chunk.end_key;
+
+            for trie_item in chunk.items {
+                if items.len() >= limit {
+                    // Continue with the first item that didn't fit into the page.
+                    next_key = Some(trie_item.key);
+                    break;
+                }
+                if let Some(mut item) = AccountsTreeItem::from_trie_item(&trie_item) {
+                    if let (AccountsTreeItem::Validator(validator), Some(staking_contract)) =
+                        (&mut item, &staking_contract)
+                    {
+                        validator.commission = staking_contract
+                            .get_validator(&data_store.read(txn), &validator.address)
+                            .map_or(validator.commission, |stored| stored.commission);
+                    }
+                    if filter(&item) {
+                        items.push(item);
+                    }
+                }
+            }
+        }
+
+        AccountsPage {
+            end_key: next_key,
+            items,
+        }
+    }
 }
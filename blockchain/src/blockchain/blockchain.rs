@@ -13,14 +13,20 @@ use nimiq_primitives::{
     coin::Coin, networks::NetworkId, policy::Policy, slots_allocation::Validators, trie::TrieItem,
 };
 use nimiq_utils::time::OffsetTime;
+use parking_lot::Mutex;
 use tokio::sync::broadcast::{channel as broadcast, Sender as BroadcastSender};
 
 #[cfg(feature = "metrics")]
 use crate::chain_metrics::BlockchainMetrics;
 use crate::{
-    blockchain_state::BlockchainState, chain_store::ChainStore, history::HistoryStore,
-    history_store_proxy::HistoryStoreProxy, interface::HistoryInterface,
-    reward::genesis_parameters, HistoryStoreIndex,
+    blockchain_state::BlockchainState,
+    chain_store::ChainStore,
+    history::HistoryStore,
+    history_store_proxy::HistoryStoreProxy,
+    interface::HistoryInterface,
+    reward::genesis_parameters,
+    signaling::{SignalTally, SignalThresholdCrossed},
+    HistoryStoreIndex,
 };
 
 const BROADCAST_MAX_CAPACITY: usize = 256;
@@ -42,6 +48,10 @@ pub struct Blockchain {
     pub fork_notifier: BroadcastSender<ForkEvent>,
     /// The log notifier processes all events regarding accounts changes.
     pub log_notifier: BroadcastSender<BlockLog>,
+    /// The signal notifier processes validator signals crossing one of the configured thresholds.
+    pub signal_notifier: BroadcastSender<SignalThresholdCrossed>,
+    /// The validator signal tally taken at the last election block.
+    pub(crate) signal_tally: Mutex<Option<SignalTally>>,
     /// The chain store is a database containing all of the chain infos, blocks and receipts.
     pub chain_store: ChainStore,
     /// The history store is a database containing all of the history trees and transactions.
@@ -72,6 +82,8 @@ pub struct BlockchainConfig {
     pub max_epochs_stored: u32,
     /// Enables/Disables indices in the history store.
     pub index_history: bool,
+    /// Thresholds (in slots) for which an event is emitted when a validator signal reaches them.
+    pub signal_thresholds: Vec<u16>,
 }
 
 impl Default for BlockchainConfig {
@@ -80,6 +92,7 @@ impl Default for BlockchainConfig {
             keep_history: true,
             max_epochs_stored: Policy::MIN_EPOCHS_STORED,
             index_history: true,
+            signal_thresholds: vec![Policy::TWO_F_PLUS_ONE],
         }
     }
 }
@@ -137,10 +150,9 @@ impl Blockchain {
         }
 
         let history_store = if config.index_history {
-            Arc::new(HistoryStoreProxy::WithIndex(HistoryStoreIndex::new(
-                env.clone(),
-                network_id,
-            )))
+            let history_store_index = HistoryStoreIndex::new(env.clone(), network_id);
+            history_store_index.rebuild_index_if_necessary();
+            Arc::new(HistoryStoreProxy::WithIndex(history_store_index))
         } else {
             Arc::new(HistoryStoreProxy::WithoutIndex(Box::new(HistoryStore::new(
                 env.clone(),
@@ -150,6 +162,7 @@ impl Blockchain {
         };
 
         let chain_store = ChainStore::new(env.clone(), Arc::clone(&history_store));
+        chain_store.rebuild_timestamp_index_if_necessary();
 
         Ok(match chain_store.get_head(None) {
             Some(head_hash) => Blockchain::load(
@@ -280,8 +293,9 @@ impl Blockchain {
         let (tx, _rx) = broadcast(BROADCAST_MAX_CAPACITY);
         let (tx_fork, _rx_fork) = broadcast(BROADCAST_MAX_CAPACITY);
         let (tx_log, _rx_log) = broadcast(BROADCAST_MAX_CAPACITY);
+        let (tx_signal, _rx_signal) = broadcast(BROADCAST_MAX_CAPACITY);
 
-        Ok(Blockchain {
+        let blockchain = Blockchain {
             db: env,
             config,
             network_id,
@@ -289,6 +303,8 @@ impl Blockchain {
             notifier: tx,
             fork_notifier: tx_fork,
             log_notifier: tx_log,
+            signal_notifier: tx_signal,
+            signal_tally: Mutex::new(None),
             chain_store,
             history_store,
             state: BlockchainState {
@@ -309,7 +325,12 @@ impl Blockchain {
             genesis_timestamp,
             genesis_block_number,
             genesis_hash,
-        })
+        };
+
+        // Signal thresholds are crossed relative to the tally at startup.
+        *blockchain.signal_tally.lock() = blockchain.tally_signals();
+
+        Ok(blockchain)
     }
 
     /// Initializes a blockchain.
@@ -347,8 +368,9 @@ impl Blockchain {
         let (tx, _rx) = broadcast(BROADCAST_MAX_CAPACITY);
         let (tx_fork, _rx_fork) = broadcast(BROADCAST_MAX_CAPACITY);
         let (tx_log, _rx_log) = broadcast(BROADCAST_MAX_CAPACITY);
+        let (tx_signal, _rx_signal) = broadcast(BROADCAST_MAX_CAPACITY);
 
-        Ok(Blockchain {
+        let blockchain = Blockchain {
             db: env,
             config,
             network_id,
@@ -356,6 +378,8 @@ impl Blockchain {
             notifier: tx,
             fork_notifier: tx_fork,
             log_notifier: tx_log,
+            signal_notifier: tx_signal,
+            signal_tally: Mutex::new(None),
             chain_store,
             history_store,
             state: BlockchainState {
@@ -376,7 +400,12 @@ impl Blockchain {
             genesis_timestamp,
             genesis_block_number,
             genesis_hash,
-        })
+        };
+
+        // Signal thresholds are crossed relative to the tally at startup.
+        *blockchain.signal_tally.lock() = blockchain.tally_signals();
+
+        Ok(blockchain)
     }
 
     pub fn get_genesis_parameters(&self) -> (Coin, u64) {
@@ -0,0 +1,172 @@
+use std::cmp;
+
+use nimiq_block::{Block, BlockError, MacroBlock};
+use nimiq_blockchain_interface::{ChainInfo, PushError};
+use nimiq_database::{mdbx::MdbxReadTransaction, traits::WriteTransaction};
+use nimiq_hash::Blake2bHash;
+use nimiq_primitives::policy::Policy;
+use nimiq_transaction::historic_transaction::HistoricTransaction;
+use parking_lot::RwLockUpgradableReadGuard;
+
+use crate::{interface::HistoryInterface, Blockchain};
+
+/// Implements methods to backfill the history of past epochs, e.g. when a full node is turned into
+/// a history node, and to prune it again when a history node is turned into a full node.
+impl Blockchain {
+    /// Returns the hash of the most recent election block whose epoch history is missing from the
+    /// history store, or `None` if the history is complete back to the genesis block.
+    ///
+    /// The election block itself might not be stored yet, in which case it needs to be requested
+    /// by its hash together with its history.
+    pub fn history_backfill_target(&self) -> Option<Blake2bHash> {
+        let txn = self.read_transaction();
+        self.find_history_backfill_target(&txn)
+    }
+
+    /// Walks the election blocks backwards from the election head until it finds an epoch whose
+    /// history doesn't match the history root of its election block. Returns the hash of that
+    /// election block.
+    fn find_history_backfill_target(&self, txn: &MdbxReadTransaction) -> Option<Blake2bHash> {
+        let mut hash = self.state.election_head_hash.clone();
+        let mut block = self.state.election_head.clone();
+
+        loop {
+            // The genesis block doesn't have any history.
+            if block.block_number() == Policy::genesis_block_number() {
+                return None;
+            }
+
+            let history_root = self
+                .history_store
+                .get_history_tree_root(block.block_number(), Some(txn));
+            if history_root.as_ref() != Some(&block.header.history_root) {
+                return Some(hash);
+            }
+
+            let parent_hash = block.header.parent_election_hash.clone();
+            match self.chain_store.get_block(&parent_hash, false, Some(txn)) {
+                Ok(Block::Macro(parent)) => {
+                    hash = parent_hash;
+                    block = parent;
+                }
+                _ => return Some(parent_hash),
+            }
+        }
+    }
+
+    /// Adds the complete history of the epoch ending with the given election block. The block
+    /// must be the current history backfill target (see `history_backfill_target`), which
+    /// authenticates it through the chain of election blocks. The history is verified against
+    /// the history root of the block.
+    ///
+    /// The election block is stored if it isn't known yet.
+    /// The upgradable read lock keeps blocks from being pushed concurrently, which might change
+    /// the backfill target or the history store.
+    pub fn push_history_backfill(
+        this: RwLockUpgradableReadGuard<Self>,
+        block: &MacroBlock,
+        history: &[HistoricTransaction],
+    ) -> Result<(), PushError> {
+        let mut txn = this.write_transaction();
+
+        let block_hash = block.hash();
+        let target_hash = this
+            .find_history_backfill_target(&txn)
+            .ok_or(PushError::InvalidPredecessor)?;
+        if !block.is_election() || block_hash != target_hash {
+            warn!(
+                block = %block,
+                expected = %target_hash,
+                reason = "not the history backfill target",
+                "Rejecting history backfill",
+            );
+            return Err(PushError::InvalidPredecessor);
+        }
+
+        let history_root =
+            this.history_store
+                .add_past_epoch_history(&mut txn, block.epoch_number(), history);
+        if history_root.as_ref() != Some(&block.header.history_root) {
+            warn!(
+                block = %block,
+                reason = "wrong history root",
+                history_root = %block.header.history_root,
+                actual_history_root = ?history_root,
+                "Rejecting history backfill",
+            );
+            txn.abort();
+            return Err(PushError::InvalidBlock(BlockError::InvalidHistoryRoot));
+        }
+
+        // Store the election block if we don't know it yet, e.g. because the chain was synced
+        // from a zero-knowledge proof. The blocks following it aren't stored, so it doesn't have a
+        // main chain successor, the same way the most recent block doesn't have one.
+        if this
+            .chain_store
+            .get_chain_info(&block_hash, false, Some(&txn))
+            .is_err()
+        {
+            let (cum_tx_fees, cum_hist_tx_size) =
+                Self::cumulative_batch_values(block.block_number(), history);
+            let chain_info = ChainInfo {
+                on_main_chain: true,
+                main_chain_successor: None,
+                head: Block::Macro(block.clone()),
+                cum_tx_fees,
+                cum_hist_tx_size,
+                history_tree_len: history.len() as u64,
+                prunable: false,
+                prev_missing_range: None,
+            };
+            this.chain_store.put_chain_info(
+                &mut txn,
+                &block_hash,
+                &chain_info,
+                block.body.is_some(),
+            );
+        }
+
+        txn.commit();
+
+        debug!(
+            block = %block,
+            history_len = history.len(),
+            "Backfilled epoch history",
+        );
+
+        Ok(())
+    }
+
+    /// Removes the history of past epochs that a full node doesn't keep. The history of the last
+    /// `max_epochs_stored` epochs is kept since the chain store still stores their blocks, which
+    /// are completed from the history. Returns the number of epochs whose history was removed.
+    pub fn prune_history(this: RwLockUpgradableReadGuard<Self>) -> u32 {
+        let max_epochs_stored = cmp::max(this.config.max_epochs_stored, Policy::MIN_EPOCHS_STORED);
+        let last_pruned_epoch = this
+            .state
+            .election_head
+            .epoch_number()
+            .saturating_sub(max_epochs_stored);
+        let mut num_pruned = 0;
+
+        for epoch_number in 1..=last_pruned_epoch {
+            let mut txn = this.write_transaction();
+            if this
+                .history_store
+                .num_epoch_transactions(epoch_number, Some(&txn))
+                == 0
+            {
+                txn.abort();
+                continue;
+            }
+
+            this.history_store.remove_history(&mut txn, epoch_number);
+            txn.commit();
+            num_pruned += 1;
+
+            debug!(epoch_number, "Pruned epoch history");
+        }
+
+        num_pruned
+    }
+}
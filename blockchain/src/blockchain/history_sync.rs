@@ -1,6 +1,6 @@
 use std::{collections::BTreeMap, error::Error};
 
-use nimiq_account::{BlockLogger, BlockState};
+use nimiq_account::{BlockLog, BlockLogger, BlockState};
 use nimiq_block::{Block, BlockError};
 use nimiq_blockchain_interface::{
     AbstractBlockchain, BlockchainEvent, ChainInfo, PushError, PushResult,
@@ -38,10 +38,15 @@ impl Blockchain {
     /// You can push election blocks after checkpoint blocks and vice-versa. You can also push macro
     /// blocks even after you pushed micro blocks.
     /// You just cannot push micro blocks with this method.
+    ///
+    /// If the justification of the block was already verified, e.g. as part of a batch
+    /// verification using `MacroBlock::verify_validators_batch`, the `trusted_justification` flag
+    /// can be set to skip its verification.
     pub fn push_history_sync(
         this: RwLockUpgradableReadGuard<Self>,
         block: Block,
         history: &[HistoricTransaction],
+        trusted_justification: bool,
     ) -> Result<PushResult, PushError> {
         // Check that it is a macro block. We can't push micro blocks with this function.
         assert!(
@@ -84,7 +89,9 @@ impl Blockchain {
         block.verify_macro_successor(this.state.macro_info.head.unwrap_macro_ref())?;
 
         // Verify that the block is valid for the current validators.
-        block.verify_validators(this.current_validators().unwrap())?;
+        if !trusted_justification {
+            block.verify_validators(this.current_validators().unwrap())?;
+        }
 
         drop(read_txn);
 
@@ -143,18 +150,8 @@ impl Blockchain {
 
         // Calculate the cumulative transaction fees and size for the given batch. This is necessary to
         // create the chain info for the block.
-        let mut cum_tx_fees = Coin::ZERO;
-        let mut cum_hist_tx_size = 0u64;
-        let current_batch = Policy::batch_at(block.block_number());
-        for i in (0..history.len()).rev() {
-            if Policy::batch_at(history[i].block_number) != current_batch {
-                break;
-            }
-            if let HistoricTransactionData::Basic(tx) = &history[i].data {
-                cum_tx_fees += tx.get_raw_transaction().fee;
-            }
-            cum_hist_tx_size += history[i].data.serialized_size() as u64;
-        }
+        let (cum_tx_fees, cum_hist_tx_size) =
+            Self::cumulative_batch_values(block.block_number(), history);
 
         // Create the chain info for the given block and store it.
         let mut chain_info = ChainInfo {
@@ -187,7 +184,8 @@ impl Blockchain {
         // We might already know the given epoch partially.
         // Revert our chain to a common ancestor state in case we have adopted a different history.
         // Also skip over any transactions that we already know.
-        let first_new_hist_tx = this.revert_to_common_state(history, &mut txn);
+        let (first_new_hist_tx, reverted_blocks, reverted_block_logs) =
+            this.revert_to_common_state(history, &mut txn);
 
         // Separate the historic transactions by block number and type.
         // We know it comes sorted because we already checked it against the history root and
@@ -359,6 +357,14 @@ impl Blockchain {
             &history[first_new_hist_tx..],
         );
 
+        // Index the timestamps of the blocks we only know through their history, replacing the
+        // ones of the reverted blocks.
+        for (_, reverted_block) in &reverted_blocks {
+            this.chain_store.unindex_timestamp(&mut txn, reverted_block);
+        }
+        this.chain_store
+            .index_history_timestamps(&mut txn, &history[first_new_hist_tx..]);
+
         // Use the just built history tree to set the `ChainInfo`'s total history length
         chain_info.history_tree_len =
             this.history_store
@@ -447,10 +453,14 @@ impl Blockchain {
 
         // If there are no listeners we do not log errors
         this.notifier
-            .send(BlockchainEvent::HistoryAdopted(block_hash.clone()))
+            .send(BlockchainEvent::HistoryAdopted(
+                block_hash.clone(),
+                reverted_blocks,
+            ))
             .ok();
 
         if is_election_block {
+            this.update_signal_tally();
             this.notifier
                 .send(BlockchainEvent::EpochFinalized(block_hash))
                 .ok();
@@ -460,15 +470,46 @@ impl Blockchain {
                 .ok();
         }
 
+        // The log notifier is for informational purposes only, thus may have no listeners.
+        for block_log in reverted_block_logs {
+            this.log_notifier.send(block_log).ok();
+        }
+
         // Return result.
         Ok(PushResult::Extended)
     }
 
+    /// Calculates the cumulative transaction fees and historic transaction size of the batch of
+    /// the given macro block from the history of its epoch.
+    pub(crate) fn cumulative_batch_values(
+        block_number: u32,
+        history: &[HistoricTransaction],
+    ) -> (Coin, u64) {
+        let mut cum_tx_fees = Coin::ZERO;
+        let mut cum_hist_tx_size = 0u64;
+        let current_batch = Policy::batch_at(block_number);
+        for hist_tx in history.iter().rev() {
+            if Policy::batch_at(hist_tx.block_number) != current_batch {
+                break;
+            }
+            if let HistoricTransactionData::Basic(tx) = &hist_tx.data {
+                cum_tx_fees += tx.get_raw_transaction().fee;
+            }
+            cum_hist_tx_size += hist_tx.data.serialized_size() as u64;
+        }
+        (cum_tx_fees, cum_hist_tx_size)
+    }
+
+    /// Returns the index of the first historic transaction that is not known yet, as well as the
+    /// blocks that were reverted in ascending order and their logs in the order of reverting.
     fn revert_to_common_state(
         &self,
         history: &[HistoricTransaction],
         txn: &mut MdbxWriteTransaction,
-    ) -> usize {
+    ) -> (usize, Vec<(Blake2bHash, Block)>, Vec<BlockLog>) {
+        let mut reverted_blocks = vec![];
+        let mut block_logs = vec![];
+
         // Find the index of the first historic transaction in the current batch.
         let last_macro_block = Policy::last_macro_block(self.block_number());
         let mut first_new_hist_tx = history
@@ -511,7 +552,8 @@ impl Blockchain {
             if let Some(diverging_block) = diverging_block {
                 // The histories diverge, so revert our state to the block before the divergence.
                 let num_blocks_to_revert = self.block_number() - diverging_block + 1;
-                self.revert_blocks(num_blocks_to_revert, txn)
+                (reverted_blocks, block_logs) = self
+                    .revert_blocks_with_logs(num_blocks_to_revert, txn)
                     .expect("Failed to revert chain");
 
                 // TODO We could incorporate this into the parallel iteration loop above.
@@ -531,12 +573,13 @@ impl Blockchain {
             let first_block_number = history[first_new_hist_tx].block_number;
             if first_block_number <= self.block_number() {
                 let num_blocks_to_revert = self.block_number() - first_block_number + 1;
-                self.revert_blocks(num_blocks_to_revert, txn)
+                (reverted_blocks, block_logs) = self
+                    .revert_blocks_with_logs(num_blocks_to_revert, txn)
                     .expect("Failed to revert chain");
             }
         };
 
-        first_new_hist_tx
+        (first_new_hist_tx, reverted_blocks, block_logs)
     }
 
     /// Reverts a given number of micro or skip blocks from the blockchain.
@@ -545,6 +588,17 @@ impl Blockchain {
         num_blocks: u32,
         write_txn: &mut MdbxWriteTransaction,
     ) -> Result<(), PushError> {
+        self.revert_blocks_with_logs(num_blocks, write_txn)
+            .map(|_| ())
+    }
+
+    /// Reverts a given number of micro or skip blocks from the blockchain. Returns the reverted
+    /// blocks in ascending order and their logs in the order of reverting.
+    fn revert_blocks_with_logs(
+        &self,
+        num_blocks: u32,
+        write_txn: &mut MdbxWriteTransaction,
+    ) -> Result<(Vec<(Blake2bHash, Block)>, Vec<BlockLog>), PushError> {
         debug!(
             num_blocks,
             "Need to revert micro blocks from the current epoch",
@@ -556,6 +610,8 @@ impl Blockchain {
             .expect("Couldn't fetch chain info for the head of the chain");
 
         // Revert each block individually.
+        let mut reverted_blocks = Vec::with_capacity(num_blocks as usize);
+        let mut block_logs = Vec::with_capacity(num_blocks as usize);
         for _ in 0..num_blocks {
             // Get the chain info for the parent of the current head of the chain.
             let prev_info = self
@@ -563,12 +619,17 @@ impl Blockchain {
                 .expect("Failed to find main chain predecessor while reverting blocks");
 
             // Revert the accounts tree. This also reverts the history store.
-            self.revert_accounts(
+            let mut block_logger = BlockLogger::new_reverted(
+                current_info.head.hash(),
+                current_info.head.block_number(),
+            );
+            let total_tx_size = self.revert_accounts(
                 &self.state.accounts,
                 &mut write_txn.into(),
                 &current_info.head,
-                &mut BlockLogger::empty(),
+                &mut block_logger,
             )?;
+            block_logs.push(block_logger.build(total_tx_size));
 
             // Check that the block reverted cleanly.
             // Since we are doing history sync, the accounts tree should always be complete.
@@ -582,9 +643,11 @@ impl Blockchain {
             );
 
             // Move on to the next block.
+            reverted_blocks.push((current_info.head.hash(), current_info.head));
             current_info = prev_info;
         }
+        reverted_blocks.reverse();
 
-        Ok(())
+        Ok((reverted_blocks, block_logs))
     }
 }
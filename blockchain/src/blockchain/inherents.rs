@@ -12,7 +12,10 @@ use nimiq_primitives::{
 use nimiq_transaction::{inherent::Inherent, reward::RewardTransaction};
 use nimiq_vrf::{DiscreteDistribution, VrfUseCase};
 
-use crate::{reward::block_reward_for_batch, Blockchain};
+use crate::{
+    reward::{block_reward_for_batch, split_validator_reward},
+    Blockchain,
+};
 
 /// Implements methods that create inherents.
 impl Blockchain {
@@ -161,8 +164,6 @@ impl Blockchain {
         macro_header: &MacroHeader,
         staking_contract: &StakingContract,
     ) -> Vec<RewardTransaction> {
-        let prev_macro_info = &self.state.macro_info;
-
         // Special case for first batch: Batch 0 is finalized by definition.
         if Policy::batch_at(macro_header.block_number) - 1 == 0 {
             return vec![];
@@ -188,21 +189,8 @@ impl Blockchain {
             .punished_slots
             .previous_batch_punished_slots();
 
-        // Total reward for the previous batch
-        let block_reward = block_reward_for_batch(
-            macro_header,
-            &prev_macro_info.head.unwrap_macro_ref().header,
-            self.genesis_supply,
-            self.genesis_timestamp,
-        );
-
-        let tx_fees = prev_macro_info.cum_tx_fees;
-
-        let reward_pot = block_reward + tx_fees;
-
         // Distribute reward between all slots and calculate the remainder
-        let slot_reward = reward_pot / Policy::SLOTS as u64;
-        let remainder = reward_pot % Policy::SLOTS as u64;
+        let (slot_reward, remainder) = self.slot_reward(macro_header);
 
         // The first slot number of the current validator
         let mut first_slot_number = 0;
@@ -216,6 +204,9 @@ impl Blockchain {
         // Remember the number of eligible slots that a validator had (that was able to accept the inherent)
         let mut num_eligible_slots_for_accepted_tx = Vec::new();
 
+        // Remember the index of the first accepted inherent of each of those validators.
+        let mut first_accepted_tx = Vec::new();
+
         // Remember that the total amount of reward must be burned. The reward for a slot is burned
         // either because the slot was penalized or because the corresponding validator was unable to
         // accept the inherent.
@@ -263,10 +254,26 @@ impl Blockchain {
                 .get_validator(&data_store.read(&txn), &validator_slot.address)
                 .expect("Couldn't find validator in the accounts trie when paying rewards!");
 
+            // Split the reward into the commission the validator keeps and the share of the
+            // stakers delegating to it. Before the commission fork, validators receive the whole
+            // reward.
+            let (commission, staker_reward) =
+                if macro_header.block_number >= Policy::commission_fork_block_number() {
+                    split_validator_reward(
+                        reward,
+                        validator.commission,
+                        validator.total_stake - validator.deposit,
+                    )
+                } else {
+                    (reward, Coin::ZERO)
+                };
+
+            let mut validator_transactions = Vec::new();
+
             let tx = RewardTransaction {
                 validator_address: validator.address.clone(),
                 recipient: validator.reward_address.clone(),
-                value: reward,
+                value: commission,
             };
 
             // Test whether account will accept inherent. If it can't then the reward will be
@@ -274,25 +281,40 @@ impl Blockchain {
             // TODO Improve this check: it assumes that only BasicAccounts can receive transactions.
             let account = self.state.accounts.get_complete(&tx.recipient, Some(&txn));
             if account.account_type() == AccountType::Basic {
-                num_eligible_slots_for_accepted_tx.push(num_eligible_slots);
-                transactions.push(tx);
+                validator_transactions.push(tx);
             } else {
                 debug!(
                     target_address = %tx.recipient,
                     reward = %tx.value,
                     "Can't accept batch reward"
                 );
-                burned_reward += reward;
+                burned_reward += commission;
+            }
+
+            // The staking contract distributes the stakers' share to their active balances.
+            if !staker_reward.is_zero() {
+                validator_transactions.push(RewardTransaction {
+                    validator_address: validator.address.clone(),
+                    recipient: Policy::STAKING_CONTRACT_ADDRESS,
+                    value: staker_reward,
+                });
+            }
+
+            if !validator_transactions.is_empty() {
+                num_eligible_slots_for_accepted_tx.push(num_eligible_slots);
+                first_accepted_tx.push(transactions.len());
+                transactions.append(&mut validator_transactions);
             }
 
             // Update first_slot_number for next iteration
             first_slot_number = last_slot_number;
         }
 
-        // Check that number of accepted inherents is equal to length of the map that gives us the
-        // corresponding number of slots for that staker (which should be equal to the number of
-        // validators that will receive rewards).
-        assert_eq!(transactions.len(), num_eligible_slots_for_accepted_tx.len());
+        // Check that we know the number of slots for each validator that will receive rewards.
+        assert_eq!(
+            first_accepted_tx.len(),
+            num_eligible_slots_for_accepted_tx.len()
+        );
 
         // Get RNG from last block's seed and build lookup table based on number of eligible slots.
         let mut rng = macro_header.seed.rng(VrfUseCase::RewardDistribution);
@@ -301,7 +323,7 @@ impl Blockchain {
         // Randomly give remainder to one accepting slot. We don't bother to distribute it over all
         // accepting slots because the remainder is always at most SLOTS - 1 Lunas.
         let index = lookup.sample(&mut rng);
-        transactions[index].value += remainder;
+        transactions[first_accepted_tx[index]].value += remainder;
 
         // Do not create reward transactions for zero rewards
         transactions.retain(|transaction| !transaction.value.is_zero());
@@ -320,6 +342,51 @@ impl Blockchain {
         transactions
     }
 
+    /// Returns the reward of the previous batch that is withheld from its penalized slots and
+    /// burned by the given macro block instead.
+    pub fn penalized_reward(
+        &self,
+        macro_header: &MacroHeader,
+        staking_contract: &StakingContract,
+    ) -> Coin {
+        // Batch 0 is finalized by definition and not rewarded.
+        if Policy::batch_at(macro_header.block_number) - 1 == 0 {
+            return Coin::ZERO;
+        }
+
+        let (slot_reward, _) = self.slot_reward(macro_header);
+        let num_penalized_slots = staking_contract
+            .punished_slots
+            .previous_batch_punished_slots()
+            .len();
+        slot_reward
+            .checked_mul(num_penalized_slots as u64)
+            .expect("Overflow in reward")
+    }
+
+    /// Returns the reward of a single slot for the batch finalized by the given macro block and
+    /// the remainder of the total reward that can't be split evenly between the slots.
+    fn slot_reward(&self, macro_header: &MacroHeader) -> (Coin, Coin) {
+        let prev_macro_info = &self.state.macro_info;
+
+        // Total reward for the previous batch
+        let block_reward = block_reward_for_batch(
+            macro_header,
+            &prev_macro_info.head.unwrap_macro_ref().header,
+            self.genesis_supply,
+            self.genesis_timestamp,
+        );
+
+        let tx_fees = prev_macro_info.cum_tx_fees;
+
+        let reward_pot = block_reward + tx_fees;
+
+        (
+            reward_pot / Policy::SLOTS as u64,
+            reward_pot % Policy::SLOTS as u64,
+        )
+    }
+
     /// Creates the inherent to finalize an epoch. The inherent is for updating the StakingContract.
     pub fn finalize_previous_epoch(&self) -> Inherent {
         // Create the FinalizeEpoch inherent.
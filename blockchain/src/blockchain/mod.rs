@@ -2,6 +2,7 @@ mod abstract_blockchain;
 pub mod accounts;
 #[allow(clippy::module_inception)]
 pub mod blockchain;
+pub mod history_backfill;
 pub mod history_sync;
 pub mod inherents;
 pub mod push;
@@ -325,6 +325,11 @@ impl Blockchain {
                 // Prune the History Store.
                 this.history_store
                     .remove_history(&mut txn, Policy::epoch_at(block_number).saturating_sub(1));
+                // Only keep the stake snapshots of this and the previous election block.
+                this.chain_store.remove_stake_snapshots_before(
+                    &mut txn,
+                    Policy::election_block_before(block_number),
+                );
             }
         }
 
@@ -372,6 +377,7 @@ impl Blockchain {
             .send(BlockchainEvent::Extended(block_hash.clone()))
             .ok();
         if is_election_block {
+            this.update_signal_tally();
             this.notifier
                 .send(BlockchainEvent::EpochFinalized(block_hash))
                 .ok();
@@ -509,6 +515,7 @@ impl Blockchain {
             .send(BlockchainEvent::Rebranched(reverted_blocks, adopted_blocks))
             .ok();
         if this.state.main_chain.head.is_election() {
+            this.update_signal_tally();
             this.notifier
                 .send(BlockchainEvent::EpochFinalized(
                     this.state.head_hash.clone(),
@@ -34,6 +34,18 @@ impl Blockchain {
             .get_block_at(height, include_body, txn_option)
     }
 
+    /// Returns the number of the first main chain block with a timestamp at or after the given
+    /// timestamp. Returns `None` if the timestamp is after the head block or if the block can't
+    /// be determined from the blocks and history this node has stored.
+    pub fn get_block_number_at_timestamp(
+        &self,
+        timestamp: u64,
+        txn_option: Option<&DBTransaction>,
+    ) -> Option<u32> {
+        self.chain_store
+            .get_block_number_at_timestamp(timestamp, txn_option)
+    }
+
     pub fn get_block(
         &self,
         hash: &Blake2bHash,
@@ -203,6 +215,18 @@ impl Blockchain {
         self.history_store.tx_in_validity_window(tx_hash, txn_opt)
     }
 
+    /// Returns the number of the block that includes the transaction with this hash, if it was
+    /// included recently enough to still be kept for the validity window checks. Unlike the
+    /// history index, this is available on full nodes too.
+    pub fn get_tx_block_number_in_validity_window(
+        &self,
+        tx_hash: &RawTransactionHash,
+        txn_opt: Option<&DBTransaction>,
+    ) -> Option<u32> {
+        self.history_store
+            .get_tx_block_number_in_validity_window(tx_hash, txn_opt)
+    }
+
     pub fn staking_contract_address(&self) -> Address {
         Policy::STAKING_CONTRACT_ADDRESS
     }
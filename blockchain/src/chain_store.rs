@@ -1,18 +1,23 @@
-use std::sync::Arc;
+use std::{borrow::Cow, collections::BTreeMap, sync::Arc};
 
-use nimiq_account::RevertInfo;
+use nimiq_account::{RevertInfo, Staker, Validator};
 use nimiq_block::{Block, BlockType, EquivocationProof, MacroBody, MicroBody};
 use nimiq_blockchain_interface::{BlockchainError, ChainInfo, Direction};
 use nimiq_database::{
     declare_table,
     mdbx::{MdbxDatabase, MdbxReadTransaction, MdbxWriteTransaction, OptionalTransaction},
-    traits::{Database, DupReadCursor, ReadCursor, ReadTransaction, WriteTransaction},
+    traits::{Database, DupReadCursor, ReadCursor, ReadTransaction, WriteCursor, WriteTransaction},
 };
+use nimiq_database_value::{AsDatabaseBytes, FromDatabaseBytes};
 use nimiq_database_value_derive::DbSerializable;
 use nimiq_hash::Blake2bHash;
-use nimiq_primitives::{policy::Policy, trie::trie_diff::TrieDiff};
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, policy::Policy, trie::trie_diff::TrieDiff};
 use nimiq_serde::{Deserialize, Serialize};
-use nimiq_transaction::{historic_transaction::HistoricTransactionData, reward::RewardTransaction};
+use nimiq_transaction::{
+    historic_transaction::{HistoricTransaction, HistoricTransactionData},
+    reward::RewardTransaction,
+};
 
 use crate::{history::interface::HistoryInterface, history_store_proxy::HistoryStoreProxy};
 
@@ -23,6 +28,61 @@ declare_table!(StoredBlockTable, "StoredBlockTable", Blake2bHash => Block);
 declare_table!(HeightIndex, "HeightIndex", u32 => dup(Blake2bHash));
 declare_table!(RevertTable, "Receipts", u32 => RevertInfo);
 declare_table!(AccountsDiffTable, "AccountsDiff", Blake2bHash => TrieDiff);
+declare_table!(TimestampIndex, "TimestampIndex", u64 => u32);
+// The version of the timestamp index, used to detect indices that need to be rebuilt.
+declare_table!(TimestampIndexVersion, "TimestampIndexVersion", () => u32);
+declare_table!(StakeSnapshotTable, "StakeSnapshots", StakeSnapshotKey => StakeSnapshot);
+declare_table!(BurnedSupplyTable, "BurnedSupply", u32 => BurnedSupply);
+
+/// The coins burned by the protocol since genesis, as recorded at a macro block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, DbSerializable)]
+pub struct BurnedSupply {
+    /// The rewards burned because slots were penalized, because their recipients couldn't accept
+    /// them or because they couldn't be distributed to the stakers of a validator.
+    pub burned: Coin,
+    /// The part of the burned rewards that was withheld from penalized slots.
+    pub penalized: Coin,
+}
+
+/// The stake of a validator and of the stakers delegating to it, as recorded at an election block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, DbSerializable)]
+pub struct StakeSnapshot {
+    pub validator: Validator,
+    pub stakers: Vec<Staker>,
+}
+
+/// Identifies the stake snapshot of a validator at an election block. The snapshots are ordered by
+/// their election block.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct StakeSnapshotKey {
+    block_number: u32,
+    validator_address: Address,
+}
+
+impl AsDatabaseBytes for StakeSnapshotKey {
+    fn as_key_bytes(&self) -> Cow<[u8]> {
+        let bytes = [
+            &self.block_number.to_be_bytes()[..],
+            self.validator_address.as_bytes(),
+        ]
+        .concat();
+        Cow::Owned(bytes)
+    }
+
+    const FIXED_SIZE: Option<usize> = Some(4 + Address::SIZE);
+}
+
+impl FromDatabaseBytes for StakeSnapshotKey {
+    fn from_key_bytes(bytes: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        StakeSnapshotKey {
+            block_number: u32::from_be_bytes(bytes[..4].try_into().unwrap()),
+            validator_address: Address::from_key_bytes(&bytes[4..]),
+        }
+    }
+}
 
 /// The non-header content of a block except that transactions are not stored to
 /// optimize blocks storage. This assumes that a block has been pushed and that there
@@ -113,7 +173,8 @@ impl PushedBlock {
 }
 
 /// A struct that contains the DB tables to store the chain related data such as
-/// chain table, block table, height index table, revert table and accounts diff table.
+/// chain table, block table, height index table, timestamp index table, revert table and accounts
+/// diff table.
 #[derive(Debug)]
 pub struct ChainStore {
     /// Database handle.
@@ -128,15 +189,28 @@ pub struct ChainStore {
     stored_block_table: StoredBlockTable,
     /// A database of block hashes indexed by their block number.
     height_idx: HeightIndex,
+    /// A database of the first known main chain block number for each block timestamp. Unlike
+    /// the blocks themselves, the entries are not pruned.
+    timestamp_idx: TimestampIndex,
+    /// A database holding the version of the timestamp index.
+    timestamp_idx_version: TimestampIndexVersion,
     /// A database of revert infos indexed by their corresponding block hashes.
     revert_table: RevertTable,
     /// A database of accounts trie diffs for a block.
     accounts_diff_table: AccountsDiffTable,
+    /// A database of the stake of all validators and their stakers at election blocks.
+    stake_snapshot_table: StakeSnapshotTable,
+    /// A database of the coins burned since genesis at each macro block.
+    burned_supply_table: BurnedSupplyTable,
     /// A reference to the history store to recover micro block transactions.
     history_store: Arc<HistoryStoreProxy>,
 }
 
 impl ChainStore {
+    /// The current version of the timestamp index. Indices with an older version are rebuilt on
+    /// startup.
+    const TIMESTAMP_INDEX_VERSION: u32 = 1;
+
     pub fn new(db: MdbxDatabase, history_store: Arc<HistoryStoreProxy>) -> Self {
         let chain_store = ChainStore {
             db,
@@ -145,8 +219,12 @@ impl ChainStore {
             pushed_block_table: PushedBlockTable,
             stored_block_table: StoredBlockTable,
             height_idx: HeightIndex,
+            timestamp_idx: TimestampIndex,
+            timestamp_idx_version: TimestampIndexVersion,
             revert_table: RevertTable,
             accounts_diff_table: AccountsDiffTable,
+            stake_snapshot_table: StakeSnapshotTable,
+            burned_supply_table: BurnedSupplyTable,
             history_store,
         };
 
@@ -161,23 +239,94 @@ impl ChainStore {
             .db
             .create_regular_table(&chain_store.stored_block_table);
         chain_store.db.create_dup_table(&chain_store.height_idx);
+        chain_store
+            .db
+            .create_regular_table(&chain_store.timestamp_idx);
+        chain_store
+            .db
+            .create_regular_table(&chain_store.timestamp_idx_version);
         chain_store
             .db
             .create_regular_table(&chain_store.revert_table);
         chain_store
             .db
             .create_regular_table(&chain_store.accounts_diff_table);
+        chain_store
+            .db
+            .create_regular_table(&chain_store.stake_snapshot_table);
+        chain_store
+            .db
+            .create_regular_table(&chain_store.burned_supply_table);
 
         chain_store
     }
 
+    /// Rebuilds the timestamp index if it was built by an older version, e.g. because the
+    /// database predates the index. This writes to the database and is meant to be called once
+    /// on startup.
+    pub fn rebuild_timestamp_index_if_necessary(&self) {
+        let mut txn = self.db.write_transaction();
+
+        let version = txn.get(&self.timestamp_idx_version, &()).unwrap_or(0);
+        if version < Self::TIMESTAMP_INDEX_VERSION {
+            info!("Timestamp index out-of-date. Starting to rebuild index.");
+            self.rebuild_timestamp_index(&mut txn);
+            txn.put(
+                &self.timestamp_idx_version,
+                &(),
+                &Self::TIMESTAMP_INDEX_VERSION,
+            );
+            txn.commit();
+            info!("Finished rebuilding timestamp index.");
+        }
+    }
+
+    /// Rebuilds the timestamp index from the main chain blocks in the chain store and the block
+    /// timestamps of the historic transactions in the history store.
+    pub fn rebuild_timestamp_index(&self, txn: &mut MdbxWriteTransaction) {
+        // Collect the first block number for each timestamp.
+        let mut timestamps = BTreeMap::new();
+        let mut index = |timestamp: u64, block_number: u32| {
+            timestamps
+                .entry(timestamp)
+                .and_modify(|first_block: &mut u32| *first_block = block_number.min(*first_block))
+                .or_insert(block_number);
+        };
+
+        let cursor = WriteTransaction::cursor(txn, &self.chain_table);
+        for (_, chain_info) in cursor.into_iter_start() {
+            if chain_info.on_main_chain {
+                index(chain_info.head.timestamp(), chain_info.head.block_number());
+            }
+        }
+
+        let (first_block, last_block) = self.history_store.history_store_range(Some(txn));
+        for epoch_number in Policy::epoch_at(first_block)..=Policy::epoch_at(last_block) {
+            for hist_tx in self
+                .history_store
+                .get_epoch_transactions(epoch_number, Some(txn))
+            {
+                index(hist_tx.block_time, hist_tx.block_number);
+            }
+        }
+
+        // We insert the entries by append, which gives us much better performance.
+        txn.clear_table(&self.timestamp_idx);
+        let mut cursor = WriteTransaction::cursor(txn, &self.timestamp_idx);
+        for (timestamp, block_number) in timestamps.iter() {
+            cursor.append(timestamp, block_number);
+        }
+    }
+
     pub fn clear(&self, txn: &mut MdbxWriteTransaction) {
         txn.clear_table(&self.chain_table);
         txn.clear_table(&self.pushed_block_table);
         txn.clear_table(&self.stored_block_table);
         txn.clear_table(&self.height_idx);
+        txn.clear_table(&self.timestamp_idx);
         txn.clear_table(&self.revert_table);
         txn.clear_table(&self.accounts_diff_table);
+        txn.clear_table(&self.stake_snapshot_table);
     }
 
     pub fn get_head(&self, txn_option: Option<&MdbxReadTransaction>) -> Option<Blake2bHash> {
@@ -278,6 +427,12 @@ impl ChainStore {
         chain_info: &ChainInfo,
         include_body: bool,
     ) {
+        // Blocks that were on the main chain before are being reverted.
+        let reverted = !chain_info.on_main_chain
+            && txn
+                .get(&self.chain_table, hash)
+                .is_some_and(|chain_info: ChainInfo| chain_info.on_main_chain);
+
         // Store chain data. Block body will not be persisted because the serialization of ChainInfo
         // ignores the block body.
         txn.put_reserve(&self.chain_table, hash, chain_info);
@@ -301,6 +456,17 @@ impl ChainStore {
         // Add to height index.
         let height = chain_info.head.block_number();
         txn.put(&self.height_idx, &height, hash);
+
+        // Update the timestamp index.
+        if chain_info.on_main_chain {
+            self.index_timestamp(
+                txn,
+                chain_info.head.timestamp(),
+                chain_info.head.block_number(),
+            );
+        } else if reverted {
+            self.unindex_timestamp(txn, &chain_info.head);
+        }
     }
 
     /// Gets the set of macro block hashes that delimits the epoch chunks.
@@ -343,12 +509,118 @@ impl ChainStore {
         hash: &Blake2bHash,
         height: u32,
     ) {
+        let chain_info: Option<ChainInfo> = txn.get(&self.chain_table, hash);
+        if let Some(chain_info) = chain_info.filter(|chain_info| chain_info.on_main_chain) {
+            self.unindex_timestamp(txn, &chain_info.head);
+        }
+
         txn.remove(&self.chain_table, hash);
         txn.remove(&self.pushed_block_table, hash);
         txn.remove(&self.stored_block_table, hash);
         txn.remove_item(&self.height_idx, &height, hash);
     }
 
+    /// Removes the given hash from the height index without touching the chain info it refers to.
+    pub fn remove_height_index_entry(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        height: u32,
+        hash: &Blake2bHash,
+    ) {
+        txn.remove_item(&self.height_idx, &height, hash);
+    }
+
+    /// Returns the number of the first main chain block with a timestamp at or after the given
+    /// timestamp. Returns `None` if there is no such block or if it can't be determined, i.e. if
+    /// it might precede the indexed blocks, e.g. because the chain was synced from a
+    /// zero-knowledge proof, or if a preceding block's timestamp is unknown, e.g. a pruned or
+    /// history synced micro block without transactions.
+    pub fn get_block_number_at_timestamp(
+        &self,
+        timestamp: u64,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Option<u32> {
+        let txn = txn_option.or_new(&self.db);
+
+        let mut cursor = txn.cursor(&self.timestamp_idx);
+        let (first_timestamp, first_block) = cursor.first()?;
+        if timestamp < first_timestamp && first_block != Policy::genesis_block_number() {
+            return None;
+        }
+
+        let (_, block_number) = cursor.set_lowerbound_key(&timestamp)?;
+        let Some((_, prev_block)) = cursor.prev() else {
+            return Some(block_number);
+        };
+
+        // The blocks between the preceding entry and the found one are either covered by the
+        // preceding entry or missing from the index, so their timestamps are looked up instead.
+        for gap_block in prev_block + 1..block_number {
+            if self.get_main_chain_timestamp(gap_block, Some(&txn))? >= timestamp {
+                return Some(gap_block);
+            }
+        }
+
+        Some(block_number)
+    }
+
+    /// Returns the timestamp of the main chain block at the given height if either the block or
+    /// any of its historic transactions are stored.
+    fn get_main_chain_timestamp(
+        &self,
+        block_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Option<u64> {
+        if let Ok(chain_info) = self.get_chain_info_at(block_number, false, txn_option) {
+            return Some(chain_info.head.timestamp());
+        }
+
+        self.history_store
+            .get_block_transactions(block_number, txn_option)
+            .first()
+            .map(|hist_tx| hist_tx.block_time)
+    }
+
+    /// Adds the main chain block with the given number and timestamp to the timestamp index.
+    /// Since timestamps are non-decreasing along the chain, only the first block with a given
+    /// timestamp is indexed. Blocks may be added in any order.
+    pub fn index_timestamp(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        timestamp: u64,
+        block_number: u32,
+    ) {
+        // The block is either indexed already or covered by the entry of a preceding block.
+        if txn
+            .get(&self.timestamp_idx, &timestamp)
+            .is_some_and(|first_block: u32| first_block <= block_number)
+        {
+            return;
+        }
+
+        txn.put(&self.timestamp_idx, &timestamp, &block_number);
+    }
+
+    /// Adds the blocks of the given historic transactions to the timestamp index. This covers
+    /// micro blocks that are only known through their history, e.g. during history sync.
+    pub fn index_history_timestamps(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        history: &[HistoricTransaction],
+    ) {
+        for hist_tx in history {
+            self.index_timestamp(txn, hist_tx.block_time, hist_tx.block_number);
+        }
+    }
+
+    /// Removes a block that was reverted from the main chain from the timestamp index.
+    pub fn unindex_timestamp(&self, txn: &mut MdbxWriteTransaction, block: &Block) {
+        let timestamp = block.timestamp();
+        if txn.get(&self.timestamp_idx, &timestamp) == Some(block.block_number()) {
+            txn.remove(&self.timestamp_idx, &timestamp);
+        }
+    }
+
     pub fn get_block(
         &self,
         hash: &Blake2bHash,
@@ -668,6 +940,88 @@ impl ChainStore {
         txn.get(&self.revert_table, &block_height)
     }
 
+    /// Puts the stake snapshots of all validators at the given election block.
+    pub fn put_stake_snapshots(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        block_number: u32,
+        snapshots: &[StakeSnapshot],
+    ) {
+        for snapshot in snapshots {
+            let key = StakeSnapshotKey {
+                block_number,
+                validator_address: snapshot.validator.address.clone(),
+            };
+            txn.put_reserve(&self.stake_snapshot_table, &key, snapshot);
+        }
+    }
+
+    /// Gets the stake snapshot of a validator at the given election block.
+    pub fn get_stake_snapshot(
+        &self,
+        block_number: u32,
+        validator_address: &Address,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Option<StakeSnapshot> {
+        let txn = txn_option.or_new(&self.db);
+        let key = StakeSnapshotKey {
+            block_number,
+            validator_address: validator_address.clone(),
+        };
+        txn.get(&self.stake_snapshot_table, &key)
+    }
+
+    /// Returns whether stake snapshots were recorded at the given election block.
+    pub fn has_stake_snapshots(
+        &self,
+        block_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> bool {
+        let txn = txn_option.or_new(&self.db);
+        let mut cursor = txn.cursor(&self.stake_snapshot_table);
+        let start = StakeSnapshotKey {
+            block_number,
+            validator_address: Address::START_ADDRESS,
+        };
+        matches!(
+            cursor.set_lowerbound_key(&start),
+            Some((key, _)) if key.block_number == block_number
+        )
+    }
+
+    /// Removes the stake snapshots of all election blocks before the given block number.
+    pub fn remove_stake_snapshots_before(&self, txn: &mut MdbxWriteTransaction, block_number: u32) {
+        let mut cursor = WriteTransaction::cursor(txn, &self.stake_snapshot_table);
+        let mut pos: Option<(StakeSnapshotKey, StakeSnapshot)> = cursor.first();
+        while let Some((key, _)) = pos {
+            if key.block_number >= block_number {
+                break;
+            }
+            cursor.remove();
+            pos = cursor.next();
+        }
+    }
+
+    /// Puts the coins burned since genesis up to and including the given macro block.
+    pub fn put_burned_supply(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        block_number: u32,
+        burned_supply: &BurnedSupply,
+    ) {
+        txn.put_reserve(&self.burned_supply_table, &block_number, burned_supply);
+    }
+
+    /// Gets the coins burned since genesis up to and including the given macro block.
+    pub fn get_burned_supply(
+        &self,
+        block_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Option<BurnedSupply> {
+        let txn = txn_option.or_new(&self.db);
+        txn.get(&self.burned_supply_table, &block_number)
+    }
+
     pub fn put_accounts_diff(
         &self,
         txn: &mut MdbxWriteTransaction,
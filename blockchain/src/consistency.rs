@@ -0,0 +1,758 @@
+use std::{fmt, sync::Arc};
+
+use nimiq_account::Accounts;
+use nimiq_block::Block;
+use nimiq_blockchain_interface::ChainInfo;
+use nimiq_database::{
+    mdbx::{MdbxDatabase, MdbxReadTransaction, MdbxWriteTransaction},
+    traits::{Database, ReadCursor, ReadTransaction, WriteTransaction},
+};
+use nimiq_genesis::NetworkInfo;
+use nimiq_hash::Blake2bHash;
+use nimiq_primitives::{networks::NetworkId, policy::Policy};
+use nimiq_transaction::historic_transaction::RawTransactionHash;
+use thiserror::Error;
+
+use crate::{
+    chain_store::ChainStore,
+    history::{validity_store::ValidityStore, HistoryStore},
+    history_store_proxy::HistoryStoreProxy,
+    interface::{HistoryIndexInterface, HistoryInterface},
+    HistoryStoreIndex,
+};
+
+/// An inconsistency found in the consensus database.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum Inconsistency {
+    #[error("No head block is stored")]
+    MissingHead,
+    #[error("The head block {0} is missing")]
+    MissingHeadBlock(Blake2bHash),
+    #[error("The genesis block is not stored on the main chain")]
+    InvalidGenesisBlock,
+    #[error("The main chain references the missing block {hash} at #{block_number}")]
+    MissingChainInfo {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    #[error("Block {hash} at #{block_number} is part of the main chain but not marked as such")]
+    NotOnMainChain {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    #[error("The main chain successor of block {hash} at #{block_number} does not point to the next main chain block")]
+    InvalidMainChainSuccessor {
+        block_number: u32,
+        hash: Blake2bHash,
+        successor: Option<Blake2bHash>,
+    },
+    #[error("The body of main chain block {hash} at #{block_number} is missing")]
+    MissingBody {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    #[error("The height index at #{block_number} does not contain main chain block {hash}")]
+    MissingHeightIndexEntry {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    #[error("The height index at #{block_number} references the missing block {hash}")]
+    DanglingHeightIndexEntry {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    #[error("The accounts trie root {actual} does not match the state root {expected} of head block #{block_number}")]
+    StateRootMismatch {
+        block_number: u32,
+        expected: Blake2bHash,
+        actual: Blake2bHash,
+    },
+    #[error("The history root {actual:?} does not match the history root {expected} of block #{block_number}")]
+    HistoryRootMismatch {
+        block_number: u32,
+        expected: Blake2bHash,
+        actual: Option<Blake2bHash>,
+    },
+    #[error("The history index is missing {missing} transactions of epoch {epoch_number}")]
+    HistoryIndexMismatch { epoch_number: u32, missing: usize },
+    #[error("The validity store {0}")]
+    ValidityStoreMismatch(String),
+}
+
+impl Inconsistency {
+    /// Returns true if the inconsistency can be repaired without reverting any blocks.
+    fn is_repairable_in_place(&self) -> bool {
+        matches!(
+            self,
+            Inconsistency::NotOnMainChain { .. }
+                | Inconsistency::InvalidMainChainSuccessor { .. }
+                | Inconsistency::MissingHeightIndexEntry { .. }
+                | Inconsistency::DanglingHeightIndexEntry { .. }
+                | Inconsistency::HistoryIndexMismatch { .. }
+                | Inconsistency::ValidityStoreMismatch(_)
+        )
+    }
+
+    /// Returns true if the inconsistency can be repaired by reverting to a consistent macro block
+    /// before it.
+    fn is_repairable_by_revert(&self) -> bool {
+        matches!(
+            self,
+            Inconsistency::MissingBody { .. }
+                | Inconsistency::StateRootMismatch { .. }
+                | Inconsistency::HistoryRootMismatch { .. }
+        )
+    }
+
+    /// Returns the block number at which the inconsistency occurs, if it concerns a block.
+    pub fn block_number(&self) -> Option<u32> {
+        match self {
+            Inconsistency::MissingChainInfo { block_number, .. }
+            | Inconsistency::NotOnMainChain { block_number, .. }
+            | Inconsistency::InvalidMainChainSuccessor { block_number, .. }
+            | Inconsistency::MissingBody { block_number, .. }
+            | Inconsistency::MissingHeightIndexEntry { block_number, .. }
+            | Inconsistency::DanglingHeightIndexEntry { block_number, .. }
+            | Inconsistency::StateRootMismatch { block_number, .. }
+            | Inconsistency::HistoryRootMismatch { block_number, .. } => Some(*block_number),
+            Inconsistency::MissingHead
+            | Inconsistency::MissingHeadBlock(_)
+            | Inconsistency::InvalidGenesisBlock
+            | Inconsistency::HistoryIndexMismatch { .. }
+            | Inconsistency::ValidityStoreMismatch(_) => None,
+        }
+    }
+}
+
+/// The result of a consistency check of the consensus database.
+#[derive(Clone, Debug)]
+pub struct ConsistencyReport {
+    /// The block number and hash of the stored head block.
+    pub head: Option<(u32, Blake2bHash)>,
+    /// The most recent macro block before which no inconsistencies were found.
+    pub last_consistent_macro_block: Option<(u32, Blake2bHash)>,
+    /// The inconsistencies found.
+    pub inconsistencies: Vec<Inconsistency>,
+}
+
+impl ConsistencyReport {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+
+    fn requires_index_rebuild(&self) -> bool {
+        self.inconsistencies.iter().any(|inconsistency| {
+            matches!(inconsistency, Inconsistency::HistoryIndexMismatch { .. })
+        })
+    }
+
+    /// Returns true if there are inconsistencies in blocks that can only be repaired by reverting
+    /// them.
+    fn requires_revert(&self) -> bool {
+        self.inconsistencies
+            .iter()
+            .any(Inconsistency::is_repairable_by_revert)
+    }
+
+    fn requires_validity_store_rebuild(&self) -> bool {
+        self.inconsistencies
+            .iter()
+            .any(|inconsistency| matches!(inconsistency, Inconsistency::ValidityStoreMismatch(_)))
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.head {
+            Some((block_number, hash)) => writeln!(f, "Head: #{block_number} ({hash})")?,
+            None => writeln!(f, "Head: none")?,
+        }
+        if let Some((block_number, hash)) = &self.last_consistent_macro_block {
+            writeln!(f, "Last consistent macro block: #{block_number} ({hash})")?;
+        }
+        if self.is_consistent() {
+            write!(f, "No inconsistencies found")
+        } else {
+            write!(f, "{} inconsistencies found:", self.inconsistencies.len())?;
+            for inconsistency in &self.inconsistencies {
+                write!(f, "\n  - {inconsistency}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A repair performed on the consensus database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Repair {
+    /// The given block hash was removed from the height index.
+    RemovedHeightIndexEntry {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    /// The given main chain block was marked as such and linked to its main chain successor.
+    RepairedChainInfo {
+        block_number: u32,
+        hash: Blake2bHash,
+    },
+    /// The blocks after the given macro block were reverted, making it the head of the main chain.
+    RevertedToMacroBlock { block_number: u32, num_blocks: u32 },
+    /// The accounts trie was reinitialized as incomplete and needs to be synced again.
+    ReinitializedAccounts,
+    /// The history index was rebuilt from the history store.
+    RebuiltHistoryIndex,
+    /// The validity store was rebuilt from the history store.
+    RebuiltValidityStore,
+}
+
+#[derive(Debug, Error)]
+pub enum RepairError {
+    #[error("The inconsistencies cannot be repaired, reset your consensus database: {0}")]
+    Unrepairable(Inconsistency),
+}
+
+/// Checks and repairs the consistency of the chain store, the accounts trie, the history store
+/// and its index, and the validity store of a consensus database.
+///
+/// The chain is checked from the head back to the last election block.
+pub struct ConsistencyChecker {
+    db: MdbxDatabase,
+    network_id: NetworkId,
+    index_history: bool,
+    chain_store: ChainStore,
+    history_store: Arc<HistoryStoreProxy>,
+    validity_store: ValidityStore,
+    accounts: Accounts,
+}
+
+impl ConsistencyChecker {
+    pub fn new(db: MdbxDatabase, network_id: NetworkId, index_history: bool) -> Self {
+        let history_store = if index_history {
+            Arc::new(HistoryStoreProxy::WithIndex(HistoryStoreIndex::new(
+                db.clone(),
+                network_id,
+            )))
+        } else {
+            Arc::new(HistoryStoreProxy::WithoutIndex(Box::new(HistoryStore::new(
+                db.clone(),
+                network_id,
+            ))
+                as Box<dyn HistoryInterface + Sync + Send>))
+        };
+
+        ConsistencyChecker {
+            chain_store: ChainStore::new(db.clone(), Arc::clone(&history_store)),
+            validity_store: ValidityStore::new(db.clone()),
+            accounts: Accounts::new(db.clone()),
+            history_store,
+            db,
+            network_id,
+            index_history,
+        }
+    }
+
+    /// Checks the database for inconsistencies.
+    pub fn check(&self) -> ConsistencyReport {
+        let txn = self.db.read_transaction();
+        let mut inconsistencies = vec![];
+
+        let genesis_hash = NetworkInfo::from_network_id(self.network_id).genesis_hash();
+        if !self
+            .chain_store
+            .get_chain_info(genesis_hash, false, Some(&txn))
+            .is_ok_and(|info| info.on_main_chain)
+        {
+            inconsistencies.push(Inconsistency::InvalidGenesisBlock);
+        }
+
+        let Some(head_hash) = self.chain_store.get_head(Some(&txn)) else {
+            inconsistencies.push(Inconsistency::MissingHead);
+            return ConsistencyReport {
+                head: None,
+                last_consistent_macro_block: None,
+                inconsistencies,
+            };
+        };
+
+        let head_info = match self
+            .chain_store
+            .get_chain_info(&head_hash, false, Some(&txn))
+        {
+            Ok(head_info) => head_info,
+            Err(_) => {
+                inconsistencies.push(Inconsistency::MissingHeadBlock(head_hash));
+                return ConsistencyReport {
+                    head: None,
+                    last_consistent_macro_block: None,
+                    inconsistencies,
+                };
+            }
+        };
+        let head_number = head_info.head.block_number();
+
+        // The accounts trie must match the state of the head block. An incomplete trie can't be
+        // checked.
+        if let Some(accounts_hash) = self.accounts.get_root_hash(Some(&txn)) {
+            if head_info.head.state_root() != &accounts_hash {
+                inconsistencies.push(Inconsistency::StateRootMismatch {
+                    block_number: head_number,
+                    expected: head_info.head.state_root().clone(),
+                    actual: accounts_hash,
+                });
+            }
+        }
+
+        // The history of the current epoch must match the history root of the head block.
+        self.check_history_root(&head_info.head, &txn, &mut inconsistencies);
+
+        let macro_blocks =
+            self.check_main_chain(head_hash.clone(), head_info, &txn, &mut inconsistencies);
+
+        self.check_validity_store(head_number, &txn, &mut inconsistencies);
+
+        if self.index_history {
+            self.check_history_index(Policy::epoch_at(head_number), &txn, &mut inconsistencies);
+        }
+
+        // The last consistent macro block is the most recent one that precedes all
+        // inconsistencies found in the chain.
+        let first_inconsistent_block = inconsistencies
+            .iter()
+            .filter_map(Inconsistency::block_number)
+            .min();
+        let last_consistent_macro_block = macro_blocks.into_iter().find(|(block_number, _)| {
+            !matches!(first_inconsistent_block, Some(first) if *block_number >= first)
+        });
+
+        ConsistencyReport {
+            head: Some((head_number, head_hash)),
+            last_consistent_macro_block,
+            inconsistencies,
+        }
+    }
+
+    /// Walks the main chain from the head back to the last election block and checks the chain
+    /// infos, main chain pointers, bodies and the height index.
+    /// Returns the macro blocks encountered, most recent first.
+    fn check_main_chain(
+        &self,
+        head_hash: Blake2bHash,
+        head_info: ChainInfo,
+        txn: &MdbxReadTransaction,
+        inconsistencies: &mut Vec<Inconsistency>,
+    ) -> Vec<(u32, Blake2bHash)> {
+        let mut macro_blocks = vec![];
+        let mut successor: Option<Blake2bHash> = None;
+        let mut current = (head_hash, head_info);
+
+        loop {
+            let (hash, info) = &current;
+            let block_number = info.head.block_number();
+
+            if !info.on_main_chain {
+                inconsistencies.push(Inconsistency::NotOnMainChain {
+                    block_number,
+                    hash: hash.clone(),
+                });
+            }
+            if info.main_chain_successor != successor {
+                inconsistencies.push(Inconsistency::InvalidMainChainSuccessor {
+                    block_number,
+                    hash: hash.clone(),
+                    successor: successor.clone(),
+                });
+            }
+            if self
+                .chain_store
+                .get_chain_info(hash, true, Some(txn))
+                .is_err()
+            {
+                inconsistencies.push(Inconsistency::MissingBody {
+                    block_number,
+                    hash: hash.clone(),
+                });
+            }
+            self.check_height_index(block_number, hash, txn, inconsistencies);
+
+            if info.head.is_macro() {
+                macro_blocks.push((block_number, hash.clone()));
+
+                // The history tree of a finished epoch must match its election block.
+                // The head has been checked already.
+                if info.head.is_election()
+                    && successor.is_some()
+                    && block_number != Policy::genesis_block_number()
+                {
+                    self.check_history_root(&info.head, txn, inconsistencies);
+                }
+                // Stop at the last election block or at a macro block with a pruned predecessor.
+                if info.head.is_election()
+                    || block_number == Policy::genesis_block_number()
+                    || self
+                        .chain_store
+                        .get_chain_info(info.head.parent_hash(), false, Some(txn))
+                        .is_err()
+                {
+                    break;
+                }
+            }
+
+            let parent_hash = info.head.parent_hash().clone();
+            match self
+                .chain_store
+                .get_chain_info(&parent_hash, false, Some(txn))
+            {
+                Ok(parent_info) => {
+                    successor = Some(hash.clone());
+                    current = (parent_hash, parent_info);
+                }
+                Err(_) => {
+                    inconsistencies.push(Inconsistency::MissingChainInfo {
+                        block_number: block_number - 1,
+                        hash: parent_hash,
+                    });
+                    break;
+                }
+            }
+        }
+
+        macro_blocks
+    }
+
+    /// Checks that the height index contains the given main chain block and that all blocks it
+    /// references at that height exist.
+    fn check_height_index(
+        &self,
+        block_number: u32,
+        hash: &Blake2bHash,
+        txn: &MdbxReadTransaction,
+        inconsistencies: &mut Vec<Inconsistency>,
+    ) {
+        let hashes = self
+            .chain_store
+            .get_block_hashes_at(block_number, Some(txn));
+        if !hashes.contains(hash) {
+            inconsistencies.push(Inconsistency::MissingHeightIndexEntry {
+                block_number,
+                hash: hash.clone(),
+            });
+        }
+        for indexed_hash in hashes {
+            if self
+                .chain_store
+                .get_chain_info(&indexed_hash, false, Some(txn))
+                .is_err()
+            {
+                inconsistencies.push(Inconsistency::DanglingHeightIndexEntry {
+                    block_number,
+                    hash: indexed_hash,
+                });
+            }
+        }
+    }
+
+    /// Checks that the history tree of the block's epoch matches the history root of the block.
+    fn check_history_root(
+        &self,
+        block: &Block,
+        txn: &MdbxReadTransaction,
+        inconsistencies: &mut Vec<Inconsistency>,
+    ) {
+        let block_number = block.block_number();
+        let history_root = self
+            .history_store
+            .get_history_tree_root(block_number, Some(txn));
+        if history_root.as_ref() != Some(block.history_root()) {
+            inconsistencies.push(Inconsistency::HistoryRootMismatch {
+                block_number,
+                expected: block.history_root().clone(),
+                actual: history_root,
+            });
+        }
+    }
+
+    /// Checks that the validity store is up to date with the head and that both of its tables
+    /// agree with each other.
+    fn check_validity_store(
+        &self,
+        head_number: u32,
+        txn: &MdbxReadTransaction,
+        inconsistencies: &mut Vec<Inconsistency>,
+    ) {
+        let last_bn = self.validity_store.last_bn(txn);
+        // An empty validity store is valid right after the genesis block.
+        if last_bn == 0 && head_number == Policy::genesis_block_number() {
+            return;
+        }
+        if last_bn != head_number {
+            inconsistencies.push(Inconsistency::ValidityStoreMismatch(format!(
+                "ends at #{last_bn} instead of the head #{head_number}"
+            )));
+            return;
+        }
+
+        let cursor = txn.dup_cursor(&self.validity_store.block_txns);
+        let mut num_mismatches = 0;
+        for (block_number, tx_hash) in cursor.into_iter_start() {
+            if tx_hash == RawTransactionHash::default() {
+                continue;
+            }
+            if txn.get(&self.validity_store.txn_hashes, &tx_hash) != Some(block_number) {
+                num_mismatches += 1;
+            }
+        }
+        if num_mismatches > 0 {
+            inconsistencies.push(Inconsistency::ValidityStoreMismatch(format!(
+                "has {num_mismatches} transactions that are not indexed by hash"
+            )));
+        }
+    }
+
+    /// Checks that all transactions of the given epoch can be found through the history index.
+    fn check_history_index(
+        &self,
+        epoch_number: u32,
+        txn: &MdbxReadTransaction,
+        inconsistencies: &mut Vec<Inconsistency>,
+    ) {
+        let Some(index) = self.history_store.history_index() else {
+            return;
+        };
+
+        let missing = self
+            .history_store
+            .get_epoch_transactions(epoch_number, Some(txn))
+            .iter()
+            .filter(|hist_tx| {
+                let tx_hash = hist_tx.tx_hash();
+                index
+                    .get_hist_tx_by_hash(&tx_hash.clone().into(), Some(txn))
+                    .map(|indexed_tx| indexed_tx.tx_hash())
+                    != Some(tx_hash)
+            })
+            .count();
+        if missing > 0 {
+            inconsistencies.push(Inconsistency::HistoryIndexMismatch {
+                epoch_number,
+                missing,
+            });
+        }
+    }
+
+    /// Repairs the inconsistencies of the given report.
+    ///
+    /// The chain store entries that are inconsistent are fixed in place: dangling height index
+    /// entries are removed, and main chain blocks are marked as such, linked to their successor
+    /// and added to the height index. Missing bodies and mismatching state or history roots are
+    /// repaired by reverting to the last consistent macro block, see
+    /// [`ConsistencyChecker::revert_to_macro_block`]. The history index and the validity store are
+    /// rebuilt from the history store. All repairs are committed in a single transaction, so
+    /// nothing is changed if any of the inconsistencies can't be repaired.
+    pub fn repair(&self, report: &ConsistencyReport) -> Result<Vec<Repair>, RepairError> {
+        let requires_revert = report.requires_revert();
+        if let Some(inconsistency) = report.inconsistencies.iter().find(|inconsistency| {
+            !inconsistency.is_repairable_in_place()
+                && !(inconsistency.is_repairable_by_revert()
+                    && report.last_consistent_macro_block.is_some())
+        }) {
+            return Err(RepairError::Unrepairable(inconsistency.clone()));
+        }
+
+        let mut repairs = vec![];
+        let mut txn = self.db.write_transaction();
+
+        let head_number = match &report.last_consistent_macro_block {
+            Some((block_number, hash)) if requires_revert => {
+                // Reverting removes all blocks after the macro block, so the in-place repairs are
+                // not needed.
+                let num_blocks = self.revert_to_macro_block(&mut txn, *block_number, hash)?;
+                repairs.push(Repair::RevertedToMacroBlock {
+                    block_number: *block_number,
+                    num_blocks,
+                });
+                repairs.push(Repair::ReinitializedAccounts);
+                Some(*block_number)
+            }
+            _ => {
+                for inconsistency in &report.inconsistencies {
+                    repairs.extend(self.repair_in_place(&mut txn, inconsistency)?);
+                }
+                report.head.as_ref().map(|(head_number, _)| *head_number)
+            }
+        };
+
+        if report.requires_validity_store_rebuild() || requires_revert {
+            if let Some(head_number) = head_number {
+                self.validity_store
+                    .rebuild(&mut txn, self.history_store.as_ref(), head_number);
+                repairs.push(Repair::RebuiltValidityStore);
+            }
+        }
+
+        if report.requires_index_rebuild() || requires_revert {
+            if let Some(index) = self.history_store.history_index() {
+                index.rebuild_index(&mut txn);
+                repairs.push(Repair::RebuiltHistoryIndex);
+            }
+        }
+
+        txn.commit();
+
+        Ok(repairs)
+    }
+
+    /// Repairs an inconsistent chain store entry in place.
+    fn repair_in_place(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        inconsistency: &Inconsistency,
+    ) -> Result<Option<Repair>, RepairError> {
+        match inconsistency {
+            Inconsistency::DanglingHeightIndexEntry { block_number, hash } => {
+                self.chain_store
+                    .remove_height_index_entry(txn, *block_number, hash);
+                Ok(Some(Repair::RemovedHeightIndexEntry {
+                    block_number: *block_number,
+                    hash: hash.clone(),
+                }))
+            }
+            Inconsistency::NotOnMainChain { block_number, hash }
+            | Inconsistency::MissingHeightIndexEntry { block_number, hash } => {
+                self.repair_chain_info(txn, *block_number, hash, None)
+            }
+            Inconsistency::InvalidMainChainSuccessor {
+                block_number,
+                hash,
+                successor,
+            } => self.repair_chain_info(txn, *block_number, hash, Some(successor)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Reverts all blocks after the given macro block, making it the head of the main chain.
+    ///
+    /// The chain infos and bodies of all blocks after the macro block are removed, including the
+    /// ones on forks, and the history of these blocks is removed from the history store. The
+    /// accounts trie can't be rewound without the bodies of the reverted blocks and a state that
+    /// matches the head, which is what is inconsistent, so it is reinitialized as incomplete and
+    /// synced from peers again.
+    /// Returns the number of removed blocks.
+    fn revert_to_macro_block(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        block_number: u32,
+        hash: &Blake2bHash,
+    ) -> Result<u32, RepairError> {
+        let mut macro_info = self
+            .chain_store
+            .get_chain_info(hash, false, Some(txn))
+            .map_err(|_| {
+                RepairError::Unrepairable(Inconsistency::MissingChainInfo {
+                    block_number,
+                    hash: hash.clone(),
+                })
+            })?;
+
+        // Remove the main chain blocks, some of which might be missing from the height index.
+        let mut num_blocks = 0;
+        let mut current = self.chain_store.get_head(Some(txn));
+        while let Some(current_hash) = current.filter(|current_hash| current_hash != hash) {
+            let Ok(info) = self
+                .chain_store
+                .get_chain_info(&current_hash, false, Some(txn))
+            else {
+                break;
+            };
+            self.chain_store
+                .remove_chain_info(txn, &current_hash, info.head.block_number());
+            num_blocks += 1;
+            current = Some(info.head.parent_hash().clone());
+        }
+
+        // Remove all remaining blocks after the macro block from the height index.
+        let mut height = block_number + 1;
+        loop {
+            let hashes = self.chain_store.get_block_hashes_at(height, Some(txn));
+            if hashes.is_empty() {
+                break;
+            }
+            for block_hash in hashes {
+                self.chain_store.remove_chain_info(txn, &block_hash, height);
+            }
+            height += 1;
+        }
+
+        // The macro block is the new head of the main chain. This also drops the revert infos
+        // of the reverted blocks.
+        macro_info.main_chain_successor = None;
+        self.chain_store
+            .put_chain_info(txn, hash, &macro_info, false);
+        self.chain_store.set_head(txn, hash);
+        self.chain_store.finalize_batch(txn);
+
+        // Remove the history after the macro block.
+        if Policy::is_election_block_at(block_number) {
+            self.history_store
+                .remove_history(txn, Policy::epoch_at(block_number) + 1);
+        } else {
+            let epoch_number = Policy::epoch_at(block_number);
+            let num_hist_txs = self
+                .history_store
+                .num_epoch_transactions(epoch_number, Some(txn))
+                - self
+                    .history_store
+                    .num_epoch_transactions_before(block_number, Some(txn));
+            if num_hist_txs > 0 {
+                self.history_store
+                    .remove_partial_history(txn, epoch_number, num_hist_txs);
+            }
+        }
+
+        self.accounts
+            .reinitialize_as_incomplete(&mut (&mut *txn).into());
+
+        Ok(num_blocks)
+    }
+
+    /// Marks the given block as part of the main chain and stores it again, which adds it to the
+    /// height index. If `successor` is given, the main chain successor is set to it as well.
+    /// Returns `None` if a previous repair of the same block already did this.
+    fn repair_chain_info(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        block_number: u32,
+        hash: &Blake2bHash,
+        successor: Option<&Option<Blake2bHash>>,
+    ) -> Result<Option<Repair>, RepairError> {
+        let mut info = self
+            .chain_store
+            .get_chain_info(hash, false, Some(txn))
+            .map_err(|_| {
+                RepairError::Unrepairable(Inconsistency::MissingChainInfo {
+                    block_number,
+                    hash: hash.clone(),
+                })
+            })?;
+
+        let is_indexed = self
+            .chain_store
+            .get_block_hashes_at(block_number, Some(txn))
+            .contains(hash);
+        let has_invalid_successor =
+            matches!(successor, Some(successor) if info.main_chain_successor != *successor);
+        if info.on_main_chain && is_indexed && !has_invalid_successor {
+            return Ok(None);
+        }
+
+        info.on_main_chain = true;
+        if let Some(successor) = successor {
+            info.main_chain_successor = successor.clone();
+        }
+        self.chain_store.put_chain_info(txn, hash, &info, false);
+
+        Ok(Some(Repair::RepairedChainInfo {
+            block_number,
+            hash: hash.clone(),
+        }))
+    }
+}
@@ -345,6 +345,19 @@ impl HistoryStore {
         epoch_number: u32,
         block_number: u32,
         hist_txs: &[HistoricTransaction],
+    ) -> Option<(Blake2bHash, u64, Vec<u32>)> {
+        self.put_historic_txns_with_validity(txn, epoch_number, block_number, hist_txs, true)
+    }
+
+    /// Same as `put_historic_txns`, but only adds the transactions to the validity store if
+    /// `update_validity_store` is set. Histories of past epochs are outside of the validity window.
+    pub(crate) fn put_historic_txns_with_validity(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        block_number: u32,
+        hist_txs: &[HistoricTransaction],
+        update_validity_store: bool,
     ) -> Option<(Blake2bHash, u64, Vec<u32>)> {
         // Get the history tree.
         let mut tree = MerkleMountainRange::new(MMRStore::with_write_transaction(
@@ -382,15 +395,19 @@ impl HistoryStore {
             };
             cursor.append_dup(&epoch_number, &value);
 
-            self.validity_store
-                .add_transaction(txn, hist_tx.block_number, hist_tx.tx_hash());
+            if update_validity_store {
+                self.validity_store
+                    .add_transaction(txn, hist_tx.block_number, hist_tx.tx_hash());
+            }
 
             txn.put(&self.last_leaf_table, &hist_tx.block_number, &leaf_index);
 
             txns_size += hist_tx.serialized_size() as u64;
         }
 
-        self.validity_store.update_validity_store(txn, block_number);
+        if update_validity_store {
+            self.validity_store.update_validity_store(txn, block_number);
+        }
 
         // Return the history root.
         Some((root, txns_size, leaf_idx))
@@ -485,6 +502,22 @@ impl HistoryInterface for HistoryStore {
             .map(|(root, size, _)| (root, size))
     }
 
+    /// Replaces the history of a past epoch with the given historic transactions. It returns the
+    /// root of the resulting tree.
+    /// Unlike `add_to_history_for_epoch`, this doesn't update the validity store.
+    fn add_past_epoch_history(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        hist_txs: &[HistoricTransaction],
+    ) -> Option<Blake2bHash> {
+        self.remove_history(txn, epoch_number);
+
+        let block_number = Policy::election_block_of(epoch_number)?;
+        self.put_historic_txns_with_validity(txn, epoch_number, block_number, hist_txs, false)
+            .map(|(root, _, _)| root)
+    }
+
     /// Removes a number of historic transactions from an existing history tree. It returns the root
     /// of the resulting tree and the total size of the transactions removed.
     fn remove_partial_history(
@@ -540,6 +573,14 @@ impl HistoryInterface for HistoryStore {
         self.validity_store.has_transaction(txn_opt, raw_tx_hash)
     }
 
+    fn get_tx_block_number_in_validity_window(
+        &self,
+        raw_tx_hash: &RawTransactionHash,
+        txn_opt: Option<&MdbxReadTransaction>,
+    ) -> Option<u32> {
+        self.validity_store.get_block_number(txn_opt, raw_tx_hash)
+    }
+
     /// Gets all historic transactions for a given block number.
     /// This method returns the transactions in the same order that they appear in the block.
     fn get_block_transactions(
@@ -31,6 +31,12 @@ use crate::{history::HistoryTreeChunk, interface::HistoryIndexInterface, History
 declare_table!(TxHashTable, "LeafIndexByTxHash", RawTransactionHash => EpochBasedIndex);
 // `Address` -> `EpochBasedIndex` -> `Blake2bHash`
 declare_table!(AddressTable, "TxHashesByAddress", Address => EpochBasedIndex => Blake2bHash);
+// `epoch number` -> `leaf_index` of equivocation, penalize and jail events
+declare_table!(EquivocationTable, "EquivocationLeavesByEpoch", u32 => dup(u32));
+// `epoch number` -> `EquivocationLocator` of equivocation proofs observed by this node
+declare_table!(ObservedEquivocationTable, "ObservedEquivocationsByEpoch", u32 => dup(EquivocationLocator));
+// The version of the index, used to detect indices that need to be rebuilt.
+declare_table!(IndexVersionTable, "HistoryIndexVersion", () => u32);
 
 #[derive(Debug)]
 /// A struct that contains databases to store history indices.
@@ -44,11 +50,23 @@ pub struct HistoryStoreIndex {
     /// A database of all raw transaction (and reward inherent) hashes indexed by their sender and
     /// recipient addresses.
     address_table: AddressTable,
+    /// A database of the leaf indices of all equivocation and punishment events indexed by their
+    /// epoch number.
+    equivocation_table: EquivocationTable,
+    /// A database of the locators of all equivocation proofs observed by this node indexed by the
+    /// epoch in which the equivocation occurred. Unlike the other tables, it can't be rebuilt from
+    /// the history.
+    observed_equivocation_table: ObservedEquivocationTable,
+    /// A database holding the version of the index.
+    version_table: IndexVersionTable,
     /// The history store.
     history_store: HistoryStore,
 }
 
 impl HistoryStoreIndex {
+    /// The current version of the index. Indices with an older version are rebuilt on startup.
+    const VERSION: u32 = 1;
+
     /// Creates a new HistoryStore.
     pub fn new(db: MdbxDatabase, network_id: NetworkId) -> Self {
         let index = HistoryStoreIndex {
@@ -56,33 +74,49 @@ impl HistoryStoreIndex {
             db,
             tx_hash_table: TxHashTable,
             address_table: AddressTable,
+            equivocation_table: EquivocationTable,
+            observed_equivocation_table: ObservedEquivocationTable,
+            version_table: IndexVersionTable,
         };
 
         index.db.create_regular_table(&index.tx_hash_table);
         index.db.create_dup_table(&index.address_table);
+        index.db.create_dup_table(&index.equivocation_table);
+        index
+            .db
+            .create_dup_table(&index.observed_equivocation_table);
+        index.db.create_regular_table(&index.version_table);
 
-        index.rebuild_index_if_necessary();
         index
     }
 
-    /// Rebuild index if necessary.
-    fn rebuild_index_if_necessary(&self) {
+    /// Rebuilds the index if it was built by an older version or doesn't contain the last
+    /// historic transaction. This writes to the database and is meant to be called once on
+    /// startup.
+    pub fn rebuild_index_if_necessary(&self) {
         let mut txn = self.db.write_transaction();
         let mut hist_tx_cursor =
             WriteTransaction::dup_cursor(&txn, &self.history_store.hist_tx_table);
 
         trace!("Check if history index needs to be rebuilt.");
+        let version = txn.get(&self.version_table, &()).unwrap_or(0);
         // Check if last transaction is part of index.
         if let Some((_, hist_tx)) = hist_tx_cursor.last() {
             let raw_tx_hash = hist_tx.value.tx_hash();
-            if txn.get(&self.tx_hash_table, &raw_tx_hash).is_none() {
+            if version < Self::VERSION || txn.get(&self.tx_hash_table, &raw_tx_hash).is_none() {
                 info!("History index out-of-date. Starting to rebuild index (this can take a long time).");
                 self.rebuild_index(&mut txn);
                 debug!("Committing rebuilt index.");
                 txn.commit();
-                info!("Finished rebuilding history index.")
+                info!("Finished rebuilding history index.");
+                return;
             }
         }
+
+        if version < Self::VERSION {
+            txn.put(&self.version_table, &(), &Self::VERSION);
+            txn.commit();
+        }
     }
 
     fn remove_txns_from_history(
@@ -117,7 +151,53 @@ impl HistoryStoreIndex {
                 }
                 HistoricTransactionData::Equivocation(_)
                 | HistoricTransactionData::Penalize(_)
-                | HistoricTransactionData::Jail(_) => {}
+                | HistoricTransactionData::Jail(_) => {
+                    txn.remove_item(&self.equivocation_table, &epoch_number, &leaf_index);
+                }
+            }
+        }
+    }
+
+    /// Adds the given historic transactions of an epoch, located at the given leaf indices, to the
+    /// index.
+    fn index_historic_txns(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        hist_txs: &[HistoricTransaction],
+        leaf_idx: &[u32],
+    ) {
+        // Add the historic transactions into the respective database.
+        // Sort everything first and then put with a cursor for improved database performance.
+        let mut hashes = BTreeMap::new();
+        let mut addresses = BTreeMap::new();
+        let mut equivocations = BTreeMap::new();
+        for (tx, i) in hist_txs.iter().zip(leaf_idx.iter()) {
+            self.put_historic_tx(
+                &mut hashes,
+                &mut addresses,
+                &mut equivocations,
+                epoch_number,
+                *i,
+                tx,
+            );
+        }
+
+        // Put the hashes and addresses into the respective databases.
+        let mut hashes_cursor = WriteTransaction::cursor(txn, &self.tx_hash_table);
+        for (hash, key) in hashes.iter() {
+            hashes_cursor.put(hash, key);
+        }
+        let mut address_cursor = WriteTransaction::dup_cursor(txn, &self.address_table);
+        for (address, keys) in addresses.iter() {
+            for ordered_hash in keys.iter() {
+                address_cursor.put(address, ordered_hash);
+            }
+        }
+        let mut equivocation_cursor = WriteTransaction::dup_cursor(txn, &self.equivocation_table);
+        for (epoch_number, leaf_indices) in equivocations.iter() {
+            for leaf_index in leaf_indices.iter() {
+                equivocation_cursor.put(epoch_number, leaf_index);
             }
         }
     }
@@ -128,6 +208,7 @@ impl HistoryStoreIndex {
         &self,
         hashes: &mut BTreeMap<RawTransactionHash, EpochBasedIndex>,
         addresses: &mut BTreeMap<Address, Vec<OrderedHash>>,
+        equivocations: &mut BTreeMap<u32, Vec<u32>>,
         epoch_number: u32,
         leaf_index: u32,
         hist_tx: &HistoricTransaction,
@@ -162,11 +243,16 @@ impl HistoryStoreIndex {
                     .or_default()
                     .push(ordered_hash);
             }
-            // Do not index equivocation or punishments events, since I do not see a use case
-            // for this at the time.
+            // Equivocation and punishment events are indexed by epoch, such that the
+            // misbehavior of validators can be looked up.
             HistoricTransactionData::Equivocation(_)
             | HistoricTransactionData::Penalize(_)
-            | HistoricTransactionData::Jail(_) => {}
+            | HistoricTransactionData::Jail(_) => {
+                equivocations
+                    .entry(epoch_number)
+                    .or_default()
+                    .push(leaf_index);
+            }
         }
     }
 
@@ -193,16 +279,19 @@ impl HistoryStoreIndex {
         // Clear the tables.
         txn.clear_table(&self.tx_hash_table);
         txn.clear_table(&self.address_table);
+        txn.clear_table(&self.equivocation_table);
 
         // Iterate over all epochs and leafs.
         let mut hashes = BTreeMap::new();
         let mut addresses = BTreeMap::new();
+        let mut equivocations = BTreeMap::new();
         let cursor = WriteTransaction::dup_cursor(txn, &self.history_store.hist_tx_table);
         debug!("Reading historic transactions.");
         for (epoch_number, hist_tx) in cursor.into_iter_start() {
             self.put_historic_tx(
                 &mut hashes,
                 &mut addresses,
+                &mut equivocations,
                 epoch_number,
                 hist_tx.index,
                 &hist_tx.value,
@@ -223,6 +312,16 @@ impl HistoryStoreIndex {
                 addresses_cursor.append(address, ordered_hash);
             }
         }
+
+        debug!("Writing equivocation index");
+        let mut equivocations_cursor = WriteTransaction::dup_cursor(txn, &self.equivocation_table);
+        for (epoch_number, leaf_indices) in equivocations.iter() {
+            for leaf_index in leaf_indices.iter() {
+                equivocations_cursor.append(epoch_number, leaf_index);
+            }
+        }
+
+        txn.put(&self.version_table, &(), &Self::VERSION);
     }
 }
 
@@ -316,6 +415,8 @@ impl HistoryInterface for HistoryStoreIndex {
         self.history_store.clear(txn);
         txn.clear_table(&self.tx_hash_table);
         txn.clear_table(&self.address_table);
+        txn.clear_table(&self.equivocation_table);
+        txn.clear_table(&self.observed_equivocation_table);
     }
 
     fn length_at(
@@ -348,6 +449,15 @@ impl HistoryInterface for HistoryStoreIndex {
             .tx_in_validity_window(raw_tx_hash, txn_opt)
     }
 
+    fn get_tx_block_number_in_validity_window(
+        &self,
+        raw_tx_hash: &RawTransactionHash,
+        txn_opt: Option<&MdbxReadTransaction>,
+    ) -> Option<u32> {
+        self.history_store
+            .get_tx_block_number_in_validity_window(raw_tx_hash, txn_opt)
+    }
+
     fn get_block_transactions(
         &self,
         block_number: u32,
@@ -471,30 +581,35 @@ impl HistoryInterface for HistoryStoreIndex {
             self.history_store
                 .put_historic_txns(txn, epoch_number, block_number, hist_txs)
         {
-            // Add the historic transactions into the respective database.
-            // Sort everything first and then put with a cursor for improved database performance.
-            let mut hashes = BTreeMap::new();
-            let mut addresses = BTreeMap::new();
-            for (tx, i) in hist_txs.iter().zip(leaf_idx.iter()) {
-                self.put_historic_tx(&mut hashes, &mut addresses, epoch_number, *i, tx);
-            }
-
-            // Put the hashes and addresses into the respective databases.
-            let mut hashes_cursor = WriteTransaction::cursor(txn, &self.tx_hash_table);
-            for (hash, key) in hashes.iter() {
-                hashes_cursor.put(hash, key);
-            }
-            let mut address_cursor = WriteTransaction::dup_cursor(txn, &self.address_table);
-            for (address, keys) in addresses.iter() {
-                for ordered_hash in keys.iter() {
-                    address_cursor.put(address, ordered_hash);
-                }
-            }
+            self.index_historic_txns(txn, epoch_number, hist_txs, &leaf_idx);
             return Some((root, size));
         }
         None
     }
 
+    /// Replaces the history of a past epoch with the given historic transactions. It returns the
+    /// root of the resulting tree.
+    /// Unlike `add_to_history_for_epoch`, this doesn't update the validity store.
+    fn add_past_epoch_history(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        hist_txs: &[HistoricTransaction],
+    ) -> Option<Blake2bHash> {
+        self.remove_history(txn, epoch_number);
+
+        let block_number = Policy::election_block_of(epoch_number)?;
+        let (root, _, leaf_idx) = self.history_store.put_historic_txns_with_validity(
+            txn,
+            epoch_number,
+            block_number,
+            hist_txs,
+            false,
+        )?;
+        self.index_historic_txns(txn, epoch_number, hist_txs, &leaf_idx);
+        Some(root)
+    }
+
     /// Removes a number of historic transactions from an existing history tree. It returns the root
     /// of the resulting tree and the total size of the transactions removed.
     fn remove_partial_history(
@@ -521,6 +636,7 @@ impl HistoryInterface for HistoryStoreIndex {
     /// Removes an existing history tree and all the historic transactions that were part of it.
     /// Returns None if there's no history tree corresponding to the given epoch number.
     fn remove_history(&self, txn: &mut MdbxWriteTransaction, epoch_number: u32) -> Option<()> {
+        txn.remove(&self.observed_equivocation_table, &epoch_number);
         let (_, leaf_indices) =
             self.history_store
                 .remove_leaves_from_history(txn, epoch_number, None)?;
@@ -614,6 +730,62 @@ impl HistoryIndexInterface for HistoryStoreIndex {
         self.history_store
             .prove_with_position(epoch_number, positions, verifier_state, txn_option)
     }
+
+    /// Returns all equivocation and punishment (penalize and jail) events of the given epoch,
+    /// ordered by their position in the history tree.
+    fn get_equivocation_events(
+        &self,
+        epoch_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Vec<HistoricTransaction> {
+        let txn = txn_option.or_new(&self.db);
+
+        let leaf_indices: Vec<u32> = txn
+            .dup_cursor(&self.equivocation_table)
+            .into_iter_dup_of(&epoch_number)
+            .map(|(_, leaf_index)| leaf_index)
+            .collect();
+
+        leaf_indices
+            .into_iter()
+            .filter_map(|leaf_index| {
+                self.history_store
+                    .get_historic_tx(epoch_number, leaf_index, Some(&txn))
+            })
+            .collect()
+    }
+
+    /// Records an equivocation proof observed by this node, e.g. in the equivocation proof pool.
+    /// Returns whether the proof wasn't recorded before.
+    fn put_observed_equivocation(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        locator: &EquivocationLocator,
+    ) -> bool {
+        let epoch_number = Policy::epoch_at(locator.block_number());
+        let is_observed = WriteTransaction::dup_cursor(txn, &self.observed_equivocation_table)
+            .into_iter_dup_of(&epoch_number)
+            .any(|(_, observed)| &observed == locator);
+        if is_observed {
+            return false;
+        }
+        txn.put(&self.observed_equivocation_table, &epoch_number, locator);
+        true
+    }
+
+    /// Returns the locators of all equivocation proofs observed by this node for equivocations
+    /// that occurred in the given epoch.
+    fn get_observed_equivocations(
+        &self,
+        epoch_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Vec<EquivocationLocator> {
+        let txn = txn_option.or_new(&self.db);
+        txn.dup_cursor(&self.observed_equivocation_table)
+            .into_iter_dup_of(&epoch_number)
+            .map(|(_, locator)| locator)
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -1237,6 +1409,99 @@ mod tests {
         assert_eq!(query_4.len(), 0);
     }
 
+    #[test]
+    fn get_equivocation_events_works() {
+        // Initialize History Store.
+        let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+        let history_store = HistoryStoreIndex::new(env.clone(), NetworkId::UnitAlbatross);
+
+        // Create historic transactions.
+        let hist_txs = gen_hist_txs();
+
+        // Add historic transactions to History Store.
+        let mut txn = env.write_transaction();
+        history_store.add_to_history(&mut txn, Policy::genesis_block_number() + 0, &hist_txs[..3]);
+        history_store.add_to_history(&mut txn, Policy::genesis_block_number() + 2, &hist_txs[3..]);
+
+        // Verify method works.
+        assert!(history_store
+            .get_equivocation_events(0, Some(&txn))
+            .is_empty());
+        assert_eq!(
+            history_store.get_equivocation_events(1, Some(&txn)),
+            hist_txs[8..].to_vec()
+        );
+
+        // Verify that the index is rebuilt correctly.
+        history_store.rebuild_index(&mut txn);
+        assert_eq!(
+            history_store.get_equivocation_events(1, Some(&txn)),
+            hist_txs[8..].to_vec()
+        );
+
+        // Remove historic transactions from History Store.
+        history_store.remove_partial_history(&mut txn, 1, 2);
+
+        // Verify method works.
+        assert_eq!(
+            history_store.get_equivocation_events(1, Some(&txn)),
+            hist_txs[8..9].to_vec()
+        );
+    }
+
+    #[test]
+    fn observed_equivocations_work() {
+        // Initialize History Store.
+        let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+        let history_store = HistoryStoreIndex::new(env.clone(), NetworkId::UnitAlbatross);
+
+        let locator = |block_number| {
+            EquivocationLocator::Fork(ForkLocator {
+                validator_address: Address::burn_address(),
+                block_number,
+            })
+        };
+        let epoch_1 = Policy::genesis_block_number() + 1;
+        let epoch_2 = Policy::genesis_block_number() + Policy::blocks_per_epoch() + 1;
+
+        // Record observed equivocations, each of them only once.
+        let mut txn = env.write_transaction();
+        assert!(history_store.put_observed_equivocation(&mut txn, &locator(epoch_1)));
+        assert!(history_store.put_observed_equivocation(&mut txn, &locator(epoch_1 + 1)));
+        assert!(history_store.put_observed_equivocation(&mut txn, &locator(epoch_2)));
+        assert!(!history_store.put_observed_equivocation(&mut txn, &locator(epoch_1)));
+
+        // Verify method works.
+        assert!(history_store
+            .get_observed_equivocations(0, Some(&txn))
+            .is_empty());
+        assert_eq!(
+            history_store.get_observed_equivocations(1, Some(&txn)),
+            vec![locator(epoch_1), locator(epoch_1 + 1)]
+        );
+        assert_eq!(
+            history_store.get_observed_equivocations(2, Some(&txn)),
+            vec![locator(epoch_2)]
+        );
+
+        // Observed equivocations are kept when the index is rebuilt.
+        history_store.rebuild_index(&mut txn);
+        assert_eq!(
+            history_store.get_observed_equivocations(2, Some(&txn)),
+            vec![locator(epoch_2)]
+        );
+
+        // Observed equivocations are removed together with the history of their epoch.
+        history_store.remove_history(&mut txn, 1);
+        assert!(history_store
+            .get_observed_equivocations(1, Some(&txn))
+            .is_empty());
+        assert_eq!(
+            history_store.get_observed_equivocations(2, Some(&txn)),
+            vec![locator(epoch_2)]
+        );
+    }
+
     #[test]
     fn prove_works() {
         // Initialize History Store.
@@ -176,6 +176,24 @@ impl HistoryInterface for HistoryStoreProxy {
         }
     }
 
+    /// Replaces the history of a past epoch with the given historic transactions. It returns the
+    /// root of the resulting tree.
+    fn add_past_epoch_history(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        hist_txs: &[HistoricTransaction],
+    ) -> Option<Blake2bHash> {
+        match self {
+            HistoryStoreProxy::WithIndex(index) => {
+                index.add_past_epoch_history(txn, epoch_number, hist_txs)
+            }
+            HistoryStoreProxy::WithoutIndex(store) => {
+                store.add_past_epoch_history(txn, epoch_number, hist_txs)
+            }
+        }
+    }
+
     /// Removes a number of historic transactions from an existing history tree. It returns the root
     /// of the resulting tree and the total size of of the transactions removed.
     fn remove_partial_history(
@@ -209,6 +227,21 @@ impl HistoryInterface for HistoryStoreProxy {
         }
     }
 
+    fn get_tx_block_number_in_validity_window(
+        &self,
+        raw_tx_hash: &RawTransactionHash,
+        txn_opt: Option<&MdbxReadTransaction>,
+    ) -> Option<u32> {
+        match self {
+            HistoryStoreProxy::WithIndex(index) => {
+                index.get_tx_block_number_in_validity_window(raw_tx_hash, txn_opt)
+            }
+            HistoryStoreProxy::WithoutIndex(store) => {
+                store.get_tx_block_number_in_validity_window(raw_tx_hash, txn_opt)
+            }
+        }
+    }
+
     /// Gets all historic transactions for a given block number.
     /// This method returns the transactions in the same order that they appear in the block.
     fn get_block_transactions(
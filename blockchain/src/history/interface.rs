@@ -89,6 +89,17 @@ pub trait HistoryInterface: std::fmt::Debug {
         hist_txs: &[HistoricTransaction],
     ) -> Option<(Blake2bHash, u64)>;
 
+    /// Replaces the history of a past epoch with the given historic transactions. It returns the
+    /// root of the resulting tree.
+    /// This is used to backfill the history of epochs that were pruned or never synced. Unlike
+    /// `add_to_history_for_epoch`, it doesn't update the validity store.
+    fn add_past_epoch_history(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        epoch_number: u32,
+        hist_txs: &[HistoricTransaction],
+    ) -> Option<Blake2bHash>;
+
     /// Removes a number of historic transactions from an existing history tree. It returns the root
     /// of the resulting tree and the total size of of the transactions removed.
     fn remove_partial_history(
@@ -104,6 +115,14 @@ pub trait HistoryInterface: std::fmt::Debug {
         txn_opt: Option<&MdbxReadTransaction>,
     ) -> bool;
 
+    /// Returns the number of the block that includes the given transaction, if it is recent
+    /// enough to still be kept for the validity window checks.
+    fn get_tx_block_number_in_validity_window(
+        &self,
+        raw_tx_hash: &RawTransactionHash,
+        txn_opt: Option<&MdbxReadTransaction>,
+    ) -> Option<u32>;
+
     /// Gets all historic transactions for a given block number.
     /// This method returns the transactions in the same order that they appear in the block.
     fn get_block_transactions(
@@ -214,4 +233,25 @@ pub trait HistoryIndexInterface {
         verifier_state: Option<usize>,
         txn_option: Option<&MdbxReadTransaction>,
     ) -> Option<HistoryTreeProof>;
+    /// Returns all equivocation and punishment (penalize and jail) events of the given epoch,
+    /// ordered by their position in the history tree.
+    fn get_equivocation_events(
+        &self,
+        epoch_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Vec<HistoricTransaction>;
+    /// Records an equivocation proof observed by this node, e.g. in the equivocation proof pool.
+    /// Returns whether the proof wasn't recorded before.
+    fn put_observed_equivocation(
+        &self,
+        txn: &mut MdbxWriteTransaction,
+        locator: &EquivocationLocator,
+    ) -> bool;
+    /// Returns the locators of all equivocation proofs observed by this node for equivocations
+    /// that occurred in the given epoch.
+    fn get_observed_equivocations(
+        &self,
+        epoch_number: u32,
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> Vec<EquivocationLocator>;
 }
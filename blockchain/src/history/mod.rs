@@ -9,4 +9,4 @@ mod history_tree_chunk;
 pub mod interface;
 mod mmr_store;
 mod utils;
-mod validity_store;
+pub(crate) mod validity_store;
@@ -6,6 +6,8 @@ use nimiq_database::{
 use nimiq_primitives::policy::Policy;
 use nimiq_transaction::historic_transaction::RawTransactionHash;
 
+use crate::interface::HistoryInterface;
+
 // `RawTransactionHash` -> `u32` (block number)
 declare_table!(TxnHashesTable, "ValidityTxnHashes", RawTransactionHash => u32);
 // `u32` (block number) -> `RawTransactionHash`
@@ -66,6 +68,17 @@ impl ValidityStore {
         false
     }
 
+    /// Returns the number of the block that includes the given transaction, if the validity
+    /// store still has it.
+    pub(crate) fn get_block_number(
+        &self,
+        txn_option: Option<&MdbxReadTransaction>,
+        raw_tx_hash: &RawTransactionHash,
+    ) -> Option<u32> {
+        let txn = txn_option.or_new(&self.db);
+        txn.get(&self.txn_hashes, raw_tx_hash)
+    }
+
     /// Returns the first block number stored in the validity store
     pub(crate) fn first_bn(&self, db_tx: &MdbxReadTransaction) -> u32 {
         // Initialize the cursor for the database.
@@ -165,4 +178,30 @@ impl ValidityStore {
 
         self.prune_validity_store(db_txn)
     }
+
+    /// Rebuilds the validity store from the transactions in the history store, keeping
+    /// `validity_window_blocks + blocks_per_batch` blocks up to `latest_bn`.
+    pub(crate) fn rebuild(
+        &self,
+        db_txn: &mut MdbxWriteTransaction,
+        history_store: &dyn HistoryInterface,
+        latest_bn: u32,
+    ) {
+        db_txn.clear_table(&self.txn_hashes);
+        db_txn.clear_table(&self.block_txns);
+
+        let num_blocks_to_keep =
+            Policy::transaction_validity_window_blocks() + Policy::blocks_per_batch();
+        let first_bn = latest_bn
+            .saturating_sub(num_blocks_to_keep - 1)
+            .max(Policy::genesis_block_number());
+
+        for block_number in first_bn..=latest_bn {
+            for hist_tx in history_store.get_block_transactions(block_number, Some(db_txn)) {
+                self.add_transaction(db_txn, hist_tx.block_number, hist_tx.tx_hash());
+            }
+        }
+
+        self.update_validity_store(db_txn, latest_bn);
+    }
 }
@@ -2,14 +2,22 @@
 extern crate log;
 
 pub use block_production::{BlockProducer, BlockProducerError};
-pub use blockchain::blockchain::{Blockchain, BlockchainConfig, TransactionVerificationCache};
+pub use blockchain::{
+    accounts::{AccountsPage, AccountsTreeItem},
+    blockchain::{Blockchain, BlockchainConfig, TransactionVerificationCache},
+};
+pub use chain_store::{BurnedSupply, StakeSnapshot};
 pub use history::*;
 
+pub mod block_file;
 pub(crate) mod block_production;
 pub(crate) mod blockchain;
 pub(crate) mod blockchain_state;
 #[cfg(feature = "metrics")]
 pub mod chain_metrics;
 pub(crate) mod chain_store;
+pub mod consistency;
 pub(crate) mod history;
 pub mod reward;
+pub mod signaling;
+pub mod state_reader;
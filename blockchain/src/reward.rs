@@ -28,6 +28,20 @@ pub fn batch_delay(previous_timestamp: u64, current_timestamp: u64) -> u64 {
     current_timestamp.saturating_sub(target_ts)
 }
 
+/// The block reward for a batch, broken down into the supply increase allowed for the batch and
+/// the penalty for producing the batch late.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchReward {
+    /// The maximum reward for the batch, i.e. the increase of the supply since the previous batch.
+    pub max_reward: Coin,
+    /// The delay producing the batch (in ms).
+    pub batch_delay: u64,
+    /// The share of the maximum reward that is paid out given the delay, in the range [0, 1].
+    pub batch_delay_penalty: f64,
+    /// The reward that is paid out for the batch.
+    pub reward: Coin,
+}
+
 /// Compute the block reward for a batch from the current macro block, the previous macro block,
 /// and the genesis parameters.
 /// This does not include the reward from transaction fees.
@@ -37,6 +51,23 @@ pub fn block_reward_for_batch(
     genesis_supply: Coin,
     genesis_timestamp: u64,
 ) -> Coin {
+    batch_reward(
+        current_block,
+        previous_macro,
+        genesis_supply,
+        genesis_timestamp,
+    )
+    .reward
+}
+
+/// Compute the block reward for a batch like [`block_reward_for_batch`], but returns it broken
+/// down into its components.
+pub fn batch_reward(
+    current_block: &MacroHeader,
+    previous_macro: &MacroHeader,
+    genesis_supply: Coin,
+    genesis_timestamp: u64,
+) -> BatchReward {
     let current_timestamp = current_block.timestamp;
 
     let previous_timestamp = previous_macro.timestamp;
@@ -69,7 +100,32 @@ pub fn block_reward_for_batch(
 
     // The final rewards that are given are a percentage based on the penalty (if any) for not producing blocks in time.
     // i.e.: batch_delay_penalty returns a number in the range [0, 1]
-    Coin::from_u64_unchecked((max_rewards as f64 * batch_delay_penalty) as u64)
+    BatchReward {
+        max_reward: Coin::from_u64_unchecked(max_rewards),
+        batch_delay,
+        batch_delay_penalty,
+        reward: Coin::from_u64_unchecked((max_rewards as f64 * batch_delay_penalty) as u64),
+    }
+}
+
+/// Splits the reward of a validator into the commission it keeps and the share that is distributed
+/// to the stakers delegating to it. The commission is given in basis points, see
+/// [`Policy::MAX_COMMISSION`]. If there is no stake delegated to the validator, it keeps the whole
+/// reward.
+pub fn split_validator_reward(
+    reward: Coin,
+    commission: u16,
+    delegated_stake: Coin,
+) -> (Coin, Coin) {
+    if delegated_stake.is_zero() {
+        return (reward, Coin::ZERO);
+    }
+
+    let commission =
+        u128::from(u64::from(reward)) * u128::from(commission) / u128::from(Policy::MAX_COMMISSION);
+    let commission = Coin::from_u64_unchecked(commission as u64).min(reward);
+
+    (commission, reward - commission)
 }
 
 /// Compute the block reward for a batch from the current macro block, the previous macro block,
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_hash::Blake2bHash;
+use nimiq_primitives::policy::Policy;
+
+use crate::Blockchain;
+
+/// The number of slots of the current validators signaling each `signal_data` value.
+///
+/// Validators can set an arbitrary `signal_data` hash to announce that they are ready for a
+/// coordinated upgrade. Each validator counts with the number of slots it was assigned in the
+/// current election, so that a signal reaching e.g. `Policy::TWO_F_PLUS_ONE` slots is supported
+/// by a supermajority of the current validator set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignalTally {
+    /// The block number of the head when the tally was taken.
+    pub block_number: u32,
+    /// The number of slots signaling each value.
+    pub signals: BTreeMap<Blake2bHash, u16>,
+    /// The number of slots of validators that don't signal anything.
+    pub unsignaled_slots: u16,
+}
+
+impl SignalTally {
+    /// Returns the number of slots signaling the given value.
+    pub fn slots(&self, signal: &Blake2bHash) -> u16 {
+        self.signals.get(signal).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of slots that signal any value.
+    pub fn signaled_slots(&self) -> u16 {
+        Policy::SLOTS - self.unsignaled_slots
+    }
+
+    /// Returns the signal with the most slots together with its number of slots.
+    pub fn leading_signal(&self) -> Option<(&Blake2bHash, u16)> {
+        self.signals
+            .iter()
+            .max_by_key(|(_, slots)| **slots)
+            .map(|(signal, slots)| (signal, *slots))
+    }
+
+    /// Returns all signals that reach one of the given thresholds in this tally but didn't reach
+    /// it in the `previous` tally.
+    pub fn crossed_thresholds(
+        &self,
+        previous: &SignalTally,
+        thresholds: &[u16],
+    ) -> Vec<SignalThresholdCrossed> {
+        let mut crossed = vec![];
+        for (signal, slots) in &self.signals {
+            let previous_slots = previous.slots(signal);
+            for threshold in thresholds {
+                if *slots >= *threshold && previous_slots < *threshold {
+                    crossed.push(SignalThresholdCrossed {
+                        signal: signal.clone(),
+                        threshold: *threshold,
+                        slots: *slots,
+                        previous_slots,
+                        block_number: self.block_number,
+                    });
+                }
+            }
+        }
+        crossed
+    }
+}
+
+/// Emitted at an election block when a signal reached a configured threshold for the first time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignalThresholdCrossed {
+    /// The signal that crossed the threshold.
+    pub signal: Blake2bHash,
+    /// The threshold that was crossed, in slots.
+    pub threshold: u16,
+    /// The number of slots signaling the value at the election block.
+    pub slots: u16,
+    /// The number of slots signaling the value at the previous election block.
+    pub previous_slots: u16,
+    /// The election block at which the threshold was crossed.
+    pub block_number: u32,
+}
+
+impl Blockchain {
+    /// Tallies the `signal_data` of the current validators, weighted by their slots. Returns
+    /// `None` if the staking contract isn't complete, e.g. while the accounts are still syncing.
+    pub fn tally_signals(&self) -> Option<SignalTally> {
+        let validators = self.current_validators()?;
+        let staking_contract = self.get_staking_contract_if_complete(None)?;
+        let data_store = self.get_staking_contract_store();
+        let txn = self.read_transaction();
+        let data_store = data_store.read(&txn);
+
+        let mut tally = SignalTally {
+            block_number: self.block_number(),
+            ..Default::default()
+        };
+        for validator in validators.iter() {
+            let num_slots = validator.num_slots();
+            let signal_data = staking_contract
+                .get_validator(&data_store, &validator.address)
+                .and_then(|validator| validator.signal_data);
+            match signal_data {
+                Some(signal) => *tally.signals.entry(signal).or_default() += num_slots,
+                None => tally.unsignaled_slots += num_slots,
+            }
+        }
+        Some(tally)
+    }
+
+    /// Returns the tally taken at the last election block, or at startup if no election block
+    /// was pushed since. Unlike `tally_signals`, this doesn't read the staking contract.
+    pub fn signal_tally(&self) -> Option<SignalTally> {
+        self.signal_tally.lock().clone()
+    }
+
+    /// Takes a new tally at an election block and notifies the signals that crossed one of the
+    /// configured thresholds since the previous election block.
+    pub(crate) fn update_signal_tally(&self) {
+        let Some(tally) = self.tally_signals() else {
+            return;
+        };
+
+        let mut last_tally = self.signal_tally.lock();
+        if let Some(previous) = last_tally.as_ref() {
+            for event in tally.crossed_thresholds(previous, &self.config.signal_thresholds) {
+                info!(
+                    signal = %event.signal,
+                    threshold = event.threshold,
+                    slots = event.slots,
+                    block_number = event.block_number,
+                    "Validator signal crossed threshold",
+                );
+                // We shouldn't log errors if there are no listeners.
+                self.signal_notifier.send(event).ok();
+            }
+        }
+        *last_tally = Some(tally);
+    }
+}
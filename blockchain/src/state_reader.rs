@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use nimiq_account::{Accounts, BlockLogger, BlockState};
+use nimiq_block::{Block, MacroBlock};
+use nimiq_blockchain_interface::BlockchainError;
+use nimiq_database::{
+    mdbx::{MdbxDatabase, MdbxReadTransaction},
+    traits::{Database, WriteTransaction},
+};
+use nimiq_hash::Blake2bHash;
+use nimiq_primitives::{
+    key_nibbles::KeyNibbles,
+    networks::NetworkId,
+    policy::Policy,
+    slots_allocation::{JailedValidator, PenalizedSlot},
+};
+use nimiq_transaction::{historic_transaction::HistoricTransactionData, inherent::Inherent};
+use thiserror::Error;
+
+use crate::{
+    chain_store::ChainStore, history::HistoryStore, history_store_proxy::HistoryStoreProxy,
+    interface::HistoryInterface,
+};
+
+/// Number of items of the accounts tree that are copied at once.
+const COPY_CHUNK_SIZE: usize = 10_000;
+
+/// An error reading the state of a node's database.
+#[derive(Debug, Error)]
+pub enum StateReaderError {
+    #[error("No head block is stored")]
+    MissingHead,
+    #[error("The state at block {0} is not available, the latest macro block is {1}")]
+    UnavailableBlock(u32, u32),
+    #[error("The accounts tree is incomplete")]
+    IncompleteAccounts,
+    #[error("Failed to read a block: {0}")]
+    Blockchain(#[from] BlockchainError),
+    #[error("Missing revert info of block {0}")]
+    MissingRevertInfo(u32),
+    #[error("Failed to revert block {0}: {1}")]
+    RevertBlock(u32, String),
+    #[error("The state doesn't match the state root of block {0}")]
+    StateRootMismatch(u32),
+    #[error("Database error: {0}")]
+    Database(#[from] nimiq_database::Error),
+}
+
+/// Reads the chain and the accounts of a stopped node's database without modifying it. Unlike
+/// [`Blockchain::new`](crate::Blockchain::new), it neither initializes the database nor rebuilds
+/// any indices, so it works on databases opened with [`MdbxDatabase::new_read_only`].
+pub struct StateReader {
+    db: MdbxDatabase,
+    chain_store: ChainStore,
+    history_store: Arc<HistoryStoreProxy>,
+}
+
+impl StateReader {
+    pub fn new(db: MdbxDatabase, network_id: NetworkId) -> Self {
+        // The history index is not needed to read blocks.
+        let history_store = Arc::new(HistoryStoreProxy::WithoutIndex(Box::new(HistoryStore::new(
+            db.clone(),
+            network_id,
+        ))
+            as Box<dyn HistoryInterface + Sync + Send>));
+
+        StateReader {
+            chain_store: ChainStore::new(db.clone(), Arc::clone(&history_store)),
+            history_store,
+            db,
+        }
+    }
+
+    /// Returns the given macro block of the main chain together with the accounts at that block.
+    /// Defaults to the latest macro block. Older macro blocks are not available, since their
+    /// state is not stored.
+    ///
+    /// If the head of the chain is a micro block, its changes and those of its predecessors are
+    /// reverted on a copy of the accounts tree in a volatile database.
+    pub fn read_macro_state(
+        &self,
+        block_number: Option<u32>,
+    ) -> Result<(MacroBlock, Accounts), StateReaderError> {
+        let txn = self.db.read_transaction();
+        let head_hash = self
+            .chain_store
+            .get_head(Some(&txn))
+            .ok_or(StateReaderError::MissingHead)?;
+        let head = self.chain_store.get_block(&head_hash, false, Some(&txn))?;
+
+        let macro_block_number = Policy::last_macro_block(head.block_number());
+        let block_number = block_number.unwrap_or(macro_block_number);
+        if block_number != macro_block_number {
+            return Err(StateReaderError::UnavailableBlock(
+                block_number,
+                macro_block_number,
+            ));
+        }
+        let macro_block = self
+            .chain_store
+            .get_block_at(block_number, true, Some(&txn))?
+            .unwrap_macro();
+
+        let accounts = Accounts::new(self.db.clone());
+        if !accounts.is_complete(Some(&txn)) {
+            return Err(StateReaderError::IncompleteAccounts);
+        }
+        let accounts = if head.is_macro() {
+            accounts
+        } else {
+            let copy = copy_accounts(&accounts, &txn)?;
+            self.revert_micro_blocks(&copy, &head_hash, block_number, &txn)?;
+            copy
+        };
+
+        if accounts.get_root_hash(None).as_ref() != Some(&macro_block.header.state_root) {
+            return Err(StateReaderError::StateRootMismatch(block_number));
+        }
+
+        Ok((macro_block, accounts))
+    }
+
+    /// Reverts the micro blocks from the given head down to the given macro block on the given
+    /// accounts.
+    fn revert_micro_blocks(
+        &self,
+        accounts: &Accounts,
+        head_hash: &Blake2bHash,
+        macro_block_number: u32,
+        txn: &MdbxReadTransaction,
+    ) -> Result<(), StateReaderError> {
+        let mut hash = head_hash.clone();
+        let mut write_txn = accounts.env.write_transaction();
+        loop {
+            let block = self.chain_store.get_block(&hash, true, Some(txn))?;
+            if block.block_number() == macro_block_number {
+                break;
+            }
+            let Block::Micro(ref micro_block) = block else {
+                unreachable!("Only micro blocks follow the latest macro block");
+            };
+            let block_number = block.block_number();
+
+            let revert_info = self
+                .chain_store
+                .get_revert_info(block_number, Some(txn))
+                .ok_or(StateReaderError::MissingRevertInfo(block_number))?;
+            let transactions = micro_block
+                .body
+                .as_ref()
+                .map(|body| body.get_raw_transactions())
+                .unwrap_or_default();
+            let inherents = self.punishment_inherents(block_number, txn);
+
+            accounts
+                .revert(
+                    &mut (&mut write_txn).into(),
+                    &transactions,
+                    &inherents,
+                    &BlockState::new(block_number, block.timestamp()),
+                    revert_info,
+                    &mut BlockLogger::empty_reverted(),
+                )
+                .map_err(|error| StateReaderError::RevertBlock(block_number, error.to_string()))?;
+
+            hash = block.parent_hash().clone();
+        }
+        write_txn.commit();
+
+        Ok(())
+    }
+
+    /// Recovers the punishment inherents of a micro block from the history, in the order they
+    /// were applied.
+    fn punishment_inherents(&self, block_number: u32, txn: &MdbxReadTransaction) -> Vec<Inherent> {
+        self.history_store
+            .get_block_transactions(block_number, Some(txn))
+            .into_iter()
+            .filter_map(|hist_tx| match hist_tx.data {
+                HistoricTransactionData::Penalize(event) => Some(Inherent::Penalize {
+                    slot: PenalizedSlot {
+                        slot: event.slot,
+                        validator_address: event.validator_address,
+                        offense_event_block: event.offense_event_block,
+                    },
+                }),
+                HistoricTransactionData::Jail(event) => Some(Inherent::Jail {
+                    jailed_validator: JailedValidator {
+                        slots: event.slots,
+                        validator_address: event.validator_address,
+                        offense_event_block: event.offense_event_block,
+                    },
+                    new_epoch_slot_range: event.new_epoch_slot_range,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Copies the complete accounts tree into a volatile database.
+fn copy_accounts(
+    accounts: &Accounts,
+    txn: &MdbxReadTransaction,
+) -> Result<Accounts, StateReaderError> {
+    let mut items = vec![];
+    let mut start = Some(KeyNibbles::ROOT);
+    while let Some(start_key) = start {
+        let chunk = accounts.get_chunk(start_key, COPY_CHUNK_SIZE, Some(txn));
+        start = chunk.end_key;
+        items.extend(chunk.items);
+    }
+
+    let copy = Accounts::new(MdbxDatabase::new_volatile(Default::default())?);
+    let mut write_txn = copy.env.write_transaction();
+    copy.init(&mut (&mut write_txn).into(), items);
+    write_txn.commit();
+
+    Ok(copy)
+}
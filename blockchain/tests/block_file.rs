@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use nimiq_blockchain::{
+    block_file::{export_blocks, import_blocks, BlockFileError, BlockFileReader, BlockFileWriter},
+    BlockProducer, Blockchain, BlockchainConfig,
+};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_primitives::{networks::NetworkId, policy::Policy};
+use nimiq_test_log::test;
+use nimiq_test_utils::blockchain::{produce_macro_blocks, signing_key, voting_key};
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+fn new_blockchain() -> Arc<RwLock<Blockchain>> {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            Arc::new(OffsetTime::new()),
+        )
+        .unwrap(),
+    ))
+}
+
+fn export_all(blockchain: &Blockchain) -> Vec<u8> {
+    let mut writer = BlockFileWriter::new(vec![], blockchain.network_id()).unwrap();
+    let num_blocks = export_blocks(blockchain, &mut writer, 0, u32::MAX).unwrap();
+    assert_eq!(
+        num_blocks,
+        blockchain.block_number() - Policy::genesis_block_number()
+    );
+    assert_eq!(writer.num_blocks(), num_blocks);
+    writer.finish().unwrap()
+}
+
+#[test]
+fn can_export_and_import_blocks() {
+    let source = new_blockchain();
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks(&producer, &source, Policy::batches_per_epoch() as usize + 1);
+
+    let file = export_all(&source.read());
+
+    let target = new_blockchain();
+    let reader = BlockFileReader::new(&file[..]).unwrap();
+    assert_eq!(reader.network_id(), NetworkId::UnitAlbatross);
+    let stats = import_blocks(&target, reader).unwrap();
+    assert_eq!(stats.known, 0);
+    assert_eq!(
+        stats.imported,
+        source.read().block_number() - Policy::genesis_block_number()
+    );
+    assert_eq!(target.read().head_hash(), source.read().head_hash());
+
+    // Importing the same blocks again doesn't change anything.
+    let stats = import_blocks(&target, BlockFileReader::new(&file[..]).unwrap()).unwrap();
+    assert_eq!(stats.imported, 0);
+    assert_eq!(target.read().head_hash(), source.read().head_hash());
+}
+
+#[test]
+fn can_export_block_range() {
+    let source = new_blockchain();
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks(&producer, &source, 2);
+
+    let from = Policy::genesis_block_number() + 1;
+    let to = Policy::macro_block_after(from);
+    let mut writer = BlockFileWriter::new(vec![], NetworkId::UnitAlbatross).unwrap();
+    assert_eq!(
+        export_blocks(&source.read(), &mut writer, from, to).unwrap(),
+        to - from + 1
+    );
+    let file = writer.finish().unwrap();
+
+    let block_numbers: Vec<u32> = BlockFileReader::new(&file[..])
+        .unwrap()
+        .map(|block| block.unwrap().block_number())
+        .collect();
+    assert_eq!(block_numbers, (from..=to).collect::<Vec<_>>());
+
+    // The imported blocks end with the first macro block.
+    let target = new_blockchain();
+    import_blocks(&target, BlockFileReader::new(&file[..]).unwrap()).unwrap();
+    assert_eq!(target.read().block_number(), to);
+    assert_eq!(target.read().macro_head().block_number(), to);
+}
+
+#[test]
+fn rejects_invalid_block_files() {
+    assert!(matches!(
+        BlockFileReader::new(&b"NOPE\x01\x05"[..]),
+        Err(BlockFileError::InvalidMagic)
+    ));
+
+    let mut writer = BlockFileWriter::new(vec![], NetworkId::TestAlbatross).unwrap();
+    writer.write_block(&new_blockchain().read().head()).unwrap();
+    let file = writer.finish().unwrap();
+    assert!(matches!(
+        import_blocks(&new_blockchain(), BlockFileReader::new(&file[..]).unwrap()),
+        Err(BlockFileError::WrongNetwork { .. })
+    ));
+
+    // A truncated block is reported instead of being silently dropped.
+    let source = new_blockchain();
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks(&producer, &source, 1);
+    let file = export_all(&source.read());
+    let mut reader = BlockFileReader::new(&file[..file.len() - 1]).unwrap();
+    assert!(reader.any(|block| matches!(block, Err(BlockFileError::Io(_)))));
+}
@@ -959,6 +959,7 @@ fn it_can_consume_all_validator_deposit() {
         &voting_key_pair,
         Address::from([0u8; 20]),
         None,
+        Policy::MAX_COMMISSION,
         Coin::ZERO,
         blockchain.read().block_number() + 1,
         NetworkId::UnitAlbatross,
@@ -1178,6 +1179,7 @@ fn it_can_revert_failed_delete_validator() {
         &voting_key_pair,
         Address::from([0u8; 20]),
         None,
+        Policy::MAX_COMMISSION,
         Coin::ZERO,
         blockchain.read().block_number() + 1,
         NetworkId::UnitAlbatross,
@@ -1,14 +1,16 @@
 use std::sync::Arc;
 
 use nimiq_block::{Block, BlockError};
-use nimiq_blockchain::Blockchain;
+use nimiq_blockchain::{AccountsTreeItem, Blockchain};
 use nimiq_blockchain_interface::{AbstractBlockchain, PushError, PushResult};
 use nimiq_hash::Hash;
-use nimiq_primitives::policy::Policy;
+use nimiq_keys::Address;
+use nimiq_primitives::{account::AccountType, key_nibbles::KeyNibbles, policy::Policy};
 use nimiq_tendermint::ProposalMessage;
 use nimiq_test_log::test;
 use nimiq_test_utils::{
     block_production::TemporaryBlockProducer,
+    blockchain::validator_address,
     test_custom_block::{finalize_macro_block, next_macro_block_proposal},
 };
 
@@ -169,3 +171,82 @@ fn can_detect_invalid_punished_set() {
         Err(PushError::InvalidBlock(BlockError::InvalidValidators))
     );
 }
+
+#[test]
+fn can_page_through_accounts() {
+    let temp_producer = TemporaryBlockProducer::new();
+    let blockchain = temp_producer.blockchain.read();
+    let txn = blockchain.read_transaction();
+
+    let all_accounts = blockchain
+        .get_accounts_chunk(Some(&txn), KeyNibbles::ROOT, 10_000)
+        .accounts;
+    assert!(all_accounts.len() > 1);
+
+    // Page through all items with a small page size and scan limit.
+    let mut items = vec![];
+    let mut start = Some(KeyNibbles::ROOT);
+    while let Some(start_key) = start {
+        let page = blockchain.get_accounts_page(&txn, start_key, 2, 3, |_| true);
+        assert!(page.items.len() <= 2);
+        items.extend(page.items);
+        start = page.end_key;
+    }
+    let accounts: Vec<_> = items
+        .iter()
+        .filter_map(|item| match item {
+            AccountsTreeItem::Account(address, account) => Some((address.clone(), account.clone())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(accounts, all_accounts);
+
+    // Only the staking contract is a staking account.
+    let page = blockchain.get_accounts_page(&txn, KeyNibbles::ROOT, 10, usize::MAX, |item| {
+        matches!(
+            item,
+            AccountsTreeItem::Account(_, account) if account.account_type() == AccountType::Staking
+        )
+    });
+    assert_eq!(page.end_key, None);
+    assert_eq!(page.items.len(), 1);
+    assert!(matches!(
+        &page.items[0],
+        AccountsTreeItem::Account(address, _) if *address == Policy::STAKING_CONTRACT_ADDRESS
+    ));
+}
+
+#[test]
+fn can_page_through_validators_and_stakers() {
+    let temp_producer = TemporaryBlockProducer::new();
+    let blockchain = temp_producer.blockchain.read();
+    let txn = blockchain.read_transaction();
+
+    // The validator and its staker in `genesis/src/genesis/unit-albatross.toml`.
+    let mut validators = vec![];
+    let mut stakers = vec![];
+    let mut start = Some(KeyNibbles::ROOT);
+    while let Some(start_key) = start {
+        let page = blockchain.get_accounts_page(&txn, start_key, 1, 2, |item| {
+            !matches!(item, AccountsTreeItem::Account(..))
+        });
+        for item in page.items {
+            match item {
+                AccountsTreeItem::Validator(validator) => validators.push(validator),
+                AccountsTreeItem::Staker(staker) => stakers.push(staker),
+                AccountsTreeItem::Account(..) => panic!("Accounts are filtered out"),
+            }
+        }
+        start = page.end_key;
+    }
+
+    assert_eq!(validators.len(), 1);
+    assert_eq!(validators[0].address, validator_address());
+    assert_eq!(stakers.len(), 1);
+    assert_eq!(
+        stakers[0].address,
+        Address::from_user_friendly_address("NQ39 VBTN P2HX Q3MF KHF1 CCLA G6FS 9B8S VY28")
+            .unwrap()
+    );
+    assert_eq!(stakers[0].delegation, Some(validator_address()));
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use nimiq_account::{Account, BasicAccount};
+use nimiq_block::Block;
+use nimiq_blockchain::{
+    consistency::{ConsistencyChecker, ConsistencyReport, Inconsistency, Repair, RepairError},
+    BlockProducer, Blockchain, BlockchainConfig,
+};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_database::{
+    declare_table,
+    mdbx::MdbxDatabase,
+    traits::{Database, WriteTransaction},
+};
+use nimiq_hash::Blake2bHash;
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, key_nibbles::KeyNibbles, networks::NetworkId};
+use nimiq_test_log::test;
+use nimiq_test_utils::blockchain::{
+    produce_macro_blocks, push_micro_block, signing_key, voting_key,
+};
+use nimiq_transaction::historic_transaction::RawTransactionHash;
+use nimiq_trie::WriteTransactionProxy;
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+declare_table!(HeightIndex, "HeightIndex", u32 => dup(Blake2bHash));
+declare_table!(ValidityBlockTxnsTable, "ValidityBlockTxnHashes", u32 => dup(RawTransactionHash));
+declare_table!(PushedBlockTable, "PushedBlockTable", Blake2bHash => Vec<u8>);
+
+const NUM_MICRO_BLOCKS: u32 = 3;
+
+fn setup_blockchain() -> (MdbxDatabase, Arc<RwLock<Blockchain>>, Block) {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let blockchain = Arc::new(RwLock::new(
+        Blockchain::new(
+            env.clone(),
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            Arc::new(OffsetTime::new()),
+        )
+        .unwrap(),
+    ));
+    let producer = BlockProducer::new(signing_key(), voting_key());
+
+    produce_macro_blocks(&producer, &blockchain, 1);
+    let macro_head = blockchain.read().head().clone();
+    for _ in 0..NUM_MICRO_BLOCKS {
+        push_micro_block(&producer, &blockchain);
+    }
+
+    (env, blockchain, macro_head)
+}
+
+#[test]
+fn consistent_database_passes_check() {
+    let (env, blockchain, macro_head) = setup_blockchain();
+    let head = blockchain.read().head().clone();
+
+    let checker = ConsistencyChecker::new(env, NetworkId::UnitAlbatross, true);
+    let report = checker.check();
+
+    assert!(report.is_consistent(), "{report}");
+    assert_eq!(report.head, Some((head.block_number(), head.hash())));
+    assert_eq!(
+        report.last_consistent_macro_block,
+        Some((macro_head.block_number(), macro_head.hash()))
+    );
+}
+
+#[test]
+fn repairs_inconsistent_entries_in_place() {
+    let (env, blockchain, macro_head) = setup_blockchain();
+    let head = blockchain.read().head().clone();
+    let head_number = head.block_number();
+
+    // Add a dangling entry to the height index, drop the head from the validity store and unlink
+    // the head from its predecessor.
+    let mut txn = env.write_transaction();
+    txn.put(&HeightIndex, &head_number, &Blake2bHash::default());
+    txn.remove(&ValidityBlockTxnsTable, &head_number);
+    let parent_hash = head.parent_hash().clone();
+    let mut parent_info = blockchain
+        .read()
+        .chain_store
+        .get_chain_info(&parent_hash, false, Some(&txn))
+        .unwrap();
+    parent_info.main_chain_successor = None;
+    blockchain
+        .read()
+        .chain_store
+        .put_chain_info(&mut txn, &parent_hash, &parent_info, false);
+    txn.commit();
+
+    let checker = ConsistencyChecker::new(env.clone(), NetworkId::UnitAlbatross, true);
+    let report = checker.check();
+
+    assert!(report
+        .inconsistencies
+        .contains(&Inconsistency::DanglingHeightIndexEntry {
+            block_number: head_number,
+            hash: Blake2bHash::default(),
+        }));
+    assert!(report
+        .inconsistencies
+        .contains(&Inconsistency::InvalidMainChainSuccessor {
+            block_number: head_number - 1,
+            hash: parent_hash.clone(),
+            successor: Some(head.hash()),
+        }));
+    assert!(report
+        .inconsistencies
+        .iter()
+        .any(|inconsistency| matches!(inconsistency, Inconsistency::ValidityStoreMismatch(_))));
+    assert_eq!(
+        report.last_consistent_macro_block,
+        Some((macro_head.block_number(), macro_head.hash()))
+    );
+
+    let repairs = checker.repair(&report).unwrap();
+    assert_eq!(
+        repairs,
+        vec![
+            Repair::RemovedHeightIndexEntry {
+                block_number: head_number,
+                hash: Blake2bHash::default(),
+            },
+            Repair::RepairedChainInfo {
+                block_number: head_number - 1,
+                hash: parent_hash,
+            },
+            Repair::RebuiltValidityStore,
+        ]
+    );
+
+    // The dangling entry is gone while the head and its entry are untouched.
+    let hashes = blockchain
+        .read()
+        .chain_store
+        .get_block_hashes_at(head_number, None);
+    assert_eq!(hashes, vec![head.hash()]);
+
+    let report = checker.check();
+    assert!(report.is_consistent(), "{report}");
+    assert_eq!(report.head, Some((head_number, head.hash())));
+}
+
+#[test]
+fn does_not_repair_missing_blocks() {
+    let (env, blockchain, _) = setup_blockchain();
+    let head = blockchain.read().head().clone();
+    let parent_hash = head.parent_hash().clone();
+
+    let mut txn = env.write_transaction();
+    txn.put(&HeightIndex, &head.block_number(), &Blake2bHash::default());
+    blockchain.read().chain_store.remove_chain_info(
+        &mut txn,
+        &parent_hash,
+        head.block_number() - 1,
+    );
+    txn.commit();
+
+    let checker = ConsistencyChecker::new(env, NetworkId::UnitAlbatross, true);
+    let report = checker.check();
+    let missing_chain_info = Inconsistency::MissingChainInfo {
+        block_number: head.block_number() - 1,
+        hash: parent_hash,
+    };
+    assert!(report.inconsistencies.contains(&missing_chain_info));
+
+    assert!(matches!(
+        checker.repair(&report),
+        Err(RepairError::Unrepairable(inconsistency)) if inconsistency == missing_chain_info
+    ));
+
+    // Nothing is changed if the report contains inconsistencies that can't be repaired.
+    assert_eq!(checker.check().inconsistencies, report.inconsistencies);
+}
+
+/// Repairs the database and checks that it was reverted to the given macro block.
+fn assert_repair_reverts_to(
+    env: MdbxDatabase,
+    checker: &ConsistencyChecker,
+    report: &ConsistencyReport,
+    macro_head: &Block,
+) {
+    let repairs = checker.repair(report).unwrap();
+    assert_eq!(
+        repairs,
+        vec![
+            Repair::RevertedToMacroBlock {
+                block_number: macro_head.block_number(),
+                num_blocks: NUM_MICRO_BLOCKS,
+            },
+            Repair::ReinitializedAccounts,
+            Repair::RebuiltValidityStore,
+            Repair::RebuiltHistoryIndex,
+        ]
+    );
+
+    let report = checker.check();
+    assert!(report.is_consistent(), "{report}");
+    assert_eq!(
+        report.head,
+        Some((macro_head.block_number(), macro_head.hash()))
+    );
+
+    // The repaired database can be loaded and needs to sync the accounts again.
+    let blockchain = Blockchain::new(
+        env,
+        BlockchainConfig::default(),
+        NetworkId::UnitAlbatross,
+        Arc::new(OffsetTime::new()),
+    )
+    .unwrap();
+    assert_eq!(blockchain.head_hash(), macro_head.hash());
+    assert!(!blockchain.state.accounts.is_complete(None));
+}
+
+#[test]
+fn reverts_blocks_with_missing_bodies() {
+    let (env, blockchain, macro_head) = setup_blockchain();
+    let head = blockchain.read().head().clone();
+    drop(blockchain);
+
+    let mut txn = env.write_transaction();
+    txn.remove(&PushedBlockTable, head.parent_hash());
+    txn.commit();
+
+    let checker = ConsistencyChecker::new(env.clone(), NetworkId::UnitAlbatross, true);
+    let report = checker.check();
+    assert_eq!(
+        report.inconsistencies,
+        vec![Inconsistency::MissingBody {
+            block_number: head.block_number() - 1,
+            hash: head.parent_hash().clone(),
+        }]
+    );
+    assert_eq!(
+        report.last_consistent_macro_block,
+        Some((macro_head.block_number(), macro_head.hash()))
+    );
+
+    assert_repair_reverts_to(env, &checker, &report, &macro_head);
+}
+
+#[test]
+fn reverts_blocks_with_a_mismatching_state_root() {
+    let (env, blockchain, macro_head) = setup_blockchain();
+    let head = blockchain.read().head().clone();
+
+    // Change an account without a block.
+    let mut txn = env.write_transaction();
+    {
+        let blockchain_rg = blockchain.read();
+        let accounts = &blockchain_rg.state.accounts;
+        let mut txn_proxy = WriteTransactionProxy::from(&mut txn);
+        accounts
+            .tree
+            .put(
+                &mut txn_proxy,
+                &KeyNibbles::from(&Address::from([1; Address::SIZE])),
+                Account::Basic(BasicAccount {
+                    balance: Coin::from_u64_unchecked(1),
+                }),
+            )
+            .unwrap();
+        accounts.tree.update_root(&mut txn_proxy).unwrap();
+    }
+    txn.commit();
+    drop(blockchain);
+
+    let checker = ConsistencyChecker::new(env.clone(), NetworkId::UnitAlbatross, true);
+    let report = checker.check();
+    assert!(matches!(
+        report.inconsistencies[..],
+        [Inconsistency::StateRootMismatch { block_number, .. }] if block_number == head.block_number()
+    ));
+
+    assert_repair_reverts_to(env, &checker, &report, &macro_head);
+}
@@ -0,0 +1,235 @@
+use std::{collections::HashMap, sync::Arc};
+
+use nimiq_block::{Block, BlockError};
+use nimiq_blockchain::{interface::HistoryInterface, BlockProducer, Blockchain, BlockchainConfig};
+use nimiq_blockchain_interface::{AbstractBlockchain, PushError};
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
+use nimiq_serde::Serialize;
+use nimiq_test_log::test;
+use nimiq_test_utils::blockchain::{produce_macro_blocks, signing_key, voting_key};
+use nimiq_transaction::historic_transaction::HistoricTransactionData;
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+const NUM_EPOCHS: usize = 3;
+
+fn setup_blockchain() -> Arc<RwLock<Blockchain>> {
+    setup_blockchain_with_config(BlockchainConfig::default())
+}
+
+fn setup_blockchain_with_config(config: BlockchainConfig) -> Arc<RwLock<Blockchain>> {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let blockchain = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            config,
+            NetworkId::UnitAlbatross,
+            Arc::new(OffsetTime::new()),
+        )
+        .unwrap(),
+    ));
+    let producer = BlockProducer::new(signing_key(), voting_key());
+
+    produce_macro_blocks(
+        &producer,
+        &blockchain,
+        NUM_EPOCHS * Policy::batches_per_epoch() as usize,
+    );
+
+    blockchain
+}
+
+#[test]
+fn complete_history_needs_no_backfill() {
+    let blockchain = setup_blockchain();
+
+    assert_eq!(blockchain.read().history_backfill_target(), None);
+}
+
+#[test]
+fn can_prune_and_backfill_history() {
+    let blockchain = setup_blockchain();
+    let election_head = blockchain.read().election_head().clone();
+    assert_eq!(election_head.epoch_number(), NUM_EPOCHS as u32);
+
+    // Remember the history of all past epochs before pruning it.
+    let mut epoch_history = HashMap::new();
+    for epoch_number in 1..election_head.epoch_number() {
+        let history = blockchain
+            .read()
+            .history_store
+            .get_epoch_transactions(epoch_number, None);
+        assert!(!history.is_empty());
+        epoch_history.insert(epoch_number, history);
+    }
+
+    assert_eq!(
+        Blockchain::prune_history(blockchain.upgradable_read()),
+        election_head.epoch_number() - 1
+    );
+    assert_eq!(Blockchain::prune_history(blockchain.upgradable_read()), 0);
+    assert_eq!(
+        blockchain.read().history_backfill_target(),
+        Some(election_head.header.parent_election_hash.clone())
+    );
+
+    // Backfill the history epoch by epoch, from the most recent epoch to the first one.
+    let mut expected_epoch_number = election_head.epoch_number() - 1;
+    loop {
+        let target = blockchain.read().history_backfill_target();
+        let Some(target) = target else {
+            break;
+        };
+        let Ok(Block::Macro(block)) = blockchain.read().chain_store.get_block(&target, true, None)
+        else {
+            panic!("Election block should be stored");
+        };
+        assert_eq!(block.epoch_number(), expected_epoch_number);
+
+        Blockchain::push_history_backfill(
+            blockchain.upgradable_read(),
+            &block,
+            &epoch_history[&block.epoch_number()],
+        )
+        .unwrap();
+        expected_epoch_number -= 1;
+    }
+    assert_eq!(expected_epoch_number, 0);
+
+    for (epoch_number, history) in epoch_history {
+        let block_number = Policy::election_block_of(epoch_number).unwrap();
+        let block = blockchain
+            .read()
+            .chain_store
+            .get_block_at(block_number, false, None)
+            .unwrap();
+        assert_eq!(
+            blockchain
+                .read()
+                .history_store
+                .get_history_tree_root(block_number, None),
+            Some(block.history_root().clone())
+        );
+        assert_eq!(
+            blockchain
+                .read()
+                .history_store
+                .get_epoch_transactions(epoch_number, None),
+            history
+        );
+    }
+}
+
+#[test]
+fn backfill_rejects_invalid_history() {
+    let blockchain = setup_blockchain();
+    let election_head = blockchain.read().election_head().clone();
+
+    Blockchain::prune_history(blockchain.upgradable_read());
+
+    let target = blockchain.read().history_backfill_target().unwrap();
+    let Ok(Block::Macro(block)) = blockchain.read().chain_store.get_block(&target, true, None)
+    else {
+        panic!("Election block should be stored");
+    };
+
+    // The history of a different epoch doesn't match the history root.
+    let wrong_history = blockchain
+        .read()
+        .history_store
+        .get_epoch_transactions(election_head.epoch_number(), None);
+    assert_eq!(
+        Blockchain::push_history_backfill(blockchain.upgradable_read(), &block, &wrong_history),
+        Err(PushError::InvalidBlock(BlockError::InvalidHistoryRoot))
+    );
+
+    // Only the current target is accepted.
+    assert_eq!(
+        Blockchain::push_history_backfill(
+            blockchain.upgradable_read(),
+            &election_head,
+            &wrong_history
+        ),
+        Err(PushError::InvalidPredecessor)
+    );
+
+    // The rejected history must not have been stored.
+    assert_eq!(blockchain.read().history_backfill_target(), Some(target));
+}
+
+#[test]
+fn prune_keeps_the_history_of_stored_epochs() {
+    let blockchain = setup_blockchain_with_config(BlockchainConfig {
+        max_epochs_stored: 2,
+        ..Default::default()
+    });
+    let election_epoch = blockchain.read().election_head().epoch_number();
+
+    assert_eq!(
+        Blockchain::prune_history(blockchain.upgradable_read()),
+        election_epoch - 2
+    );
+    for epoch_number in 1..election_epoch {
+        let num_transactions = blockchain
+            .read()
+            .history_store
+            .num_epoch_transactions(epoch_number, None);
+        assert_eq!(num_transactions > 0, epoch_number > election_epoch - 2);
+    }
+}
+
+#[test]
+fn backfill_stores_unknown_election_blocks() {
+    let blockchain = setup_blockchain();
+    let election_head = blockchain.read().election_head().clone();
+    let epoch_number = election_head.epoch_number() - 1;
+    let history = blockchain
+        .read()
+        .history_store
+        .get_epoch_transactions(epoch_number, None);
+    let target = election_head.header.parent_election_hash.clone();
+    let Ok(Block::Macro(block)) = blockchain.read().chain_store.get_block(&target, true, None)
+    else {
+        panic!("Election block should be stored");
+    };
+
+    // Forget the election block as if the chain was synced from a zero-knowledge proof.
+    Blockchain::prune_history(blockchain.upgradable_read());
+    {
+        let blockchain = blockchain.read();
+        let mut txn = blockchain.write_transaction();
+        blockchain
+            .chain_store
+            .remove_chain_info(&mut txn, &target, block.block_number());
+        txn.commit();
+    }
+    assert_eq!(
+        blockchain.read().history_backfill_target(),
+        Some(target.clone())
+    );
+
+    Blockchain::push_history_backfill(blockchain.upgradable_read(), &block, &history).unwrap();
+
+    let chain_info = blockchain
+        .read()
+        .chain_store
+        .get_chain_info(&target, false, None)
+        .unwrap();
+    let (cum_tx_fees, cum_hist_tx_size) = history
+        .iter()
+        .filter(|hist_tx| Policy::batch_at(hist_tx.block_number) == block.batch_number())
+        .fold((Coin::ZERO, 0), |(fees, size), hist_tx| {
+            let fee = match &hist_tx.data {
+                HistoricTransactionData::Basic(tx) => tx.get_raw_transaction().fee,
+                _ => Coin::ZERO,
+            };
+            (fees + fee, size + hist_tx.data.serialized_size() as u64)
+        });
+    assert!(chain_info.on_main_chain);
+    assert_eq!(chain_info.main_chain_successor, None);
+    assert_eq!(chain_info.cum_tx_fees, cum_tx_fees);
+    assert_eq!(chain_info.cum_hist_tx_size, cum_hist_tx_size);
+    assert!(cum_hist_tx_size > 0);
+    assert_eq!(chain_info.history_tree_len, history.len() as u64);
+}
@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
+use nimiq_account::BlockLog;
+use nimiq_block::{Block, BlockError, MacroBlock};
 use nimiq_blockchain::{interface::HistoryInterface, BlockProducer, Blockchain, BlockchainConfig};
-use nimiq_blockchain_interface::{AbstractBlockchain, PushResult};
+use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainEvent, PushResult};
 use nimiq_database::mdbx::MdbxDatabase;
 use nimiq_genesis::NetworkId;
 use nimiq_primitives::policy::Policy;
@@ -87,12 +89,22 @@ fn history_sync_does_not_need_to_revert_when_pushing_empty_batch() {
     ));
 
     assert_eq!(
-        Blockchain::push_history_sync(blockchain2.upgradable_read(), macro_block1, &batch1_txns,),
+        Blockchain::push_history_sync(
+            blockchain2.upgradable_read(),
+            macro_block1,
+            &batch1_txns,
+            false
+        ),
         Ok(PushResult::Extended)
     );
 
     assert_eq!(
-        Blockchain::push_history_sync(blockchain2.upgradable_read(), macro_block2, &batch2_txns,),
+        Blockchain::push_history_sync(
+            blockchain2.upgradable_read(),
+            macro_block2,
+            &batch2_txns,
+            false
+        ),
         Ok(PushResult::Extended)
     );
 }
@@ -223,7 +235,8 @@ fn history_sync_works() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             election_block_1,
-            &election_txs_1
+            &election_txs_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -232,7 +245,8 @@ fn history_sync_works() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             checkpoint_block_2_1,
-            &checkpoint_txs_2_1
+            &checkpoint_txs_2_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -241,7 +255,8 @@ fn history_sync_works() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             checkpoint_block_2_3,
-            &checkpoint_txs_2_3
+            &checkpoint_txs_2_3,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -250,7 +265,8 @@ fn history_sync_works() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             election_block_2,
-            &election_txs_2
+            &election_txs_2,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -259,7 +275,8 @@ fn history_sync_works() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             checkpoint_block_3_1,
-            &checkpoint_txs_3_1
+            &checkpoint_txs_3_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -404,7 +421,8 @@ fn history_sync_works_with_micro_blocks() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             election_block_1,
-            &election_txs_1
+            &election_txs_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -413,7 +431,8 @@ fn history_sync_works_with_micro_blocks() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             checkpoint_block_2_1,
-            &checkpoint_txs_2_1
+            &checkpoint_txs_2_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -431,7 +450,8 @@ fn history_sync_works_with_micro_blocks() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             election_block_2,
-            &election_txs_2
+            &election_txs_2,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -449,7 +469,8 @@ fn history_sync_works_with_micro_blocks() {
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
             checkpoint_block_3_2,
-            &checkpoint_txs_3_2
+            &checkpoint_txs_3_2,
+            false
         ),
         Ok(PushResult::Extended)
     );
@@ -511,15 +532,209 @@ fn history_sync_works_with_diverging_history() {
         .unwrap();
     let election_txs_1 = blockchain.history_store.get_epoch_transactions(1, None);
 
+    // Remember the micro blocks of blockchain2, which diverge from the pushed history.
+    let old_head_hash = blockchain2.read().head_hash();
+    let mut event_rx = blockchain2.read().notifier.subscribe();
+    let mut log_rx = blockchain2.read().log_notifier.subscribe();
+
     // Push the epoch to blockchain2.
     assert_eq!(
         Blockchain::push_history_sync(
             blockchain2.upgradable_read(),
-            election_block_1,
-            &election_txs_1
+            election_block_1.clone(),
+            &election_txs_1,
+            false
         ),
         Ok(PushResult::Extended)
     );
 
     assert_eq!(blockchain.head(), blockchain2.read().head());
+
+    // The reverted micro blocks are reported in ascending order together with the adopted block.
+    let Ok(BlockchainEvent::HistoryAdopted(block_hash, reverted_blocks)) = event_rx.try_recv()
+    else {
+        panic!("Expected the history to be adopted");
+    };
+    assert_eq!(block_hash, election_block_1.hash());
+    assert!(!reverted_blocks.is_empty());
+    assert_eq!(reverted_blocks.last().unwrap().0, old_head_hash);
+    for window in reverted_blocks.windows(2) {
+        assert_eq!(window[1].1.parent_hash(), &window[0].0);
+    }
+
+    // The logs of the reverted blocks are reported in the order of reverting.
+    for (hash, block) in reverted_blocks.iter().rev() {
+        match log_rx.try_recv() {
+            Ok(BlockLog::RevertedBlock {
+                block_hash,
+                block_number,
+                ..
+            }) => {
+                assert_eq!(&block_hash, hash);
+                assert_eq!(block_number, block.block_number());
+            }
+            log => panic!("Expected a reverted block log, got {log:?}"),
+        }
+    }
+    assert!(log_rx.try_recv().is_err());
+}
+
+// Tests that macro blocks whose justifications were verified in a single batch can be pushed
+// without verifying them again, and that a single invalid justification fails the whole batch.
+#[test]
+fn history_sync_works_with_batch_verified_justifications() {
+    let genesis_block_number = Policy::genesis_block_number();
+    let num_macro_blocks = (2 * Policy::batches_per_epoch() + 1) as usize;
+
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let time = Arc::new(OffsetTime::new());
+    let blockchain1 = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            time,
+        )
+        .unwrap(),
+    ));
+
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks(&producer, &blockchain1, num_macro_blocks);
+
+    // Get two election blocks and the following checkpoint block, like in a macro chain.
+    let blockchain = blockchain1.read();
+    let blocks: Vec<MacroBlock> = [
+        Policy::blocks_per_epoch(),
+        2 * Policy::blocks_per_epoch(),
+        2 * Policy::blocks_per_epoch() + Policy::blocks_per_batch(),
+    ]
+    .into_iter()
+    .map(|block_number| {
+        blockchain
+            .chain_store
+            .get_block_at(block_number + genesis_block_number, true, None)
+            .unwrap()
+            .unwrap_macro()
+    })
+    .collect();
+
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let time = Arc::new(OffsetTime::new());
+    let blockchain2 = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            time,
+        )
+        .unwrap(),
+    ));
+    let election_hash = blockchain2.read().election_head_hash();
+    let validators = blockchain2.read().current_validators().unwrap().clone();
+
+    let mut invalid_blocks = blocks.clone();
+    invalid_blocks[1].justification = blocks[0].justification.clone();
+    assert_eq!(
+        MacroBlock::verify_validators_batch(&invalid_blocks, &election_hash, &validators),
+        Err(BlockError::InvalidJustification)
+    );
+
+    assert_eq!(
+        MacroBlock::verify_validators_batch(&blocks, &election_hash, &validators),
+        Ok(())
+    );
+
+    for (block, epoch_number) in blocks.into_iter().zip(1..) {
+        let history = blockchain
+            .history_store
+            .get_epoch_transactions(epoch_number, None);
+        assert_eq!(
+            Blockchain::push_history_sync(
+                blockchain2.upgradable_read(),
+                Block::Macro(block),
+                &history,
+                true
+            ),
+            Ok(PushResult::Extended)
+        );
+    }
+
+    assert_eq!(blockchain.head(), blockchain2.read().head());
+}
+
+// Tests that the timestamp index covers the blocks that were history synced, although only the
+// chain infos of their macro blocks are stored.
+#[test]
+fn history_sync_indexes_block_timestamps() {
+    let genesis_block_number = Policy::genesis_block_number();
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let time = Arc::new(OffsetTime::new());
+    let blockchain1 = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            time,
+        )
+        .unwrap(),
+    ));
+
+    // Produce an epoch in which every micro block contains a transaction.
+    let num_macro_blocks = Policy::batches_per_epoch() as usize;
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks_with_txns(&producer, &blockchain1, num_macro_blocks, 1, 0);
+
+    let blockchain = blockchain1.read();
+    let election_block_1 = blockchain
+        .chain_store
+        .get_block_at(
+            Policy::blocks_per_epoch() + genesis_block_number,
+            true,
+            None,
+        )
+        .unwrap();
+    let election_txs_1 = blockchain.history_store.get_epoch_transactions(1, None);
+
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let time = Arc::new(OffsetTime::new());
+    let blockchain2 = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            time,
+        )
+        .unwrap(),
+    ));
+
+    assert_eq!(
+        Blockchain::push_history_sync(
+            blockchain2.upgradable_read(),
+            election_block_1.clone(),
+            &election_txs_1,
+            false
+        ),
+        Ok(PushResult::Extended)
+    );
+
+    // All blocks of the epoch are known through their transactions.
+    let blockchain2 = blockchain2.read();
+    for hist_tx in &election_txs_1 {
+        assert_eq!(
+            blockchain2.get_block_number_at_timestamp(hist_tx.block_time, None),
+            Some(hist_tx.block_number)
+        );
+    }
+    assert_eq!(
+        blockchain2.get_block_number_at_timestamp(election_block_1.timestamp(), None),
+        Some(election_block_1.block_number())
+    );
+    assert_eq!(
+        blockchain2.get_block_number_at_timestamp(0, None),
+        Some(genesis_block_number)
+    );
+    assert_eq!(
+        blockchain2.get_block_number_at_timestamp(election_block_1.timestamp() + 1, None),
+        None
+    );
 }
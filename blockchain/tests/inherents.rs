@@ -5,7 +5,10 @@ use nimiq_block::{
     Block, DoubleProposalProof, DoubleVoteProof, ForkProof, MacroBlock, MacroBody, MacroHeader,
     SkipBlockInfo,
 };
-use nimiq_blockchain::{Blockchain, BlockchainConfig};
+use nimiq_blockchain::{
+    reward::{batch_reward, block_reward_for_batch},
+    Blockchain, BlockchainConfig,
+};
 use nimiq_blockchain_interface::AbstractBlockchain;
 use nimiq_bls::AggregateSignature;
 use nimiq_database::{mdbx::MdbxDatabase, traits::WriteTransaction};
@@ -687,3 +690,42 @@ async fn create_fork_proof() {
     // Verify that the fork proof was generated
     assert!(fork_rx.next().await.is_some());
 }
+
+#[test]
+fn batch_reward_is_penalized_for_delay() {
+    let genesis_supply = Coin::from_u64_unchecked(1_000_000_000);
+    let previous = MacroHeader {
+        block_number: Policy::genesis_block_number(),
+        timestamp: 1_000,
+        ..Default::default()
+    };
+    let batch_time = Policy::BLOCK_SEPARATION_TIME * Policy::blocks_per_batch() as u64;
+
+    // A batch produced in time pays out the maximum reward.
+    let on_time = MacroHeader {
+        block_number: Policy::macro_block_of(1).unwrap(),
+        timestamp: previous.timestamp + batch_time,
+        ..Default::default()
+    };
+    let reward = batch_reward(&on_time, &previous, genesis_supply, previous.timestamp);
+    assert_eq!(reward.batch_delay, 0);
+    assert_eq!(reward.reward, reward.max_reward);
+    assert_eq!(
+        reward.reward,
+        block_reward_for_batch(&on_time, &previous, genesis_supply, previous.timestamp)
+    );
+
+    // A late batch only pays out a share of the maximum reward.
+    let late = MacroHeader {
+        timestamp: on_time.timestamp + 60_000,
+        ..on_time
+    };
+    let reward = batch_reward(&late, &previous, genesis_supply, previous.timestamp);
+    assert_eq!(reward.batch_delay, 60_000);
+    assert!(reward.batch_delay_penalty < 1.0);
+    assert!(reward.reward < reward.max_reward);
+    assert_eq!(
+        reward.reward,
+        block_reward_for_batch(&late, &previous, genesis_supply, previous.timestamp)
+    );
+}
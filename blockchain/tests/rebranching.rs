@@ -232,3 +232,49 @@ fn it_can_rebranch_to_inferior_macro_block() {
         blockchain2.state.previous_slots
     );
 }
+
+#[test]
+fn it_updates_the_timestamp_index_on_rebranch() {
+    let temp_producer1 = TemporaryBlockProducer::new();
+    let temp_producer2 = TemporaryBlockProducer::new();
+
+    let block = temp_producer1.next_block(vec![], false);
+    temp_producer2.push(block.clone()).unwrap();
+
+    let inferior1 = temp_producer1.next_block(vec![], false);
+    let inferior2 = temp_producer1.next_block(vec![], false);
+    let fork = temp_producer2.next_block(vec![], true);
+
+    assert_eq!(
+        temp_producer1
+            .blockchain
+            .read()
+            .get_block_number_at_timestamp(inferior2.timestamp(), None),
+        Some(inferior2.block_number())
+    );
+
+    // The skip block is produced after both inferior blocks, so their timestamps must resolve to
+    // it once the inferior chain is reverted.
+    assert_eq!(
+        temp_producer1.push(fork.clone()),
+        Ok(PushResult::Rebranched)
+    );
+
+    let blockchain = temp_producer1.blockchain.read();
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(block.timestamp(), None),
+        Some(block.block_number())
+    );
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(inferior1.timestamp(), None),
+        Some(fork.block_number())
+    );
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(inferior2.timestamp(), None),
+        Some(fork.block_number())
+    );
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(fork.timestamp() + 1, None),
+        None
+    );
+}
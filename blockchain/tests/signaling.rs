@@ -0,0 +1,150 @@
+use std::{str::FromStr, sync::Arc};
+
+use nimiq_blockchain::{
+    signaling::{SignalTally, SignalThresholdCrossed},
+    BlockProducer, Blockchain, BlockchainConfig,
+};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_hash::{Blake2bHash, Hash};
+use nimiq_keys::{KeyPair, PrivateKey};
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
+use nimiq_test_log::test;
+use nimiq_test_utils::{
+    block_production::TemporaryBlockProducer,
+    blockchain::{produce_macro_blocks, signing_key, validator_key, voting_key, REWARD_KEY},
+};
+use nimiq_transaction_builder::TransactionBuilder;
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+#[test]
+fn tally_counts_slots_of_current_validators() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let blockchain = Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            Arc::new(OffsetTime::new()),
+        )
+        .unwrap(),
+    ));
+    let mut signal_rx = blockchain.read().signal_notifier.subscribe();
+
+    let tally = blockchain.read().tally_signals().unwrap();
+    assert_eq!(tally.block_number, blockchain.read().block_number());
+    assert_eq!(
+        tally.signaled_slots() + tally.unsignaled_slots,
+        Policy::SLOTS
+    );
+
+    // Nothing changes the signal data, so no threshold can be crossed at the next election block.
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks(&producer, &blockchain, Policy::batches_per_epoch() as usize);
+
+    let tally_after_election = blockchain.read().tally_signals().unwrap();
+    assert_eq!(tally_after_election.signals, tally.signals);
+    assert!(signal_rx.try_recv().is_err());
+}
+
+#[test]
+fn signal_crossing_a_threshold_is_notified_at_the_election_block() {
+    let producer = TemporaryBlockProducer::new();
+    let mut signal_rx = producer.blockchain.read().signal_notifier.subscribe();
+    let signal = "upgrade".hash::<Blake2bHash>();
+
+    // The only validator of the unit genesis holds all slots and starts signaling.
+    let tx = TransactionBuilder::new_update_validator(
+        &KeyPair::from(PrivateKey::from_str(REWARD_KEY).unwrap()),
+        &validator_key(),
+        None,
+        None,
+        None,
+        Some(Some(signal.clone())),
+        None,
+        Coin::from_u64_unchecked(100),
+        producer.blockchain.read().block_number(),
+        NetworkId::UnitAlbatross,
+    );
+    producer.next_block_with_txs(vec![], false, vec![tx]);
+
+    // The tally is only taken at election blocks.
+    let election_block = loop {
+        let block = producer.next_block(vec![], false);
+        if block.is_election() {
+            break block.block_number();
+        }
+        assert!(signal_rx.try_recv().is_err());
+    };
+
+    assert_eq!(
+        signal_rx.try_recv().unwrap(),
+        SignalThresholdCrossed {
+            signal: signal.clone(),
+            threshold: Policy::TWO_F_PLUS_ONE,
+            slots: Policy::SLOTS,
+            previous_slots: 0,
+            block_number: election_block,
+        }
+    );
+    assert!(signal_rx.try_recv().is_err());
+
+    let tally = producer.blockchain.read().signal_tally().unwrap();
+    assert_eq!(tally.block_number, election_block);
+    assert_eq!(tally.leading_signal(), Some((&signal, Policy::SLOTS)));
+
+    // The signal keeps its support, so the threshold isn't crossed again.
+    loop {
+        if producer.next_block(vec![], false).is_election() {
+            break;
+        }
+    }
+    assert!(signal_rx.try_recv().is_err());
+}
+
+#[test]
+fn crossed_thresholds_only_reports_new_crossings() {
+    let signal_a = "a".hash::<Blake2bHash>();
+    let signal_b = "b".hash::<Blake2bHash>();
+    let thresholds = [Policy::SLOTS / 2, Policy::TWO_F_PLUS_ONE];
+
+    let mut previous = SignalTally {
+        block_number: Policy::blocks_per_epoch(),
+        ..Default::default()
+    };
+    previous.signals.insert(signal_a.clone(), Policy::SLOTS / 2);
+    previous.signals.insert(signal_b.clone(), 10);
+    previous.unsignaled_slots = Policy::SLOTS - Policy::SLOTS / 2 - 10;
+
+    let mut current = SignalTally {
+        block_number: 2 * Policy::blocks_per_epoch(),
+        ..Default::default()
+    };
+    current
+        .signals
+        .insert(signal_a.clone(), Policy::TWO_F_PLUS_ONE);
+    current.signals.insert(signal_b, 10);
+    current.unsignaled_slots = Policy::SLOTS - Policy::TWO_F_PLUS_ONE - 10;
+
+    assert_eq!(
+        current.crossed_thresholds(&previous, &thresholds),
+        vec![SignalThresholdCrossed {
+            signal: signal_a.clone(),
+            threshold: Policy::TWO_F_PLUS_ONE,
+            slots: Policy::TWO_F_PLUS_ONE,
+            previous_slots: Policy::SLOTS / 2,
+            block_number: 2 * Policy::blocks_per_epoch(),
+        }]
+    );
+    assert_eq!(
+        current.leading_signal(),
+        Some((&signal_a, Policy::TWO_F_PLUS_ONE))
+    );
+    assert_eq!(current.signaled_slots(), Policy::TWO_F_PLUS_ONE + 10);
+
+    // Signals losing support don't emit any events.
+    assert!(previous
+        .crossed_thresholds(&current, &thresholds)
+        .is_empty());
+}
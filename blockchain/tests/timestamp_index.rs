@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use nimiq_blockchain::{interface::HistoryInterface, BlockProducer, Blockchain, BlockchainConfig};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_database::{
+    declare_table,
+    mdbx::MdbxDatabase,
+    traits::{Database, WriteTransaction},
+};
+use nimiq_primitives::{networks::NetworkId, policy::Policy};
+use nimiq_test_log::test;
+use nimiq_test_utils::blockchain::{
+    produce_macro_blocks_with_txns, push_micro_block, signing_key, voting_key,
+};
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+declare_table!(TimestampIndex, "TimestampIndex", u64 => u32);
+declare_table!(TimestampIndexVersion, "TimestampIndexVersion", () => u32);
+
+fn new_blockchain(env: MdbxDatabase) -> Arc<RwLock<Blockchain>> {
+    Arc::new(RwLock::new(
+        Blockchain::new(
+            env,
+            BlockchainConfig::default(),
+            NetworkId::UnitAlbatross,
+            Arc::new(OffsetTime::new()),
+        )
+        .unwrap(),
+    ))
+}
+
+// Tests that the timestamp index of a database that predates it is built on startup.
+#[test]
+fn it_rebuilds_the_timestamp_index_of_an_upgraded_database() {
+    let genesis_block_number = Policy::genesis_block_number();
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let blockchain = new_blockchain(env.clone());
+
+    // Produce an epoch in which every micro block contains a transaction and a few micro blocks
+    // of the next one.
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    produce_macro_blocks_with_txns(
+        &producer,
+        &blockchain,
+        Policy::batches_per_epoch() as usize,
+        1,
+        0,
+    );
+    let micro_blocks: Vec<_> = (0..3)
+        .map(|_| push_micro_block(&producer, &blockchain))
+        .collect();
+    let head = blockchain.read().head().clone();
+    let election_txs_1 = blockchain
+        .read()
+        .history_store
+        .get_epoch_transactions(1, None);
+
+    // Remove the index and its version, like in a database created before the index existed, and
+    // prune the micro blocks of the first epoch, so that they are only known through their history.
+    let mut txn = env.write_transaction();
+    blockchain.read().chain_store.prune_epoch(1, &mut txn);
+    txn.clear_table(&TimestampIndex);
+    txn.clear_table(&TimestampIndexVersion);
+    txn.commit();
+    drop(blockchain);
+
+    let blockchain = new_blockchain(env);
+    let blockchain = blockchain.read();
+    assert_eq!(blockchain.head(), &head);
+
+    // The pruned micro blocks are indexed through their transactions.
+    for hist_tx in &election_txs_1 {
+        assert_eq!(
+            blockchain.get_block_number_at_timestamp(hist_tx.block_time, None),
+            Some(hist_tx.block_number)
+        );
+    }
+    for block in &micro_blocks {
+        assert_eq!(
+            blockchain.get_block_number_at_timestamp(block.timestamp(), None),
+            Some(block.block_number())
+        );
+    }
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(0, None),
+        Some(genesis_block_number)
+    );
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(head.timestamp() + 1, None),
+        None
+    );
+}
+
+// Tests that blocks missing from the timestamp index are looked up in the chain store instead of
+// being skipped.
+#[test]
+fn it_looks_up_blocks_missing_from_the_timestamp_index() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let blockchain = new_blockchain(env.clone());
+    let producer = BlockProducer::new(signing_key(), voting_key());
+    let blocks: Vec<_> = (0..4)
+        .map(|_| push_micro_block(&producer, &blockchain))
+        .collect();
+
+    // Remove the entries of the second and third block from the index.
+    let mut txn = env.write_transaction();
+    txn.remove(&TimestampIndex, &blocks[1].timestamp());
+    txn.remove(&TimestampIndex, &blocks[2].timestamp());
+    txn.commit();
+
+    let blockchain = blockchain.read();
+    for block in &blocks {
+        assert_eq!(
+            blockchain.get_block_number_at_timestamp(block.timestamp(), None),
+            Some(block.block_number())
+        );
+        assert_eq!(
+            blockchain.get_block_number_at_timestamp(block.timestamp() - 1, None),
+            Some(block.block_number())
+        );
+    }
+
+    // Without the chain info of the second block, the blocks after it can't be determined
+    // anymore.
+    let mut txn = env.write_transaction();
+    blockchain
+        .chain_store
+        .remove_chain_info(&mut txn, &blocks[1].hash(), blocks[1].block_number());
+    txn.commit();
+
+    assert_eq!(
+        blockchain.get_block_number_at_timestamp(blocks[0].timestamp(), None),
+        Some(blocks[0].block_number())
+    );
+    for block in &blocks[1..] {
+        assert_eq!(
+            blockchain.get_block_number_at_timestamp(block.timestamp(), None),
+            None
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::Neg;
+
+use ark_ec::{pairing::Pairing, Group};
+use ark_ff::Zero;
+use ark_mnt6_753::{G1Projective, G2Projective, MNT6_753};
+use nimiq_hash::Hash;
+use nimiq_utils::key_rng::{CryptoRng, RngCore, SecureRng};
+
+use crate::{AggregatePublicKey, AggregateSignature, PublicKey, SigHash, Signature};
+
+/// A single entry of a batch verification. It holds the (already hashed to the curve) message,
+/// the public key and the signature that should be checked.
+#[derive(Clone)]
+struct BatchEntry {
+    hash_curve: G1Projective,
+    public_key: G2Projective,
+    signature: G1Projective,
+}
+
+/// Verifies many (public key, message, signature) triples at once using a randomized linear
+/// combination of the individual verification equations.
+///
+/// For the entries `(pk_i, H(m_i), sig_i)` and random scalars `r_i`, the verifier checks that
+///
+///   e(sum(r_i * sig_i), g2) == prod(e(r_i * H(m_i), pk_i))
+///
+/// which is done with a single multi-pairing instead of two pairings per entry. The random scalars
+/// prevent an adversary from crafting invalid signatures that cancel each other out. If the batch
+/// fails to verify, it is not possible to tell which entry is invalid. Callers that need to know
+/// should fall back to verifying each entry individually.
+#[derive(Clone, Default)]
+pub struct BatchVerifier {
+    entries: Vec<BatchEntry>,
+    /// Whether an entry was added that can never be valid (e.g. a public key at infinity).
+    invalid: bool,
+}
+
+impl BatchVerifier {
+    /// The number of random bits used for each of the scalars of the linear combination.
+    /// The probability that an invalid batch passes the verification is at most 2^-128.
+    const SCALAR_BITS: usize = 128;
+
+    /// Creates a new, empty batch verifier.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, empty batch verifier with room for `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        BatchVerifier {
+            entries: Vec::with_capacity(capacity),
+            invalid: false,
+        }
+    }
+
+    /// Returns the number of entries in this batch.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries in this batch.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a signature over the given message to the batch.
+    pub fn push<M: Hash>(&mut self, public_key: &PublicKey, msg: &M, signature: &Signature) {
+        self.push_hash(public_key, msg.hash(), signature)
+    }
+
+    /// Adds a signature over the given message hash to the batch.
+    pub fn push_hash(&mut self, public_key: &PublicKey, hash: SigHash, signature: &Signature) {
+        self.push_g1(public_key, Signature::hash_to_g1(hash), signature)
+    }
+
+    /// Adds a signature over the given G1 point to the batch.
+    /// As with `PublicKey::verify_g1`, a public key at the point at infinity makes the whole batch
+    /// invalid.
+    pub fn push_g1(
+        &mut self,
+        public_key: &PublicKey,
+        hash_curve: G1Projective,
+        signature: &Signature,
+    ) {
+        if public_key.public_key.is_zero() {
+            self.invalid = true;
+        }
+        self.entries.push(BatchEntry {
+            hash_curve,
+            public_key: public_key.public_key,
+            signature: signature.signature,
+        });
+    }
+
+    /// Adds an aggregate signature over the given message to the batch.
+    pub fn push_aggregate<M: Hash>(
+        &mut self,
+        public_key: &AggregatePublicKey,
+        msg: &M,
+        signature: &AggregateSignature,
+    ) {
+        self.push(&public_key.0, msg, &signature.0)
+    }
+
+    /// Adds an aggregate signature over the given message hash to the batch.
+    pub fn push_aggregate_hash(
+        &mut self,
+        public_key: &AggregatePublicKey,
+        hash: SigHash,
+        signature: &AggregateSignature,
+    ) {
+        self.push_hash(&public_key.0, hash, &signature.0)
+    }
+
+    /// Merges the entries of another batch into this one.
+    pub fn merge(&mut self, other: BatchVerifier) {
+        self.invalid |= other.invalid;
+        self.entries.extend(other.entries);
+    }
+
+    /// Verifies all entries of the batch using the default secure random number generator.
+    /// Returns true if and only if all signatures are valid (with overwhelming probability).
+    /// An empty batch is trivially valid.
+    pub fn verify(&self) -> bool {
+        self.verify_with_rng(&mut SecureRng::default())
+    }
+
+    /// Verifies all entries of the batch using the given random number generator to sample the
+    /// scalars of the linear combination.
+    pub fn verify_with_rng<R: RngCore + CryptoRng>(&self, rng: &mut R) -> bool {
+        if self.invalid {
+            return false;
+        }
+
+        match self.entries.len() {
+            0 => return true,
+            // A single entry doesn't benefit from the randomization.
+            1 => {
+                let entry = &self.entries[0];
+                let public_key = PublicKey {
+                    public_key: entry.public_key,
+                };
+                return public_key.verify_g1(entry.hash_curve, &Signature::from(entry.signature));
+            }
+            _ => {}
+        }
+
+        let mut signature_sum = G1Projective::zero();
+        let mut g1_points = Vec::with_capacity(self.entries.len() + 1);
+        let mut g2_points = Vec::with_capacity(self.entries.len() + 1);
+
+        for entry in &self.entries {
+            let scalar = Self::random_scalar(rng);
+
+            // Scaling in G1 is considerably cheaper than in G2, so we apply the scalar to the
+            // hash point instead of the public key.
+            signature_sum += entry.signature.mul_bigint(scalar);
+            g1_points.push(entry.hash_curve.mul_bigint(scalar));
+            g2_points.push(entry.public_key);
+        }
+
+        // Move the signature side of the equation to the right, so that the product of all
+        // pairings needs to be the identity element of the target group.
+        g1_points.push(signature_sum.neg());
+        g2_points.push(G2Projective::generator());
+
+        MNT6_753::multi_pairing(g1_points, g2_points).is_zero()
+    }
+
+    /// Samples a non-zero scalar with `SCALAR_BITS` random bits.
+    fn random_scalar<R: RngCore + CryptoRng>(rng: &mut R) -> [u64; Self::SCALAR_BITS / 64] {
+        loop {
+            let mut scalar = [0u64; Self::SCALAR_BITS / 64];
+            for limb in scalar.iter_mut() {
+                *limb = rng.next_u64();
+            }
+            if scalar.iter().any(|limb| *limb != 0) {
+                return scalar;
+            }
+        }
+    }
+}
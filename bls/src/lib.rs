@@ -1,6 +1,10 @@
+pub use batch::*;
 use nimiq_hash::Blake2sHash;
 pub use types::*;
 
+// Implements randomized batch verification of many signatures with a single multi-pairing.
+mod batch;
+
 // Implements the LazyPublicKey type. Which is a faster, cached version of PublicKey.
 #[cfg(feature = "lazy")]
 pub mod lazy;
@@ -188,3 +188,67 @@ fn aggregate_signatures_serialization() {
         &AggregateSignature::deserialize_from_vec(&ser_agg_sig).unwrap()
     ));
 }
+
+#[test]
+fn batch_verify_signatures() {
+    let rng = &mut test_rng(false);
+
+    let mut batch = BatchVerifier::new();
+    assert!(batch.verify_with_rng(rng));
+
+    for i in 0..20 {
+        let keypair = KeyPair::generate(rng);
+
+        let message = format!("Message {}", i);
+
+        let sig = keypair.sign(&message);
+
+        batch.push(&keypair.public_key, &message, &sig);
+    }
+
+    assert_eq!(batch.len(), 20);
+    assert!(batch.verify_with_rng(rng));
+
+    // A signature over a different message makes the whole batch invalid.
+    let keypair = KeyPair::generate(rng);
+    let sig = keypair.sign(&"Message");
+    batch.push(&keypair.public_key, &"Another message", &sig);
+
+    assert!(!batch.verify_with_rng(rng));
+}
+
+#[test]
+fn batch_verify_aggregate_signatures() {
+    let rng = &mut test_rng(false);
+
+    let mut batch = BatchVerifier::new();
+    let mut invalid_batch = BatchVerifier::new();
+
+    for i in 0..5 {
+        let message = format!("Message {}", i);
+
+        let mut public_keys = Vec::new();
+        let mut signatures = Vec::new();
+
+        for _ in 0..10 {
+            let keypair = KeyPair::generate(rng);
+            signatures.push(keypair.sign(&message));
+            public_keys.push(keypair.public_key);
+        }
+
+        let agg_key = AggregatePublicKey::from_public_keys(&public_keys);
+        let agg_sig = AggregateSignature::from_signatures(&signatures);
+
+        batch.push_aggregate(&agg_key, &message, &agg_sig);
+
+        // Drop one of the signers from the aggregate key.
+        let partial_key = AggregatePublicKey::from_public_keys(&public_keys[1..]);
+        invalid_batch.push_aggregate(&partial_key, &message, &agg_sig);
+    }
+
+    assert!(batch.verify_with_rng(rng));
+    assert!(!invalid_batch.verify_with_rng(rng));
+
+    batch.merge(invalid_batch);
+    assert!(!batch.verify_with_rng(rng));
+}
@@ -4,7 +4,11 @@ use log::info;
 use nimiq::prover::prover_main;
 pub use nimiq::{
     client::Client,
-    config::{command_line::CommandLine, config::ClientConfig, config_file::ConfigFile},
+    config::{
+        command_line::{Command, CommandLine},
+        config::ClientConfig,
+        config_file::ConfigFile,
+    },
     error::Error,
     extras::{
         logging::{initialize_logging, log_error_cause_chain},
@@ -59,6 +63,20 @@ async fn main_inner() -> Result<(), Error> {
     let config = builder.build()?;
     log::debug!("Final configuration: {:#?}", config);
 
+    // Run maintenance commands instead of starting the client.
+    match command_line.command {
+        Some(Command::CheckDb { repair }) => {
+            return nimiq::extras::check_db::check_database(config, repair);
+        }
+        Some(Command::ExportBlocks { from, to, output }) => {
+            return nimiq::extras::block_file::export_blocks(config, &output, from, to);
+        }
+        Some(Command::ImportBlocks { input }) => {
+            return nimiq::extras::block_file::import_blocks(config, &input);
+        }
+        None => {}
+    }
+
     // Clone config for RPC and metrics server
     let rpc_config = config.rpc_server.clone();
     let metrics_config = config.metrics_server.clone();
@@ -100,10 +118,12 @@ async fn main_inner() -> Result<(), Error> {
 
     // Start validator
     let val_metric_monitor = tokio_metrics::TaskMonitor::new();
+    let mut validator_metrics = None;
     if let Some(validator) = client.take_validator() {
         info!("Initializing validator {}", validator.validator_address());
 
         if metrics_enabled {
+            validator_metrics = Some(validator.metrics());
             let mp_metrics_monitor = validator.get_mempool_monitor();
             let inst_validator = val_metric_monitor.instrument(validator);
             spawn(inst_validator);
@@ -146,6 +166,7 @@ async fn main_inner() -> Result<(), Error> {
             mempool,
             client.consensus_proxy(),
             client.network(),
+            validator_metrics,
             &nimiq_task_metric,
         )
     }
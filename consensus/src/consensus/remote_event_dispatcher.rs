@@ -257,7 +257,7 @@ impl<N: Network> Future for RemoteEventDispatcher<N> {
                     // We don't notify about reverted block, only adopted blocks
                     new_blocks.extend(adopted_blocks.into_iter().map(|(_, block)| block));
                 }
-                BlockchainEvent::HistoryAdopted(_) => {
+                BlockchainEvent::HistoryAdopted(..) => {
                     // In the future we might be interested in other events
                 }
                 BlockchainEvent::Stored(_block) => {
@@ -16,7 +16,7 @@ use nimiq_macros::test_max_req_size;
 use nimiq_mmr::mmr::proof::SizeProof;
 use nimiq_network_interface::{
     network::Topic,
-    request::{RequestCommon, RequestMarker},
+    request::{request_type_name, RequestCommon, RequestMarker},
 };
 use nimiq_primitives::{key_nibbles::KeyNibbles, trie::trie_proof::TrieProof};
 use nimiq_serde::{Deserialize, Serialize, SerializedMaxSize};
@@ -29,6 +29,30 @@ use crate::error::SubscribeToAddressesError;
 
 mod handlers;
 
+/// Returns the names of the request types served by the consensus.
+pub fn request_type_names() -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut names = vec![
+        request_type_name::<RequestMacroChain>(),
+        request_type_name::<RequestBatchSet>(),
+        request_type_name::<RequestBlock>(),
+        request_type_name::<RequestMissingBlocks>(),
+        request_type_name::<RequestHead>(),
+        request_type_name::<RequestTransactionsProof>(),
+        request_type_name::<RequestTransactionReceiptsByAddress>(),
+        request_type_name::<RequestTrieProof>(),
+        request_type_name::<RequestBlocksProof>(),
+        request_type_name::<RequestSubscribeToAddress>(),
+    ];
+    #[cfg(feature = "full")]
+    names.extend([
+        request_type_name::<RequestHistoryChunk>(),
+        request_type_name::<crate::sync::live::state_queue::RequestChunk>(),
+        request_type_name::<crate::sync::live::diff_queue::RequestTrieDiff>(),
+    ]);
+    names
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum BlockHeaderMessage {
     Macro {
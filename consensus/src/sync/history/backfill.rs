@@ -0,0 +1,130 @@
+use std::{sync::Arc, time::Duration};
+
+use nimiq_blockchain::{Blockchain, CHUNK_SIZE};
+use nimiq_hash::Blake2bHash;
+use nimiq_network_interface::{network::Network, peer_info::Services};
+use nimiq_time::sleep;
+use parking_lot::RwLock;
+
+use super::cluster::{HistoryChunkRequest, HistoryRequestError, SyncCluster};
+
+/// Backfills the history of past epochs from peers that provide the full history.
+///
+/// The history is fetched epoch by epoch, going backwards from the current election block to the
+/// genesis block. Each election block is authenticated by its hash, which is referenced by its
+/// succeeding election block, and each epoch history is verified against the history root of its
+/// election block. This turns a full node into a history node without resyncing the chain, while
+/// the node keeps following the chain.
+pub struct HistoryBackfill<TNetwork: Network> {
+    blockchain: Arc<RwLock<Blockchain>>,
+    network: Arc<TNetwork>,
+}
+
+impl<TNetwork: Network + 'static> HistoryBackfill<TNetwork> {
+    /// Time to wait before trying again if no peer could provide the history of an epoch.
+    const RETRY_DELAY: Duration = Duration::from_secs(10);
+
+    pub fn new(blockchain: Arc<RwLock<Blockchain>>, network: Arc<TNetwork>) -> Self {
+        Self {
+            blockchain,
+            network,
+        }
+    }
+
+    /// Returns true if the history is incomplete and needs to be backfilled.
+    pub fn is_required(&self) -> bool {
+        self.blockchain.read().history_backfill_target().is_some()
+    }
+
+    /// Backfills the history until it is complete back to the genesis block.
+    pub async fn run(self) {
+        loop {
+            let target = self.blockchain.read().history_backfill_target();
+            let Some(target) = target else {
+                break;
+            };
+
+            let mut backfilled = false;
+            for peer_id in self.history_peers() {
+                match self.backfill_epoch(peer_id, target.clone()).await {
+                    Ok(()) => {
+                        backfilled = true;
+                        break;
+                    }
+                    Err(error) => {
+                        debug!(%peer_id, %target, %error, "Failed to backfill epoch history")
+                    }
+                }
+            }
+
+            if !backfilled {
+                sleep(Self::RETRY_DELAY).await;
+            }
+        }
+
+        info!("History backfill complete");
+    }
+
+    /// Returns the connected peers that provide the full history.
+    fn history_peers(&self) -> Vec<TNetwork::PeerId> {
+        self.network
+            .get_peers()
+            .into_iter()
+            .filter(|peer_id| {
+                self.network
+                    .peer_provides_services(*peer_id, Services::HISTORY)
+            })
+            .collect()
+    }
+
+    /// Requests the election block with the given hash and the history of its epoch from the
+    /// given peer and adds it to the history store.
+    async fn backfill_epoch(
+        &self,
+        peer_id: TNetwork::PeerId,
+        hash: Blake2bHash,
+    ) -> Result<(), HistoryRequestError> {
+        // The batch set info is checked to match the requested hash.
+        let batch_set_info =
+            SyncCluster::<TNetwork>::request_epoch(Arc::clone(&self.network), peer_id, hash)
+                .await?;
+        let Some(election_block) = batch_set_info.election_macro_block.clone() else {
+            return Err(HistoryRequestError::InvalidBatchSetInfo);
+        };
+
+        // Verify the size of the history against the history root.
+        let history_len = batch_set_info.total_history_len();
+        if !batch_set_info.batch_sets.last().is_some_and(|batch_set| {
+            batch_set
+                .history_len
+                .verify(&election_block.header.history_root)
+        }) {
+            return Err(HistoryRequestError::InvalidSizeProof);
+        }
+
+        let mut history = Vec::with_capacity(history_len as usize);
+        for chunk_index in 0..history_len.div_ceil(CHUNK_SIZE as u64) {
+            let chunk = SyncCluster::<TNetwork>::request_history_chunk(
+                Arc::clone(&self.network),
+                peer_id,
+                HistoryChunkRequest::from_block(&election_block, chunk_index),
+            )
+            .await?;
+            history.extend(chunk.history);
+        }
+
+        Blockchain::push_history_backfill(
+            self.blockchain.upgradable_read(),
+            &election_block,
+            &history,
+        )
+        .map_err(|_| HistoryRequestError::InvalidHistoryChunk)?;
+
+        info!(
+            epoch_number = election_block.epoch_number(),
+            history_len, "Backfilled epoch history",
+        );
+
+        Ok(())
+    }
+}
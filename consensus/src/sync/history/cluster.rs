@@ -10,7 +10,7 @@ use std::{
 };
 
 use futures::{FutureExt, Stream, StreamExt};
-use nimiq_block::{Block, MacroBlock};
+use nimiq_block::{Block, MacroBlock, TendermintProof};
 use nimiq_blockchain::{interface::HistoryInterface, Blockchain, HistoryTreeChunk, CHUNK_SIZE};
 use nimiq_blockchain_interface::{AbstractBlockchain, PushError, PushResult};
 use nimiq_hash::Blake2bHash;
@@ -284,11 +284,12 @@ impl<TNetwork: Network + 'static> SyncCluster<TNetwork> {
         }
     }
 
+    /// Performs the intrinsic and successor checks of a macro block. The justification is not
+    /// verified here, see `verify_justifications`.
     fn verify_macro_block(
         block: &Block,
         network: NetworkId,
         macro_predecessor: &Block,
-        validators: &Validators,
     ) -> Result<(), HistoryRequestError> {
         if let Err(error) = block.verify(network) {
             warn!(%block, %error, reason = "Block intrinsic checks failed", "Invalid macro block");
@@ -300,8 +301,32 @@ impl<TNetwork: Network + 'static> SyncCluster<TNetwork> {
             return Err(HistoryRequestError::InvalidMacroBlock);
         }
 
-        if let Err(error) = block.verify_validators(validators) {
-            warn!(%block, %error, reason = "Block verification for slot failed", "Invalid macro block");
+        Ok(())
+    }
+
+    /// Verifies the justifications of all macro blocks of a batch set info in a single batch.
+    /// All of these blocks belong to the same epoch and thus share the same validators.
+    fn verify_justifications(
+        batch_set_info: &BatchSetInfo,
+        validators: &Validators,
+    ) -> Result<(), HistoryRequestError> {
+        let blocks = batch_set_info
+            .election_macro_block
+            .iter()
+            .chain(
+                batch_set_info
+                    .batch_sets
+                    .iter()
+                    .map(|batch_set| &batch_set.macro_block),
+            )
+            .map(|block| (block, validators));
+
+        if !TendermintProof::verify_batch(blocks) {
+            warn!(
+                epoch_number = batch_set_info.final_macro_block().epoch_number(),
+                reason = "Batch verification of justifications failed",
+                "Invalid batch set info"
+            );
             return Err(HistoryRequestError::InvalidMacroBlock);
         }
 
@@ -322,7 +347,7 @@ impl<TNetwork: Network + 'static> SyncCluster<TNetwork> {
                 return Err(HistoryRequestError::InvalidBatchSetInfo);
             }
 
-            Self::verify_macro_block(&block, network, predecessor_macro_block, validators)?;
+            Self::verify_macro_block(&block, network, predecessor_macro_block)?;
         }
 
         let mut last_seen_macro_block = predecessor_macro_block.clone();
@@ -339,7 +364,7 @@ impl<TNetwork: Network + 'static> SyncCluster<TNetwork> {
             }
 
             // Check the macro block of the batch set.
-            Self::verify_macro_block(&block, network, &last_seen_macro_block, validators)?;
+            Self::verify_macro_block(&block, network, &last_seen_macro_block)?;
 
             // Check the history size proof.
             if !batch_set.history_len.verify(block.history_root()) {
@@ -349,7 +374,8 @@ impl<TNetwork: Network + 'static> SyncCluster<TNetwork> {
             last_seen_macro_block = block;
         }
 
-        Ok(())
+        // Finally, verify the justifications of all macro blocks at once.
+        Self::verify_justifications(batch_set_info, validators)
     }
 
     fn on_epoch_received(&mut self, epoch: BatchSetInfo) -> Result<(), SyncClusterResult> {
@@ -762,3 +788,92 @@ impl From<Result<PushResult, PushError>> for SyncClusterResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use nimiq_blockchain::{
+        interface::HistoryInterface, BlockProducer, Blockchain, BlockchainConfig,
+    };
+    use nimiq_blockchain_interface::AbstractBlockchain;
+    use nimiq_database::mdbx::MdbxDatabase;
+    use nimiq_network_mock::MockNetwork;
+    use nimiq_primitives::{networks::NetworkId, policy::Policy};
+    use nimiq_test_log::test;
+    use nimiq_test_utils::blockchain::{produce_macro_blocks, signing_key, voting_key};
+    use nimiq_utils::time::OffsetTime;
+    use parking_lot::RwLock;
+
+    use super::{HistoryRequestError, SyncCluster};
+    use crate::messages::{BatchSet, BatchSetInfo};
+
+    #[test]
+    fn it_batch_verifies_the_justifications_of_a_batch_set_info() {
+        let blockchain = Arc::new(RwLock::new(
+            Blockchain::new(
+                MdbxDatabase::new_volatile(Default::default()).unwrap(),
+                BlockchainConfig::default(),
+                NetworkId::UnitAlbatross,
+                Arc::new(OffsetTime::new()),
+            )
+            .unwrap(),
+        ));
+        let genesis_block = blockchain.read().head().clone();
+        let validators = blockchain.read().current_validators().unwrap().clone();
+
+        let producer = BlockProducer::new(signing_key(), voting_key());
+        produce_macro_blocks(&producer, &blockchain, Policy::batches_per_epoch() as usize);
+
+        // Build the batch set info of the first epoch with all of its macro blocks.
+        let blockchain = blockchain.read();
+        let batch_sets: Vec<BatchSet> = (1..=Policy::batches_per_epoch())
+            .map(|batch_number| {
+                let block_number =
+                    Policy::genesis_block_number() + batch_number * Policy::blocks_per_batch();
+                BatchSet {
+                    macro_block: blockchain
+                        .get_block_at(block_number, true, None)
+                        .unwrap()
+                        .unwrap_macro(),
+                    history_len: blockchain
+                        .history_store
+                        .prove_num_leaves(block_number, None)
+                        .unwrap(),
+                }
+            })
+            .collect();
+        let batch_set_info = BatchSetInfo {
+            election_macro_block: batch_sets
+                .last()
+                .map(|batch_set| batch_set.macro_block.clone()),
+            batch_sets,
+        };
+
+        assert!(SyncCluster::<MockNetwork>::verify_batch_set_info(
+            NetworkId::UnitAlbatross,
+            &batch_set_info,
+            &genesis_block,
+            &validators,
+        )
+        .is_ok());
+
+        // A single invalid justification fails the whole batch.
+        let mut invalid_batch_set_info = batch_set_info.clone();
+        invalid_batch_set_info.batch_sets[0]
+            .macro_block
+            .justification = batch_set_info.batch_sets[1]
+            .macro_block
+            .justification
+            .clone();
+        assert!(matches!(
+            SyncCluster::<MockNetwork>::verify_batch_set_info(
+                NetworkId::UnitAlbatross,
+                &invalid_batch_set_info,
+                &genesis_block,
+                &validators,
+            ),
+            Err(HistoryRequestError::InvalidMacroBlock)
+        ));
+    }
+}
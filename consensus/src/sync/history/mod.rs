@@ -1,6 +1,8 @@
+mod backfill;
 pub mod cluster;
 mod sync;
 mod sync_clustering;
 mod sync_stream;
 
+pub use backfill::HistoryBackfill;
 pub use sync::HistoryMacroSync;
@@ -126,11 +126,14 @@ impl<TNetwork: Network> HistoryMacroSync<TNetwork> {
                                 batch_set.block.epoch_number(),
                                 batch_set.history.len()
                             );
+                            // The justifications of all blocks emitted by the cluster were already
+                            // batch verified in `SyncCluster::verify_batch_set_info`.
                             let result = spawn_blocking(move || {
                                 Blockchain::push_history_sync(
                                     blockchain.upgradable_read(),
                                     Block::Macro(batch_set.block),
                                     &batch_set.history,
+                                    true,
                                 )
                             })
                             .await
@@ -5,7 +5,7 @@ use std::{
 };
 
 use futures::{FutureExt, Stream, StreamExt};
-use nimiq_block::Block;
+use nimiq_block::{Block, MacroBlock};
 #[cfg(feature = "full")]
 use nimiq_blockchain::Blockchain;
 use nimiq_blockchain_interface::AbstractBlockchain;
@@ -270,6 +270,33 @@ impl<TNetwork: Network> LightMacroSync<TNetwork> {
         Poll::Pending
     }
 
+    /// Batch verifies the justifications of the given macro blocks that extend our current macro
+    /// head. Returns true only if there was at least one such block and all of them are valid.
+    fn verify_justifications(blockchain: &BlockchainProxy, blocks: &[Block]) -> bool {
+        let blockchain = blockchain.read();
+        let macro_head_number = blockchain.macro_head().block_number();
+
+        let blocks: Vec<&MacroBlock> = blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Macro(block) if block.block_number() > macro_head_number => Some(block),
+                _ => None,
+            })
+            .collect();
+
+        if blocks.is_empty() {
+            return false;
+        }
+
+        let validators = match blockchain.current_validators() {
+            Some(validators) => validators,
+            None => return false,
+        };
+
+        MacroBlock::verify_validators_batch(blocks, &blockchain.election_head_hash(), validators)
+            .is_ok()
+    }
+
     fn poll_macro_blocks(
         &mut self,
         cx: &mut Context<'_>,
@@ -290,9 +317,18 @@ impl<TNetwork: Network> LightMacroSync<TNetwork> {
                         if peer_requests.is_ready() {
                             log::trace!(%peer_id, "All pending requests are ready");
 
+                            let mut blocks = vec![];
                             while let Some((_, block)) = peer_requests.pop_request() {
-                                let block = block.expect("At this point the queue should be ready");
+                                blocks
+                                    .push(block.expect("At this point the queue should be ready"));
+                            }
+
+                            // Verify the justifications of all blocks at once. If this fails, we
+                            // fall back to verifying each block individually when pushing it.
+                            let trusted_justification =
+                                Self::verify_justifications(&self.blockchain, &blocks);
 
+                            for block in blocks {
                                 // Check if the block is still valid for us or if it is outdated before trying to apply it
                                 let push_result = match self.blockchain {
                                     #[cfg(feature = "full")]
@@ -328,7 +364,11 @@ impl<TNetwork: Network> LightMacroSync<TNetwork> {
                                                 peer_id,
                                             )));
                                         } else {
-                                            Blockchain::push_macro(blockchain, block.clone())
+                                            Blockchain::push_macro(
+                                                blockchain,
+                                                block.clone(),
+                                                trusted_justification,
+                                            )
                                         }
                                     }
                                     BlockchainProxy::Light(ref light_blockchain) => {
@@ -344,7 +384,11 @@ impl<TNetwork: Network> LightMacroSync<TNetwork> {
                                             )));
                                         }
 
-                                        LightBlockchain::push_macro(blockchain, block.clone())
+                                        LightBlockchain::push_macro(
+                                            blockchain,
+                                            block.clone(),
+                                            trusted_justification,
+                                        )
                                     }
                                 };
 
@@ -452,6 +496,7 @@ mod tests {
     use std::sync::Arc;
 
     use futures::StreamExt;
+    use nimiq_block::Block;
     use nimiq_blockchain::{BlockProducer, Blockchain, BlockchainConfig};
     use nimiq_blockchain_interface::{AbstractBlockchain, PushResult};
     use nimiq_blockchain_proxy::BlockchainProxy;
@@ -461,7 +506,9 @@ mod tests {
     use nimiq_network_mock::{MockHub, MockNetwork};
     use nimiq_primitives::{networks::NetworkId, policy::Policy};
     use nimiq_test_log::test;
-    use nimiq_test_utils::blockchain::{produce_macro_blocks_with_txns, signing_key, voting_key};
+    use nimiq_test_utils::blockchain::{
+        produce_macro_blocks, produce_macro_blocks_with_txns, signing_key, voting_key,
+    };
     use nimiq_utils::{spawn, time::OffsetTime};
     use parking_lot::RwLock;
 
@@ -518,6 +565,54 @@ mod tests {
         };
     }
 
+    #[test]
+    fn it_batch_verifies_the_justifications_of_a_macro_chain() {
+        let chain2 = blockchain();
+        let producer = BlockProducer::new(signing_key(), voting_key());
+        if let BlockchainProxy::Full(ref chain2) = chain2 {
+            produce_macro_blocks(
+                &producer,
+                chain2,
+                2 * Policy::batches_per_epoch() as usize + 1,
+            );
+        }
+
+        // Two election blocks and the following checkpoint block, like in a macro chain.
+        let blocks: Vec<Block> = [
+            Policy::blocks_per_epoch(),
+            2 * Policy::blocks_per_epoch(),
+            2 * Policy::blocks_per_epoch() + Policy::blocks_per_batch(),
+        ]
+        .into_iter()
+        .map(|block_number| {
+            chain2
+                .read()
+                .get_block_at(block_number + Policy::genesis_block_number(), true)
+                .unwrap()
+        })
+        .collect();
+
+        let mut invalid_blocks = blocks.clone();
+        if let Block::Macro(ref mut block) = invalid_blocks[1] {
+            block.justification = blocks[0].unwrap_macro_ref().justification.clone();
+        }
+
+        for chain1 in [blockchain(), light_blockchain()] {
+            assert!(LightMacroSync::<MockNetwork>::verify_justifications(
+                &chain1, &blocks
+            ));
+            assert!(!LightMacroSync::<MockNetwork>::verify_justifications(
+                &chain1,
+                &invalid_blocks
+            ));
+            // An empty batch is never considered verified.
+            assert!(!LightMacroSync::<MockNetwork>::verify_justifications(
+                &chain1,
+                &blocks[..0]
+            ));
+        }
+    }
+
     #[test(tokio::test)]
     async fn it_terminates_if_the_peer_does_not_answer_the_zkp_request() {
         async fn test(chain1: BlockchainProxy, chain2: BlockchainProxy) {
@@ -823,7 +918,11 @@ mod tests {
                     .unwrap();
 
                 assert_eq!(
-                    Blockchain::push_macro(chain1.upgradable_read(), block_to_delete.clone()),
+                    Blockchain::push_macro(
+                        chain1.upgradable_read(),
+                        block_to_delete.clone(),
+                        false
+                    ),
                     Ok(PushResult::Extended),
                 );
                 assert_eq!(
@@ -836,6 +935,7 @@ mod tests {
                                 true
                             )
                             .unwrap(),
+                        false,
                     ),
                     Ok(PushResult::Extended),
                 );
@@ -528,7 +528,7 @@ impl<N: Network> BlockQueue<N> {
                     block_infos.push(block);
                 }
             }
-            BlockchainEvent::HistoryAdopted(block_hash)
+            BlockchainEvent::HistoryAdopted(block_hash, _)
             | BlockchainEvent::Finalized(block_hash)
             | BlockchainEvent::EpochFinalized(block_hash) => {
                 if let Ok(block) = self.blockchain.read().get_block(&block_hash, false) {
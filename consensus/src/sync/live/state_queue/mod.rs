@@ -542,7 +542,7 @@ impl<N: Network> Stream for StateQueue<N> {
                         self.diff_queue.set_diff_needed(true);
                     }
                 }
-                BlockchainEvent::HistoryAdopted(_) => {
+                BlockchainEvent::HistoryAdopted(..) => {
                     // Nothing to do for adopted history
                 }
                 BlockchainEvent::Stored(_block) => {
@@ -73,11 +73,18 @@ pub struct MdbxDatabase {
     /// For volatile databases, this is the temporary directory handle,
     /// which will clean up on `Drop`.
     temp_dir: Option<Arc<TempDir>>,
+    /// Whether the database was opened without write access.
+    read_only: bool,
 }
 
 impl MdbxDatabase {
     /// Create a table with additional flags.
     fn create_table<T: Table>(&self, _table: &T, mut flags: libmdbx::TableFlags) {
+        // Tables can't be created in read-only databases, they must exist already.
+        if self.read_only {
+            return;
+        }
+
         // Ensure `CREATE` flag is set.
         flags.insert(libmdbx::TableFlags::CREATE);
 
@@ -108,11 +115,26 @@ impl MdbxDatabase {
         let mdbx = MdbxDatabase {
             db: Arc::new(db),
             temp_dir: None,
+            read_only: false,
         };
 
         Ok(mdbx)
     }
 
+    /// Opens an existing database at the given path without write access. Tables are not created,
+    /// so only tables that already exist can be accessed, and write transactions fail.
+    pub fn new_read_only<P: AsRef<Path>>(path: P, config: DatabaseConfig) -> Result<Self, Error> {
+        let mut options = libmdbx::DatabaseOptions::from(config);
+        options.mode = libmdbx::Mode::ReadOnly;
+        let db = libmdbx::Database::open_with_options(path, options)?;
+
+        Ok(MdbxDatabase {
+            db: Arc::new(db),
+            temp_dir: None,
+            read_only: true,
+        })
+    }
+
     /// Creates a volatile database (in a temporary directory, which cleans itself after use).
     pub fn new_volatile(config: DatabaseConfig) -> Result<Self, Error> {
         let temp_dir = Arc::new(TempDir::new()?);
@@ -121,6 +143,11 @@ impl MdbxDatabase {
 
         Ok(mdbx)
     }
+
+    /// Returns whether the database was opened without write access.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
 }
 
 impl Database for MdbxDatabase {
@@ -46,6 +46,29 @@ mod tests {
     declare_table!(U32DupTable, "u32_dup", u32 => dup(u32));
     declare_table!(U32Table, "u32_nodup", u32 => u32);
 
+    #[test]
+    fn it_can_read_existing_databases_without_write_access() {
+        let tempdir = tempdir().unwrap();
+        let path = tempdir.path().join("test");
+        let table = TestTable {};
+        {
+            let db = MdbxDatabase::new(&path, Default::default()).unwrap();
+            db.create_regular_table(&table);
+
+            let mut tx = db.write_transaction();
+            tx.put(&table, &"test".to_string(), &"one".to_string());
+            tx.commit();
+        }
+
+        let db = MdbxDatabase::new_read_only(&path, Default::default()).unwrap();
+        assert!(db.is_read_only());
+        // Creating an existing table is a no-op.
+        db.create_regular_table(&table);
+
+        let tx = db.read_transaction();
+        assert_eq!(tx.get(&table, &"test".to_string()), Some("one".to_string()));
+    }
+
     #[test]
     fn it_can_save_basic_objects() {
         let tempdir = tempdir().unwrap();
@@ -0,0 +1,110 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use async_trait::async_trait;
+use nimiq_jsonrpc_server::{Config, ModularDispatcher, Server as _Server};
+use nimiq_keys::Address;
+use nimiq_rpc_interface::types::RPCResult;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::{devnet::Devnet, error::Error};
+
+pub type Server = _Server<ModularDispatcher>;
+
+/// State of a devnet node as reported by the control API.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfo {
+    pub index: usize,
+    pub validator_address: Address,
+    pub p2p_address: String,
+    pub rpc_url: String,
+    pub running: bool,
+    pub partitioned: bool,
+}
+
+#[nimiq_jsonrpc_derive::proxy(name = "DevnetProxy", rename_all = "camelCase")]
+#[async_trait]
+pub trait DevnetInterface {
+    type Error;
+
+    /// Returns the state of all nodes of the devnet.
+    async fn get_nodes(&mut self) -> RPCResult<Vec<NodeInfo>, (), Self::Error>;
+
+    /// Starts a stopped node.
+    async fn start_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error>;
+
+    /// Stops a running node. Its state is kept, so it can be started again later.
+    async fn stop_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error>;
+
+    /// Stops and starts a node again.
+    async fn restart_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error>;
+
+    /// Disconnects a node from all other nodes and prevents it from reconnecting until it is
+    /// healed. All other nodes must have been started at least once.
+    async fn partition_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error>;
+
+    /// Lifts the partition of a node and reconnects it to the other nodes.
+    async fn heal_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error>;
+}
+
+pub struct DevnetDispatcher {
+    devnet: Arc<Mutex<Devnet>>,
+}
+
+impl DevnetDispatcher {
+    pub fn new(devnet: Arc<Mutex<Devnet>>) -> Self {
+        DevnetDispatcher { devnet }
+    }
+}
+
+#[nimiq_jsonrpc_derive::service(rename_all = "camelCase")]
+#[async_trait]
+impl DevnetInterface for DevnetDispatcher {
+    type Error = Error;
+
+    async fn get_nodes(&mut self) -> RPCResult<Vec<NodeInfo>, (), Self::Error> {
+        Ok(self.devnet.lock().await.node_infos().into())
+    }
+
+    async fn start_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error> {
+        self.devnet.lock().await.start(index)?;
+        Ok(().into())
+    }
+
+    async fn stop_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error> {
+        self.devnet.lock().await.stop(index).await?;
+        Ok(().into())
+    }
+
+    async fn restart_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error> {
+        self.devnet.lock().await.restart(index).await?;
+        Ok(().into())
+    }
+
+    async fn partition_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error> {
+        self.devnet.lock().await.partition(index).await?;
+        Ok(().into())
+    }
+
+    async fn heal_node(&mut self, index: usize) -> RPCResult<(), (), Self::Error> {
+        self.devnet.lock().await.heal(index).await?;
+        Ok(().into())
+    }
+}
+
+/// Creates the control API server of the devnet.
+pub fn control_server(bind_to: SocketAddr, devnet: Arc<Mutex<Devnet>>) -> Server {
+    let mut dispatcher = ModularDispatcher::default();
+    dispatcher.add(DevnetDispatcher::new(devnet));
+
+    Server::new(
+        Config {
+            bind_to,
+            enable_websocket: true,
+            ip_whitelist: None,
+            basic_auth: None,
+        },
+        dispatcher,
+    )
+}
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use crate::{control::NodeInfo, error::Error, node::Node};
+
+/// The set of nodes forming a local devnet.
+pub struct Devnet {
+    nodes: Vec<Node>,
+    client_bin: PathBuf,
+    genesis_file: PathBuf,
+}
+
+impl Devnet {
+    pub fn new(nodes: Vec<Node>, client_bin: PathBuf, genesis_file: PathBuf) -> Self {
+        Devnet {
+            nodes,
+            client_bin,
+            genesis_file,
+        }
+    }
+
+    fn node(&mut self, index: usize) -> Result<&mut Node, Error> {
+        self.nodes.get_mut(index).ok_or(Error::UnknownNode(index))
+    }
+
+    pub fn node_infos(&mut self) -> Vec<NodeInfo> {
+        self.nodes
+            .iter_mut()
+            .map(|node| NodeInfo {
+                index: node.index,
+                validator_address: node.keys.address(),
+                p2p_address: node.p2p_address(),
+                rpc_url: node.rpc_url().to_string(),
+                running: node.is_running(),
+                partitioned: node.is_partitioned(),
+            })
+            .collect()
+    }
+
+    pub fn start_all(&mut self) -> Result<(), Error> {
+        for node in &mut self.nodes {
+            node.start(&self.client_bin, &self.genesis_file)?;
+        }
+        Ok(())
+    }
+
+    /// Stops all nodes that are still running.
+    pub async fn stop_all(&mut self) {
+        for node in &mut self.nodes {
+            if node.is_running() {
+                if let Err(error) = node.stop().await {
+                    log::error!(node = node.index, %error, "Failed to stop node");
+                }
+            }
+        }
+    }
+
+    pub fn start(&mut self, index: usize) -> Result<(), Error> {
+        let client_bin = self.client_bin.clone();
+        let genesis_file = self.genesis_file.clone();
+        self.node(index)?.start(&client_bin, &genesis_file)
+    }
+
+    pub async fn stop(&mut self, index: usize) -> Result<(), Error> {
+        self.node(index)?.stop().await
+    }
+
+    pub async fn restart(&mut self, index: usize) -> Result<(), Error> {
+        self.stop(index).await?;
+        self.start(index)
+    }
+
+    /// Cuts the node off from all other nodes of the devnet.
+    pub async fn partition(&mut self, index: usize) -> Result<(), Error> {
+        self.node(index)?;
+
+        let mut peer_ids = vec![];
+        for other in self.nodes.iter_mut().filter(|node| node.index != index) {
+            peer_ids.push(other.peer_id().await?);
+        }
+        self.nodes[index].partition(peer_ids).await
+    }
+
+    /// Reconnects a partitioned node to all other nodes of the devnet.
+    pub async fn heal(&mut self, index: usize) -> Result<(), Error> {
+        self.node(index)?;
+
+        let addresses = self
+            .nodes
+            .iter()
+            .filter(|node| node.index != index)
+            .map(|node| node.p2p_address())
+            .collect();
+        self.nodes[index].heal(addresses).await
+    }
+}
@@ -0,0 +1,51 @@
+use std::io;
+
+use nimiq_blockchain::state_reader::StateReaderError;
+use nimiq_genesis_builder::GenesisBuilderError;
+use nimiq_jsonrpc_core::RpcError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("Failed to build the genesis: {0}")]
+    Genesis(#[from] GenesisBuilderError),
+
+    #[error("Failed to serialize TOML: {0}")]
+    TomlSerialization(#[from] toml::ser::Error),
+
+    #[error("Database error: {0}")]
+    Database(#[from] nimiq_database::Error),
+
+    #[error("Failed to read the node state: {0}")]
+    NodeState(#[from] StateReaderError),
+
+    #[error("Devnet directory is not empty: {0}")]
+    DirectoryNotEmpty(String),
+
+    #[error("Unknown node: {0}")]
+    UnknownNode(usize),
+
+    #[error("Node {0} is already running")]
+    NodeRunning(usize),
+
+    #[error("Node {0} is not running")]
+    NodeNotRunning(usize),
+
+    #[error("Node {0} is already partitioned")]
+    NodePartitioned(usize),
+
+    #[error("Peer ID of node {0} is unknown, it has to be started at least once")]
+    UnknownPeerId(usize),
+
+    #[error("RPC request to node {0} failed: {1}")]
+    NodeRpc(usize, String),
+}
+
+impl From<Error> for RpcError {
+    fn from(e: Error) -> Self {
+        RpcError::internal_error(Some(serde_json::value::Value::String(e.to_string())))
+    }
+}
@@ -0,0 +1,117 @@
+use std::{fs, path::PathBuf, process::exit};
+
+use clap::Parser;
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_devnet::{
+    error::Error,
+    genesis::parse_account,
+    keys::{write_keys_file, ValidatorKeys},
+    snapshot::read_node_state,
+};
+use nimiq_genesis_builder::GenesisBuilder;
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use time::OffsetDateTime;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Forks a new chain from the state of a synced node")]
+struct Opt {
+    /// Database directory of a stopped full or history node.
+    #[clap(long)]
+    database: PathBuf,
+
+    /// Network of the node.
+    #[clap(long, default_value = "main-albatross")]
+    network: NetworkId,
+
+    /// Macro block to fork from, defaults to the node's latest macro block. The state of older
+    /// macro blocks is not stored by the node.
+    #[clap(long)]
+    block_number: Option<u32>,
+
+    /// Network of the forked chain.
+    #[clap(long, default_value = "dev-albatross")]
+    fork_network: NetworkId,
+
+    /// Maximum number of validators that stay active, the ones with the most stake are kept.
+    #[clap(long)]
+    max_validators: Option<usize>,
+
+    /// Balance override for a basic account, as `ADDRESS=BALANCE` with the balance in NIM.
+    #[clap(long = "balance", value_parser = parse_account)]
+    balances: Vec<(Address, Coin)>,
+
+    /// Directory for the genesis config, block, accounts and the validator keys.
+    #[clap(short, long, default_value = "fork")]
+    output: PathBuf,
+}
+
+fn run(opt: Opt) -> Result<(), Error> {
+    let (block, mut snapshot) = read_node_state(&opt.database, opt.network, opt.block_number)?;
+    let block_number = block.header.block_number;
+    log::info!(
+        block_number,
+        accounts = snapshot.basic_accounts.len()
+            + snapshot.vesting_accounts.len()
+            + snapshot.htlc_accounts.len(),
+        validators = snapshot.validators.len(),
+        stakers = snapshot.stakers.len(),
+        "Read node state"
+    );
+
+    if let Some(max_validators) = opt.max_validators {
+        snapshot.limit_active_validators(max_validators, block_number);
+    }
+
+    // Every validator gets new signing and voting keys, so the forked chain can be run locally.
+    let mut validators = vec![];
+    snapshot.replace_validator_keys(|address| {
+        let keys = ValidatorKeys::generate_for(address.clone());
+        let public_keys = (keys.signing.public, keys.voting.public_key);
+        validators.push(keys);
+        public_keys
+    });
+
+    for (address, balance) in opt.balances {
+        snapshot.set_balance(address, balance)?;
+    }
+
+    let mut config = snapshot.into_genesis_config(opt.fork_network);
+    config.block_number = block_number;
+    config.timestamp =
+        OffsetDateTime::from_unix_timestamp((block.header.timestamp / 1000) as i64).ok();
+    config.vrf_seed = Some(block.header.seed.clone());
+    config.parent_hash = Some(block.hash());
+    config.parent_election_hash = Some(block.header.parent_election_hash.clone());
+
+    fs::create_dir_all(&opt.output)?;
+    let genesis_file = opt.output.join("genesis.toml");
+    fs::write(&genesis_file, toml::to_string(&config)?)?;
+
+    let db = MdbxDatabase::new_volatile(Default::default())?;
+    let genesis_hash =
+        GenesisBuilder::from_config_file(&genesis_file)?.write_to_files(db, &opt.output)?;
+    write_keys_file(&validators, &opt.output.join("validators.toml"))?;
+
+    println!(
+        "Forked block {} into genesis {} at {}",
+        block_number,
+        genesis_hash,
+        opt.output.display()
+    );
+    Ok(())
+}
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    if let Err(error) = run(Opt::parse()) {
+        eprintln!("Error: {error}");
+        exit(1);
+    }
+}
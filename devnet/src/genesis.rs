@@ -0,0 +1,97 @@
+use std::{fs, path::Path, str::FromStr};
+
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_genesis_builder::{
+    config::{GenesisAccount, GenesisConfig, GenesisStaker, GenesisValidator},
+    GenesisBuilder,
+};
+use nimiq_hash::Blake2bHash;
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
+use nimiq_vrf::VrfSeed;
+use time::OffsetDateTime;
+
+use crate::{error::Error, keys::ValidatorKeys};
+
+/// Creates the genesis config of a devnet. Every validator stakes `stake` on itself and
+/// additionally gets `balance` in a basic account to pay for transactions.
+pub fn genesis_config(
+    validators: &[ValidatorKeys],
+    stake: Coin,
+    balance: Coin,
+    accounts: &[(Address, Coin)],
+) -> GenesisConfig {
+    let mut basic_accounts: Vec<GenesisAccount> = accounts
+        .iter()
+        .map(|(address, balance)| GenesisAccount {
+            address: address.clone(),
+            balance: *balance,
+        })
+        .collect();
+
+    for keys in validators {
+        basic_accounts.push(GenesisAccount {
+            address: keys.address(),
+            balance,
+        });
+    }
+
+    GenesisConfig {
+        network: NetworkId::DevAlbatross,
+        // All nodes need to derive the very same genesis block, so the timestamp and the VRF seed
+        // must be fixed in the config.
+        timestamp: Some(OffsetDateTime::now_utc().replace_nanosecond(0).unwrap()),
+        vrf_seed: Some(VrfSeed::default()),
+        parent_election_hash: None,
+        parent_hash: None,
+        history_root: None,
+        block_number: 0,
+        validators: validators
+            .iter()
+            .map(|keys| GenesisValidator {
+                validator_address: keys.address(),
+                signing_key: keys.signing.public,
+                voting_key: keys.voting.public_key,
+                reward_address: keys.address(),
+                inactive_from: None,
+                jailed_from: None,
+                retired: false,
+                commission: Policy::MAX_COMMISSION,
+            })
+            .collect(),
+        stakers: validators
+            .iter()
+            .map(|keys| GenesisStaker {
+                staker_address: keys.address(),
+                balance: stake,
+                delegation: Some(keys.address()),
+                inactive_balance: Coin::ZERO,
+                inactive_from: None,
+                retired_balance: Coin::ZERO,
+            })
+            .collect(),
+        basic_accounts,
+        vesting_accounts: vec![],
+        htlc_accounts: vec![],
+    }
+}
+
+/// Writes the genesis config to `path` and builds the genesis block from it, exactly like the
+/// nodes will do when `NIMIQ_OVERRIDE_DEVNET_CONFIG` points to that file.
+pub fn write_genesis_config(config: &GenesisConfig, path: &Path) -> Result<Blake2bHash, Error> {
+    fs::write(path, toml::to_string(config)?)?;
+
+    let db = MdbxDatabase::new_volatile(Default::default())?;
+    let genesis = GenesisBuilder::from_config_file(path)?.generate(db)?;
+    Ok(genesis.hash)
+}
+
+/// Parses an `ADDRESS=BALANCE` command line argument, with the balance in NIM.
+pub fn parse_account(s: &str) -> Result<(Address, Coin), String> {
+    let (address, balance) = s
+        .split_once('=')
+        .ok_or_else(|| "expected ADDRESS=BALANCE".to_string())?;
+    let address = Address::from_any_str(address.trim()).map_err(|e| e.to_string())?;
+    let balance = Coin::from_str(balance.trim()).map_err(|e| e.to_string())?;
+    Ok((address, balance))
+}
@@ -0,0 +1,98 @@
+use std::{fs, path::Path};
+
+use nimiq_bls::KeyPair as BlsKeyPair;
+use nimiq_keys::{Address, KeyPair};
+use nimiq_tools::keys::{
+    bls_secret_key_hex, generate_bls_key_pair, generate_key_pair, private_key_hex,
+};
+use toml::{Table, Value};
+
+use crate::error::Error;
+
+/// All keys a devnet validator needs. They are generated with the same tooling as
+/// `nimiq-address` and `nimiq-bls`.
+pub struct ValidatorKeys {
+    /// The validator address. Also used as reward address and staker.
+    pub address: Address,
+    /// Key pair controlling the validator address, if it was generated locally.
+    pub validator: Option<KeyPair>,
+    /// Schnorr key pair used to sign micro blocks.
+    pub signing: KeyPair,
+    /// BLS key pair used to vote in Tendermint and sign macro blocks.
+    pub voting: BlsKeyPair,
+    /// Key pair used to pay the fees of the validator's automatic transactions.
+    pub fee: KeyPair,
+}
+
+impl ValidatorKeys {
+    /// Generates the keys for a new validator, including its address.
+    pub fn generate() -> Self {
+        let validator = generate_key_pair();
+        let mut keys = Self::generate_for(Address::from(&validator.public));
+        keys.validator = Some(validator);
+        keys
+    }
+
+    /// Generates new keys to run the existing validator with the given address.
+    pub fn generate_for(address: Address) -> Self {
+        ValidatorKeys {
+            address,
+            validator: None,
+            signing: generate_key_pair(),
+            voting: generate_bls_key_pair(),
+            fee: generate_key_pair(),
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address.clone()
+    }
+
+    /// The signing secret key as expected by the `[validator]` section of the client config.
+    pub fn signing_key_hex(&self) -> String {
+        private_key_hex(&self.signing.private)
+    }
+
+    /// The voting secret key as expected by the `[validator]` section of the client config.
+    pub fn voting_key_hex(&self) -> String {
+        bls_secret_key_hex(&self.voting.secret_key)
+    }
+
+    /// The fee secret key as expected by the `[validator]` section of the client config.
+    pub fn fee_key_hex(&self) -> String {
+        private_key_hex(&self.fee.private)
+    }
+}
+
+/// Writes the secret keys of all validators to a TOML file, so that they can be used to
+/// configure nodes and to send validator transactions.
+pub fn write_keys_file(validators: &[ValidatorKeys], path: &Path) -> Result<(), Error> {
+    let validators = validators
+        .iter()
+        .map(|keys| {
+            let mut table = Table::new();
+            table.insert(
+                "validator_address".to_string(),
+                Value::from(keys.address.to_user_friendly_address()),
+            );
+            if let Some(validator) = &keys.validator {
+                table.insert(
+                    "validator_private_key".to_string(),
+                    Value::from(private_key_hex(&validator.private)),
+                );
+            }
+            table.insert(
+                "signing_key".to_string(),
+                Value::from(keys.signing_key_hex()),
+            );
+            table.insert("voting_key".to_string(), Value::from(keys.voting_key_hex()));
+            table.insert("fee_key".to_string(), Value::from(keys.fee_key_hex()));
+            Value::Table(table)
+        })
+        .collect::<Vec<_>>();
+
+    let mut file = Table::new();
+    file.insert("validators".to_string(), Value::Array(validators));
+    fs::write(path, toml::to_string(&file)?)?;
+    Ok(())
+}
@@ -0,0 +1,14 @@
+//! Launches a local Albatross devnet: generates validator keys, builds a genesis for them and runs
+//! every validator as a `nimiq-client` child process. The nodes can be stopped, restarted and
+//! partitioned through a JSON-RPC control API.
+//!
+//! Additionally, a genesis can be forked from the state of an existing chain, see
+//! [`snapshot::read_node_state`].
+
+pub mod control;
+pub mod devnet;
+pub mod error;
+pub mod genesis;
+pub mod keys;
+pub mod node;
+pub mod snapshot;
@@ -0,0 +1,144 @@
+use std::{env, fs, net::SocketAddr, path::PathBuf, process::exit, sync::Arc};
+
+use clap::Parser;
+use nimiq_devnet::{
+    control::control_server,
+    devnet::Devnet,
+    error::Error,
+    genesis::{genesis_config, parse_account, write_genesis_config},
+    keys::{write_keys_file, ValidatorKeys},
+    node::Node,
+};
+use nimiq_keys::Address;
+use nimiq_primitives::coin::Coin;
+use tokio::sync::Mutex;
+
+#[derive(Debug, Parser)]
+#[clap(about = "Launches a local devnet with the given number of validators")]
+struct Opt {
+    /// Number of validators to run.
+    #[clap(short = 'n', long, default_value_t = 4)]
+    validators: usize,
+
+    /// Pre-funded basic account added to the genesis, as `ADDRESS=BALANCE` with the balance in NIM.
+    #[clap(short, long = "account", value_parser = parse_account)]
+    accounts: Vec<(Address, Coin)>,
+
+    /// Stake each validator delegates to itself, in NIM.
+    #[clap(long, default_value = "100000")]
+    stake: Coin,
+
+    /// Balance of each validator's basic account, in NIM.
+    #[clap(long, default_value = "10000")]
+    validator_balance: Coin,
+
+    /// Directory for the genesis, the node configs, databases and logs. Must be empty.
+    #[clap(short, long, default_value = "devnet")]
+    dir: PathBuf,
+
+    /// Path of the `nimiq-client` binary. Defaults to the one next to this binary.
+    #[clap(long)]
+    client_bin: Option<PathBuf>,
+
+    /// P2P port of the first node, the following nodes use the subsequent ports.
+    #[clap(long, default_value_t = 8443)]
+    p2p_port: u16,
+
+    /// RPC port of the first node, the following nodes use the subsequent ports.
+    #[clap(long, default_value_t = 8648)]
+    rpc_port: u16,
+
+    /// Address the control API listens on.
+    #[clap(long, default_value = "127.0.0.1:8600")]
+    control_bind: SocketAddr,
+}
+
+fn default_client_bin() -> PathBuf {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join("nimiq-client")))
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| PathBuf::from("nimiq-client"))
+}
+
+async fn run(opt: Opt) -> Result<(), Error> {
+    if opt.dir.exists() && fs::read_dir(&opt.dir)?.next().is_some() {
+        return Err(Error::DirectoryNotEmpty(opt.dir.display().to_string()));
+    }
+    fs::create_dir_all(&opt.dir)?;
+    let dir = fs::canonicalize(&opt.dir)?;
+
+    let validators: Vec<ValidatorKeys> = (0..opt.validators)
+        .map(|_| ValidatorKeys::generate())
+        .collect();
+
+    let genesis_file = dir.join("dev-albatross.toml");
+    let genesis = genesis_config(&validators, opt.stake, opt.validator_balance, &opt.accounts);
+    let genesis_hash = write_genesis_config(&genesis, &genesis_file)?;
+    log::info!(%genesis_hash, path = %genesis_file.display(), "Wrote genesis config");
+    write_keys_file(&validators, &dir.join("validators.toml"))?;
+
+    let nodes: Vec<Node> = validators
+        .into_iter()
+        .enumerate()
+        .map(|(index, keys)| {
+            Node::new(
+                index,
+                keys,
+                &dir,
+                opt.p2p_port + index as u16,
+                opt.rpc_port + index as u16,
+            )
+        })
+        .collect();
+
+    // Every node uses all other nodes as seeds, so any of them can be stopped or partitioned.
+    for node in &nodes {
+        let seed_nodes: Vec<String> = nodes
+            .iter()
+            .filter(|other| other.index != node.index)
+            .map(|other| other.p2p_address())
+            .collect();
+        node.write_config(&seed_nodes)?;
+    }
+
+    let client_bin = opt.client_bin.unwrap_or_else(default_client_bin);
+    let devnet = Arc::new(Mutex::new(Devnet::new(nodes, client_bin, genesis_file)));
+    devnet.lock().await.start_all()?;
+
+    for node in devnet.lock().await.node_infos() {
+        println!(
+            "node{}: validator {}, RPC {}, P2P {}",
+            node.index,
+            node.validator_address.to_user_friendly_address(),
+            node.rpc_url,
+            node.p2p_address,
+        );
+    }
+    println!("Control API listening on {}", opt.control_bind);
+
+    let server = control_server(opt.control_bind, Arc::clone(&devnet));
+    tokio::select! {
+        _ = server.run() => {},
+        _ = tokio::signal::ctrl_c() => {},
+    }
+
+    log::info!("Shutting down devnet");
+    devnet.lock().await.stop_all().await;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    if let Err(error) = run(Opt::parse()).await {
+        eprintln!("Error: {error}");
+        exit(1);
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    fs::{self, OpenOptions},
+    path::{Path, PathBuf},
+    process::Stdio,
+};
+
+use nimiq_jsonrpc_client::{websocket::WebsocketClient, ArcClient};
+use nimiq_rpc_interface::network::{NetworkInterface, NetworkProxy};
+use tokio::process::{Child, Command};
+use url::Url;
+
+use crate::{error::Error, keys::ValidatorKeys};
+
+/// A validator node of the devnet, running as a `nimiq-client` child process.
+pub struct Node {
+    pub index: usize,
+    pub keys: ValidatorKeys,
+    pub p2p_port: u16,
+    pub rpc_port: u16,
+    dir: PathBuf,
+    process: Option<Child>,
+    /// The peer ID of the node, known once it was started. It doesn't change across restarts
+    /// since the peer key is persisted in the node's directory.
+    peer_id: Option<String>,
+    /// Peer IDs the node has banned in order to partition it from the rest of the devnet.
+    partitioned_from: Vec<String>,
+}
+
+impl Node {
+    pub fn new(
+        index: usize,
+        keys: ValidatorKeys,
+        devnet_dir: &Path,
+        p2p_port: u16,
+        rpc_port: u16,
+    ) -> Self {
+        Node {
+            index,
+            keys,
+            p2p_port,
+            rpc_port,
+            dir: devnet_dir.join(format!("node{index}")),
+            process: None,
+            peer_id: None,
+            partitioned_from: vec![],
+        }
+    }
+
+    pub fn p2p_address(&self) -> String {
+        format!("/ip4/127.0.0.1/tcp/{}/ws", self.p2p_port)
+    }
+
+    pub fn rpc_url(&self) -> Url {
+        format!("ws://127.0.0.1:{}/ws", self.rpc_port)
+            .parse()
+            .unwrap()
+    }
+
+    pub fn config_path(&self) -> PathBuf {
+        self.dir.join("client.toml")
+    }
+
+    pub fn log_path(&self) -> PathBuf {
+        self.dir.join("node.log")
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        !self.partitioned_from.is_empty()
+    }
+
+    /// Writes the client config of this node, using `seed_nodes` to join the devnet.
+    pub fn write_config(&self, seed_nodes: &[String]) -> Result<(), Error> {
+        fs::create_dir_all(&self.dir)?;
+
+        let dir = self.dir.to_string_lossy();
+        let seed_nodes: String = seed_nodes
+            .iter()
+            .map(|address| format!("    {{ address = {} }},\n", toml_string(address)))
+            .collect();
+
+        let config = format!(
+            r#"[network]
+peer_key_file = {peer_key_file}
+listen_addresses = [
+    {listen_address},
+]
+seed_nodes = [
+{seed_nodes}]
+allow_loopback_addresses = true
+autonat_allow_non_global_ips = true
+
+[consensus]
+network = "dev-albatross"
+sync_mode = "full"
+min_peers = 1
+
+[database]
+path = {database_path}
+
+[log]
+level = "debug"
+timestamps = true
+
+[rpc-server]
+bind = "127.0.0.1"
+port = {rpc_port}
+
+[validator]
+validator_address = {validator_address}
+signing_key = {signing_key}
+voting_key = {voting_key}
+fee_key = {fee_key}
+automatic_reactivate = true
+"#,
+            peer_key_file = toml_string(&format!("{dir}/peer_key.dat")),
+            listen_address = toml_string(&self.p2p_address()),
+            database_path = toml_string(&dir),
+            rpc_port = self.rpc_port,
+            validator_address = toml_string(&self.keys.address().to_user_friendly_address()),
+            signing_key = toml_string(&self.keys.signing_key_hex()),
+            voting_key = toml_string(&self.keys.voting_key_hex()),
+            fee_key = toml_string(&self.keys.fee_key_hex()),
+        );
+
+        fs::write(self.config_path(), config)?;
+        Ok(())
+    }
+
+    /// Returns whether the node process is running, reaping it if it exited on its own.
+    pub fn is_running(&mut self) -> bool {
+        if let Some(process) = &mut self.process {
+            match process.try_wait() {
+                Ok(None) => return true,
+                Ok(Some(status)) => log::warn!(node = self.index, %status, "Node exited"),
+                Err(error) => log::warn!(node = self.index, %error, "Failed to check node process"),
+            }
+            self.process = None;
+        }
+        false
+    }
+
+    /// Launches `client_bin` for this node with the devnet genesis.
+    pub fn start(&mut self, client_bin: &Path, genesis_file: &Path) -> Result<(), Error> {
+        if self.is_running() {
+            return Err(Error::NodeRunning(self.index));
+        }
+
+        let log_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.log_path())?;
+
+        let process = Command::new(client_bin)
+            .arg("-c")
+            .arg(self.config_path())
+            .env("NIMIQ_OVERRIDE_DEVNET_CONFIG", genesis_file)
+            .stdin(Stdio::null())
+            .stdout(log_file.try_clone()?)
+            .stderr(log_file)
+            .kill_on_drop(true)
+            .spawn()?;
+
+        log::info!(
+            node = self.index,
+            pid = process.id(),
+            rpc_url = %self.rpc_url(),
+            "Started node"
+        );
+        self.process = Some(process);
+        Ok(())
+    }
+
+    /// Kills the node process.
+    pub async fn stop(&mut self) -> Result<(), Error> {
+        if !self.is_running() {
+            return Err(Error::NodeNotRunning(self.index));
+        }
+
+        if let Some(mut process) = self.process.take() {
+            process.kill().await?;
+        }
+        log::info!(node = self.index, "Stopped node");
+        Ok(())
+    }
+
+    /// Connects to the network RPC interface of the node.
+    async fn network(&mut self) -> Result<NetworkProxy<ArcClient<WebsocketClient>>, Error> {
+        if !self.is_running() {
+            return Err(Error::NodeNotRunning(self.index));
+        }
+
+        let client = WebsocketClient::new(self.rpc_url(), None)
+            .await
+            .map_err(|error| Error::NodeRpc(self.index, error.to_string()))?;
+        Ok(NetworkProxy::new(ArcClient::new(client)))
+    }
+
+    /// Returns the peer ID of the node, asking the node for it if it isn't known yet.
+    pub async fn peer_id(&mut self) -> Result<String, Error> {
+        if let Some(peer_id) = &self.peer_id {
+            return Ok(peer_id.clone());
+        }
+
+        if !self.is_running() {
+            return Err(Error::UnknownPeerId(self.index));
+        }
+
+        let index = self.index;
+        let peer_id = self
+            .network()
+            .await?
+            .get_peer_id()
+            .await
+            .map_err(|error| Error::NodeRpc(index, error.to_string()))?
+            .data;
+        self.peer_id = Some(peer_id.clone());
+        Ok(peer_id)
+    }
+
+    /// Bans the given peers on this node, which cuts it off from them. Since bans are persisted,
+    /// the partition survives restarts of the node.
+    pub async fn partition(&mut self, peer_ids: Vec<String>) -> Result<(), Error> {
+        if self.is_partitioned() {
+            return Err(Error::NodePartitioned(self.index));
+        }
+
+        let index = self.index;
+        let mut network = self.network().await?;
+        for peer_id in peer_ids {
+            network
+                .ban(peer_id.clone(), None)
+                .await
+                .map_err(|error| Error::NodeRpc(index, error.to_string()))?;
+            self.partitioned_from.push(peer_id);
+        }
+        log::info!(node = index, "Partitioned node");
+        Ok(())
+    }
+
+    /// Lifts the bans of a partition and dials the given addresses to reconnect.
+    pub async fn heal(&mut self, addresses: Vec<String>) -> Result<(), Error> {
+        let index = self.index;
+        let mut network = self.network().await?;
+        for peer_id in self.partitioned_from.clone() {
+            network
+                .unban(peer_id)
+                .await
+                .map_err(|error| Error::NodeRpc(index, error.to_string()))?;
+        }
+        self.partitioned_from.clear();
+        for address in addresses {
+            // Other nodes might be stopped, they will reconnect on their own once restarted.
+            if let Err(error) = network.dial_address(address.clone()).await {
+                log::debug!(node = index, address, %error, "Failed to dial node");
+            }
+        }
+        log::info!(node = index, "Healed node");
+        Ok(())
+    }
+}
+
+/// Quotes and escapes a string for use in the client config.
+fn toml_string(s: &str) -> String {
+    toml::Value::String(s.to_owned()).to_string()
+}
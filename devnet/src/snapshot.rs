@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use nimiq_block::MacroBlock;
+use nimiq_blockchain::state_reader::StateReader;
+use nimiq_database::{mdbx::MdbxDatabase, traits::Database};
+use nimiq_genesis::NetworkInfo;
+use nimiq_genesis_builder::snapshot::StateSnapshot;
+use nimiq_primitives::{networks::NetworkId, policy::Policy};
+
+use crate::error::Error;
+
+/// Reads the state of a stopped node's database at a macro block. The database is opened
+/// read-only and is never modified.
+///
+/// `block_number` defaults to the node's latest macro block. Other macro blocks are rejected,
+/// since the node doesn't store the state of older macro blocks.
+pub fn read_node_state(
+    database: &Path,
+    network_id: NetworkId,
+    block_number: Option<u32>,
+) -> Result<(MacroBlock, StateSnapshot), Error> {
+    // The policy needs to know the genesis block number before any blocks can be read.
+    let network_info = NetworkInfo::from_network_id(network_id);
+    let _ = Policy::get_or_init(Policy {
+        genesis_block_number: network_info.genesis_block().block_number(),
+        ..Default::default()
+    });
+
+    let env = MdbxDatabase::new_read_only(database, Default::default())?;
+    let (macro_block, accounts) =
+        StateReader::new(env, network_id).read_macro_state(block_number)?;
+
+    let txn = accounts.env.read_transaction();
+    let snapshot = StateSnapshot::read(&accounts, &txn)?;
+
+    Ok((macro_block, snapshot))
+}
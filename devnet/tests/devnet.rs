@@ -0,0 +1,170 @@
+use std::{fs, path::Path, sync::Arc};
+
+use nimiq_bls::{KeyPair as BlsKeyPair, SecretKey as BlsSecretKey};
+use nimiq_devnet::{
+    control::{DevnetDispatcher, DevnetInterface},
+    devnet::Devnet,
+    error::Error,
+    genesis::{genesis_config, parse_account, write_genesis_config},
+    keys::{write_keys_file, ValidatorKeys},
+    node::Node,
+};
+use nimiq_genesis_builder::config::GenesisConfig;
+use nimiq_keys::{Address, Ed25519PublicKey, PrivateKey};
+use nimiq_primitives::coin::Coin;
+use nimiq_serde::Deserialize;
+use nimiq_test_log::test;
+use tokio::sync::Mutex;
+
+fn private_key(hex: &str) -> PrivateKey {
+    PrivateKey::deserialize_from_vec(&hex::decode(hex).unwrap()).unwrap()
+}
+
+#[test]
+fn generated_keys_match_the_client_config_encoding() {
+    let keys = ValidatorKeys::generate();
+
+    let validator = keys.validator.as_ref().unwrap();
+    assert_eq!(keys.address(), Address::from(&validator.public));
+    assert_eq!(
+        Ed25519PublicKey::from(&private_key(&keys.signing_key_hex())),
+        keys.signing.public
+    );
+    assert_eq!(
+        Ed25519PublicKey::from(&private_key(&keys.fee_key_hex())),
+        keys.fee.public
+    );
+    let voting_key = BlsKeyPair::from(
+        BlsSecretKey::deserialize_from_vec(&hex::decode(keys.voting_key_hex()).unwrap()).unwrap(),
+    );
+    assert_eq!(voting_key.public_key, keys.voting.public_key);
+
+    // Keys for an existing validator don't include the key of its address.
+    let keys = ValidatorKeys::generate_for(keys.address());
+    assert!(keys.validator.is_none());
+}
+
+#[test]
+fn writes_the_keys_of_all_validators() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("validators.toml");
+    let validators = vec![ValidatorKeys::generate(), ValidatorKeys::generate()];
+    write_keys_file(&validators, &path).unwrap();
+
+    let file: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let entries = file["validators"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    for (entry, keys) in entries.iter().zip(&validators) {
+        assert_eq!(
+            entry["validator_address"].as_str().unwrap(),
+            keys.address().to_user_friendly_address()
+        );
+        assert_eq!(entry["voting_key"].as_str().unwrap(), keys.voting_key_hex());
+        assert!(entry.get("validator_private_key").is_some());
+    }
+}
+
+#[test]
+fn every_node_derives_the_same_genesis() {
+    let dir = tempfile::tempdir().unwrap();
+    let validators = vec![ValidatorKeys::generate(), ValidatorKeys::generate()];
+    let account = (
+        Address::from([1; Address::SIZE]),
+        Coin::from_u64_unchecked(42),
+    );
+    let stake = Coin::from_u64_unchecked(1_000_000);
+    let balance = Coin::from_u64_unchecked(1_000);
+    let config = genesis_config(&validators, stake, balance, &[account.clone()]);
+
+    let hash = write_genesis_config(&config, &dir.path().join("genesis.toml")).unwrap();
+    let other_hash = write_genesis_config(&config, &dir.path().join("other.toml")).unwrap();
+    assert_eq!(hash, other_hash);
+
+    let written: GenesisConfig =
+        toml::from_str(&fs::read_to_string(dir.path().join("genesis.toml")).unwrap()).unwrap();
+    assert_eq!(written.validators.len(), 2);
+    for (staker, keys) in written.stakers.iter().zip(&validators) {
+        assert_eq!(staker.staker_address, keys.address());
+        assert_eq!(staker.delegation, Some(keys.address()));
+        assert_eq!(staker.balance, stake);
+    }
+    assert_eq!(written.basic_accounts.len(), 3);
+    assert_eq!(written.basic_accounts[0].address, account.0);
+    assert_eq!(written.basic_accounts[0].balance, account.1);
+}
+
+#[test]
+fn parses_account_arguments() {
+    let address = Address::from([1; Address::SIZE]);
+    assert_eq!(
+        parse_account(&format!("{} = 12.5", address.to_user_friendly_address())),
+        Ok((address, Coin::from_u64_unchecked(1_250_000)))
+    );
+    assert!(parse_account("12.5").is_err());
+    assert!(parse_account("NQ00=12.5").is_err());
+}
+
+/// Writes a stand-in for `nimiq-client` that keeps running until it is killed.
+#[cfg(unix)]
+fn write_client_bin(dir: &Path) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join("client.sh");
+    fs::write(&path, "#!/bin/sh\nexec sleep 60\n").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[cfg(unix)]
+#[test(tokio::test)]
+async fn control_api_starts_and_stops_nodes() {
+    let dir = tempfile::tempdir().unwrap();
+    let nodes = (0..2)
+        .map(|index| {
+            let node = Node::new(
+                index,
+                ValidatorKeys::generate(),
+                dir.path(),
+                10100 + index as u16,
+                10200 + index as u16,
+            );
+            node.write_config(&[]).unwrap();
+            node
+        })
+        .collect();
+    let devnet = Devnet::new(
+        nodes,
+        write_client_bin(dir.path()),
+        dir.path().join("genesis.toml"),
+    );
+    let mut dispatcher = DevnetDispatcher::new(Arc::new(Mutex::new(devnet)));
+
+    let nodes = dispatcher.get_nodes().await.unwrap().data;
+    assert_eq!(nodes.len(), 2);
+    assert!(nodes.iter().all(|node| !node.running && !node.partitioned));
+    assert_eq!(nodes[1].rpc_url, "ws://127.0.0.1:10201/ws");
+
+    dispatcher.start_node(0).await.unwrap();
+    assert!(dispatcher.get_nodes().await.unwrap().data[0].running);
+    assert!(matches!(
+        dispatcher.start_node(0).await,
+        Err(Error::NodeRunning(0))
+    ));
+
+    // Node 1 was never started, so its peer ID is unknown.
+    assert!(matches!(
+        dispatcher.partition_node(0).await,
+        Err(Error::UnknownPeerId(1))
+    ));
+
+    dispatcher.stop_node(0).await.unwrap();
+    assert!(!dispatcher.get_nodes().await.unwrap().data[0].running);
+    assert!(matches!(
+        dispatcher.stop_node(1).await,
+        Err(Error::NodeNotRunning(1))
+    ));
+    assert!(matches!(
+        dispatcher.start_node(2).await,
+        Err(Error::UnknownNode(2))
+    ));
+}
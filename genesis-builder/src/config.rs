@@ -1,7 +1,7 @@
 use nimiq_bls::PublicKey as BlsPublicKey;
 use nimiq_hash::Blake2bHash;
 use nimiq_keys::{Address, Ed25519PublicKey as SchnorrPublicKey};
-use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
 use nimiq_serde::{Deserialize, Serialize};
 use nimiq_transaction::account::htlc_contract::AnyHash;
 use nimiq_vrf::VrfSeed;
@@ -65,16 +65,26 @@ pub struct GenesisValidator {
     pub jailed_from: Option<u32>,
     #[serde(default)]
     pub retired: bool,
+    /// The share of the rewards the validator keeps, in basis points. Validators keep all of
+    /// their rewards by default.
+    #[serde(default = "default_commission")]
+    pub commission: u16,
+}
+
+fn default_commission() -> u16 {
+    Policy::MAX_COMMISSION
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub struct GenesisStaker {
     pub staker_address: Address,
     pub balance: Coin,
-    pub delegation: Address,
+    pub delegation: Option<Address>,
     #[serde(default)]
     pub inactive_balance: Coin,
     pub inactive_from: Option<u32>,
+    #[serde(default)]
+    pub retired_balance: Coin,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
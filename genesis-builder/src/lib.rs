@@ -8,7 +8,7 @@ use std::{
 };
 
 use nimiq_account::{
-    Account, Accounts, BasicAccount, HashedTimeLockedContract, StakingContract,
+    Account, Accounts, BasicAccount, HashedTimeLockedContract, Staker, StakingContract,
     StakingContractStoreWrite, TransactionLog, VestingContract,
 };
 use nimiq_block::{Block, MacroBlock, MacroBody, MacroHeader};
@@ -31,6 +31,7 @@ use time::OffsetDateTime;
 use toml::de::Error as TomlError;
 
 pub mod config;
+pub mod snapshot;
 
 /// Errors that can be reported building the genesis
 #[derive(Debug, Error)]
@@ -50,6 +51,15 @@ pub enum GenesisBuilderError {
     /// Failure at staking
     #[error("Failed to stake: {0}")]
     StakingError(#[from] AccountError),
+    /// The accounts tree to take a snapshot of is incomplete.
+    #[error("Accounts tree is incomplete")]
+    IncompleteState,
+    /// The balance of a contract can't be overridden.
+    #[error("Not a basic account: {0}")]
+    NotABasicAccount(Address),
+    /// The commission of a validator exceeds the maximum commission.
+    #[error("Invalid commission for validator: {0}")]
+    InvalidCommission(Address),
 }
 
 /// Output of the Genesis builder that represents the Genesis block and its
@@ -209,6 +219,7 @@ impl GenesisBuilder {
             inactive_from,
             jailed_from,
             retired,
+            commission: Policy::MAX_COMMISSION,
         });
         self
     }
@@ -225,9 +236,10 @@ impl GenesisBuilder {
         self.stakers.push(config::GenesisStaker {
             staker_address,
             balance,
-            delegation: validator_address,
+            delegation: Some(validator_address),
             inactive_balance,
             inactive_from,
+            retired_balance: Coin::ZERO,
         });
         self
     }
@@ -441,6 +453,12 @@ impl GenesisBuilder {
         let mut store = StakingContractStoreWrite::new(&mut data_store_write);
 
         for validator in &self.validators {
+            if validator.commission > Policy::MAX_COMMISSION {
+                return Err(GenesisBuilderError::InvalidCommission(
+                    validator.validator_address.clone(),
+                ));
+            }
+
             staking_contract.create_validator(
                 &mut store,
                 &validator.validator_address,
@@ -448,6 +466,7 @@ impl GenesisBuilder {
                 validator.voting_key.compress(),
                 validator.reward_address.clone(),
                 None,
+                validator.commission,
                 deposit,
                 validator.inactive_from,
                 validator.jailed_from,
@@ -461,11 +480,28 @@ impl GenesisBuilder {
                 &mut store,
                 &staker.staker_address,
                 staker.balance,
-                Some(staker.delegation.clone()),
+                staker.delegation.clone(),
                 staker.inactive_balance,
                 staker.inactive_from,
                 &mut TransactionLog::empty(),
             )?;
+
+            // Creating a staker only accounts for its active balance, the retired balance has to be
+            // set and both the inactive and retired balances have to be added to the contract.
+            if !staker.retired_balance.is_zero() {
+                store.put_staker(
+                    &staker.staker_address,
+                    Staker {
+                        address: staker.staker_address.clone(),
+                        active_balance: staker.balance,
+                        inactive_balance: staker.inactive_balance,
+                        inactive_from: staker.inactive_from,
+                        retired_balance: staker.retired_balance,
+                        delegation: staker.delegation.clone(),
+                    },
+                );
+            }
+            staking_contract.balance += staker.inactive_balance + staker.retired_balance;
         }
 
         accounts
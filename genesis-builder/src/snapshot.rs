@@ -0,0 +1,238 @@
+use std::collections::{BTreeSet, HashMap};
+
+use nimiq_account::{Account, Accounts};
+use nimiq_bls::PublicKey as BlsPublicKey;
+use nimiq_database::mdbx::MdbxReadTransaction;
+use nimiq_keys::{Address, Ed25519PublicKey as SchnorrPublicKey};
+use nimiq_primitives::{coin::Coin, key_nibbles::KeyNibbles, networks::NetworkId, policy::Policy};
+use nimiq_serde::Deserialize;
+
+use crate::{
+    config::{
+        GenesisAccount, GenesisConfig, GenesisHTLC, GenesisStaker, GenesisValidator,
+        GenesisVestingContract,
+    },
+    GenesisBuilderError,
+};
+
+/// Number of trie items that are read at once while taking a snapshot.
+const SNAPSHOT_CHUNK_SIZE: usize = 10_000;
+
+/// The accounts, validators and stakers of an existing chain state, as entries of a genesis
+/// config. Used to fork a new chain from the state of an existing one.
+///
+/// Validator deposits are not part of the snapshot, since the genesis always creates validators
+/// with the standard deposit.
+#[derive(Clone, Debug, Default)]
+pub struct StateSnapshot {
+    pub basic_accounts: Vec<GenesisAccount>,
+    pub vesting_accounts: Vec<GenesisVestingContract>,
+    pub htlc_accounts: Vec<GenesisHTLC>,
+    pub validators: Vec<GenesisValidator>,
+    pub stakers: Vec<GenesisStaker>,
+}
+
+impl StateSnapshot {
+    /// Reads the complete state from the accounts tree.
+    pub fn read(
+        accounts: &Accounts,
+        txn: &MdbxReadTransaction,
+    ) -> Result<Self, GenesisBuilderError> {
+        if !accounts.is_complete(Some(txn)) {
+            return Err(GenesisBuilderError::IncompleteState);
+        }
+
+        let mut snapshot = StateSnapshot::default();
+        let mut staking_contract = None;
+
+        let mut start = Some(KeyNibbles::ROOT);
+        while let Some(start_key) = start {
+            let chunk = accounts.get_chunk(start_key, SNAPSHOT_CHUNK_SIZE, Some(txn));
+            start = chunk.end_key;
+
+            for item in chunk.items {
+                // The staking contract's data is read through the staking contract below.
+                let Some(address) = item.key.to_address() else {
+                    continue;
+                };
+
+                match Account::deserialize_from_vec(&item.value)? {
+                    Account::Basic(account) => snapshot.basic_accounts.push(GenesisAccount {
+                        address,
+                        balance: account.balance,
+                    }),
+                    Account::Vesting(contract) => {
+                        snapshot.vesting_accounts.push(GenesisVestingContract {
+                            address,
+                            owner: contract.owner,
+                            balance: contract.balance,
+                            start_time: contract.start_time,
+                            time_step: contract.time_step,
+                            step_amount: contract.step_amount,
+                            total_amount: contract.total_amount,
+                        })
+                    }
+                    Account::HTLC(contract) => snapshot.htlc_accounts.push(GenesisHTLC {
+                        address,
+                        sender: contract.sender,
+                        recipient: contract.recipient,
+                        balance: contract.balance,
+                        hash_root: contract.hash_root,
+                        hash_count: contract.hash_count,
+                        timeout: contract.timeout,
+                        total_amount: contract.total_amount,
+                    }),
+                    Account::Staking(contract) => staking_contract = Some(contract),
+                }
+            }
+        }
+
+        if let Some(staking_contract) = staking_contract {
+            let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+            let data_store = data_store.read(txn);
+
+            for validator in staking_contract.get_validators(&data_store) {
+                snapshot.validators.push(GenesisValidator {
+                    validator_address: validator.address,
+                    signing_key: validator.signing_key,
+                    voting_key: validator.voting_key.uncompress()?,
+                    reward_address: validator.reward_address,
+                    inactive_from: validator.inactive_from,
+                    jailed_from: validator.jailed_from,
+                    retired: validator.retired,
+                    commission: validator.commission,
+                });
+            }
+
+            // Stakers can still delegate to validators that were deleted in the meantime. Those
+            // are dropped from the snapshot, so their stakers become undelegated.
+            let validators: BTreeSet<&Address> = snapshot
+                .validators
+                .iter()
+                .map(|validator| &validator.validator_address)
+                .collect();
+            for staker in staking_contract.get_stakers(&data_store) {
+                snapshot.stakers.push(GenesisStaker {
+                    staker_address: staker.address,
+                    balance: staker.active_balance,
+                    delegation: staker
+                        .delegation
+                        .filter(|validator| validators.contains(validator)),
+                    inactive_balance: staker.inactive_balance,
+                    inactive_from: staker.inactive_from,
+                    retired_balance: staker.retired_balance,
+                });
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Replaces the signing and voting keys of all validators, so that the forked chain can be
+    /// run with locally controlled keys. `keys` is called once for every validator address.
+    pub fn replace_validator_keys<F>(&mut self, mut keys: F)
+    where
+        F: FnMut(&Address) -> (SchnorrPublicKey, BlsPublicKey),
+    {
+        for validator in &mut self.validators {
+            let (signing_key, voting_key) = keys(&validator.validator_address);
+            validator.signing_key = signing_key;
+            validator.voting_key = voting_key;
+        }
+    }
+
+    /// Keeps only the `max_active` active validators with the highest stake active and deactivates
+    /// all others at `block_number`. Returns the addresses of the validators that stay active.
+    pub fn limit_active_validators(
+        &mut self,
+        max_active: usize,
+        block_number: u32,
+    ) -> Vec<Address> {
+        let mut stakes: HashMap<Address, Coin> = HashMap::new();
+        for staker in &self.stakers {
+            if let Some(validator) = &staker.delegation {
+                *stakes.entry(validator.clone()).or_default() += staker.balance;
+            }
+        }
+
+        let mut active: Vec<&mut GenesisValidator> = self
+            .validators
+            .iter_mut()
+            .filter(|validator| validator.inactive_from.is_none() && !validator.retired)
+            .collect();
+        active.sort_by(|a, b| {
+            let stake_a = stakes
+                .get(&a.validator_address)
+                .copied()
+                .unwrap_or_default();
+            let stake_b = stakes
+                .get(&b.validator_address)
+                .copied()
+                .unwrap_or_default();
+            stake_b
+                .cmp(&stake_a)
+                .then_with(|| a.validator_address.cmp(&b.validator_address))
+        });
+
+        for validator in active.iter_mut().skip(max_active) {
+            validator.inactive_from = Some(block_number);
+        }
+
+        active
+            .into_iter()
+            .take(max_active)
+            .map(|validator| validator.validator_address.clone())
+            .collect()
+    }
+
+    /// Sets the balance of a basic account, creating the account if it doesn't exist yet.
+    pub fn set_balance(
+        &mut self,
+        address: Address,
+        balance: Coin,
+    ) -> Result<(), GenesisBuilderError> {
+        if self
+            .vesting_accounts
+            .iter()
+            .any(|contract| contract.address == address)
+            || self
+                .htlc_accounts
+                .iter()
+                .any(|contract| contract.address == address)
+            || address == Policy::STAKING_CONTRACT_ADDRESS
+        {
+            return Err(GenesisBuilderError::NotABasicAccount(address));
+        }
+
+        match self
+            .basic_accounts
+            .iter_mut()
+            .find(|account| account.address == address)
+        {
+            Some(account) => account.balance = balance,
+            None => self
+                .basic_accounts
+                .push(GenesisAccount { address, balance }),
+        }
+        Ok(())
+    }
+
+    /// Turns the snapshot into a genesis config. All other fields are left unset, i.e. they need
+    /// to be filled in before the config can be used.
+    pub fn into_genesis_config(self, network: NetworkId) -> GenesisConfig {
+        GenesisConfig {
+            network,
+            timestamp: None,
+            vrf_seed: None,
+            parent_election_hash: None,
+            parent_hash: None,
+            history_root: None,
+            block_number: 0,
+            validators: self.validators,
+            stakers: self.stakers,
+            basic_accounts: self.basic_accounts,
+            vesting_accounts: self.vesting_accounts,
+            htlc_accounts: self.htlc_accounts,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use nimiq_account::Accounts;
+use nimiq_bls::KeyPair as BlsKeyPair;
+use nimiq_database::{
+    mdbx::MdbxDatabase,
+    traits::{Database, WriteTransaction},
+};
+use nimiq_genesis_builder::{snapshot::StateSnapshot, GenesisBuilder, GenesisInfo};
+use nimiq_keys::{Address, Ed25519PublicKey, KeyPair, SecureGenerate};
+use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use nimiq_test_log::test;
+
+fn validator(builder: &mut GenesisBuilder, address: &Address) {
+    builder.with_genesis_validator(
+        address.clone(),
+        KeyPair::generate_default_csprng().public,
+        BlsKeyPair::generate_default_csprng().public_key,
+        address.clone(),
+        None,
+        None,
+        false,
+    );
+}
+
+fn snapshot_of(genesis: &GenesisInfo) -> StateSnapshot {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let accounts = Accounts::new(env.clone());
+    let mut txn = env.write_transaction();
+    accounts.init(&mut (&mut txn).into(), genesis.accounts.clone());
+    let snapshot = StateSnapshot::read(&accounts, &txn).unwrap();
+    txn.abort();
+    snapshot
+}
+
+#[test]
+fn snapshot_regenerates_the_same_state() {
+    let validator_address = Address::from([1u8; Address::SIZE]);
+    let staker_address = Address::from([2u8; Address::SIZE]);
+
+    let mut builder = GenesisBuilder::default();
+    builder.with_network(NetworkId::UnitAlbatross);
+    validator(&mut builder, &validator_address);
+    builder
+        .with_genesis_staker(
+            staker_address.clone(),
+            validator_address.clone(),
+            Coin::from_u64_unchecked(100_000_000),
+            Coin::from_u64_unchecked(50_000_000),
+            Some(0),
+        )
+        .with_basic_account(
+            Address::from([3u8; Address::SIZE]),
+            Coin::from_u64_unchecked(1_000),
+        );
+    let genesis = builder
+        .generate(MdbxDatabase::new_volatile(Default::default()).unwrap())
+        .unwrap();
+
+    let snapshot = snapshot_of(&genesis);
+    assert_eq!(snapshot.basic_accounts.len(), 1);
+    assert_eq!(snapshot.validators.len(), 1);
+    assert_eq!(snapshot.stakers.len(), 1);
+    assert_eq!(snapshot.stakers[0].delegation, Some(validator_address));
+    assert_eq!(
+        snapshot.stakers[0].inactive_balance,
+        Coin::from_u64_unchecked(50_000_000)
+    );
+
+    // Building a genesis from the snapshot results in the very same state.
+    let config = snapshot.into_genesis_config(NetworkId::UnitAlbatross);
+    let mut forked_builder = GenesisBuilder::default();
+    forked_builder.with_network(config.network);
+    forked_builder.validators = config.validators;
+    forked_builder.stakers = config.stakers;
+    forked_builder.basic_accounts = config.basic_accounts;
+    let forked = forked_builder
+        .generate(MdbxDatabase::new_volatile(Default::default()).unwrap())
+        .unwrap();
+
+    assert_eq!(forked.accounts, genesis.accounts);
+    assert_eq!(forked.block.state_root(), genesis.block.state_root());
+}
+
+#[test]
+fn snapshot_can_limit_validators_and_override_balances() {
+    let mut builder = GenesisBuilder::default();
+    builder.with_network(NetworkId::UnitAlbatross);
+    for i in 1..=3u8 {
+        let validator_address = Address::from([i; Address::SIZE]);
+        validator(&mut builder, &validator_address);
+        builder.with_genesis_staker(
+            Address::from([10 + i; Address::SIZE]),
+            validator_address,
+            Coin::from_u64_unchecked(100_000_000 * i as u64),
+            Coin::ZERO,
+            None,
+        );
+    }
+    let genesis = builder
+        .generate(MdbxDatabase::new_volatile(Default::default()).unwrap())
+        .unwrap();
+
+    let mut snapshot = snapshot_of(&genesis);
+    let active = snapshot.limit_active_validators(2, 100);
+    assert_eq!(
+        active,
+        vec![
+            Address::from([3u8; Address::SIZE]),
+            Address::from([2u8; Address::SIZE])
+        ]
+    );
+    let inactive = snapshot
+        .validators
+        .iter()
+        .find(|validator| validator.validator_address == Address::from([1u8; Address::SIZE]))
+        .unwrap();
+    assert_eq!(inactive.inactive_from, Some(100));
+
+    let address = Address::from([20u8; Address::SIZE]);
+    snapshot
+        .set_balance(address.clone(), Coin::from_u64_unchecked(42))
+        .unwrap();
+    assert!(snapshot.basic_accounts.iter().any(
+        |account| account.address == address && account.balance == Coin::from_u64_unchecked(42)
+    ));
+}
@@ -7,6 +7,8 @@ use nimiq_blockchain_interface::AbstractBlockchain;
 use nimiq_blockchain_proxy::BlockchainProxy;
 use nimiq_bls::cache::PublicKeyCache;
 #[cfg(feature = "full-consensus")]
+use nimiq_consensus::sync::history::HistoryBackfill;
+#[cfg(feature = "full-consensus")]
 use nimiq_consensus::Error::BlockchainError;
 use nimiq_consensus::{
     sync::syncer_proxy::SyncerProxy, Consensus as AbstractConsensus,
@@ -26,10 +28,13 @@ use nimiq_network_interface::{
     Multiaddr, Protocol,
 };
 use nimiq_network_libp2p::{
-    discovery::peer_contacts::PeerContact, Config as NetworkConfig, Network,
+    discovery::peer_contacts::PeerContact, Ban, Config as NetworkConfig, Network,
     TlsConfig as NetworkTls,
 };
 use nimiq_primitives::policy::Policy;
+use nimiq_utils::file_store::{Error as FileStoreError, FileStore};
+#[cfg(feature = "full-consensus")]
+use nimiq_utils::spawn;
 #[cfg(feature = "full-consensus")]
 use nimiq_utils::time::OffsetTime;
 #[cfg(feature = "validator")]
@@ -106,6 +111,9 @@ pub(crate) struct ClientInner {
     #[cfg(feature = "wallet")]
     wallet_store: Arc<WalletStore>,
 
+    /// File store in which the manual network bans are persisted
+    ban_list_store: Option<Arc<FileStore>>,
+
     zkp_component: ZKPComponentProxy,
 }
 
@@ -314,7 +322,7 @@ impl ClientInner {
         };
 
         // Setup libp2p network
-        let network_config = NetworkConfig::new(
+        let mut network_config = NetworkConfig::new(
             identity_keypair,
             peer_contact,
             seeds,
@@ -332,6 +340,18 @@ impl ClientInner {
                 .unwrap_or(NonZeroU8::new(3).unwrap()),
         );
 
+        network_config.rate_limits = config.network.rate_limits;
+
+        // Restore the manual bans from a previous run
+        let ban_list_store = config.storage.ban_list_store().map(Arc::new);
+        if let Some(ban_list_store) = &ban_list_store {
+            match ban_list_store.load::<Vec<Ban>>() {
+                Ok(bans) => network_config.bans = bans,
+                Err(FileStoreError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(error) => log::warn!(%error, "Failed to load the ban list"),
+            }
+        }
+
         log::debug!(
             addresses = ?config.network.listen_addresses,
             "Listen addresses");
@@ -360,6 +380,7 @@ impl ClientInner {
         #[cfg(feature = "full-consensus")]
         let mut blockchain_config = BlockchainConfig {
             max_epochs_stored: config.consensus.max_epochs_stored,
+            signal_thresholds: config.consensus.signal_thresholds.clone(),
             ..Default::default()
         };
 
@@ -394,6 +415,28 @@ impl ClientInner {
                     }
                 };
 
+                // A database that was used by a full node lacks the history of past epochs. It is
+                // backfilled from other history nodes while we already follow the chain. Until
+                // then, we only advertise the services of a full node.
+                let history_backfill =
+                    HistoryBackfill::new(Arc::clone(&blockchain), Arc::clone(&network));
+                if history_backfill.is_required() {
+                    log::info!("History is incomplete, backfilling it from history nodes");
+                    let incomplete_services =
+                        provided_services - (Services::HISTORY | Services::TRANSACTION_INDEX);
+                    if let Err(error) = network.set_provided_services(incomplete_services).await {
+                        log::error!(%error, "Failed to update provided services");
+                    }
+
+                    let network = Arc::clone(&network);
+                    spawn(async move {
+                        history_backfill.run().await;
+                        if let Err(error) = network.set_provided_services(provided_services).await {
+                            log::error!(%error, "Failed to update provided services");
+                        }
+                    });
+                }
+
                 let blockchain_proxy = BlockchainProxy::from(&blockchain);
                 #[cfg(feature = "zkp-prover")]
                 let zkp_component = if let Some(zk_prover_config) = config.zk_prover {
@@ -440,6 +483,13 @@ impl ClientInner {
                     }
                 };
 
+                // A database that was used by a history node still contains the history of past
+                // epochs, which a full node doesn't keep.
+                let num_pruned = Blockchain::prune_history(blockchain.upgradable_read());
+                if num_pruned > 0 {
+                    log::info!(num_pruned, "Pruned the history of past epochs");
+                }
+
                 let blockchain_proxy = BlockchainProxy::from(&blockchain);
                 #[cfg(feature = "zkp-prover")]
                 let zkp_component = if let Some(zk_prover_config) = config.zk_prover {
@@ -585,6 +635,7 @@ impl ClientInner {
                 validator: validator_proxy,
                 #[cfg(feature = "wallet")]
                 wallet_store,
+                ban_list_store,
                 zkp_component: zkp_component.proxy(),
             }),
             consensus: Some(consensus),
@@ -655,6 +706,11 @@ impl Client {
         Arc::clone(&self.inner.wallet_store)
     }
 
+    /// Returns the file store in which the manual network bans are persisted, if any.
+    pub fn ban_list_store(&self) -> Option<Arc<FileStore>> {
+        self.inner.ban_list_store.clone()
+    }
+
     /// Returns the *Validator* or `None`.
     #[cfg(feature = "validator")]
     pub fn take_validator(&mut self) -> Option<Validator> {
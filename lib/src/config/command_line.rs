@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use log::level_filters::{LevelFilter, ParseLevelFilterError};
 use nimiq_primitives::networks::NetworkId;
 use thiserror::Error;
@@ -67,6 +67,56 @@ pub struct CommandLine {
     /// Internally used flag to start a zero-knowledge prover process.
     #[clap(long, action)]
     pub prove: bool,
+
+    /// Run a maintenance command instead of starting the client.
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Check the consensus database for inconsistencies.
+    ///
+    /// # Examples
+    ///
+    /// * `nimiq-client check-db --repair`
+    ///
+    CheckDb {
+        /// Repair the inconsistencies found where possible.
+        #[clap(long)]
+        repair: bool,
+    },
+
+    /// Export a range of main chain blocks, including their bodies and justifications, to a
+    /// block file.
+    ///
+    /// # Examples
+    ///
+    /// * `nimiq-client export-blocks --from 1 --to 100000 blocks.bin`
+    ///
+    ExportBlocks {
+        /// The number of the first block to export.
+        #[clap(long, default_value_t = 0)]
+        from: u32,
+
+        /// The number of the last block to export. Defaults to the current head.
+        #[clap(long)]
+        to: Option<u32>,
+
+        /// The block file to write.
+        output: PathBuf,
+    },
+
+    /// Import the blocks of a block file, fully verifying each of them.
+    ///
+    /// # Examples
+    ///
+    /// * `nimiq-client import-blocks blocks.bin`
+    ///
+    ImportBlocks {
+        /// The block file to read.
+        input: PathBuf,
+    },
 }
 
 impl CommandLine {
@@ -3,10 +3,12 @@ use std::net::IpAddr;
 #[cfg(feature = "metrics-server")]
 use std::net::SocketAddr;
 use std::{
+    collections::{BTreeSet, HashMap},
     fmt,
     num::NonZeroU8,
     path::{Path, PathBuf},
     string::ToString,
+    time::Duration,
 };
 
 use derive_builder::Builder;
@@ -20,7 +22,9 @@ use nimiq_keys::{Address, KeyPair, PrivateKey};
 #[cfg(feature = "nimiq-mempool")]
 use nimiq_mempool::{config::MempoolConfig, filter::MempoolRules};
 use nimiq_network_interface::Multiaddr;
-use nimiq_network_libp2p::{Keypair as IdentityKeypair, Libp2pKeyPair};
+use nimiq_network_libp2p::{
+    Keypair as IdentityKeypair, Libp2pKeyPair, PeerId, RateLimitConfig, RequestRateLimit,
+};
 use nimiq_primitives::{networks::NetworkId, policy::Policy};
 use nimiq_serde::Deserialize;
 #[cfg(feature = "validator")]
@@ -38,7 +42,7 @@ use crate::config::consts::default_bind;
 use crate::{
     config::{
         command_line::CommandLine,
-        config_file::{ConfigFile, Seed, TlsSettings},
+        config_file::{ConfigFile, RateLimitSettings, RequestRateLimitSettings, Seed, TlsSettings},
         paths,
         user_agent::UserAgent,
     },
@@ -92,6 +96,9 @@ pub struct ConsensusConfig {
     #[builder(default = "true")]
     /// History indices enabled. Only effective for history nodes (default: `true`)
     pub index_history: bool,
+    #[builder(default = "vec![Policy::TWO_F_PLUS_ONE]")]
+    /// Thresholds, in slots, for which validator signal events are emitted
+    pub signal_thresholds: Vec<u16>,
 }
 
 impl Default for ConsensusConfig {
@@ -102,6 +109,7 @@ impl Default for ConsensusConfig {
             max_epochs_stored: Policy::MIN_EPOCHS_STORED,
             full_sync_threshold: 10800,
             index_history: true,
+            signal_thresholds: vec![Policy::TWO_F_PLUS_ONE],
         }
     }
 }
@@ -155,6 +163,10 @@ pub struct NetworkConfig {
     /// Optional quorum value for the network DHT
     #[builder(default)]
     pub dht_quorum: Option<NonZeroU8>,
+
+    /// Overrides of the rate limits of incoming requests
+    #[builder(default)]
+    pub rate_limits: RateLimitConfig,
 }
 
 /// Configuration for setting TLS for secure WebSocket
@@ -176,6 +188,66 @@ impl From<TlsSettings> for TlsConfig {
     }
 }
 
+/// Returns the names of the request types whose rate limits can be configured. These are
+/// registered by the crates serving the requests.
+fn rate_limited_request_types() -> BTreeSet<&'static str> {
+    #[allow(unused_mut)]
+    let mut names: BTreeSet<_> = nimiq_consensus::messages::request_type_names()
+        .into_iter()
+        .chain(nimiq_zkp_component::types::request_type_names())
+        .collect();
+    #[cfg(feature = "validator")]
+    names.extend(nimiq_validator::aggregation::request_type_names());
+    names
+}
+
+/// Converts the rate limit settings of the config file into the network rate limit configuration.
+/// Fails if a rate limit is given for an unknown request type, since it would be ignored.
+fn rate_limit_config(settings: &RateLimitSettings) -> Result<RateLimitConfig, Error> {
+    let request_types = rate_limited_request_types();
+    if let Some(request_type) = settings
+        .requests
+        .keys()
+        .chain(settings.allowlist.keys())
+        .find(|request_type| !request_types.contains(request_type.as_str()))
+    {
+        return Err(Error::config_error(format!(
+            "Unknown request type in rate limits: {} (known request types: {})",
+            request_type,
+            request_types.into_iter().collect::<Vec<_>>().join(", ")
+        )));
+    }
+
+    let to_limits = |limits: &HashMap<String, RequestRateLimitSettings>| {
+        limits
+            .iter()
+            .map(|(request_type, limit)| {
+                (
+                    request_type.clone(),
+                    RequestRateLimit {
+                        max_requests: limit.max_requests,
+                        time_window: Duration::from_secs(limit.time_window),
+                    },
+                )
+            })
+            .collect()
+    };
+
+    Ok(RateLimitConfig {
+        limits: to_limits(&settings.requests),
+        allowlisted_peers: settings
+            .allowlisted_peers
+            .iter()
+            .map(|peer_id| {
+                peer_id.parse::<PeerId>().map_err(|_| {
+                    Error::config_error(format!("Invalid allowlisted peer ID: {}", peer_id))
+                })
+            })
+            .collect::<Result<_, _>>()?,
+        allowlist_limits: to_limits(&settings.allowlist),
+    })
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct FileStorageConfig {
     /// The parent directory where the database will be stored. The database directory name
@@ -189,6 +261,9 @@ pub struct FileStorageConfig {
     /// The key used for the peer key, if the file is not present.
     pub peer_key: Option<Sensitive<String>>,
 
+    /// Path to the list of manually banned peers and IP addresses.
+    pub ban_list_path: PathBuf,
+
     /// Path to voting key.
     #[cfg(feature = "validator")]
     pub voting_key_path: Option<PathBuf>,
@@ -223,6 +298,7 @@ impl FileStorageConfig {
             database_parent: path.to_path_buf(),
             peer_key_path: path.join("peer_key.dat"),
             peer_key: None,
+            ban_list_path: path.join("bans.dat"),
             #[cfg(feature = "validator")]
             voting_key_path: Some(path.join("voting_key.dat")),
             #[cfg(feature = "validator")]
@@ -275,6 +351,11 @@ pub struct DatabaseConfig {
     /// Recommended: 600
     #[builder(default = "600")]
     max_readers: u32,
+
+    /// Whether to take over the database of the other consensus sync mode (full or history) if
+    /// there is none for the configured one yet. Default: false
+    #[builder(default)]
+    reuse_sync_mode_database: bool,
 }
 #[cfg(feature = "database-storage")]
 impl Default for DatabaseConfig {
@@ -284,6 +365,7 @@ impl Default for DatabaseConfig {
             size: 1024 * 1024 * 1024 * 1024,
             max_dbs: 20,
             max_readers: 600,
+            reuse_sync_mode_database: false,
         }
     }
 }
@@ -297,6 +379,9 @@ impl From<Option<DatabaseSettings>> for DatabaseConfig {
                 size: db_settings.size.unwrap_or(default.size),
                 max_dbs: db_settings.max_dbs.unwrap_or(default.max_dbs),
                 max_readers: db_settings.max_readers.unwrap_or(default.max_readers),
+                reuse_sync_mode_database: db_settings
+                    .reuse_sync_mode_database
+                    .unwrap_or(default.reuse_sync_mode_database),
             }
         } else {
             default
@@ -359,7 +444,40 @@ impl StorageConfig {
         Ok(match self {
             StorageConfig::Volatile => MdbxDatabase::new_volatile(config)?,
             StorageConfig::Filesystem(file_storage) => {
-                let db_path = file_storage.database_parent.join(db_name);
+                let db_path = file_storage.database_parent.join(&db_name);
+
+                // Full and history nodes share the same database layout, so switching between
+                // them can reuse the existing database if configured. The history is backfilled
+                // or pruned once the blockchain is loaded.
+                let other_sync_mode = match sync_mode {
+                    SyncMode::Full => Some(SyncMode::History),
+                    SyncMode::History => Some(SyncMode::Full),
+                    SyncMode::Light => None,
+                };
+                if let Some(other_sync_mode) = other_sync_mode {
+                    let other_db_path = file_storage
+                        .database_parent
+                        .join(format!("{network_id}-{other_sync_mode}-consensus").to_lowercase());
+                    if !db_path.exists() && other_db_path.exists() {
+                        if db_config.reuse_sync_mode_database {
+                            log::info!(
+                                from = %other_db_path.display(),
+                                to = %db_path.display(),
+                                "Moving the {} node database to be used as {} node database",
+                                other_sync_mode,
+                                sync_mode,
+                            );
+                            std::fs::rename(&other_db_path, &db_path)?;
+                        } else {
+                            log::info!(
+                                path = %other_db_path.display(),
+                                "Found a {} node database, set `reuse_sync_mode_database = true` in the [database] section to use it instead of syncing from scratch",
+                                other_sync_mode,
+                            );
+                        }
+                    }
+                }
+
                 let db_path = db_path
                     .to_str()
                     .ok_or_else(|| {
@@ -473,6 +591,17 @@ impl StorageConfig {
         })
     }
 
+    /// Returns the file store in which manual network bans are persisted, or `None` for a
+    /// volatile storage.
+    pub(crate) fn ban_list_store(&self) -> Option<FileStore> {
+        match self {
+            StorageConfig::Volatile => None,
+            StorageConfig::Filesystem(file_storage) => {
+                Some(FileStore::new(&file_storage.ban_list_path))
+            }
+        }
+    }
+
     pub(crate) fn identity_keypair(&self) -> Result<IdentityKeypair, Error> {
         match self {
             StorageConfig::Volatile => Ok(IdentityKeypair::generate_ed25519()),
@@ -573,6 +702,11 @@ pub struct RpcServerConfig {
     /// If specified, require HTTP basic auth with these credentials
     #[builder(setter(strip_option))]
     pub credentials: Option<Credentials>,
+
+    /// If specified, unlocked wallet accounts are locked again after not being used for this
+    /// duration.
+    #[builder(setter(strip_option))]
+    pub wallet_idle_timeout: Option<Duration>,
 }
 
 #[cfg(feature = "metrics-server")]
@@ -762,6 +896,7 @@ impl ClientConfigBuilder {
             only_secure_ws_connections: false,
             allow_loopback_addresses: config_file.network.allow_loopback_addresses,
             dht_quorum: config_file.network.dht_quorum,
+            rate_limits: rate_limit_config(&config_file.network.rate_limits)?,
         });
 
         // Configure consensus
@@ -776,6 +911,9 @@ impl ClientConfigBuilder {
         if let Some(full_sync_threshold) = config_file.consensus.full_sync_threshold {
             consensus.full_sync_threshold = full_sync_threshold;
         }
+        if let Some(signal_thresholds) = config_file.consensus.signal_thresholds.clone() {
+            consensus.signal_thresholds = signal_thresholds;
+        }
         self.consensus(consensus);
 
         // Configure network
@@ -885,6 +1023,7 @@ impl ClientConfigBuilder {
                     allow_ips,
                     allowed_methods: Some(rpc_config.methods.clone()),
                     credentials,
+                    wallet_idle_timeout: rpc_config.wallet_idle_timeout.map(Duration::from_secs),
                 }));
             }
         }
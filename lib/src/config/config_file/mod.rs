@@ -182,6 +182,8 @@ pub struct NetworkSettings {
     pub allow_loopback_addresses: bool,
     #[serde(default)]
     pub dht_quorum: Option<NonZeroU8>,
+    #[serde(default)]
+    pub rate_limits: RateLimitSettings,
 }
 
 impl NetworkSettings {
@@ -190,6 +192,31 @@ impl NetworkSettings {
     }
 }
 
+/// Overrides of the rate limits of incoming requests
+#[derive(Clone, Debug, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitSettings {
+    /// Peer IDs of the peers that are subject to the `allowlist` limits instead of the
+    /// regular limits.
+    #[serde(default)]
+    pub allowlisted_peers: Vec<String>,
+    /// Rate limits by request type name, e.g. `RequestMacroChain`.
+    #[serde(default)]
+    pub requests: HashMap<String, RequestRateLimitSettings>,
+    /// Rate limits for allowlisted peers by request type name.
+    #[serde(default)]
+    pub allowlist: HashMap<String, RequestRateLimitSettings>,
+}
+
+/// The maximum number of requests of a type that a peer may send within a time window
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequestRateLimitSettings {
+    pub max_requests: u32,
+    /// The time window in seconds.
+    pub time_window: u64,
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct Seed {
     pub address: Multiaddr,
@@ -225,6 +252,8 @@ pub struct ConsensusSettings {
     /// History indices enabled. Only effective for history nodes (default: `true`)
     #[serde(default = "default_true")]
     pub index_history: bool,
+    /// Thresholds, in slots, for which validator signal events are emitted (default: `[342]`)
+    pub signal_thresholds: Option<Vec<u16>>,
 }
 
 impl Default for ConsensusSettings {
@@ -236,6 +265,7 @@ impl Default for ConsensusSettings {
             min_peers: None,
             full_sync_threshold: None,
             index_history: true,
+            signal_thresholds: None,
         }
     }
 }
@@ -295,6 +325,7 @@ pub struct RpcServerSettings {
     pub methods: Vec<String>,
     pub username: Option<String>,
     pub password: Option<Sensitive<String>>,
+    pub wallet_idle_timeout: Option<u64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Default)]
@@ -370,6 +401,7 @@ pub struct DatabaseSettings {
     pub size: Option<usize>,
     pub max_dbs: Option<u32>,
     pub max_readers: Option<u32>,
+    pub reuse_sync_mode_database: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
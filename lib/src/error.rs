@@ -14,6 +14,14 @@ pub enum Error {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    #[cfg(feature = "full-consensus")]
+    #[error("Inconsistent consensus database: {0}")]
+    InconsistentDatabase(String),
+
+    #[cfg(feature = "full-consensus")]
+    #[error("Block file error: {0}")]
+    BlockFile(#[from] nimiq_blockchain::block_file::BlockFileError),
+
     #[error("Network error: {0}")]
     Network(#[from] nimiq_network_libp2p::NetworkError),
 
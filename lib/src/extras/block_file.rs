@@ -0,0 +1,96 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+    sync::Arc,
+};
+
+use nimiq_blockchain::{
+    block_file::{self, BlockFileReader, BlockFileWriter},
+    Blockchain, BlockchainConfig,
+};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_consensus::Error::BlockchainError;
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+use crate::{
+    config::{config::ClientConfig, config_file::SyncMode},
+    error::Error,
+};
+
+/// Opens the blockchain stored in the consensus database configured in `config`.
+fn open_blockchain(config: ClientConfig) -> Result<Blockchain, Error> {
+    let mut blockchain_config = BlockchainConfig {
+        max_epochs_stored: config.consensus.max_epochs_stored,
+        ..Default::default()
+    };
+    match config.consensus.sync_mode {
+        SyncMode::History => {
+            blockchain_config.keep_history = true;
+            blockchain_config.index_history = config.consensus.index_history;
+        }
+        SyncMode::Full => {
+            blockchain_config.keep_history = false;
+            blockchain_config.index_history = false;
+        }
+        SyncMode::Light => {
+            return Err(Error::config_error(
+                "Light clients don't store blocks that can be exported or imported",
+            ))
+        }
+    }
+
+    let environment = config.storage.database(
+        config.network_id,
+        config.consensus.sync_mode,
+        config.database,
+    )?;
+    Blockchain::new(
+        environment,
+        blockchain_config,
+        config.network_id,
+        Arc::new(OffsetTime::new()),
+    )
+    .map_err(|error| Error::Consensus(BlockchainError(error)))
+}
+
+/// Exports the main chain blocks from `from` to `to` (inclusive) to the block file at `path`.
+/// If `to` is not given, all blocks up to the current head are exported.
+pub fn export_blocks(
+    config: ClientConfig,
+    path: &Path,
+    from: u32,
+    to: Option<u32>,
+) -> Result<(), Error> {
+    let blockchain = open_blockchain(config)?;
+    let to = to.unwrap_or(u32::MAX);
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut writer = BlockFileWriter::new(file, blockchain.network_id)?;
+
+    log::info!(from, to, path = %path.display(), "Exporting blocks");
+    let num_blocks = block_file::export_blocks(&blockchain, &mut writer, from, to)?;
+    writer.finish()?;
+    log::info!(num_blocks, path = %path.display(), "Exported blocks");
+
+    Ok(())
+}
+
+/// Imports the blocks of the block file at `path`, verifying each of them.
+pub fn import_blocks(config: ClientConfig, path: &Path) -> Result<(), Error> {
+    let blockchain = Arc::new(RwLock::new(open_blockchain(config)?));
+
+    let reader = BlockFileReader::new(BufReader::new(File::open(path)?))?;
+
+    log::info!(path = %path.display(), "Importing blocks");
+    let stats = block_file::import_blocks(&blockchain, reader)?;
+    log::info!(
+        imported = stats.imported,
+        known = stats.known,
+        head = %blockchain.read().head(),
+        "Imported blocks",
+    );
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use nimiq_blockchain::consistency::ConsistencyChecker;
+
+use crate::{
+    config::{config::ClientConfig, config_file::SyncMode},
+    error::Error,
+};
+
+/// Checks the consistency of the consensus database configured in `config` and logs the result.
+///
+/// If `repair` is set, inconsistencies are repaired where possible and the database is checked
+/// again afterwards. Returns an error if the database is (still) inconsistent.
+pub fn check_database(config: ClientConfig, repair: bool) -> Result<(), Error> {
+    let index_history = match config.consensus.sync_mode {
+        SyncMode::History => config.consensus.index_history,
+        SyncMode::Full => false,
+        SyncMode::Light => {
+            return Err(Error::config_error(
+                "Light clients don't store a consensus database that can be checked",
+            ))
+        }
+    };
+
+    let environment = config.storage.database(
+        config.network_id,
+        config.consensus.sync_mode,
+        config.database,
+    )?;
+    let checker = ConsistencyChecker::new(environment, config.network_id, index_history);
+
+    log::info!("Checking consensus database");
+    let mut report = checker.check();
+    log::info!("{}", report);
+
+    if !report.is_consistent() && repair {
+        log::info!("Repairing consensus database");
+        match checker.repair(&report) {
+            Ok(repairs) => {
+                for repair in repairs {
+                    log::info!(?repair, "Repaired consensus database");
+                }
+            }
+            Err(error) => return Err(Error::InconsistentDatabase(error.to_string())),
+        }
+
+        report = checker.check();
+        log::info!("{}", report);
+    }
+
+    if report.is_consistent() {
+        Ok(())
+    } else if repair {
+        Err(Error::InconsistentDatabase(
+            "Failed to repair all inconsistencies, reset your consensus database".to_string(),
+        ))
+    } else {
+        Err(Error::InconsistentDatabase(
+            "Run with `--repair` to attempt to repair it".to_string(),
+        ))
+    }
+}
@@ -6,6 +6,7 @@ use nimiq_consensus::ConsensusProxy;
 use nimiq_mempool::mempool::Mempool;
 pub use nimiq_metrics_server::NimiqTaskMonitor;
 use nimiq_network_interface::network::Network;
+use nimiq_validator::validator_metrics::ValidatorMetrics;
 
 pub fn start_metrics_server<TNetwork: Network>(
     addr: SocketAddr,
@@ -13,6 +14,7 @@ pub fn start_metrics_server<TNetwork: Network>(
     #[cfg(feature = "nimiq-mempool")] mempool: Option<Arc<Mempool>>,
     consensus_proxy: ConsensusProxy<TNetwork>,
     network: Arc<nimiq_network_libp2p::Network>,
+    validator_metrics: Option<Arc<ValidatorMetrics>>,
     task_monitors: &[NimiqTaskMonitor],
 ) {
     #[cfg(not(feature = "nimiq-mempool"))]
@@ -23,6 +25,7 @@ pub fn start_metrics_server<TNetwork: Network>(
         mempool,
         consensus_proxy,
         network,
+        validator_metrics,
         task_monitors,
     );
 }
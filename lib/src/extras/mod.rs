@@ -1,3 +1,7 @@
+#[cfg(feature = "full-consensus")]
+pub mod block_file;
+#[cfg(feature = "full-consensus")]
+pub mod check_db;
 #[cfg(feature = "deadlock")]
 pub mod deadlock;
 #[cfg(feature = "launcher")]
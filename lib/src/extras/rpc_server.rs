@@ -1,5 +1,6 @@
 use std::{collections::HashSet, iter::FromIterator, sync::Arc};
 
+use nimiq_blockchain_proxy::BlockchainProxy;
 use nimiq_jsonrpc_server::{
     AllowListDispatcher, Config, Credentials, ModularDispatcher, Server as _Server,
 };
@@ -38,18 +39,26 @@ pub fn initialize_rpc_server(
 
     let mut dispatcher = ModularDispatcher::default();
 
-    let wallet_dispatcher = WalletDispatcher::new(wallet_store);
+    let wallet_dispatcher = WalletDispatcher::new(wallet_store, config.wallet_idle_timeout);
     let unlocked_wallets = Arc::clone(&wallet_dispatcher.unlocked_wallets);
 
-    dispatcher.add(BlockchainDispatcher::new(client.blockchain()));
+    dispatcher.add(BlockchainDispatcher::new(
+        client.blockchain(),
+        client.validator_proxy(),
+    ));
 
     dispatcher.add(ConsensusDispatcher::new(
         client.consensus_proxy(),
         Some(unlocked_wallets),
     ));
-    dispatcher.add(NetworkDispatcher::new(client.network()));
-    if let Some(mempool) = client.mempool() {
-        dispatcher.add(MempoolDispatcher::new(mempool));
+    dispatcher.add(NetworkDispatcher::new(
+        client.network(),
+        client.ban_list_store(),
+    ));
+    if let (Some(mempool), BlockchainProxy::Full(blockchain)) =
+        (client.mempool(), client.blockchain())
+    {
+        dispatcher.add(MempoolDispatcher::new(mempool, blockchain));
     }
     dispatcher.add(PolicyDispatcher {});
     if let Some(validator_proxy) = client.validator_proxy() {
@@ -1,9 +1,10 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 use nimiq_lib::config::{
     config::{ClientConfigBuilder, DatabaseConfig, DatabaseConfigBuilder, FileStorageConfig},
     config_file::ConfigFile,
 };
+use nimiq_network_libp2p::RequestRateLimit;
 use nimiq_test_log::test;
 
 #[test]
@@ -113,3 +114,54 @@ fn config_file_partial_db_entry() {
 
     assert_eq!(config.storage, db_config.into());
 }
+
+#[test]
+fn config_file_rate_limits() {
+    let config_file: ConfigFile = toml::from_str(
+        r#"
+    [network.rate_limits.requests]
+    RequestMacroChain = { max_requests = 1000, time_window = 60 }
+
+    [network.rate_limits.allowlist]
+    RequestTransactionsProof = { max_requests = 10000, time_window = 30 }
+    "#,
+    )
+    .unwrap();
+
+    let mut config_builder = ClientConfigBuilder::default();
+    config_builder.config_file(&config_file).unwrap();
+    let config = config_builder.build().unwrap();
+
+    let rate_limits = &config.network.rate_limits;
+    assert_eq!(
+        rate_limits.limits.get("RequestMacroChain"),
+        Some(&RequestRateLimit {
+            max_requests: 1000,
+            time_window: Duration::from_secs(60),
+        })
+    );
+    assert_eq!(
+        rate_limits.allowlist_limits.get("RequestTransactionsProof"),
+        Some(&RequestRateLimit {
+            max_requests: 10000,
+            time_window: Duration::from_secs(30),
+        })
+    );
+    assert!(rate_limits.allowlisted_peers.is_empty());
+}
+
+#[test]
+fn config_file_rate_limits_reject_unknown_request_types() {
+    for section in ["requests", "allowlist"] {
+        let config_file: ConfigFile = toml::from_str(&format!(
+            r#"
+    [network.rate_limits.{section}]
+    RequestMacroChains = {{ max_requests = 1000, time_window = 60 }}
+    "#
+        ))
+        .unwrap();
+
+        let mut config_builder = ClientConfigBuilder::default();
+        assert!(config_builder.config_file(&config_file).is_err());
+    }
+}
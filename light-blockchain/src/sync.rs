@@ -109,9 +109,14 @@ impl LightBlockchain {
     /// most recent election block and now need to push a checkpoint block.
     /// But this function is general enough to allow pushing any macro block (checkpoint or election)
     /// at any state of the node (synced, partially synced, not synced).
+    ///
+    /// If the justification of the block was already verified, e.g. as part of a batch
+    /// verification using `MacroBlock::verify_validators_batch`, the `trusted_justification` flag
+    /// can be set to skip its verification.
     pub fn push_macro(
         this: RwLockUpgradableReadGuard<Self>,
         mut block: Block,
+        trusted_justification: bool,
     ) -> Result<PushResult, PushError> {
         // Must be a macro block.
         assert!(block.is_macro());
@@ -141,7 +146,9 @@ impl LightBlockchain {
         block.verify_macro_successor(&this.macro_head)?;
 
         // Verify that the block is valid for the current validators.
-        block.verify_validators(this.current_validators().unwrap())?;
+        if !trusted_justification {
+            block.verify_validators(this.current_validators().unwrap())?;
+        }
 
         // At this point we know that the block is correct. We just have to push it.
 
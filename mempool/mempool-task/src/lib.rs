@@ -135,7 +135,7 @@ impl<N: Network> MempoolTask<N> {
 
     fn on_blockchain_event(&mut self, event: &BlockchainEvent) {
         match event {
-            BlockchainEvent::HistoryAdopted(_) => {
+            BlockchainEvent::HistoryAdopted(..) => {
                 // Mempool updates are only done once we are synced.
                 if self.consensus.is_ready_for_validation() {
                     self.mempool.cleanup();
@@ -0,0 +1,55 @@
+use nimiq_hash::Blake2bHash;
+use nimiq_primitives::account::AccountType;
+use nimiq_transaction::Transaction;
+
+/// The mempool container a transaction is stored in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MempoolKind {
+    /// Container for regular transactions.
+    Regular,
+    /// Container for control transactions, i.e. transactions sent from or to the staking contract.
+    Control,
+}
+
+impl MempoolKind {
+    /// Returns the kind of mempool container the given transaction belongs to.
+    pub fn of(tx: &Transaction) -> Self {
+        if tx.sender_type == AccountType::Staking || tx.recipient_type == AccountType::Staking {
+            MempoolKind::Control
+        } else {
+            MempoolKind::Regular
+        }
+    }
+}
+
+/// The reason why a transaction was removed from the mempool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictionReason {
+    /// The transaction was handed out to be included in a block that is being produced.
+    BlockBuilding,
+    /// The transaction is no longer valid at the current block height.
+    Expired,
+    /// The transaction was included in a block.
+    AlreadyIncluded,
+    /// The transaction was invalidated, e.g. because the sender can no longer pay for it.
+    Invalid,
+    /// The transaction was evicted to keep the mempool within its size limit.
+    TooFull,
+}
+
+/// An event describing a change of the mempool content.
+#[derive(Clone, Debug)]
+pub enum MempoolEvent {
+    /// The transaction was added to the mempool.
+    TransactionAdded(Transaction),
+    /// The transaction was removed from the mempool for the given reason.
+    TransactionRemoved(Transaction, EvictionReason),
+    /// The transaction with the given hash was rejected and blacklisted by the mempool filter.
+    TransactionFiltered(Blake2bHash),
+    /// The minimum fee per byte a transaction needs to pay to not be evicted right away from the
+    /// given mempool container changed.
+    FeeThresholdChanged(MempoolKind, f64),
+    /// The subscriber didn't keep up and missed the given number of events, so its view of the
+    /// mempool might be outdated.
+    Lagged(u64),
+}
@@ -12,6 +12,8 @@ mod mempool_state;
 
 /// Mempool config module
 pub mod config;
+/// Mempool events module
+pub mod events;
 /// Mempool executor module
 pub mod executor;
 
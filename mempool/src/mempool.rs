@@ -23,14 +23,16 @@ use nimiq_transaction::{
 use nimiq_utils::spawn;
 use parking_lot::RwLock;
 use tokio_metrics::TaskMonitor;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
 
 #[cfg(feature = "metrics")]
 use crate::mempool_metrics::MempoolMetrics;
 use crate::{
     config::MempoolConfig,
+    events::{EvictionReason, MempoolEvent, MempoolKind},
     executor::MempoolExecutor,
     filter::{MempoolFilter, MempoolRules},
-    mempool_state::{EvictionReason, MempoolState},
+    mempool_state::MempoolState,
     mempool_transactions::{MempoolTransactions, TxPriority},
     verify::{verify_tx, VerifyErr},
 };
@@ -437,12 +439,7 @@ impl Mempool {
                 None => {
                     // We don't have the sender account so we can't do any balance tracking.
                     // Remove all transactions from this sender.
-                    for hash in &sender_state.txns {
-                        mempool_state
-                            .regular_transactions
-                            .delete(hash)
-                            .or_else(|| mempool_state.control_transactions.delete(hash));
-                    }
+                    mempool_state.delete_all(&sender_state.txns, EvictionReason::Invalid);
                     continue;
                 }
             };
@@ -624,6 +621,35 @@ impl Mempool {
         self.filter.read().blacklisted(hash)
     }
 
+    /// Returns the reason why a transaction was removed from the mempool, if it was removed
+    /// recently and hasn't been added again since.
+    pub fn get_removal_reason(&self, hash: &Blake2bHash) -> Option<EvictionReason> {
+        self.state.read().removed_transactions.get(hash).copied()
+    }
+
+    /// Returns the minimum fee per byte a transaction needs to pay to not be the next one to be
+    /// evicted from the given mempool container. This is zero unless the container is almost full.
+    pub fn get_fee_threshold(&self, kind: MempoolKind) -> f64 {
+        let state = self.state.read();
+        match kind {
+            MempoolKind::Regular => state.regular_transactions.fee_threshold(),
+            MempoolKind::Control => state.control_transactions.fee_threshold(),
+        }
+    }
+
+    /// Returns a stream of events describing the changes of the mempool content. If the
+    /// subscriber falls behind, the missed events are replaced by a [`MempoolEvent::Lagged`].
+    pub fn subscribe_events(&self) -> BoxStream<'static, MempoolEvent> {
+        BroadcastStream::new(self.state.read().notifier.subscribe())
+            .map(|event| match event {
+                Ok(event) => event,
+                Err(BroadcastStreamRecvError::Lagged(num_events)) => {
+                    MempoolEvent::Lagged(num_events)
+                }
+            })
+            .boxed()
+    }
+
     /// Returns the rules for the mempool.
     pub fn get_rules(&self) -> MempoolRules {
         self.filter.read().rules.clone()
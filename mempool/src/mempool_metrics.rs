@@ -4,7 +4,7 @@ use prometheus_client::{
     registry::Registry,
 };
 
-use crate::mempool_state::EvictionReason;
+use crate::events::EvictionReason;
 
 #[derive(Default, Clone)]
 pub struct MempoolMetrics {
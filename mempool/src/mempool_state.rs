@@ -2,16 +2,18 @@ use std::collections::{HashMap, HashSet};
 #[cfg(feature = "metrics")]
 use std::sync::Arc;
 
+use linked_hash_map::LinkedHashMap;
 use nimiq_account::ReservedBalance;
 use nimiq_blockchain::Blockchain;
 use nimiq_hash::{Blake2bHash, Hash};
 use nimiq_keys::Address;
-use nimiq_primitives::account::AccountType;
 use nimiq_transaction::Transaction;
+use tokio::sync::broadcast::{channel as broadcast, Sender as BroadcastSender};
 
 #[cfg(feature = "metrics")]
 use crate::mempool_metrics::MempoolMetrics;
 use crate::{
+    events::{EvictionReason, MempoolEvent, MempoolKind},
     mempool_transactions::{MempoolTransactions, TxPriority},
     verify::VerifyErr,
 };
@@ -26,16 +28,31 @@ pub(crate) struct MempoolState {
     // The pending balance per sender.
     pub(crate) state_by_sender: HashMap<Address, SenderPendingState>,
 
+    // The reasons why the most recently removed transactions were removed.
+    pub(crate) removed_transactions: LinkedHashMap<Blake2bHash, EvictionReason>,
+
+    // Notifier for changes of the mempool content.
+    pub(crate) notifier: BroadcastSender<MempoolEvent>,
+
     #[cfg(feature = "metrics")]
     pub(crate) metrics: Arc<MempoolMetrics>,
 }
 
 impl MempoolState {
+    /// Maximum number of removed transactions for which the removal reason is kept.
+    const REMOVED_TXNS_LIMIT: usize = 25_000;
+
+    /// Maximum number of events buffered for each subscriber.
+    const NOTIFIER_CAPACITY: usize = 1024;
+
     pub fn new(regular_txns_limit: usize, control_txns_limit: usize) -> Self {
+        let (notifier, _rx) = broadcast(Self::NOTIFIER_CAPACITY);
         MempoolState {
             regular_transactions: MempoolTransactions::new(regular_txns_limit),
             control_transactions: MempoolTransactions::new(control_txns_limit),
             state_by_sender: HashMap::new(),
+            removed_transactions: LinkedHashMap::new(),
+            notifier,
             #[cfg(feature = "metrics")]
             metrics: Default::default(),
         }
@@ -87,13 +104,17 @@ impl MempoolState {
             self.state_by_sender.insert(tx.sender.clone(), sender_state);
         }
 
+        self.removed_transactions.remove(&tx_hash);
+        self.notifier
+            .send(MempoolEvent::TransactionAdded(tx.clone()))
+            .ok();
+
         // If we are adding a staking transaction we insert it into the control txns container
         // Staking txns are control txns
-        if tx.sender_type == AccountType::Staking || tx.recipient_type == AccountType::Staking {
-            self.control_transactions.insert(tx, priority);
-        } else {
-            self.regular_transactions.insert(tx, priority);
-        }
+        match MempoolKind::of(&tx) {
+            MempoolKind::Control => self.control_transactions.insert(tx, priority),
+            MempoolKind::Regular => self.regular_transactions.insert(tx, priority),
+        };
 
         // After inserting the new txn, check if we need to remove txns
         while self.regular_transactions.total_size > self.regular_transactions.total_size_limit {
@@ -106,6 +127,8 @@ impl MempoolState {
             self.remove(blockchain, &tx_hash, EvictionReason::TooFull);
         }
 
+        self.notify_fee_thresholds();
+
         Ok(())
     }
 
@@ -113,12 +136,14 @@ impl MempoolState {
         &mut self,
         blockchain: &Blockchain,
         tx_hash: &Blake2bHash,
-        #[allow(unused_variables)] reason: EvictionReason,
+        reason: EvictionReason,
     ) -> Option<Transaction> {
         let tx = self
             .regular_transactions
             .delete(tx_hash)
             .or_else(|| self.control_transactions.delete(tx_hash))?;
+        self.notify_removed(tx.clone(), reason);
+        self.notify_fee_thresholds();
 
         let sender_state = match self.state_by_sender.get_mut(&tx.sender) {
             Some(state) => state,
@@ -130,12 +155,8 @@ impl MempoolState {
             None => {
                 // We don't know the sender account so we can't do any balance tracking.
                 // Throw away all transactions from this sender.
-                for hash in &sender_state.txns {
-                    self.regular_transactions
-                        .delete(hash)
-                        .or_else(|| self.control_transactions.delete(hash));
-                }
-                self.state_by_sender.remove(&tx.sender);
+                let sender_state = self.state_by_sender.remove(&tx.sender).unwrap();
+                self.delete_all(&sender_state.txns, EvictionReason::Invalid);
                 return Some(tx);
             }
         };
@@ -158,6 +179,48 @@ impl MempoolState {
         Some(tx)
     }
 
+    /// Deletes the given transactions without any balance tracking.
+    pub(crate) fn delete_all(&mut self, tx_hashes: &HashSet<Blake2bHash>, reason: EvictionReason) {
+        for hash in tx_hashes {
+            if let Some(tx) = self
+                .regular_transactions
+                .delete(hash)
+                .or_else(|| self.control_transactions.delete(hash))
+            {
+                self.notify_removed(tx, reason);
+            }
+        }
+        self.notify_fee_thresholds();
+    }
+
+    /// Remembers why the given transaction was removed and notifies the subscribers.
+    fn notify_removed(&mut self, tx: Transaction, reason: EvictionReason) {
+        while self.removed_transactions.len() >= Self::REMOVED_TXNS_LIMIT {
+            self.removed_transactions.pop_front();
+        }
+        self.removed_transactions.insert(tx.hash(), reason);
+
+        self.notifier
+            .send(MempoolEvent::TransactionRemoved(tx, reason))
+            .ok();
+    }
+
+    /// Notifies the subscribers about changes of the fee threshold of the mempool containers.
+    fn notify_fee_thresholds(&mut self) {
+        for (kind, transactions) in [
+            (MempoolKind::Regular, &mut self.regular_transactions),
+            (MempoolKind::Control, &mut self.control_transactions),
+        ] {
+            let fee_threshold = transactions.fee_threshold();
+            if fee_threshold != transactions.announced_fee_threshold {
+                transactions.announced_fee_threshold = fee_threshold;
+                self.notifier
+                    .send(MempoolEvent::FeeThresholdChanged(kind, fee_threshold))
+                    .ok();
+            }
+        }
+    }
+
     /// Retrieves all expired transaction hashes from both the `regular_transactions` and `control_transactions` vectors
     pub fn get_expired_txns(&mut self, block_number: u32) -> Vec<Blake2bHash> {
         let mut expired_txns = self.control_transactions.get_expired_txns(block_number);
@@ -166,15 +229,6 @@ impl MempoolState {
     }
 }
 
-#[derive(Clone)]
-pub(crate) enum EvictionReason {
-    BlockBuilding,
-    Expired,
-    AlreadyIncluded,
-    Invalid,
-    TooFull,
-}
-
 pub(crate) struct SenderPendingState {
     // The balance reserved by transactions that are currently stored in the mempool for this sender.
     pub(crate) reserved_balance: ReservedBalance,
@@ -116,9 +116,15 @@ pub(crate) struct MempoolTransactions {
 
     // Counter that increases for every added transaction, to order them for removal.
     pub(crate) tx_counter: u64,
+
+    // The fee threshold that was last announced to the mempool subscribers.
+    pub(crate) announced_fee_threshold: f64,
 }
 
 impl MempoolTransactions {
+    // Percentage of the size limit above which the container is considered full.
+    const FULL_PERCENTAGE: usize = 90;
+
     pub fn new(size_limit: usize) -> Self {
         Self {
             transactions: HashMap::new(),
@@ -128,6 +134,7 @@ impl MempoolTransactions {
             total_size_limit: size_limit,
             total_size: 0,
             tx_counter: 0,
+            announced_fee_threshold: 0.0,
         }
     }
 
@@ -139,6 +146,20 @@ impl MempoolTransactions {
         self.transactions.len()
     }
 
+    // Returns the minimum fee per byte a transaction needs to pay to not be the next one to be
+    // evicted. This is zero while the container is filled to less than FULL_PERCENTAGE of its
+    // size limit, otherwise it is the fee per byte of the worst transaction in the container.
+    pub fn fee_threshold(&self) -> f64 {
+        if self.total_size * 100 < self.total_size_limit * Self::FULL_PERCENTAGE {
+            return 0.0;
+        }
+
+        self.worst_transactions
+            .peek()
+            .map(|(_, order)| order.fee_per_byte)
+            .unwrap_or(0.0)
+    }
+
     // This function is used to remove the transactions that are no longer valid at a given block number.
     pub fn get_expired_txns(&mut self, block_number: u32) -> Vec<Blake2bHash> {
         let mut expired_txns = vec![];
@@ -5,10 +5,13 @@ use nimiq_blockchain_interface::AbstractBlockchain;
 use nimiq_hash::{Blake2bHash, Hash};
 use nimiq_primitives::{account::AccountError, networks::NetworkId, transaction::TransactionError};
 use nimiq_transaction::Transaction;
-use parking_lot::RwLock;
+use parking_lot::{RwLock, RwLockUpgradableReadGuard};
 use thiserror::Error;
 
-use crate::{filter::MempoolFilter, mempool_state::MempoolState, mempool_transactions::TxPriority};
+use crate::{
+    events::MempoolEvent, filter::MempoolFilter, mempool_state::MempoolState,
+    mempool_transactions::TxPriority,
+};
 
 /// Error codes for the transaction verification
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -67,9 +70,16 @@ pub(crate) fn verify_tx(
 
     // 6. Check if the transaction is going to be filtered.
     {
-        let filter = filter.read();
-        if !filter.accepts_transaction(&transaction) || filter.blacklisted(&hash) {
-            // FIXME add transaction to blacklist
+        let filter = filter.upgradable_read();
+        if filter.blacklisted(&hash) {
+            return Err(VerifyErr::Filtered);
+        }
+        if !filter.accepts_transaction(&transaction) {
+            RwLockUpgradableReadGuard::upgrade(filter).blacklist(hash.clone());
+            mempool_state
+                .notifier
+                .send(MempoolEvent::TransactionFiltered(hash))
+                .ok();
             return Err(VerifyErr::Filtered);
         }
 
@@ -1,5 +1,6 @@
 use std::{env, str::FromStr, sync::Arc};
 
+use futures::StreamExt;
 use nimiq_block::{Block, MicroBlock, MicroBody, MicroHeader};
 use nimiq_blockchain::{BlockProducer, Blockchain, BlockchainConfig};
 use nimiq_blockchain_interface::{AbstractBlockchain, PushResult};
@@ -11,7 +12,12 @@ use nimiq_keys::{
     Address, Ed25519PublicKey as SchnorrPublicKey, KeyPair as SchnorrKeyPair,
     PrivateKey as SchnorrPrivateKey, SecureGenerate,
 };
-use nimiq_mempool::{config::MempoolConfig, mempool::Mempool, mempool_transactions::TxPriority};
+use nimiq_mempool::{
+    config::MempoolConfig,
+    events::{EvictionReason, MempoolEvent, MempoolKind},
+    mempool::Mempool,
+    mempool_transactions::TxPriority,
+};
 use nimiq_network_mock::{MockHub, MockId, MockNetwork, MockPeerId};
 use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
 use nimiq_serde::{Deserialize, Serialize};
@@ -1295,6 +1301,152 @@ async fn mempool_update_aged_transaction() {
     );
 }
 
+#[test(tokio::test(flavor = "current_thread"))]
+// The purpose of this test is to verify that the subscribers are notified
+// about added and pruned transactions and that the removal reasons are kept.
+async fn mempool_notifies_and_remembers_removed_transactions() {
+    let mut rng = test_rng(true);
+    let time = Arc::new(OffsetTime::new());
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let mut genesis_builder = GenesisBuilder::default();
+    genesis_builder.with_network(NetworkId::UnitAlbatross);
+
+    // Generate and sign transactions
+    let balance = 100;
+    let num_txns = 30;
+    let mut mempool_transactions = vec![];
+    let sender_balances = vec![balance; num_txns as usize];
+    let recipient_balances = vec![0; num_txns as usize];
+
+    // Generate recipient accounts
+    let recipient_accounts =
+        generate_accounts(recipient_balances, &mut genesis_builder, false, &mut rng);
+    // Generate sender accounts
+    let sender_accounts = generate_accounts(sender_balances, &mut genesis_builder, true, &mut rng);
+
+    // Generate transactions
+    for i in 0..num_txns {
+        let mempool_transaction = TestTransaction {
+            fee: 0_u64,
+            value: 60,
+            recipient: recipient_accounts[i as usize].clone(),
+            sender: sender_accounts[i as usize].clone(),
+        };
+        mempool_transactions.push(mempool_transaction);
+    }
+    let (txns, _) = generate_transactions(mempool_transactions, true);
+    log::debug!("Done generating transactions and accounts");
+
+    // Add validator to genesis
+    genesis_builder.with_genesis_validator(
+        Address::from(&SchnorrKeyPair::generate(&mut rng)),
+        signing_key().public,
+        voting_key().public_key,
+        Address::default(),
+        None,
+        None,
+        false,
+    );
+
+    // Generate the genesis and blockchain
+    let genesis_info = genesis_builder.generate(env.clone()).unwrap();
+
+    // The genesis block number must match the specs we are setting in Policy
+    let genesis_block = genesis_info.block;
+    let genesis_block = match genesis_block {
+        Block::Macro(mut block) => {
+            block.header.block_number = Policy::genesis_block_number();
+            Block::Macro(block)
+        }
+        Block::Micro(_) => panic!(),
+    };
+
+    let blockchain = Arc::new(RwLock::new(
+        Blockchain::with_genesis(
+            env.clone(),
+            BlockchainConfig::default(),
+            time,
+            NetworkId::UnitAlbatross,
+            genesis_block,
+            genesis_info.accounts,
+        )
+        .unwrap(),
+    ));
+
+    // Create mempool and subscribe with a custom txn stream
+    let mempool = Mempool::new(blockchain.clone(), MempoolConfig::default());
+    let mut hub = MockHub::new();
+    let mock_id = MockId::new(hub.new_address().into());
+    let mock_network = Arc::new(hub.new_network());
+
+    // Send txns to mempool
+    let tx_hashes: Vec<Blake2bHash> = txns.iter().map(|txn| txn.hash()).collect();
+    let mut events = mempool.subscribe_events();
+    send_txn_to_mempool(&mempool, mock_network, mock_id, txns).await;
+
+    assert_eq!(
+        mempool.num_transactions(),
+        30,
+        "Number of txns in the mempools is not what is expected"
+    );
+
+    // The addition of each transaction is notified.
+    let mut added = 0;
+    while added < num_txns {
+        match events.next().await.unwrap() {
+            MempoolEvent::TransactionAdded(tx) => {
+                assert!(tx_hashes.contains(&tx.hash()));
+                added += 1;
+            }
+            MempoolEvent::FeeThresholdChanged(..) => {}
+            event => panic!("Unexpected event: {:?}", event),
+        }
+    }
+
+    // We need a block producer to produce blocks
+    let producer = BlockProducer::new(signing_key(), voting_key());
+
+    let macro_blocks_to_be_produced =
+        Policy::transaction_validity_window_blocks() / Policy::blocks_per_batch();
+
+    // Now we produce blocks past the transaction validity window
+    produce_macro_blocks_with_txns(
+        &producer,
+        &blockchain,
+        (macro_blocks_to_be_produced + 1).try_into().unwrap(),
+        0,
+        0,
+    );
+
+    // Call mempool update, this should prune all the old transactions
+    mempool.update([].as_ref(), [].as_ref());
+
+    assert_eq!(
+        mempool.num_transactions(),
+        0,
+        "Number of txns in the mempools is not what is expected"
+    );
+
+    // The removal of each transaction is notified and remembered.
+    let mut removed = 0;
+    while removed < num_txns {
+        match events.next().await.unwrap() {
+            MempoolEvent::TransactionRemoved(tx, EvictionReason::Expired) => {
+                assert!(tx_hashes.contains(&tx.hash()));
+                removed += 1;
+            }
+            MempoolEvent::FeeThresholdChanged(..) => {}
+            event => panic!("Unexpected event: {:?}", event),
+        }
+    }
+    for tx_hash in &tx_hashes {
+        assert_eq!(
+            mempool.get_removal_reason(tx_hash),
+            Some(EvictionReason::Expired)
+        );
+    }
+}
+
 #[test(tokio::test(flavor = "current_thread"))]
 async fn mempool_update_not_enough_balance() {
     let mut rng = test_rng(true);
@@ -1932,6 +2084,123 @@ async fn applies_total_tx_size_limits() {
     }
     assert_eq!(mempool_txns.len(), (num_txns - 1) as usize);
 }
+
+#[test(tokio::test)]
+async fn announces_fee_threshold_changes() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let mut genesis_builder = GenesisBuilder::default();
+    genesis_builder.with_network(NetworkId::UnitAlbatross);
+
+    // Generate transactions
+    let balance = 1;
+    let num_txns = 5;
+    let mut mempool_transactions = vec![];
+    let sender_balances = vec![balance + num_txns * num_txns; num_txns as usize];
+    let recipient_balances = vec![0; num_txns as usize];
+
+    let mut rng = test_rng(false);
+    let recipient_accounts =
+        generate_accounts(recipient_balances, &mut genesis_builder, false, &mut rng);
+    let sender_accounts = generate_accounts(sender_balances, &mut genesis_builder, true, &mut rng);
+
+    for i in 0..num_txns {
+        let mempool_transaction = TestTransaction {
+            fee: i + 1,
+            value: balance,
+            recipient: recipient_accounts[i as usize].clone(),
+            sender: sender_accounts[i as usize].clone(),
+        };
+        mempool_transactions.push(mempool_transaction);
+    }
+
+    let (txns, txns_len) = generate_transactions(mempool_transactions, true);
+
+    let mut rng = test_rng(true);
+    genesis_builder.with_genesis_validator(
+        Address::from(&SchnorrKeyPair::generate(&mut rng)),
+        SchnorrPublicKey::from([0u8; 32]),
+        BlsKeyPair::generate(&mut rng).public_key,
+        Address::default(),
+        None,
+        None,
+        false,
+    );
+
+    let genesis_info = genesis_builder.generate(env.clone()).unwrap();
+
+    // The genesis block number must match the specs we are setting in Policy
+    let genesis_block = genesis_info.block;
+    let genesis_block = match genesis_block {
+        Block::Macro(mut block) => {
+            block.header.block_number = Policy::genesis_block_number();
+            Block::Macro(block)
+        }
+        Block::Micro(_) => panic!(),
+    };
+
+    let blockchain = Arc::new(RwLock::new(
+        Blockchain::with_genesis(
+            env.clone(),
+            BlockchainConfig::default(),
+            Arc::new(OffsetTime::new()),
+            NetworkId::UnitAlbatross,
+            genesis_block,
+            genesis_info.accounts,
+        )
+        .unwrap(),
+    ));
+
+    // Create mempool with a total size limit that fits exactly all generated transactions
+    // Need to account for the executed txn size
+    let mempool_config = MempoolConfig {
+        size_limit: txns_len - num_txns as usize,
+        ..Default::default()
+    };
+    let mempool = Mempool::new(blockchain, mempool_config);
+    let mut events = mempool.subscribe_events();
+
+    // The threshold is only raised once the mempool is almost full.
+    let worst_fee_per_byte = txns[0].fee_per_byte();
+    for (i, tx) in txns.into_iter().enumerate() {
+        mempool.add_transaction(tx, None).unwrap();
+        assert!(matches!(
+            events.next().await.unwrap(),
+            MempoolEvent::TransactionAdded(_)
+        ));
+        if i < num_txns as usize - 1 {
+            assert_eq!(mempool.get_fee_threshold(MempoolKind::Regular), 0.0);
+        }
+    }
+    assert_eq!(
+        mempool.get_fee_threshold(MempoolKind::Regular),
+        worst_fee_per_byte
+    );
+    assert_eq!(mempool.get_fee_threshold(MempoolKind::Control), 0.0);
+    assert!(matches!(
+        events.next().await.unwrap(),
+        MempoolEvent::FeeThresholdChanged(MempoolKind::Regular, fee_per_byte) if fee_per_byte == worst_fee_per_byte
+    ));
+
+    // Taking the transactions out of the mempool lowers the threshold again.
+    let (mempool_txns, _) = mempool.get_transactions_for_block(txns_len);
+    assert_eq!(mempool_txns.len(), num_txns as usize);
+    assert_eq!(mempool.get_fee_threshold(MempoolKind::Regular), 0.0);
+
+    let mut threshold_events = vec![];
+    for _ in 0..=num_txns {
+        match events.next().await.unwrap() {
+            MempoolEvent::TransactionRemoved(_, reason) => {
+                assert_eq!(reason, EvictionReason::BlockBuilding)
+            }
+            MempoolEvent::FeeThresholdChanged(kind, fee_per_byte) => {
+                threshold_events.push((kind, fee_per_byte))
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+    assert_eq!(threshold_events, vec![(MempoolKind::Regular, 0.0)]);
+}
+
 #[test(tokio::test)]
 async fn it_can_reject_invalid_vesting_contract_transaction() {
     let time = Arc::new(OffsetTime::new());
@@ -24,7 +24,7 @@ impl BlockMetrics {
     fn register_staking(registry: &mut Registry, blockchain: Arc<RwLock<Blockchain>>) {
         let sub_registry = registry.sub_registry_with_prefix("staking");
 
-        let bc = blockchain;
+        let bc = blockchain.clone();
         let closure = NumericClosureMetric::new_gauge(Box::new(move || {
             bc.read()
                 .get_staking_contract_if_complete(None)
@@ -32,6 +32,32 @@ impl BlockMetrics {
                 .unwrap_or(0)
         }));
         sub_registry.register("active_validators", "Number of active validators", closure);
+
+        let bc = blockchain.clone();
+        let closure = NumericClosureMetric::new_gauge(Box::new(move || {
+            bc.read()
+                .signal_tally()
+                .map(|tally| tally.signaled_slots() as i64)
+                .unwrap_or(0)
+        }));
+        sub_registry.register(
+            "signaled_slots",
+            "Number of slots signaling any value at the last election block",
+            closure,
+        );
+
+        let closure = NumericClosureMetric::new_gauge(Box::new(move || {
+            blockchain
+                .read()
+                .signal_tally()
+                .and_then(|tally| tally.leading_signal().map(|(_, slots)| slots as i64))
+                .unwrap_or(0)
+        }));
+        sub_registry.register(
+            "leading_signal_slots",
+            "Number of slots signaling the most supported value at the last election block",
+            closure,
+        );
     }
 
     fn register_accounts_trie(registry: &mut Registry, blockchain: Arc<RwLock<Blockchain>>) {
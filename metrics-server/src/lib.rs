@@ -5,6 +5,7 @@ use nimiq_consensus::ConsensusProxy;
 use nimiq_mempool::mempool::Mempool;
 use nimiq_network_interface::network::Network;
 use nimiq_utils::spawn;
+use nimiq_validator::validator_metrics::ValidatorMetrics as ValidatorMetricsData;
 use parking_lot::RwLock;
 use prometheus_client::{
     encoding::{EncodeGaugeValue, EncodeMetric, MetricEncoder},
@@ -20,6 +21,7 @@ use crate::tokio_runtime::TokioRuntimeMetrics;
 use crate::{
     chain::BlockMetrics, consensus::ConsensusMetrics, mempool::MempoolMetrics,
     network::NetworkMetrics, server::metrics_server, tokio_task::TokioTaskMetrics,
+    validator::ValidatorMetrics,
 };
 
 mod chain;
@@ -30,6 +32,7 @@ mod server;
 #[cfg(tokio_unstable)]
 mod tokio_runtime;
 mod tokio_task;
+mod validator;
 
 #[derive(Clone)]
 pub struct NimiqTaskMonitor {
@@ -85,6 +88,7 @@ pub fn start_metrics_server<TNetwork: Network>(
     mempool: Option<Arc<Mempool>>,
     consensus_proxy: ConsensusProxy<TNetwork>,
     network: Arc<nimiq_network_libp2p::Network>,
+    validator_metrics: Option<Arc<ValidatorMetricsData>>,
     task_monitors: &[NimiqTaskMonitor],
 ) {
     let mut registry = Registry::default();
@@ -98,6 +102,10 @@ pub fn start_metrics_server<TNetwork: Network>(
         MempoolMetrics::register(nimiq_registry, mempool);
     }
 
+    if let Some(validator_metrics) = validator_metrics {
+        ValidatorMetrics::register(nimiq_registry, validator_metrics);
+    }
+
     // Setup the task metrics
     let task_metrics = Arc::new(RwLock::new(TokioTaskMetrics::new()));
     task_metrics.write().register(
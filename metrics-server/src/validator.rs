@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use nimiq_validator::validator_metrics::ValidatorMetrics as ValidatorMetricsData;
+use prometheus_client::registry::Registry;
+
+pub struct ValidatorMetrics {}
+
+impl ValidatorMetrics {
+    pub fn register(registry: &mut Registry, metrics: Arc<ValidatorMetricsData>) {
+        let sub_registry = registry.sub_registry_with_prefix("validator");
+
+        metrics.register(sub_registry);
+    }
+}
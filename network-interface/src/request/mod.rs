@@ -155,6 +155,12 @@ pub trait RequestCommon:
     }
 }
 
+/// Returns the name of the request type `Req`, which is used to refer to it e.g. in the rate limit
+/// configuration.
+pub fn request_type_name<Req: RequestCommon>() -> &'static str {
+    Req::type_name::<Req>()
+}
+
 pub trait RequestSerialize: RequestCommon {
     /// Serializes a request.
     /// A serialized request is composed of:
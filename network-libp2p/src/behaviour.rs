@@ -1,4 +1,4 @@
-use std::{iter, sync::Arc};
+use std::sync::Arc;
 
 use libp2p::{
     autonat, connection_limits, gossipsub,
@@ -12,7 +12,7 @@ use parking_lot::RwLock;
 use crate::{
     connection_pool,
     discovery::{self, peer_contacts::PeerContactBook},
-    dispatch::codecs::MessageCodec,
+    dispatch::codecs::{MessageCodec, COMPRESSED_REQRES_PROTOCOL, REQRES_PROTOCOL},
     Config,
 };
 
@@ -80,21 +80,28 @@ impl Behaviour {
         let ping = ping::Behaviour::new(ping::Config::new());
 
         // Connection pool behaviour
-        let pool = connection_pool::Behaviour::new(
+        let mut pool = connection_pool::Behaviour::new(
             Arc::clone(&contacts),
             peer_id,
             config.seeds,
             config.discovery.required_services,
             config.desired_peer_count,
         );
+        for ban in config.bans {
+            pool.ban(ban.target, ban.expires_at);
+        }
 
         // Request Response behaviour
-        let protocol = StreamProtocol::new("/nimiq/reqres/0.0.1");
+        // The compressed protocol is listed first so that it is preferred when negotiating
+        // with peers that support it.
+        let protocols = [COMPRESSED_REQRES_PROTOCOL, REQRES_PROTOCOL].map(|protocol| {
+            (
+                StreamProtocol::new(protocol),
+                request_response::ProtocolSupport::Full,
+            )
+        });
         let req_res_config = request_response::Config::default().with_max_concurrent_streams(1000);
-        let request_response = request_response::Behaviour::new(
-            iter::once((protocol, request_response::ProtocolSupport::Full)),
-            req_res_config,
-        );
+        let request_response = request_response::Behaviour::new(protocols, req_res_config);
 
         // Autonat behaviour
         let mut autonat_config = autonat::Config::default();
@@ -1,12 +1,17 @@
-use std::{num::NonZeroU8, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU8,
+    time::Duration,
+};
 
-use libp2p::{gossipsub, identity::Keypair, kad, Multiaddr, StreamProtocol};
+use libp2p::{gossipsub, identity::Keypair, kad, Multiaddr, PeerId, StreamProtocol};
 use nimiq_hash::Blake2bHash;
 use nimiq_network_interface::{network::MIN_SUPPORTED_MSG_SIZE, peer_info::Services};
 use sha2::{Digest, Sha256};
 
 use crate::{
     discovery::{self, peer_contacts::PeerContact},
+    network_types::Ban,
     DHT_PROTOCOL,
 };
 
@@ -19,6 +24,25 @@ pub struct TlsConfig {
     pub certificates: Vec<Vec<u8>>,
 }
 
+/// A limit of at most `max_requests` requests within `time_window`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestRateLimit {
+    pub max_requests: u32,
+    pub time_window: Duration,
+}
+
+/// Overrides of the rate limits of incoming requests.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Rate limits by request type name, replacing the limits defined by the request types.
+    pub limits: HashMap<String, RequestRateLimit>,
+    /// Peers that are subject to `allowlist_limits` instead of the regular limits.
+    pub allowlisted_peers: HashSet<PeerId>,
+    /// Rate limits for allowlisted peers by request type name. Request types without an entry
+    /// use the regular limit.
+    pub allowlist_limits: HashMap<String, RequestRateLimit>,
+}
+
 /// LibP2P network configuration
 pub struct Config {
     pub keypair: Keypair,
@@ -35,6 +59,8 @@ pub struct Config {
     pub only_secure_ws_connections: bool,
     pub allow_loopback_addresses: bool,
     pub dht_quorum: NonZeroU8,
+    pub bans: Vec<Ban>,
+    pub rate_limits: RateLimitConfig,
 }
 
 impl Config {
@@ -100,6 +126,8 @@ impl Config {
             only_secure_ws_connections,
             allow_loopback_addresses,
             dht_quorum,
+            bans: vec![],
+            rate_limits: RateLimitConfig::default(),
         }
     }
 }
@@ -28,7 +28,11 @@ use rand::{seq::IteratorRandom, thread_rng};
 use void::Void;
 
 use super::Error;
-use crate::discovery::{handler, peer_contacts::PeerContactBook};
+use crate::{
+    discovery::{handler, peer_contacts::PeerContactBook},
+    network_types::{Ban, BanTarget},
+    utils::unix_time,
+};
 
 /// Current state of connections and peers for connection limits
 #[derive(Clone, Debug)]
@@ -102,7 +106,7 @@ struct ConnectionState<T> {
     /// List of subsequent banned peers with their unban deadlines in ascending order.
     unban_deadlines: VecDeque<(T, Instant)>,
     /// Deadline for first peer that can be unbanned.
-    unban_timeout: Option<(T, BoxFuture<'static, ()>)>,
+    unban_timeout: Option<(T, Instant, BoxFuture<'static, ()>)>,
     /// The time that needs to pass to unban a banned peer.
     ban_time: Duration,
     /// Set of connection IDs mark as failed.
@@ -128,7 +132,7 @@ impl<T: Clone + Ord + Unpin> Future for ConnectionState<T> {
     type Output = ();
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        while let Some((id, sleep)) = self.unban_timeout.as_mut() {
+        while let Some((id, _, sleep)) = self.unban_timeout.as_mut() {
             if sleep.poll_unpin(cx).is_ready() {
                 let id = id.clone();
                 self.banned.remove(&id);
@@ -140,7 +144,8 @@ impl<T: Clone + Ord + Unpin> Future for ConnectionState<T> {
                     if Instant::now() >= deadline {
                         self.banned.remove(&next_id);
                     } else {
-                        self.unban_timeout = Some((next_id, Box::pin(sleep_until(deadline))));
+                        self.unban_timeout =
+                            Some((next_id, deadline, Box::pin(sleep_until(deadline))));
                         break;
                     }
                 }
@@ -203,21 +208,83 @@ impl<T: Clone + Ord> ConnectionState<T> {
         self.connected.remove(&id);
     }
 
-    /// Marks a connection ID as banned. The connection ID will be also removed
-    /// from the IDs marked as down or failed.
+    /// Marks a connection ID as banned for the configured ban time. The connection ID will be
+    /// also removed from the IDs marked as down or failed. An existing ban that lasts longer,
+    /// like a manual ban without a deadline, is kept.
     fn mark_banned(&mut self, id: T) {
+        let unban_deadline = Instant::now() + self.ban_time;
+        if self.banned.contains(&id)
+            && !matches!(self.get_unban_deadline(&id), Some(deadline) if deadline < unban_deadline)
+        {
+            return;
+        }
+        self.mark_banned_until(id, Some(unban_deadline));
+    }
+
+    /// Marks a connection ID as banned until the given deadline. Without a deadline, the
+    /// connection ID stays banned until it is unbanned explicitly. The connection ID will be
+    /// also removed from the IDs marked as down or failed.
+    fn mark_banned_until(&mut self, id: T, unban_deadline: Option<Instant>) {
         self.failed.remove(&id);
         self.down.remove(&id);
+        self.remove_unban_deadline(&id);
         self.banned.insert(id.clone());
 
-        let unban_deadline = Instant::now() + self.ban_time;
-        // If no peer is scheduled to be unbanned, put is as the first-to-be unbanned peer.
-        // Otherwise queue the peer such that it will be scheduled later when the peer is the first in line.
-        if self.unban_timeout.is_none() {
-            self.unban_timeout = Some((id, Box::pin(sleep_until(unban_deadline))));
+        if let Some(unban_deadline) = unban_deadline {
+            self.schedule_unban(id, unban_deadline);
+        }
+    }
+
+    /// Removes the ban of a connection ID. Returns whether the connection ID was banned.
+    fn unban(&mut self, id: &T) -> bool {
+        self.remove_unban_deadline(id);
+        self.banned.remove(id)
+    }
+
+    /// Schedules a connection ID to be unbanned at the given deadline.
+    fn schedule_unban(&mut self, id: T, unban_deadline: Instant) {
+        // If the deadline is after the one of the first-to-be unbanned peer, queue the peer such
+        // that it will be scheduled later when the peer is the first in line.
+        // Otherwise put it as the first-to-be unbanned peer.
+        if let Some((_, first_deadline, _)) = self.unban_timeout {
+            if first_deadline <= unban_deadline {
+                let position = self
+                    .unban_deadlines
+                    .partition_point(|(_, deadline)| *deadline <= unban_deadline);
+                self.unban_deadlines.insert(position, (id, unban_deadline));
+                return;
+            }
+
+            let (first_id, first_deadline, _) = self.unban_timeout.take().unwrap();
+            self.unban_deadlines.push_front((first_id, first_deadline));
+        }
+
+        self.unban_timeout = Some((id, unban_deadline, Box::pin(sleep_until(unban_deadline))));
+        self.waker.wake();
+    }
+
+    /// Returns the deadline at which a connection ID is scheduled to be unbanned, if any.
+    fn get_unban_deadline(&self, id: &T) -> Option<Instant> {
+        match self.unban_timeout {
+            Some((ref first_id, deadline, _)) if first_id == id => Some(deadline),
+            _ => self
+                .unban_deadlines
+                .iter()
+                .find(|(other_id, _)| other_id == id)
+                .map(|(_, deadline)| *deadline),
+        }
+    }
+
+    /// Removes any scheduled unban of a connection ID.
+    fn remove_unban_deadline(&mut self, id: &T) {
+        self.unban_deadlines.retain(|(other_id, _)| other_id != id);
+
+        if matches!(self.unban_timeout, Some((ref other_id, _, _)) if other_id == id) {
+            self.unban_timeout = self.unban_deadlines.pop_front().map(|(next_id, deadline)| {
+                let sleep: BoxFuture<'static, ()> = Box::pin(sleep_until(deadline));
+                (next_id, deadline, sleep)
+            });
             self.waker.wake();
-        } else {
-            self.unban_deadlines.push_back((id.clone(), unban_deadline));
         }
     }
 
@@ -356,6 +423,10 @@ pub struct Behaviour {
     /// Connection state per address
     addresses: ConnectionState<Multiaddr>,
 
+    /// Peer IDs and IPs that were banned manually, with the time their ban expires in seconds
+    /// since the unix epoch. `None` if the ban does not expire.
+    manual_bans: BTreeMap<BanTarget, Option<u64>>,
+
     /// Queue of actions this behaviour will emit for handler execution.
     actions: VecDeque<PoolToSwarm>,
 
@@ -413,6 +484,7 @@ impl Behaviour {
                 required_services,
                 Duration::from_secs(60 * 10), // 10 minutes
             ),
+            manual_bans: BTreeMap::new(),
             actions: VecDeque::new(),
             active: false,
             limits,
@@ -620,6 +692,74 @@ impl Behaviour {
         self.maintain_peers();
     }
 
+    /// Bans a peer ID or IP address until the given unix time (in seconds), or until it is
+    /// unbanned if no expiry is given. Existing connections to the banned peer or IP are closed.
+    pub fn ban(&mut self, target: BanTarget, expires_at: Option<u64>) {
+        let now = unix_time();
+        let unban_deadline = match expires_at {
+            Some(expires_at) if expires_at <= now => return,
+            Some(expires_at) => Some(Instant::now() + Duration::from_secs(expires_at - now)),
+            None => None,
+        };
+
+        let peers_to_close: Vec<PeerId> = match target {
+            BanTarget::Peer(peer_id) => {
+                self.peer_ids.mark_banned_until(peer_id, unban_deadline);
+                vec![peer_id]
+            }
+            BanTarget::Ip(ip) => {
+                let address = Multiaddr::from(ip);
+                self.addresses
+                    .mark_banned_until(address.clone(), unban_deadline);
+
+                let contacts = self.contacts.read();
+                self.peer_ids
+                    .connected
+                    .keys()
+                    .filter(|peer_id| {
+                        contacts.get(peer_id).is_some_and(|contact| {
+                            contact.get_outer_protocol_address().as_ref() == Some(&address)
+                        })
+                    })
+                    .copied()
+                    .collect()
+            }
+        };
+        debug!(?target, ?expires_at, "Banned manually");
+        self.manual_bans.insert(target, expires_at);
+
+        for peer_id in peers_to_close {
+            self.actions.push_back(ToSwarm::CloseConnection {
+                peer_id,
+                connection: CloseConnection::All,
+            });
+        }
+        self.waker.wake();
+    }
+
+    /// Removes the ban of a peer ID or IP address. Returns whether it was banned.
+    pub fn unban(&mut self, target: BanTarget) -> bool {
+        self.manual_bans.remove(&target);
+        match target {
+            BanTarget::Peer(peer_id) => self.peer_ids.unban(&peer_id),
+            BanTarget::Ip(ip) => self.addresses.unban(&Multiaddr::from(ip)),
+        }
+    }
+
+    /// Returns the manual bans that did not expire yet.
+    pub fn bans(&mut self) -> Vec<Ban> {
+        let now = unix_time();
+        self.manual_bans
+            .retain(|_, expires_at| expires_at.map_or(true, |expires_at| expires_at > now));
+        self.manual_bans
+            .iter()
+            .map(|(target, expires_at)| Ban {
+                target: *target,
+                expires_at: *expires_at,
+            })
+            .collect()
+    }
+
     fn ban_connection(&mut self, peer_id: PeerId) {
         // Mark the peer ID as banned
         self.peer_ids.mark_banned(peer_id);
@@ -966,11 +1106,23 @@ impl NetworkBehaviour for Behaviour {
     fn handle_established_outbound_connection(
         &mut self,
         _connection_id: ConnectionId,
-        _peer: PeerId,
-        _addr: &Multiaddr,
+        peer: PeerId,
+        addr: &Multiaddr,
         _role_override: Endpoint,
         _port_use: PortUse,
     ) -> Result<THandler<Self>, ConnectionDenied> {
+        // Connections to banned peers can also be established by dialing their address directly.
+        if self.peer_ids.is_banned(peer) {
+            debug!(peer_id=%peer, "Peer is banned");
+            return Err(ConnectionDenied::new(Error::BannedPeer));
+        }
+        if let Some(outer_protocol_address) = handler::outer_protocol_address(addr) {
+            if self.addresses.is_banned(outer_protocol_address) {
+                debug!(%addr, "Address is banned");
+                return Err(ConnectionDenied::new(Error::BannedIp));
+            }
+        }
+
         Ok(dummy::ConnectionHandler)
     }
 
@@ -1002,7 +1154,7 @@ mod tests {
     use std::task::Context;
 
     use futures::{task::noop_waker, FutureExt};
-    use instant::Duration;
+    use instant::{Duration, Instant};
     use libp2p::PeerId;
     use nimiq_network_interface::peer_info::Services;
     use nimiq_test_log::test;
@@ -1052,4 +1204,81 @@ mod tests {
         // p2 and p3 should both be unbanned
         assert!(cs.banned.is_empty());
     }
+
+    #[test(tokio::test)]
+    async fn unban_peers_at_their_deadlines() {
+        let mut cs = ConnectionState::new(
+            30,
+            Duration::from_secs(30),
+            1,
+            Services::empty(),
+            Duration::from_secs(60),
+        );
+
+        let waker = noop_waker();
+        let cx = &mut Context::from_waker(&waker);
+
+        let p1 = PeerId::random();
+        let p2 = PeerId::random();
+        let p3 = PeerId::random();
+
+        let now = Instant::now();
+        cs.mark_banned_until(p1, Some(now + Duration::from_secs(2)));
+        cs.mark_banned_until(p2, Some(now + Duration::from_secs(1)));
+        // Banning again replaces the scheduled unban.
+        cs.mark_banned_until(p3, Some(now + Duration::from_secs(1)));
+        cs.mark_banned_until(p3, None);
+
+        sleep(Duration::from_millis(1500)).await;
+        let _ = cs.poll_unpin(cx);
+
+        // Only p2 should be unbanned
+        assert!(cs.is_banned(p1));
+        assert!(!cs.is_banned(p2));
+        assert!(cs.is_banned(p3));
+
+        sleep(Duration::from_secs(1)).await;
+        let _ = cs.poll_unpin(cx);
+
+        // p3 stays banned until it is unbanned explicitly
+        assert!(!cs.is_banned(p1));
+        assert!(cs.is_banned(p3));
+        assert!(cs.unban(&p3));
+        assert!(!cs.unban(&p3));
+        assert!(cs.banned.is_empty());
+    }
+
+    #[test(tokio::test)]
+    async fn ban_does_not_shorten_longer_bans() {
+        let mut cs = ConnectionState::new(
+            30,
+            Duration::from_secs(30),
+            1,
+            Services::empty(),
+            Duration::from_secs(1), // Ban time: 1 second
+        );
+
+        let waker = noop_waker();
+        let cx = &mut Context::from_waker(&waker);
+
+        let p1 = PeerId::random();
+        let p2 = PeerId::random();
+        let p3 = PeerId::random();
+
+        cs.mark_banned_until(p1, None);
+        cs.mark_banned_until(p2, Some(Instant::now() + Duration::from_secs(60)));
+        cs.mark_banned(p3);
+
+        cs.mark_banned(p1);
+        cs.mark_banned(p2);
+        assert_eq!(cs.get_unban_deadline(&p1), None);
+        assert!(cs.get_unban_deadline(&p2) > cs.get_unban_deadline(&p3));
+
+        sleep(Duration::from_millis(1500)).await;
+        let _ = cs.poll_unpin(cx);
+
+        assert!(cs.is_banned(p1));
+        assert!(cs.is_banned(p2));
+        assert!(!cs.is_banned(p3));
+    }
 }
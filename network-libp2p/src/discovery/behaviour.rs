@@ -149,6 +149,13 @@ impl Behaviour {
             .add_own_addresses(addresses, &self.keypair)
     }
 
+    /// Sets the services provided by our own contact within the peer contact book
+    pub fn set_own_services(&self, services: Services) {
+        self.peer_contact_book
+            .write()
+            .set_own_services(services, &self.keypair)
+    }
+
     /// Returns whether an address in `Multiaddr` format is a dialable websocket address
     pub fn is_address_dialable(&self, address: &Multiaddr) -> bool {
         self.peer_contact_book.read().is_address_dialable(address)
@@ -460,12 +460,15 @@ impl PeerContactBook {
     pub fn get_addresses(&self, peer_id: &PeerId) -> Option<Vec<Multiaddr>> {
         self.peer_contacts.get(peer_id).map(|e| {
             let peer_contact = e.contact();
-            peer_contact
+            let mut addresses: Vec<Multiaddr> = peer_contact
                 .addresses
                 .iter()
                 .filter(|&address| self.is_address_dialable(address))
                 .cloned()
-                .collect()
+                .collect();
+            // Prefer QUIC addresses since they are only dialable if both sides support QUIC.
+            addresses.sort_by_key(|address| !utils::is_address_quic(address));
+            addresses
         })
     }
 
@@ -547,6 +550,13 @@ impl PeerContactBook {
         self.own_peer_contact = PeerContactInfo::from(contact.sign(keypair));
     }
 
+    /// Sets the services provided by our own contact.
+    pub fn set_own_services(&mut self, services: Services, keypair: &Keypair) {
+        let mut contact = self.own_peer_contact.contact.inner.clone();
+        contact.services = services;
+        self.own_peer_contact = PeerContactInfo::from(contact.sign(keypair));
+    }
+
     /// Updates the timestamp of our own contact
     pub fn update_own_contact(&mut self, keypair: &Keypair) {
         // Not really optimal to clone here, but *shrugs*
@@ -597,6 +607,9 @@ impl PeerContactBook {
         if self.memory_transport {
             return true;
         }
+        if utils::is_address_quic(address) {
+            return self.is_quic_address_dialable(address);
+        }
         // Otherwise check for an appropriate WS address
         let mut protocols = address.iter();
         let mut ip = protocols.next();
@@ -646,6 +659,36 @@ impl PeerContactBook {
             }
         }
     }
+
+    /// Returns true if a QUIC address is valid for dialing. QUIC addresses are only dialable if
+    /// QUIC support is compiled in and we are not restricted to secure websocket connections.
+    fn is_quic_address_dialable(&self, address: &Multiaddr) -> bool {
+        if !cfg!(feature = "quic") || self.only_secure_addresses {
+            return false;
+        }
+
+        // The address must be of the form `/<ip or dns>/udp/<port>/quic-v1`, optionally
+        // followed by the `P2p` protocol that identifies the remote.
+        let mut protocols = address.iter();
+        match protocols.next() {
+            Some(Protocol::Ip4(ip)) => {
+                if !self.allow_loopback_addresses && ip.is_loopback() {
+                    return false;
+                }
+            }
+            Some(Protocol::Ip6(ip)) => {
+                if !self.allow_loopback_addresses && ip.is_loopback() {
+                    return false;
+                }
+            }
+            Some(Protocol::Dns(_)) | Some(Protocol::Dns4(_)) | Some(Protocol::Dns6(_)) => {}
+            _ => return false,
+        }
+
+        matches!(protocols.next(), Some(Protocol::Udp(_)))
+            && matches!(protocols.next(), Some(Protocol::QuicV1))
+            && protocols.all(|protocol| matches!(protocol, Protocol::P2p(_)))
+    }
 }
 
 mod serde_public_key {
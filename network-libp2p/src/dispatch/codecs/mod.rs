@@ -3,6 +3,10 @@
 //! and a type ID. The body of the message can be arbitrary bytes which are later
 //! serialized/deserialized to the Request/Message trait.
 //!
+//! If the compressed request response protocol was negotiated with the peer, the
+//! message (without the size header) is compressed using Snappy. Peers that don't
+//! support compression negotiate the uncompressed protocol instead.
+//!
 //! Note that this doesn't actually serialize/deserialize the message content, but
 //! only handles reading/writing the message.
 
@@ -18,87 +22,126 @@ const U64_LENGTH: usize = mem::size_of::<u64>();
 const MAX_REQUEST_SIZE: u64 = network::MIN_SUPPORTED_REQ_SIZE as u64 + U64_LENGTH as u64;
 const MAX_RESPONSE_SIZE: u64 = network::MIN_SUPPORTED_RESP_SIZE as u64 + U64_LENGTH as u64;
 
+/// The request response protocol without compression.
+pub(crate) const REQRES_PROTOCOL: &str = "/nimiq/reqres/0.0.1";
+/// The request response protocol in which messages are compressed using Snappy.
+pub(crate) const COMPRESSED_REQRES_PROTOCOL: &str = "/nimiq/reqres/snappy/0.0.1";
+
 #[derive(Default, Debug, Clone)]
 pub struct MessageCodec;
 
 pub type IncomingRequest = Vec<u8>;
 pub type OutgoingResponse = Vec<u8>;
 
+fn is_compressed(protocol: &StreamProtocol) -> bool {
+    protocol.as_ref() == COMPRESSED_REQRES_PROTOCOL
+}
+
+/// Reads a message of at most `max_size` bytes (once decompressed).
+/// Returns `None` if the stream ended before a complete message was read.
+async fn read_message<T>(io: &mut T, max_size: u64, compressed: bool) -> io::Result<Option<Vec<u8>>>
+where
+    T: AsyncRead + Unpin + Send,
+{
+    // A compressed message may be slightly larger than the uncompressed one.
+    let max_read_size = if compressed {
+        (snap::raw::max_compress_len(max_size as usize) + U64_LENGTH) as u64
+    } else {
+        max_size
+    };
+
+    let mut vec = Vec::new();
+    io.take(max_read_size).read_to_end(&mut vec).await?;
+    if vec.len() < U64_LENGTH {
+        return Ok(None);
+    }
+    let mut len_bytes = [0u8; U64_LENGTH];
+    len_bytes.copy_from_slice(&vec[..U64_LENGTH]);
+    let len = u64::from_be_bytes(len_bytes) as usize;
+
+    if len as u64 > max_read_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Received data size ({len} bytes) exceeds maximum ({max_read_size} bytes)"),
+        ));
+    }
+
+    if vec.len() - U64_LENGTH < len {
+        return Ok(None);
+    }
+
+    // Skip the length header we already read
+    vec.drain(..U64_LENGTH);
+    if !compressed {
+        return Ok(Some(vec));
+    }
+
+    // Check the decompressed size announced in the Snappy header before decompressing to
+    // guard against decompression bombs.
+    let decompressed_len = snap::raw::decompress_len(&vec)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    if decompressed_len as u64 > max_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Decompressed data size ({decompressed_len} bytes) exceeds maximum ({max_size} bytes)"
+            ),
+        ));
+    }
+    snap::raw::Decoder::new()
+        .decompress_vec(&vec)
+        .map(Some)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Writes a message, compressing it first if requested.
+async fn write_message<T>(io: &mut T, message: Vec<u8>, compressed: bool) -> io::Result<()>
+where
+    T: AsyncWrite + Unpin + Send,
+{
+    let message = if compressed {
+        snap::raw::Encoder::new()
+            .compress_vec(&message)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?
+    } else {
+        message
+    };
+    io.write_all(&(message.len() as u64).to_be_bytes()).await?;
+    io.write_all(&message).await?;
+    Ok(())
+}
+
 #[async_trait::async_trait]
 impl request_response::Codec for MessageCodec {
     type Protocol = StreamProtocol;
     type Request = Option<IncomingRequest>;
     type Response = Option<OutgoingResponse>;
 
-    async fn read_request<T>(&mut self, _: &Self::Protocol, io: &mut T) -> io::Result<Self::Request>
+    async fn read_request<T>(
+        &mut self,
+        protocol: &Self::Protocol,
+        io: &mut T,
+    ) -> io::Result<Self::Request>
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut vec = Vec::new();
-        io.take(MAX_REQUEST_SIZE).read_to_end(&mut vec).await?;
-        if vec.len() < U64_LENGTH {
-            return Ok(None);
-        }
-        let mut len_bytes = [0u8; U64_LENGTH];
-        len_bytes.copy_from_slice(&vec[..U64_LENGTH]);
-        let len = u64::from_be_bytes(len_bytes) as usize;
-
-        if len as u64 > MAX_REQUEST_SIZE {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "Received data size ({len} bytes) exceeds maximum ({MAX_REQUEST_SIZE} bytes)"
-                ),
-            ));
-        }
-
-        if vec.len() - U64_LENGTH >= len {
-            // Skip the length header we already read
-            vec.drain(..U64_LENGTH);
-            Ok(Some(vec))
-        } else {
-            Ok(None)
-        }
+        read_message(io, MAX_REQUEST_SIZE, is_compressed(protocol)).await
     }
 
     async fn read_response<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         io: &mut T,
     ) -> io::Result<Self::Response>
     where
         T: AsyncRead + Unpin + Send,
     {
-        let mut vec = Vec::new();
-        io.take(MAX_RESPONSE_SIZE).read_to_end(&mut vec).await?;
-        if vec.len() < U64_LENGTH {
-            return Ok(None);
-        }
-        let mut len_bytes = [0u8; U64_LENGTH];
-        len_bytes.copy_from_slice(&vec[..U64_LENGTH]);
-        let len = u64::from_be_bytes(len_bytes) as usize;
-
-        if len as u64 > MAX_RESPONSE_SIZE {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "Received data size ({len} bytes) exceeds maximum ({MAX_RESPONSE_SIZE} bytes)"
-                ),
-            ));
-        }
-
-        if vec.len() - U64_LENGTH >= len {
-            // Skip the length header we already read
-            vec.drain(..U64_LENGTH);
-            Ok(Some(vec))
-        } else {
-            Ok(None)
-        }
+        read_message(io, MAX_RESPONSE_SIZE, is_compressed(protocol)).await
     }
 
     async fn write_request<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         io: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
@@ -106,14 +149,12 @@ impl request_response::Codec for MessageCodec {
         T: AsyncWrite + Send + Unpin,
     {
         let src = req.expect("No data to write");
-        io.write_all(&(src.len() as u64).to_be_bytes()).await?;
-        io.write_all(&src).await?;
-        Ok(())
+        write_message(io, src, is_compressed(protocol)).await
     }
 
     async fn write_response<T>(
         &mut self,
-        _: &Self::Protocol,
+        protocol: &Self::Protocol,
         io: &mut T,
         res: Self::Response,
     ) -> io::Result<()>
@@ -121,8 +162,47 @@ impl request_response::Codec for MessageCodec {
         T: AsyncWrite + Unpin + Send,
     {
         let src = res.expect("No data to write");
-        io.write_all(&(src.len() as u64).to_be_bytes()).await?;
-        io.write_all(&src).await?;
-        Ok(())
+        write_message(io, src, is_compressed(protocol)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::io::Cursor;
+    use nimiq_test_log::test;
+
+    use super::{read_message, write_message, MAX_RESPONSE_SIZE, U64_LENGTH};
+
+    #[test(tokio::test)]
+    async fn it_writes_and_reads_compressed_messages() {
+        let message = vec![42u8; 100_000];
+
+        for compressed in [false, true] {
+            let mut buf = Cursor::new(vec![]);
+            write_message(&mut buf, message.clone(), compressed)
+                .await
+                .unwrap();
+            if compressed {
+                assert!(buf.get_ref().len() < message.len());
+            }
+
+            let mut buf = Cursor::new(buf.into_inner());
+            let received = read_message(&mut buf, MAX_RESPONSE_SIZE, compressed)
+                .await
+                .unwrap();
+            assert_eq!(received, Some(message.clone()));
+        }
+    }
+
+    #[test(tokio::test)]
+    async fn it_rejects_messages_that_decompress_beyond_the_limit() {
+        let message = vec![0u8; 100_000];
+        let mut buf = Cursor::new(vec![]);
+        write_message(&mut buf, message, true).await.unwrap();
+        // The compressed message itself is within the limit.
+        assert!(buf.get_ref().len() < 10_000 + U64_LENGTH);
+
+        let mut buf = Cursor::new(buf.into_inner());
+        assert!(read_message(&mut buf, 10_000, true).await.is_err());
     }
 }
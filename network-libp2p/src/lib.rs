@@ -19,7 +19,7 @@ mod utils;
 pub const DISCOVERY_PROTOCOL: &str = "/nimiq/discovery/0.0.1";
 pub const DHT_PROTOCOL: &str = "/nimiq/kad/0.0.1";
 
-pub use config::{Config, TlsConfig};
+pub use config::{Config, RateLimitConfig, RequestRateLimit, TlsConfig};
 pub use error::NetworkError;
 pub use libp2p::{
     self,
@@ -28,6 +28,7 @@ pub use libp2p::{
     PeerId,
 };
 pub use network::Network;
+pub use network_types::{Ban, BanTarget, PeerDetails};
 use serde::{
     de::Error, ser::Error as SerializationError, Deserialize, Deserializer, Serialize, Serializer,
 };
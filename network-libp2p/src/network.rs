@@ -38,10 +38,11 @@ use tokio_stream::wrappers::{BroadcastStream, ReceiverStream};
 use crate::network_metrics::NetworkMetrics;
 use crate::{
     discovery::peer_contacts::PeerContactBook,
-    network_types::{GossipsubId, NetworkAction, ValidateMessage},
+    network_types::{Ban, BanTarget, GossipsubId, NetworkAction, PeerDetails, ValidateMessage},
     rate_limiting::RequestRateLimitData,
     swarm::{new_swarm, swarm_task},
-    Config, NetworkError,
+    utils::unix_time,
+    Config, NetworkError, RateLimitConfig,
 };
 
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
@@ -66,6 +67,8 @@ pub struct Network {
     required_services: Services,
     /// Reference to PeerContactBook, used to satisfy rpc requests for it.
     contacts: Arc<RwLock<PeerContactBook>>,
+    /// Overrides of the rate limits of incoming requests
+    rate_limits: RateLimitConfig,
 }
 
 impl Network {
@@ -90,6 +93,7 @@ impl Network {
             ..Default::default()
         };
         let dht_quorum = config.dht_quorum;
+        let rate_limits = config.rate_limits.clone();
         // Only force the server mode if we are doing a memory transport.
         // Otherwise expect the regular flow: DHT will get in server mode once a confirmed address is obtained using Autonat.
         // In memory transport we don't have a mechanism that sets the DHT in server mode such as confirming an address
@@ -124,6 +128,7 @@ impl Network {
             Arc::clone(&contacts),
             force_dht_server_mode,
             dht_quorum,
+            rate_limits.allowlisted_peers.clone(),
             #[cfg(feature = "metrics")]
             metrics.clone(),
         )));
@@ -138,6 +143,7 @@ impl Network {
             #[cfg(feature = "metrics")]
             metrics,
             required_services,
+            rate_limits,
         }
     }
 
@@ -175,6 +181,82 @@ impl Network {
         }
     }
 
+    /// Returns details about a connected peer, or `None` if we are not connected to the peer.
+    pub async fn get_peer_details(
+        &self,
+        peer_id: PeerId,
+    ) -> Result<Option<PeerDetails>, NetworkError> {
+        let Some(peer_info) = self.connected_peers.read().get(&peer_id).cloned() else {
+            return Ok(None);
+        };
+
+        let (output_tx, output_rx) = oneshot::channel();
+        self.action_tx
+            .send(NetworkAction::ConnectionInfo {
+                peer_id,
+                output: output_tx,
+            })
+            .await?;
+        let Some((connection_info, gossipsub_score)) = output_rx.await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(PeerDetails {
+            peer_id,
+            address: peer_info.get_address(),
+            advertised_addresses: self
+                .contacts
+                .read()
+                .get_addresses(&peer_id)
+                .unwrap_or_default(),
+            services: peer_info.get_services(),
+            gossipsub_score,
+            outbound: connection_info.outbound,
+            connection_age: connection_info.established_at.elapsed(),
+        }))
+    }
+
+    /// Bans a peer ID or IP address for the given duration, or until it is unbanned if no
+    /// duration is given. Existing connections to the banned peer or IP are closed.
+    pub async fn ban(&self, target: BanTarget, ttl: Option<Duration>) -> Result<(), NetworkError> {
+        let expires_at = ttl.map(|ttl| unix_time() + ttl.as_secs());
+        self.action_tx
+            .send(NetworkAction::Ban { target, expires_at })
+            .await?;
+        Ok(())
+    }
+
+    /// Removes the ban of a peer ID or IP address. Returns whether it was banned.
+    pub async fn unban(&self, target: BanTarget) -> Result<bool, NetworkError> {
+        let (output_tx, output_rx) = oneshot::channel();
+        self.action_tx
+            .send(NetworkAction::Unban {
+                target,
+                output: output_tx,
+            })
+            .await?;
+        Ok(output_rx.await?)
+    }
+
+    /// Returns the peer IDs and IP addresses that were banned manually and whose ban did not
+    /// expire yet.
+    pub async fn get_bans(&self) -> Result<Vec<Ban>, NetworkError> {
+        let (output_tx, output_rx) = oneshot::channel();
+        self.action_tx
+            .send(NetworkAction::GetBans { output: output_tx })
+            .await?;
+        Ok(output_rx.await?)
+    }
+
+    /// Changes the services we advertise to other peers, e.g. when the node has finished
+    /// backfilling its history. The new services are announced with the next contact exchange.
+    pub async fn set_provided_services(&self, services: Services) -> Result<(), NetworkError> {
+        self.action_tx
+            .send(NetworkAction::SetProvidedServices { services })
+            .await?;
+        Ok(())
+    }
+
     /// Tells the network to start connecting to any available peer or seed
     /// until meeting the configured number of desired peer connections.
     /// If there are no dial attempts being made and no connections to any
@@ -312,6 +394,7 @@ impl Network {
         }
 
         let action_tx = self.action_tx.clone();
+        let request_rate_limit_data = RequestRateLimitData::new::<Req>(&self.rate_limits);
         ReceiveStream::WaitingForRegister(Box::pin(async move {
             // TODO Make buffer size configurable
             let (tx, rx) = mpsc::channel(1024);
@@ -320,7 +403,7 @@ impl Network {
                 .send(NetworkAction::ReceiveRequests {
                     type_id: RequestType::from_request::<Req>(),
                     output: tx,
-                    request_rate_limit_data: RequestRateLimitData::new::<Req>(),
+                    request_rate_limit_data,
                 })
                 .await
                 .expect("Sending action to network task failed.");
@@ -1,6 +1,10 @@
-use std::time::Duration;
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
-use libp2p::gossipsub::TopicHash;
+use libp2p::{gossipsub::TopicHash, PeerId};
+use parking_lot::Mutex;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{counter::Counter, family::Family, histogram::Histogram},
@@ -11,6 +15,10 @@ pub struct NetworkMetrics {
     gossipsub_messages_received: Family<TopicLabels, Counter>,
     gossipsub_messages_published: Family<TopicLabels, Counter>,
     response_times: Histogram,
+    rate_limit_hits: Family<RateLimitLabels, Counter>,
+    /// The request types for which each connected peer hit the rate limit, used to remove the
+    /// per-peer metrics when the peer leaves.
+    rate_limited_peers: Mutex<HashMap<PeerId, HashSet<&'static str>>>,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
@@ -18,12 +26,20 @@ struct TopicLabels {
     topic: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RateLimitLabels {
+    peer_id: String,
+    request_type: String,
+}
+
 impl Default for NetworkMetrics {
     fn default() -> Self {
         NetworkMetrics {
             gossipsub_messages_received: Default::default(),
             gossipsub_messages_published: Default::default(),
             response_times: Histogram::new([0.01, 0.02, 0.05, 0.1, 0.2, 0.5, 1.0, 2.0].into_iter()),
+            rate_limit_hits: Default::default(),
+            rate_limited_peers: Default::default(),
         }
     }
 }
@@ -47,6 +63,12 @@ impl NetworkMetrics {
             "Time between requests and responses",
             self.response_times.clone(),
         );
+
+        registry.register(
+            "request_rate_limit_hits",
+            "Number of incoming requests denied because of the rate limit",
+            self.rate_limit_hits.clone(),
+        );
     }
 
     pub(crate) fn note_received_pubsub_message(&self, topic: &TopicHash) {
@@ -76,4 +98,76 @@ impl NetworkMetrics {
     pub(crate) fn note_response_time(&self, duration: Duration) {
         self.response_times.observe(duration.as_secs_f64());
     }
+
+    pub(crate) fn note_rate_limit_hit(&self, peer_id: &PeerId, request_type: &'static str) {
+        self.rate_limited_peers
+            .lock()
+            .entry(*peer_id)
+            .or_default()
+            .insert(request_type);
+        self.rate_limit_hits
+            .get_or_create(&RateLimitLabels {
+                peer_id: peer_id.to_string(),
+                request_type: request_type.to_string(),
+            })
+            .inc();
+    }
+
+    /// Removes the rate limit hits of a peer that left, so the number of series doesn't grow with
+    /// every peer ever seen.
+    pub(crate) fn note_peer_left(&self, peer_id: &PeerId) {
+        let Some(request_types) = self.rate_limited_peers.lock().remove(peer_id) else {
+            return;
+        };
+        for request_type in request_types {
+            self.rate_limit_hits.remove(&RateLimitLabels {
+                peer_id: peer_id.to_string(),
+                request_type: request_type.to_string(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prometheus_client::encoding::text::encode;
+
+    use super::*;
+
+    fn encode_metrics(metrics: &NetworkMetrics) -> String {
+        let mut registry = Registry::default();
+        metrics.register(&mut registry);
+        let mut buffer = String::new();
+        encode(&mut buffer, &registry).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn it_removes_the_rate_limit_hits_of_peers_that_left() {
+        let metrics = NetworkMetrics::default();
+        let peer1 = PeerId::random();
+        let peer2 = PeerId::random();
+
+        metrics.note_rate_limit_hit(&peer1, "RequestBlock");
+        metrics.note_rate_limit_hit(&peer1, "RequestBlock");
+        metrics.note_rate_limit_hit(&peer1, "RequestHead");
+        metrics.note_rate_limit_hit(&peer2, "RequestBlock");
+
+        let encoded = encode_metrics(&metrics);
+        assert!(encoded.contains(&format!(
+            "request_rate_limit_hits_total{{peer_id=\"{peer1}\",request_type=\"RequestBlock\"}} 2"
+        )));
+        assert!(encoded.contains(&format!(
+            "request_rate_limit_hits_total{{peer_id=\"{peer1}\",request_type=\"RequestHead\"}} 1"
+        )));
+
+        metrics.note_peer_left(&peer1);
+        metrics.note_peer_left(&peer1);
+
+        let encoded = encode_metrics(&metrics);
+        assert!(!encoded.contains(&peer1.to_string()));
+        assert!(encoded.contains(&format!(
+            "request_rate_limit_hits_total{{peer_id=\"{peer2}\",request_type=\"RequestBlock\"}} 1"
+        )));
+    }
 }
@@ -1,7 +1,6 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, net::IpAddr, time::Duration};
 
 use bytes::Bytes;
-#[cfg(feature = "metrics")]
 use instant::Instant;
 use libp2p::{
     gossipsub,
@@ -16,7 +15,7 @@ use nimiq_network_interface::{
     peer_info::Services,
     request::{RequestError, RequestType},
 };
-use nimiq_serde::{Deserialize, DeserializeError};
+use nimiq_serde::{Deserialize, DeserializeError, Serialize};
 use nimiq_utils::tagged_signing::{TaggedSignable, TaggedSigned};
 use nimiq_validator_network::validator_record::ValidatorRecord;
 use thiserror::Error;
@@ -28,6 +27,48 @@ use crate::{
     NetworkError,
 };
 
+/// A peer ID or IP address that can be banned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum BanTarget {
+    Peer(PeerId),
+    Ip(IpAddr),
+}
+
+/// A manual ban of a peer ID or IP address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ban {
+    pub target: BanTarget,
+    /// The time at which the ban expires in seconds since the unix epoch. `None` if the ban
+    /// does not expire.
+    pub expires_at: Option<u64>,
+}
+
+/// Details about a connected peer.
+#[derive(Clone, Debug)]
+pub struct PeerDetails {
+    pub peer_id: PeerId,
+    /// The address of the connection to the peer.
+    pub address: Multiaddr,
+    /// The addresses advertised by the peer.
+    pub advertised_addresses: Vec<Multiaddr>,
+    pub services: Services,
+    /// The score of the peer in gossipsub.
+    pub gossipsub_score: Option<f64>,
+    /// Whether the connection was established by us.
+    pub outbound: bool,
+    /// The time since the connection to the peer was established.
+    pub connection_age: Duration,
+}
+
+/// Information about the connection to a peer.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ConnectionInfo {
+    /// Whether the connection was established by us.
+    pub(crate) outbound: bool,
+    /// The time at which the first connection to the peer was established.
+    pub(crate) established_at: Instant,
+}
+
 #[derive(Debug)]
 pub(crate) enum NetworkAction {
     Dial {
@@ -99,6 +140,24 @@ pub(crate) enum NetworkAction {
         peer_id: PeerId,
         reason: CloseReason,
     },
+    ConnectionInfo {
+        peer_id: PeerId,
+        output: oneshot::Sender<Option<(ConnectionInfo, Option<f64>)>>,
+    },
+    Ban {
+        target: BanTarget,
+        expires_at: Option<u64>,
+    },
+    Unban {
+        target: BanTarget,
+        output: oneshot::Sender<bool>,
+    },
+    GetBans {
+        output: oneshot::Sender<Vec<Ban>>,
+    },
+    SetProvidedServices {
+        services: Services,
+    },
 }
 
 pub(crate) struct ValidateMessage<P: Clone> {
@@ -256,6 +315,8 @@ pub(crate) struct TaskState {
     >,
     /// DHT quorum value
     pub(crate) dht_quorum: u8,
+    /// Information about the connection to each connected peer
+    pub(crate) connections: HashMap<PeerId, ConnectionInfo>,
 }
 
 #[derive(Clone, Debug)]
@@ -1,6 +1,6 @@
 use std::{
     cmp::Ordering,
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeSet, HashMap, HashSet},
     time::Duration,
 };
 
@@ -8,21 +8,51 @@ use instant::Instant;
 use libp2p::PeerId;
 use nimiq_network_interface::request::{RequestCommon, RequestType};
 
+use crate::{RateLimitConfig, RequestRateLimit};
+
 /// The rate limiting request metadata that will be passed on between the network and the swarm.
 /// This is not sent through the wire.
 #[derive(Debug, PartialEq)]
 pub(crate) struct RequestRateLimitData {
-    /// Maximum requests allowed by this request type.
-    pub(crate) max_requests: u32,
-    ///  The range/window of time of this request type.
-    pub(crate) time_window: Duration,
+    /// The name of the request type, used for logging and metrics.
+    pub(crate) type_name: &'static str,
+    /// The rate limit of this request type.
+    pub(crate) limit: RequestRateLimit,
+    /// The rate limit of this request type for allowlisted peers.
+    pub(crate) allowlist_limit: RequestRateLimit,
 }
 
 impl RequestRateLimitData {
-    pub(crate) fn new<Req: RequestCommon>() -> Self {
+    /// Creates the rate limiting data of a request type, applying the overrides of the given
+    /// configuration to the limits defined by the request type.
+    pub(crate) fn new<Req: RequestCommon>(config: &RateLimitConfig) -> Self {
+        let type_name = Req::type_name::<Req>();
+        let limit = config
+            .limits
+            .get(type_name)
+            .copied()
+            .unwrap_or(RequestRateLimit {
+                max_requests: Req::MAX_REQUESTS,
+                time_window: Req::TIME_WINDOW,
+            });
+        let allowlist_limit = config
+            .allowlist_limits
+            .get(type_name)
+            .copied()
+            .unwrap_or(limit);
         Self {
-            max_requests: Req::MAX_REQUESTS,
-            time_window: Req::TIME_WINDOW,
+            type_name,
+            limit,
+            allowlist_limit,
+        }
+    }
+
+    /// Returns the rate limit that applies to a peer.
+    pub(crate) fn limit_for(&self, allowlisted: bool) -> &RequestRateLimit {
+        if allowlisted {
+            &self.allowlist_limit
+        } else {
+            &self.limit
         }
     }
 }
@@ -170,9 +200,23 @@ pub(crate) struct RateLimits {
     peer_request_limits: HashMap<PeerId, HashMap<RequestType, RateLimit>>,
     /// All the pending deletion rate limits.
     rate_limits_pending_deletion: PendingDeletion,
+    /// The peers for which the allowlist rate limits apply.
+    allowlisted_peers: HashSet<PeerId>,
 }
 
 impl RateLimits {
+    pub(crate) fn new(allowlisted_peers: HashSet<PeerId>) -> Self {
+        Self {
+            allowlisted_peers,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether the allowlist rate limits apply to the given peer.
+    pub(crate) fn is_allowlisted(&self, peer_id: &PeerId) -> bool {
+        self.allowlisted_peers.contains(peer_id)
+    }
+
     /// Increases the counter of the rate limit and returns a bool in case the defined rate limit is surpassed.
     pub(crate) fn exceeds_rate_limit(
         &mut self,
@@ -180,6 +224,8 @@ impl RateLimits {
         request_type: RequestType,
         request_rate_limit_data: &RequestRateLimitData,
     ) -> bool {
+        let limit = *request_rate_limit_data.limit_for(self.is_allowlisted(&peer_id));
+
         // If the peer has never sent a request of this type, creates a new entry.
         let requests_limit = self
             .peer_request_limits
@@ -187,11 +233,7 @@ impl RateLimits {
             .or_default()
             .entry(request_type)
             .or_insert_with(|| {
-                RateLimit::new(
-                    request_rate_limit_data.max_requests,
-                    request_rate_limit_data.time_window,
-                    Instant::now(),
-                )
+                RateLimit::new(limit.max_requests, limit.time_window, Instant::now())
             });
 
         // Ensures that the request is allowed based on the set limits and updates the counter.
@@ -266,3 +308,103 @@ impl RateLimits {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    };
+
+    use libp2p::PeerId;
+    use nimiq_network_interface::request::{RequestCommon, RequestMarker, RequestType};
+    use nimiq_serde::{Deserialize, Serialize};
+
+    use super::{RateLimits, RequestRateLimitData};
+    use crate::{RateLimitConfig, RequestRateLimit};
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct TestRequest {
+        request: u64,
+    }
+
+    impl RequestCommon for TestRequest {
+        type Kind = RequestMarker;
+        const TYPE_ID: u16 = 42;
+        type Response = u64;
+        const MAX_REQUESTS: u32 = 10;
+    }
+
+    fn limit(max_requests: u32) -> RequestRateLimit {
+        RequestRateLimit {
+            max_requests,
+            time_window: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn it_uses_the_limits_of_the_request_type_by_default() {
+        let data = RequestRateLimitData::new::<TestRequest>(&RateLimitConfig::default());
+
+        assert_eq!(data.type_name, "TestRequest");
+        assert_eq!(
+            data.limit,
+            RequestRateLimit {
+                max_requests: TestRequest::MAX_REQUESTS,
+                time_window: TestRequest::TIME_WINDOW,
+            }
+        );
+        assert_eq!(data.allowlist_limit, data.limit);
+    }
+
+    #[test]
+    fn it_applies_the_configured_limits() {
+        let config = RateLimitConfig {
+            limits: HashMap::from([("TestRequest".to_string(), limit(2))]),
+            allowlisted_peers: HashSet::new(),
+            allowlist_limits: HashMap::from([("TestRequest".to_string(), limit(4))]),
+        };
+        let data = RequestRateLimitData::new::<TestRequest>(&config);
+        assert_eq!(*data.limit_for(false), limit(2));
+        assert_eq!(*data.limit_for(true), limit(4));
+
+        // Without an allowlist entry, allowlisted peers get the regular limit.
+        let config = RateLimitConfig {
+            limits: HashMap::from([("TestRequest".to_string(), limit(2))]),
+            ..Default::default()
+        };
+        let data = RequestRateLimitData::new::<TestRequest>(&config);
+        assert_eq!(*data.limit_for(true), limit(2));
+
+        // Limits of other request types don't apply.
+        let config = RateLimitConfig {
+            limits: HashMap::from([("OtherRequest".to_string(), limit(2))]),
+            ..Default::default()
+        };
+        let data = RequestRateLimitData::new::<TestRequest>(&config);
+        assert_eq!(data.limit.max_requests, TestRequest::MAX_REQUESTS);
+    }
+
+    #[test]
+    fn allowlisted_peers_are_subject_to_the_allowlist_limits() {
+        let peer = PeerId::random();
+        let allowlisted_peer = PeerId::random();
+        let request_type = RequestType::from_request::<TestRequest>();
+        let data = RequestRateLimitData::new::<TestRequest>(&RateLimitConfig {
+            limits: HashMap::from([("TestRequest".to_string(), limit(2))]),
+            allowlisted_peers: HashSet::from([allowlisted_peer]),
+            allowlist_limits: HashMap::from([("TestRequest".to_string(), limit(4))]),
+        });
+        let mut rate_limits = RateLimits::new(HashSet::from([allowlisted_peer]));
+
+        for _ in 0..2 {
+            assert!(!rate_limits.exceeds_rate_limit(peer, request_type, &data));
+        }
+        assert!(rate_limits.exceeds_rate_limit(peer, request_type, &data));
+
+        for _ in 0..4 {
+            assert!(!rate_limits.exceeds_rate_limit(allowlisted_peer, request_type, &data));
+        }
+        assert!(rate_limits.exceeds_rate_limit(allowlisted_peer, request_type, &data));
+    }
+}
@@ -1,8 +1,15 @@
-use std::{collections::HashMap, num::NonZeroU8, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroU8,
+    sync::Arc,
+};
 
+#[cfg(feature = "quic")]
+use futures::future::Either;
 use futures::StreamExt;
-#[cfg(feature = "metrics")]
 use instant::Instant;
+#[cfg(feature = "quic")]
+use libp2p::quic;
 #[cfg(all(target_family = "wasm", not(feature = "tokio-websocket")))]
 use libp2p::websocket_websys;
 use libp2p::{
@@ -45,7 +52,8 @@ use crate::{
     behaviour,
     discovery::{behaviour::Event, peer_contacts::PeerContactBook},
     network_types::{
-        DhtBootStrapState, DhtRecord, DhtResults, NetworkAction, TaskState, ValidateMessage,
+        ConnectionInfo, DhtBootStrapState, DhtRecord, DhtResults, NetworkAction, TaskState,
+        ValidateMessage,
     },
     rate_limiting::RateLimits,
     Config, NetworkError, TlsConfig,
@@ -101,6 +109,7 @@ pub(crate) async fn swarm_task(
     contacts: Arc<RwLock<PeerContactBook>>,
     force_dht_server_mode: bool,
     dht_quorum: NonZeroU8,
+    allowlisted_peers: HashSet<PeerId>,
     #[cfg(feature = "metrics")] metrics: Arc<NetworkMetrics>,
 ) {
     let mut task_state = TaskState {
@@ -108,7 +117,7 @@ pub(crate) async fn swarm_task(
         dht_quorum: dht_quorum.into(),
         ..Default::default()
     };
-    let mut rate_limiting = RateLimits::default();
+    let mut rate_limiting = RateLimits::new(allowlisted_peers);
 
     let peer_id = Swarm::local_peer_id(&swarm);
     let task_span = trace_span!("swarm task", peer_id=?peer_id);
@@ -247,16 +256,37 @@ fn new_transport(
                 .timeout(std::time::Duration::from_secs(20))
                 .boxed())
         } else {
-            Ok(transport
+            let transport = transport
                 .upgrade(core::upgrade::Version::V1)
                 .authenticate(noise::Config::new(keypair).unwrap())
                 .multiplex(yamux)
                 .timeout(std::time::Duration::from_secs(20))
-                .boxed())
+                .boxed();
+
+            #[cfg(feature = "quic")]
+            let transport = with_quic_transport(keypair, transport);
+
+            Ok(transport)
         }
     }
 }
 
+/// Adds a QUIC transport to the given transport. QUIC is tried first for addresses it supports,
+/// it brings its own encryption and stream multiplexing.
+#[cfg(feature = "quic")]
+fn with_quic_transport(
+    keypair: &Keypair,
+    transport: Boxed<(PeerId, StreamMuxerBox)>,
+) -> Boxed<(PeerId, StreamMuxerBox)> {
+    quic::tokio::Transport::new(quic::Config::new(keypair))
+        .or_transport(transport)
+        .map(|output, _| match output {
+            Either::Left((peer_id, connection)) => (peer_id, StreamMuxerBox::new(connection)),
+            Either::Right(output) => output,
+        })
+        .boxed()
+}
+
 fn handle_event(
     event: SwarmEvent<behaviour::BehaviourEvent>,
     events_tx: &broadcast::Sender<NetworkEvent<PeerId>>,
@@ -297,6 +327,14 @@ fn handle_event(
                 }
             }
 
+            state
+                .connections
+                .entry(peer_id)
+                .or_insert_with(|| ConnectionInfo {
+                    outbound: endpoint.is_dialer(),
+                    established_at: Instant::now(),
+                });
+
             // Save dialed peer addresses
             if endpoint.is_dialer() {
                 let listen_addr = endpoint.get_remote_address();
@@ -342,11 +380,14 @@ fn handle_event(
             // Remove Peer
             if num_established == 0 {
                 connected_peers.write().remove(&peer_id);
+                state.connections.remove(&peer_id);
                 swarm.behaviour_mut().remove_peer(peer_id);
 
                 // Removes or marks to remove the respective rate limits.
                 // Also cleans up the expired rate limits pending to delete.
                 rate_limiting.remove_rate_limits(peer_id);
+                #[cfg(feature = "metrics")]
+                metrics.note_peer_left(&peer_id);
 
                 let _ = events_tx.send(NetworkEvent::PeerLeft(peer_id));
             }
@@ -725,14 +766,22 @@ fn handle_event(
                                             type_id,
                                             request_rate_limit_data,
                                         ) {
+                                            let limit = request_rate_limit_data
+                                                .limit_for(rate_limiting.is_allowlisted(&peer_id));
                                             debug!(
                                                 %type_id,
+                                                r#type = request_rate_limit_data.type_name,
                                                 %request_id,
                                                 %peer_id,
-                                                max_requests = %request_rate_limit_data.max_requests,
-                                                time_window = ?request_rate_limit_data.time_window,
+                                                max_requests = %limit.max_requests,
+                                                time_window = ?limit.time_window,
                                                 "Denied request - exceeded max requests rate",
                                             );
+                                            #[cfg(feature = "metrics")]
+                                            metrics.note_rate_limit_hit(
+                                                &peer_id,
+                                                request_rate_limit_data.type_name,
+                                            );
                                             let response: Result<(), InboundRequestError> =
                                                 Err(InboundRequestError::ExceedsRateLimit);
                                             if swarm
@@ -1122,6 +1171,25 @@ fn perform_action(action: NetworkAction, swarm: &mut NimiqSwarm, state: &mut Tas
         NetworkAction::DisconnectPeer { peer_id, reason } => {
             swarm.behaviour_mut().pool.close_connection(peer_id, reason)
         }
+        NetworkAction::ConnectionInfo { peer_id, output } => {
+            let connection_info = state.connections.get(&peer_id).map(|connection_info| {
+                let score = swarm.behaviour().gossipsub.peer_score(&peer_id);
+                (*connection_info, score)
+            });
+            output.send(connection_info).ok();
+        }
+        NetworkAction::Ban { target, expires_at } => {
+            swarm.behaviour_mut().pool.ban(target, expires_at);
+        }
+        NetworkAction::Unban { target, output } => {
+            output.send(swarm.behaviour_mut().pool.unban(target)).ok();
+        }
+        NetworkAction::GetBans { output } => {
+            output.send(swarm.behaviour_mut().pool.bans()).ok();
+        }
+        NetworkAction::SetProvidedServices { services } => {
+            swarm.behaviour_mut().discovery.set_own_services(services);
+        }
     }
 }
 
@@ -1,3 +1,4 @@
+use instant::SystemTime;
 use libp2p::{multiaddr::Protocol, Multiaddr};
 
 /// Returns true if an address is a secure websocket connection.
@@ -5,3 +6,16 @@ use libp2p::{multiaddr::Protocol, Multiaddr};
 pub fn is_address_ws_secure(address: &Multiaddr) -> bool {
     address.into_iter().any(|p| matches!(p, Protocol::Wss(_)))
 }
+
+/// Returns true if an address is a QUIC address.
+pub fn is_address_quic(address: &Multiaddr) -> bool {
+    address.into_iter().any(|p| matches!(p, Protocol::QuicV1))
+}
+
+/// Returns the current time in seconds since the unix epoch.
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
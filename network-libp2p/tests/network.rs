@@ -67,6 +67,8 @@ fn network_config(address: Multiaddr) -> Config {
         only_secure_ws_connections: false,
         allow_loopback_addresses: true,
         dht_quorum: NonZeroU8::new(1).unwrap(),
+        bans: vec![],
+        rate_limits: Default::default(),
     }
 }
 
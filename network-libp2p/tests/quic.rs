@@ -0,0 +1,150 @@
+use std::{net::UdpSocket, num::NonZeroU8, time::Duration};
+
+use libp2p::{
+    gossipsub,
+    identity::Keypair,
+    multiaddr::{Multiaddr, Protocol},
+};
+use nimiq_network_interface::{network::Network as NetworkInterface, peer_info::Services};
+use nimiq_network_libp2p::{
+    discovery::{
+        self,
+        peer_contacts::{PeerContact, PeerContactBook},
+    },
+    Config, Network,
+};
+use nimiq_test_log::test;
+use nimiq_time::timeout;
+
+mod helper;
+
+/// Returns a QUIC address on the loopback interface with a currently unused port.
+fn quic_loopback_address() -> Multiaddr {
+    let port = UdpSocket::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    format!("/ip4/127.0.0.1/udp/{port}/quic-v1")
+        .parse()
+        .unwrap()
+}
+
+fn peer_contact(keypair: &Keypair, addresses: Vec<Multiaddr>) -> PeerContact {
+    let mut peer_contact = PeerContact {
+        addresses,
+        public_key: keypair.public(),
+        services: Services::all(),
+        timestamp: None,
+    };
+    peer_contact.set_current_time();
+    peer_contact
+}
+
+fn network_config(address: Multiaddr) -> Config {
+    let keypair = Keypair::generate_ed25519();
+    let peer_contact = peer_contact(&keypair, vec![address]);
+
+    let gossipsub = gossipsub::ConfigBuilder::default()
+        .validation_mode(gossipsub::ValidationMode::Permissive)
+        .build()
+        .expect("Invalid Gossipsub config");
+
+    Config {
+        keypair,
+        peer_contact,
+        seeds: Vec::new(),
+        discovery: discovery::Config {
+            genesis_hash: Default::default(),
+            update_interval: Duration::from_secs(60),
+            min_recv_update_interval: Duration::from_secs(30),
+            update_limit: 64,
+            required_services: Services::all(),
+            min_send_update_interval: Duration::from_secs(30),
+            house_keeping_interval: Duration::from_secs(60),
+            keep_alive: false,
+            only_secure_ws_connections: false,
+        },
+        kademlia: Default::default(),
+        gossipsub,
+        memory_transport: false,
+        required_services: Services::all(),
+        tls: None,
+        desired_peer_count: 3,
+        autonat_allow_non_global_ips: true,
+        only_secure_ws_connections: false,
+        allow_loopback_addresses: true,
+        dht_quorum: NonZeroU8::new(1).unwrap(),
+        bans: vec![],
+        rate_limits: Default::default(),
+    }
+}
+
+#[test(tokio::test)]
+async fn connects_over_quic_on_loopback() {
+    let addr1 = quic_loopback_address();
+    let addr2 = quic_loopback_address();
+
+    let net1 = Network::new(network_config(addr1.clone())).await;
+    net1.listen_on(vec![addr1.clone()]).await;
+
+    let net2 = Network::new(network_config(addr2.clone())).await;
+    net2.listen_on(vec![addr2.clone()]).await;
+
+    let mut events1 = net1.subscribe_events();
+    let mut events2 = net2.subscribe_events();
+
+    log::debug!("Dialing peer 1 from peer 2 over QUIC...");
+    net2.dial_address(addr1.clone()).await.unwrap();
+
+    let event1 = timeout(
+        Duration::from_secs(10),
+        helper::get_next_peer_event(&mut events1),
+    )
+    .await
+    .expect("Peer 2 didn't join");
+    helper::assert_peer_joined(&event1, &net2.get_local_peer_id());
+
+    let event2 = timeout(
+        Duration::from_secs(10),
+        helper::get_next_peer_event(&mut events2),
+    )
+    .await
+    .expect("Peer 1 didn't join");
+    helper::assert_peer_joined(&event2, &net1.get_local_peer_id());
+
+    // The dialed peer is known by its QUIC address.
+    let peer_info = net2
+        .get_peer_info(net1.get_local_peer_id())
+        .expect("Peer 1 should be connected");
+    assert!(peer_info
+        .get_address()
+        .iter()
+        .any(|protocol| matches!(protocol, Protocol::QuicV1)));
+}
+
+#[test]
+fn prefers_quic_addresses() {
+    let own_keypair = Keypair::generate_ed25519();
+    let own_contact = peer_contact(&own_keypair, vec![quic_loopback_address()]);
+    let mut contact_book = PeerContactBook::new(own_contact.sign(&own_keypair), false, true, false);
+
+    let keypair = Keypair::generate_ed25519();
+    let ws_address: Multiaddr = "/ip4/127.0.0.1/tcp/8443/ws".parse().unwrap();
+    let quic_address: Multiaddr = "/ip4/127.0.0.1/udp/8443/quic-v1".parse().unwrap();
+    let invalid_quic_address: Multiaddr = "/ip4/127.0.0.1/tcp/8443/quic-v1".parse().unwrap();
+    let contact = peer_contact(
+        &keypair,
+        vec![
+            ws_address.clone(),
+            invalid_quic_address,
+            quic_address.clone(),
+        ],
+    );
+    contact_book.insert(contact.sign(&keypair));
+
+    assert_eq!(
+        contact_book.get_addresses(&keypair.public().to_peer_id()),
+        Some(vec![quic_address, ws_address])
+    );
+}
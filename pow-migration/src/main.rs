@@ -180,7 +180,7 @@ async fn main() {
                 .map(|address| {
                     pre_stakers
                         .into_iter()
-                        .filter(|pre_staker| pre_staker.delegation == address)
+                        .filter(|pre_staker| pre_staker.delegation.as_ref() == Some(&address))
                         .collect()
                 })
                 .unwrap_or_else(|error| {
@@ -196,10 +196,12 @@ async fn main() {
 
         println!("Pre-stakers:");
         for pre_staker in pre_stakers {
-            println!(
-                "{} has delegated stake to {} with a prestake of {} NIM",
-                pre_staker.staker_address, pre_staker.delegation, pre_staker.balance,
-            );
+            if let Some(delegation) = pre_staker.delegation {
+                println!(
+                    "{} has delegated stake to {} with a prestake of {} NIM",
+                    pre_staker.staker_address, delegation, pre_staker.balance,
+                );
+            }
         }
     } else {
         let validator_address = if let Some(validator_settings) = config.validator {
@@ -276,6 +276,7 @@ pub async fn get_validators(
                         inactive_from: None,
                         jailed_from: None,
                         retired: false,
+                        commission: Policy::MAX_COMMISSION,
                     },
                 };
                 log::debug!(%address, "Found possible validator");
@@ -356,9 +357,10 @@ pub async fn get_stakers(
                     staker_address,
                     balance: registered_validator.total_stake
                         - Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT),
-                    delegation: registered_validator.validator.validator_address.clone(),
+                    delegation: Some(registered_validator.validator.validator_address.clone()),
                     inactive_balance: Coin::ZERO,
                     inactive_from: None,
+                    retired_balance: Coin::ZERO,
                 },
             );
         };
@@ -408,7 +410,7 @@ pub async fn get_stakers(
 
                                             // Update the staker entry
                                             staker.delegation =
-                                                validator.validator.validator_address.clone();
+                                                Some(validator.validator.validator_address.clone());
                                             staker.balance += stake;
                                         }
                                         Entry::Vacant(entry) => {
@@ -419,12 +421,15 @@ pub async fn get_stakers(
                                                 entry.insert(GenesisStaker {
                                                     staker_address,
                                                     balance: stake,
-                                                    delegation: validator
-                                                        .validator
-                                                        .validator_address
-                                                        .clone(),
+                                                    delegation: Some(
+                                                        validator
+                                                            .validator
+                                                            .validator_address
+                                                            .clone(),
+                                                    ),
                                                     inactive_balance: Coin::ZERO,
                                                     inactive_from: None,
+                                                    retired_balance: Coin::ZERO,
                                                 });
                                             }
                                         }
@@ -453,7 +458,9 @@ pub async fn get_stakers(
         let stakers: Vec<&GenesisStaker> = stakers
             .iter()
             .filter_map(|staker| {
-                if staker.1.delegation == genesis_validator.validator.validator_address {
+                if staker.1.delegation.as_ref()
+                    == Some(&genesis_validator.validator.validator_address)
+                {
                     return Some(staker.1);
                 }
                 None
@@ -35,6 +35,15 @@ pub struct HashedTimeLockedContract {
     pub total_amount: Coin,
 }
 
+impl HashedTimeLockedContract {
+    /// Returns true if the contract has expired at the given time. Before that, the recipient can
+    /// claim the funds with a pre-image. Afterwards, only the sender can resolve the contract.
+    /// Both parties together can resolve it at any time.
+    pub fn is_expired(&self, time: u64) -> bool {
+        self.timeout < time
+    }
+}
+
 #[cfg(feature = "interaction-traits")]
 impl HashedTimeLockedContract {
     fn can_change_balance(
@@ -52,7 +61,7 @@ impl HashedTimeLockedContract {
                 signature_proof,
             } => {
                 // Check that the contract has not expired yet.
-                if self.timeout < block_state.time {
+                if self.is_expired(block_state.time) {
                     warn!("HTLC has expired: {} < {}", self.timeout, block_state.time);
                     return Err(AccountError::InvalidForSender);
                 }
@@ -109,7 +118,7 @@ impl HashedTimeLockedContract {
                 signature_proof_sender,
             } => {
                 // Check that the contract has expired.
-                if self.timeout >= block_state.time {
+                if !self.is_expired(block_state.time) {
                     warn!(
                         "HTLC has not yet expired: {} >= {}",
                         self.timeout, block_state.time
@@ -125,6 +125,17 @@ impl StakingContract {
             .collect()
     }
 
+    /// Get a list containing all stakers
+    /// IMPORTANT: This is a very expensive operation, iterating over all existing stakers in the contract.
+    pub fn get_stakers<T: DataStoreReadOps + DataStoreIterOps>(
+        &self,
+        data_store: &T,
+    ) -> Vec<Staker> {
+        StakingContractStoreRead::new(data_store)
+            .iter_stakers()
+            .collect()
+    }
+
     /// Given a seed, it randomly distributes the validator slots across all validators. It is
     /// used to select the validators for the next epoch.
     pub fn select_validators<T: DataStoreReadOps>(
@@ -4,11 +4,55 @@ use nimiq_bls::CompressedPublicKey as BlsPublicKey;
 use nimiq_collections::BitSet;
 use nimiq_hash::Blake2bHash;
 use nimiq_keys::{Address, Ed25519PublicKey as SchnorrPublicKey};
-use nimiq_primitives::{account::AccountError, coin::Coin};
+use nimiq_primitives::{account::AccountError, coin::Coin, policy::Policy};
 use nimiq_serde::{Deserialize, Serialize};
 
 use crate::{convert_receipt, AccountReceipt};
 
+/// Like `convert_receipt!`, for receipts that were extended by a validator commission. The
+/// commission is appended to the serialization from before the commission fork, but only if it
+/// differs from the default of [`Policy::MAX_COMMISSION`]. This way, receipts of blocks from before
+/// the fork can still be reverted.
+macro_rules! convert_receipt_with_commission {
+    ($t: ty, $commission: ident) => {
+        impl TryFrom<AccountReceipt> for $t {
+            type Error = AccountError;
+
+            fn try_from(value: AccountReceipt) -> Result<Self, Self::Error> {
+                <$t>::try_from(&value)
+            }
+        }
+
+        impl TryFrom<&AccountReceipt> for $t {
+            type Error = AccountError;
+
+            fn try_from(value: &AccountReceipt) -> Result<Self, Self::Error> {
+                let (mut receipt, commission) = <$t>::deserialize_take(&value.0[..])
+                    .map_err(|e| AccountError::InvalidSerialization(e))?;
+                if !commission.is_empty() {
+                    receipt.$commission = u16::deserialize_all(commission)
+                        .map_err(|e| AccountError::InvalidSerialization(e))?;
+                }
+                Ok(receipt)
+            }
+        }
+
+        impl From<$t> for AccountReceipt {
+            fn from(value: $t) -> Self {
+                let mut receipt = value.serialize_to_vec();
+                if value.$commission != Policy::MAX_COMMISSION {
+                    receipt.extend(value.$commission.serialize_to_vec());
+                }
+                AccountReceipt::from(receipt)
+            }
+        }
+    };
+}
+
+fn default_commission() -> u16 {
+    Policy::MAX_COMMISSION
+}
+
 /// Penalize receipt for the inherent. This is necessary to be able to revert
 /// these inherents.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -39,6 +83,15 @@ pub struct JailReceipt {
 }
 convert_receipt!(JailReceipt);
 
+/// Receipt for the reward inherent of a validator's stakers. This is necessary to be able to
+/// revert these inherents.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct StakerRewardReceipt {
+    /// the reward that was added to the active balance of each staker
+    pub staker_rewards: Vec<(Address, Coin)>,
+}
+convert_receipt!(StakerRewardReceipt);
+
 /// Receipt for update validator transactions. This is necessary to be able to revert
 /// these transactions.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -51,8 +104,11 @@ pub struct UpdateValidatorReceipt {
     pub old_reward_address: Address,
     // the signal data before this transaction is applied
     pub old_signal_data: Option<Blake2bHash>,
+    /// the commission before this transaction is applied
+    #[serde(skip, default = "default_commission")]
+    pub old_commission: u16,
 }
-convert_receipt!(UpdateValidatorReceipt);
+convert_receipt_with_commission!(UpdateValidatorReceipt, old_commission);
 
 /// Receipt for jailing a validator. This is necessary to be able to revert
 /// a jail.
@@ -108,8 +164,11 @@ pub struct DeleteValidatorReceipt {
     pub inactive_from: u32,
     /// the jail release before this transaction is applied
     pub jailed_from: Option<u32>,
+    /// the commission before this transaction is applied
+    #[serde(skip, default = "default_commission")]
+    pub commission: u16,
 }
-convert_receipt!(DeleteValidatorReceipt);
+convert_receipt_with_commission!(DeleteValidatorReceipt, commission);
 
 /// Receipt for most staker-related transactions. This is necessary to be able to revert
 /// these transactions.
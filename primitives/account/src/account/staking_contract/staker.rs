@@ -1,5 +1,8 @@
 #[cfg(feature = "interaction-traits")]
-use std::cmp::Ordering;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+};
 
 use nimiq_keys::Address;
 #[cfg(feature = "interaction-traits")]
@@ -15,9 +18,10 @@ use crate::{
         store::{
             StakingContractStoreReadOps, StakingContractStoreReadOpsExt, StakingContractStoreWrite,
         },
-        StakerReceipt, StakingContract, Tombstone,
+        StakerReceipt, StakerRewardReceipt, StakingContract, Tombstone, Validator,
     },
-    DeleteStakerReceipt, Log, RetireStakeReceipt, SetActiveStakeReceipt, TransactionLog,
+    DeleteStakerReceipt, InherentLogger, Log, RetireStakeReceipt, SetActiveStakeReceipt,
+    TransactionLog,
 };
 
 /// Struct representing a staker in the staking contract.
@@ -110,6 +114,64 @@ impl Staker {
         Ok(())
     }
 
+    /// Returns the block number from which on the inactive balance is released, i.e. the maximum
+    /// of the end of the lock-up period and the end of the jail of the validator the stake is
+    /// delegated to. Returns `None` if the inactive balance is not locked at all, which is always
+    /// the case if the stake is not delegated.
+    pub fn inactive_release_block(&self, validator: Option<&Validator>) -> Option<u32> {
+        // We only need to check the release of inactive funds if there is a delegation.
+        // This is because the purpose of the locked period is to ensure that we wait for the reporting window
+        // to finish and thus that the validator will not be jailed due to misbehavior.
+        if self.delegation.is_none() {
+            return None;
+        }
+
+        let inactive_release = self.inactive_from.map(Policy::block_after_reporting_window);
+        let jail_release = validator
+            .and_then(|validator| validator.jailed_from)
+            .map(Policy::block_after_jail);
+
+        inactive_release.max(jail_release)
+    }
+
+    /// Returns true if the delegation can be updated at the given block number. This requires the
+    /// stake to be undelegated, or all of it to be inactive and released.
+    pub fn can_update_delegation(&self, validator: Option<&Validator>, block_number: u32) -> bool {
+        self.delegation.is_none()
+            || (self.active_balance.is_zero()
+                && self.is_inactive_balance_released(validator, block_number))
+    }
+
+    /// Returns the maximum amount of inactive balance that can be retired at the given block
+    /// number without violating the minimum stake invariants.
+    pub fn retirable_balance(&self, validator: Option<&Validator>, block_number: u32) -> Coin {
+        if !self.is_inactive_balance_released(validator, block_number) {
+            return Coin::ZERO;
+        }
+
+        // Retiring all inactive balance is only possible if the remaining active balance respects
+        // the minimum stake on its own.
+        let min_stake = Coin::from_u64_unchecked(Policy::MINIMUM_STAKE);
+        if self.active_balance.is_zero() || self.active_balance >= min_stake {
+            self.inactive_balance
+        } else {
+            self.non_retired_balance().saturating_sub(min_stake)
+        }
+    }
+
+    /// Returns true if the inactive balance is released at the given block number, see
+    /// [`inactive_release_block`](Self::inactive_release_block).
+    pub fn is_inactive_balance_released(
+        &self,
+        validator: Option<&Validator>,
+        block_number: u32,
+    ) -> bool {
+        !matches!(
+            self.inactive_release_block(validator),
+            Some(release_block) if block_number < release_block
+        )
+    }
+
     /*  Helpers for checking release block heights and invariants */
 
     /// Returns true if the inactive funds are released both from the lockup period and any potential jail
@@ -119,25 +181,12 @@ impl Staker {
         store: &StakingContractStoreWrite,
         block_number: u32,
     ) -> bool {
-        // If stake is currently delegated we check if funds are released.
-        // We only need to check the release of inactive funds if there is a delegation.
-        // This is because the purpose of the locked period is to ensure that we wait for the reporting window
-        // to finish and thus that the validator will not be jailed due to misbehavior.
-        if let Some(validator_address) = &self.delegation {
-            // Funds are not released if release block height has not passed yet.
-            if let Some(inactive_from) = self.inactive_from {
-                if block_number < Policy::block_after_reporting_window(inactive_from) {
-                    return false;
-                }
-            }
+        let validator = self
+            .delegation
+            .as_ref()
+            .and_then(|validator_address| store.get_validator(validator_address));
 
-            // Funds are only released if the validator is not jailed.
-            if let Some(validator) = store.get_validator(validator_address) {
-                return !validator.is_jailed(block_number);
-            }
-        }
-
-        true
+        self.is_inactive_balance_released(validator.as_ref(), block_number)
     }
 
     /// Checks if the minimum stake invariants are respected.
@@ -920,6 +969,173 @@ impl StakingContract {
         panic!("inconsistent contract state");
     }
 
+    /// Distributes the share of a validator's reward that is not kept as commission to the
+    /// stakers delegating to it, see [`StakingContract::distribute_staker_rewards`].
+    /// IMPORTANT: This is a very expensive operation, iterating over all existing stakers in the contract.
+    pub fn distribute_staker_reward(
+        &mut self,
+        store: &mut StakingContractStoreWrite,
+        validator_address: &Address,
+        value: Coin,
+        inherent_logger: &mut InherentLogger,
+    ) -> Result<StakerRewardReceipt, AccountError> {
+        self.distribute_staker_rewards(
+            store,
+            &[(validator_address.clone(), value)],
+            inherent_logger,
+        )
+        .pop()
+        .expect("There is a result for every reward")
+    }
+
+    /// Distributes the shares of the validators' rewards that are not kept as commission to the
+    /// stakers delegating to them, proportionally to their active balances. The rewards are added
+    /// to the stakers' active balances. The remainder that can't be split evenly goes to the
+    /// staker with the highest active balance.
+    /// The stakers of all validators are collected in a single pass, so the rewards of all
+    /// validators of a batch should be distributed at once. Returns a result for each reward.
+    /// IMPORTANT: This is a very expensive operation, iterating over all existing stakers in the contract.
+    pub fn distribute_staker_rewards(
+        &mut self,
+        store: &mut StakingContractStoreWrite,
+        rewards: &[(Address, Coin)],
+        inherent_logger: &mut InherentLogger,
+    ) -> Vec<Result<StakerRewardReceipt, AccountError>> {
+        // Group the stakers with active stake by the rewarded validator they delegate to.
+        let mut delegators: HashMap<Address, Vec<Staker>> = rewards
+            .iter()
+            .map(|(validator_address, _)| (validator_address.clone(), vec![]))
+            .collect();
+        for staker in store.iter_stakers() {
+            if staker.active_balance.is_zero() {
+                continue;
+            }
+            if let Some(stakers) = staker
+                .delegation
+                .as_ref()
+                .and_then(|delegation| delegators.get_mut(delegation))
+            {
+                stakers.push(staker);
+            }
+        }
+
+        rewards
+            .iter()
+            .map(|(validator_address, value)| {
+                let stakers = delegators
+                    .get_mut(validator_address)
+                    .expect("Stakers were collected for every rewarded validator");
+                self.distribute_reward_to_stakers(
+                    store,
+                    validator_address,
+                    *value,
+                    stakers,
+                    inherent_logger,
+                )
+            })
+            .collect()
+    }
+
+    /// Distributes a validator's reward to the given stakers delegating to it and updates them.
+    fn distribute_reward_to_stakers(
+        &mut self,
+        store: &mut StakingContractStoreWrite,
+        validator_address: &Address,
+        value: Coin,
+        stakers: &mut [Staker],
+        inherent_logger: &mut InherentLogger,
+    ) -> Result<StakerRewardReceipt, AccountError> {
+        // Check that the validator exists.
+        store.expect_validator(validator_address)?;
+
+        let total_active_balance = stakers
+            .iter()
+            .fold(Coin::ZERO, |sum, staker| sum + staker.active_balance);
+        if total_active_balance.is_zero() {
+            debug!(
+                %validator_address,
+                "Tried to distribute a reward to a validator without active stakers"
+            );
+            return Err(AccountError::InvalidForTarget);
+        }
+
+        // All checks passed, not allowed to fail from here on!
+
+        // Split the reward proportionally to the active balances.
+        let mut staker_rewards: Vec<(Address, Coin)> = stakers
+            .iter()
+            .map(|staker| {
+                let reward = u128::from(u64::from(value))
+                    * u128::from(u64::from(staker.active_balance))
+                    / u128::from(u64::from(total_active_balance));
+                (
+                    staker.address.clone(),
+                    Coin::from_u64_unchecked(reward as u64),
+                )
+            })
+            .collect();
+
+        let distributed = staker_rewards
+            .iter()
+            .fold(Coin::ZERO, |sum, (_, reward)| sum + *reward);
+        let (index, _) = stakers
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, staker)| Reverse(staker.active_balance))
+            .expect("There is at least one staker");
+        staker_rewards[index].1 += value - distributed;
+
+        for (staker, (staker_address, reward)) in stakers.iter_mut().zip(&staker_rewards) {
+            if reward.is_zero() {
+                continue;
+            }
+
+            staker.active_balance += *reward;
+            store.put_staker(staker_address, staker.clone());
+
+            inherent_logger.push_log(Log::PayoutStakerReward {
+                staker_address: staker_address.clone(),
+                validator_address: validator_address.clone(),
+                value: *reward,
+            });
+        }
+        staker_rewards.retain(|(_, reward)| !reward.is_zero());
+
+        // Update the validator's and staking contract's balances.
+        self.increase_stake_to_validator(store, validator_address, value);
+        self.balance += value;
+
+        Ok(StakerRewardReceipt { staker_rewards })
+    }
+
+    /// Reverts distributing a validator's reward to its stakers.
+    pub fn revert_distribute_staker_reward(
+        &mut self,
+        store: &mut StakingContractStoreWrite,
+        validator_address: &Address,
+        value: Coin,
+        receipt: StakerRewardReceipt,
+        inherent_logger: &mut InherentLogger,
+    ) -> Result<(), AccountError> {
+        for (staker_address, reward) in receipt.staker_rewards {
+            let mut staker = store.expect_staker(&staker_address)?;
+            staker.active_balance -= reward;
+            store.put_staker(&staker_address, staker);
+
+            inherent_logger.push_log(Log::PayoutStakerReward {
+                staker_address,
+                validator_address: validator_address.clone(),
+                value: reward,
+            });
+        }
+
+        // Update the validator's and staking contract's balances.
+        self.decrease_stake_from_validator(store, validator_address, value);
+        self.balance -= value;
+
+        Ok(())
+    }
+
     /// Wrapper for decreasing or increasing the validator's balance.
     fn update_stake_for_validator(
         &mut self,
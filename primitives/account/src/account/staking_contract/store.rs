@@ -1,7 +1,7 @@
 use nimiq_keys::Address;
 #[cfg(feature = "interaction-traits")]
 use nimiq_primitives::account::AccountError;
-use nimiq_primitives::key_nibbles::KeyNibbles;
+use nimiq_primitives::{key_nibbles::KeyNibbles, policy::Policy};
 
 #[cfg(feature = "interaction-traits")]
 use crate::data_store::DataStoreWrite;
@@ -17,6 +17,7 @@ impl StakingContractStore {
     const PREFIX_VALIDATOR: u8 = 0;
     const PREFIX_STAKER: u8 = 1;
     const PREFIX_TOMBSTONE: u8 = 2;
+    const PREFIX_COMMISSION: u8 = 3;
 
     pub fn validator_key(address: &Address) -> KeyNibbles {
         Self::prefixed_address(Self::PREFIX_VALIDATOR, address)
@@ -30,6 +31,13 @@ impl StakingContractStore {
         Self::prefixed_address(Self::PREFIX_TOMBSTONE, address)
     }
 
+    /// The commission of a validator is stored separately from the validator, so that the
+    /// serialization of validators didn't change with the commission fork. It is only stored if
+    /// it differs from the default of [`Policy::MAX_COMMISSION`].
+    pub fn commission_key(address: &Address) -> KeyNibbles {
+        Self::prefixed_address(Self::PREFIX_COMMISSION, address)
+    }
+
     fn prefixed_address(prefix: u8, address: &Address) -> KeyNibbles {
         let mut key = [0u8; 21];
         key[0] = prefix;
@@ -58,7 +66,8 @@ impl<'read, T: DataStoreReadOps> StakingContractStoreReadOps
     for StakingContractStoreRead<'read, T>
 {
     fn get_validator(&self, address: &Address) -> Option<Validator> {
-        self.0.get(&StakingContractStore::validator_key(address))
+        let validator = self.0.get(&StakingContractStore::validator_key(address))?;
+        Some(with_commission(self.0, validator))
     }
 
     fn get_staker(&self, address: &Address) -> Option<Staker> {
@@ -78,14 +87,25 @@ impl<'read, T: DataStoreReadOps + DataStoreIterOps> StakingContractStoreRead<'re
         )
     }
 
-    pub(crate) fn iter_validators(&self) -> impl Iterator<Item = Validator> {
-        self.0.iter(
-            &StakingContractStore::validator_key(&Address::START_ADDRESS),
-            &StakingContractStore::validator_key(&Address::END_ADDRESS),
-        )
+    pub(crate) fn iter_validators(&self) -> impl Iterator<Item = Validator> + '_ {
+        let data_store = self.0;
+        data_store
+            .iter(
+                &StakingContractStore::validator_key(&Address::START_ADDRESS),
+                &StakingContractStore::validator_key(&Address::END_ADDRESS),
+            )
+            .map(move |validator| with_commission(data_store, validator))
     }
 }
 
+/// Sets the commission of a validator read from the store.
+fn with_commission<T: DataStoreReadOps>(data_store: &T, mut validator: Validator) -> Validator {
+    validator.commission = data_store
+        .get(&StakingContractStore::commission_key(&validator.address))
+        .unwrap_or(Policy::MAX_COMMISSION);
+    validator
+}
+
 #[cfg(feature = "interaction-traits")]
 pub struct StakingContractStoreWrite<'write, 'store, 'tree, 'txn, 'txni, 'env>(
     &'write mut DataStoreWrite<'store, 'tree, 'txn, 'txni, 'env>,
@@ -100,11 +120,23 @@ impl<'write, 'store, 'tree, 'txn, 'txni, 'env>
     }
 
     pub fn put_validator(&mut self, address: &Address, validator: Validator) {
+        let commission_key = StakingContractStore::commission_key(address);
+        if validator.commission != Policy::MAX_COMMISSION {
+            self.0.put(&commission_key, validator.commission);
+        } else if self.0.get::<u16>(&commission_key).is_some() {
+            self.0.remove(&commission_key);
+        }
+
         self.0
             .put(&StakingContractStore::validator_key(address), validator)
     }
 
     pub fn remove_validator(&mut self, address: &Address) {
+        let commission_key = StakingContractStore::commission_key(address);
+        if self.0.get::<u16>(&commission_key).is_some() {
+            self.0.remove(&commission_key);
+        }
+
         self.0.remove(&StakingContractStore::validator_key(address))
     }
 
@@ -125,6 +157,13 @@ impl<'write, 'store, 'tree, 'txn, 'txni, 'env>
     pub fn remove_tombstone(&mut self, address: &Address) {
         self.0.remove(&StakingContractStore::tombstone_key(address))
     }
+
+    pub fn iter_stakers(&self) -> impl Iterator<Item = Staker> + '_ {
+        self.0.iter(
+            &StakingContractStore::staker_key(&Address::START_ADDRESS),
+            &StakingContractStore::staker_key(&Address::END_ADDRESS),
+        )
+    }
 }
 
 #[cfg(feature = "interaction-traits")]
@@ -132,7 +171,12 @@ impl<'write, 'store, 'tree, 'txn, 'txni, 'env> StakingContractStoreReadOps
     for StakingContractStoreWrite<'write, 'store, 'tree, 'txn, 'txni, 'env>
 {
     fn get_validator(&self, address: &Address) -> Option<Validator> {
-        self.0.get(&StakingContractStore::validator_key(address))
+        let mut validator: Validator = self.0.get(&StakingContractStore::validator_key(address))?;
+        validator.commission = self
+            .0
+            .get(&StakingContractStore::commission_key(address))
+            .unwrap_or(Policy::MAX_COMMISSION);
+        Some(validator)
     }
 
     fn get_staker(&self, address: &Address) -> Option<Staker> {
@@ -2,6 +2,7 @@ use nimiq_keys::Address;
 use nimiq_primitives::{
     account::{AccountError, AccountType},
     coin::Coin,
+    policy::Policy,
 };
 use nimiq_serde::Deserialize;
 use nimiq_transaction::{
@@ -65,6 +66,7 @@ impl AccountTransactionInteraction for StakingContract {
                 voting_key,
                 reward_address,
                 signal_data,
+                commission,
                 proof,
                 ..
             } => {
@@ -78,6 +80,7 @@ impl AccountTransactionInteraction for StakingContract {
                     voting_key,
                     reward_address,
                     signal_data,
+                    commission,
                     transaction.value,
                     None,
                     None,
@@ -91,6 +94,7 @@ impl AccountTransactionInteraction for StakingContract {
                 new_voting_key,
                 new_reward_address,
                 new_signal_data,
+                new_commission,
                 proof,
                 ..
             } => {
@@ -104,6 +108,7 @@ impl AccountTransactionInteraction for StakingContract {
                     new_voting_key,
                     new_reward_address,
                     new_signal_data,
+                    new_commission,
                     tx_logger,
                 )
                 .map(|receipt| Some(receipt.into()))
@@ -804,7 +809,26 @@ impl AccountInherentInteraction for StakingContract {
                 // Since finalized epochs cannot be reverted, we don't need any receipts.
                 Ok(None)
             }
-            Inherent::Reward { .. } => Err(AccountError::InvalidForTarget),
+            Inherent::Reward {
+                validator_address,
+                value,
+                ..
+            } => {
+                // Before the commission fork, validators received all of their rewards.
+                if block_state.number < Policy::commission_fork_block_number() {
+                    return Err(AccountError::InvalidForTarget);
+                }
+
+                // The part of the validator's reward that goes to its stakers.
+                let receipt = self.distribute_staker_reward(
+                    &mut StakingContractStoreWrite::new(&mut data_store),
+                    validator_address,
+                    *value,
+                    inherent_logger,
+                )?;
+
+                Ok(Some(receipt.into()))
+            }
         }
     }
 
@@ -882,7 +906,25 @@ impl AccountInherentInteraction for StakingContract {
                 // We should not be able to revert finalized epochs or batches!
                 Err(AccountError::InvalidForTarget)
             }
-            Inherent::Reward { .. } => Err(AccountError::InvalidForTarget),
+            Inherent::Reward {
+                validator_address,
+                value,
+                ..
+            } => {
+                if block_state.number < Policy::commission_fork_block_number() {
+                    return Err(AccountError::InvalidForTarget);
+                }
+
+                let receipt = receipt.ok_or(AccountError::InvalidReceipt)?.try_into()?;
+
+                self.revert_distribute_staker_reward(
+                    &mut StakingContractStoreWrite::new(&mut data_store),
+                    validator_address,
+                    *value,
+                    receipt,
+                    inherent_logger,
+                )
+            }
         }
     }
 }
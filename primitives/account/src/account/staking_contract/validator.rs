@@ -1,9 +1,9 @@
 use nimiq_bls::CompressedPublicKey as BlsPublicKey;
 use nimiq_hash::Blake2bHash;
 use nimiq_keys::{Address, Ed25519PublicKey as SchnorrPublicKey};
-use nimiq_primitives::coin::Coin;
 #[cfg(feature = "interaction-traits")]
-use nimiq_primitives::{account::AccountError, policy::Policy};
+use nimiq_primitives::account::AccountError;
+use nimiq_primitives::{coin::Coin, policy::Policy};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "interaction-traits")]
@@ -60,11 +60,19 @@ pub struct Validator {
     pub signing_key: SchnorrPublicKey,
     /// The voting public key, it is used to vote for skip and macro blocks.
     pub voting_key: BlsPublicKey,
-    /// The reward address of the validator. All the block rewards are paid to this address.
+    /// The reward address of the validator. The validator's commission of the block rewards is paid
+    /// to this address.
     pub reward_address: Address,
     /// Signaling field. Can be used to do chain upgrades or for any other purpose that requires
     /// validators to coordinate among themselves.
     pub signal_data: Option<Blake2bHash>,
+    /// The share of the rewards the validator keeps for itself, in basis points. The remainder
+    /// of the rewards is distributed to the stakers delegating to this validator, proportionally
+    /// to their active balances. See [`Policy::MAX_COMMISSION`].
+    /// The commission is not part of the serialized validator, which stayed the same as before the
+    /// commission fork. The staking contract stores it separately, unless it is the default.
+    #[serde(skip, default = "default_commission")]
+    pub commission: u16,
     /// The total stake assigned to this validator. It includes the validator deposit as well as the
     /// coins delegated to him by stakers.
     pub total_stake: Coin,
@@ -86,6 +94,11 @@ pub struct Validator {
     /// A flag indicating if the validator is retired.
     pub retired: bool,
 }
+
+fn default_commission() -> u16 {
+    Policy::MAX_COMMISSION
+}
+
 #[cfg(feature = "interaction-traits")]
 impl Validator {
     pub fn is_active(&self) -> bool {
@@ -176,6 +189,7 @@ impl StakingContract {
         voting_key: BlsPublicKey,
         reward_address: Address,
         signal_data: Option<Blake2bHash>,
+        commission: u16,
         deposit: Coin,
         inactive_from: Option<u32>,
         jailed_from: Option<u32>,
@@ -203,6 +217,7 @@ impl StakingContract {
             voting_key,
             reward_address,
             signal_data,
+            commission,
             total_stake: deposit,
             deposit,
             num_stakers: 0,
@@ -260,7 +275,8 @@ impl StakingContract {
         Ok(())
     }
 
-    /// Updates some of the validator details (signing key, voting key, reward address and/or signal data).
+    /// Updates some of the validator details (signing key, voting key, reward address, signal data
+    /// and/or commission).
     pub fn update_validator(
         &mut self,
         store: &mut StakingContractStoreWrite,
@@ -269,6 +285,7 @@ impl StakingContract {
         new_voting_key: Option<BlsPublicKey>,
         new_reward_address: Option<Address>,
         new_signal_data: Option<Option<Blake2bHash>>,
+        new_commission: Option<u16>,
         tx_logger: &mut TransactionLog,
     ) -> Result<UpdateValidatorReceipt, AccountError> {
         // Get the validator.
@@ -280,6 +297,7 @@ impl StakingContract {
             old_voting_key: validator.voting_key.clone(),
             old_reward_address: validator.reward_address.clone(),
             old_signal_data: validator.signal_data.clone(),
+            old_commission: validator.commission,
         };
 
         // Update validator info.
@@ -299,6 +317,10 @@ impl StakingContract {
             validator.signal_data = value;
         }
 
+        if let Some(value) = new_commission {
+            validator.commission = value;
+        }
+
         // All checks passed, not allowed to fail from here on!
 
         tx_logger.push_log(Log::UpdateValidator {
@@ -335,6 +357,7 @@ impl StakingContract {
         validator.voting_key = receipt.old_voting_key;
         validator.reward_address = receipt.old_reward_address;
         validator.signal_data = receipt.old_signal_data;
+        validator.commission = receipt.old_commission;
 
         // Update the validator entry.
         store.put_validator(validator_address, validator);
@@ -750,6 +773,7 @@ impl StakingContract {
             voting_key: validator.voting_key,
             reward_address: validator.reward_address,
             signal_data: validator.signal_data,
+            commission: validator.commission,
             inactive_from: validator.inactive_from.unwrap(), // we checked above that this is Some
             jailed_from: validator.jailed_from,
         })
@@ -774,6 +798,7 @@ impl StakingContract {
             voting_key: receipt.voting_key,
             reward_address: receipt.reward_address,
             signal_data: receipt.signal_data,
+            commission: receipt.commission,
             total_stake: transaction_total_value,
             deposit: transaction_total_value,
             num_stakers: 0,
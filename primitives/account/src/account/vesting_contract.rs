@@ -38,6 +38,55 @@ pub struct VestingContract {
     pub total_amount: Coin,
 }
 
+impl VestingContract {
+    /// Returns the amount of coins that are still locked in the contract at the given time.
+    pub fn min_cap(&self, time: u64) -> Coin {
+        if self.time_step > 0 && self.step_amount > Coin::ZERO {
+            let steps = (time as i128 - self.start_time as i128) / self.time_step as i128;
+            let min_cap =
+                u64::from(self.total_amount) as i128 - steps * u64::from(self.step_amount) as i128;
+            // Since all parameters have been validated, this will be safe as well.
+            Coin::from_u64_unchecked(min_cap.max(0) as u64)
+        } else {
+            Coin::ZERO
+        }
+    }
+
+    /// Returns the amount of coins that were released by the vesting schedule up to the given
+    /// time, including coins that were already withdrawn.
+    pub fn vested_amount(&self, time: u64) -> Coin {
+        self.total_amount.saturating_sub(self.min_cap(time))
+    }
+
+    /// Returns the amount of coins that can be withdrawn from the contract at the given time.
+    pub fn available_balance(&self, time: u64) -> Coin {
+        self.balance.saturating_sub(self.min_cap(time))
+    }
+
+    /// Returns the time of the next release after the given time, or `None` if the whole amount
+    /// is already released.
+    pub fn next_release(&self, time: u64) -> Option<u64> {
+        if self.min_cap(time).is_zero() {
+            return None;
+        }
+
+        // A non-zero min cap implies a non-zero time step.
+        let steps = time.saturating_sub(self.start_time) / self.time_step;
+        Some(
+            self.start_time
+                .saturating_add((steps + 1).saturating_mul(self.time_step)),
+        )
+    }
+
+    /// Returns the amount of coins released at the [next release](Self::next_release) after the
+    /// given time, or `None` if the whole amount is already released.
+    pub fn next_release_amount(&self, time: u64) -> Option<Coin> {
+        let next_release = self.next_release(time)?;
+        // Before the start time, the min cap exceeds the total amount.
+        Some(self.min_cap(time).min(self.total_amount) - self.min_cap(next_release))
+    }
+}
+
 #[cfg(feature = "interaction-traits")]
 impl VestingContract {
     fn can_change_balance(
@@ -65,18 +114,6 @@ impl VestingContract {
 
         Ok(())
     }
-
-    fn min_cap(&self, time: u64) -> Coin {
-        if self.time_step > 0 && self.step_amount > Coin::ZERO {
-            let steps = (time as i128 - self.start_time as i128) / self.time_step as i128;
-            let min_cap =
-                u64::from(self.total_amount) as i128 - steps * u64::from(self.step_amount) as i128;
-            // Since all parameters have been validated, this will be safe as well.
-            Coin::from_u64_unchecked(min_cap.max(0) as u64)
-        } else {
-            Coin::ZERO
-        }
-    }
 }
 
 #[cfg(feature = "interaction-traits")]
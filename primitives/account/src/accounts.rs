@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 use nimiq_database::{
     declare_table,
     mdbx::{MdbxDatabase, MdbxReadTransaction as DBTransaction},
@@ -7,7 +9,9 @@ use nimiq_hash::{Blake2bHash, Hash};
 use nimiq_keys::Address;
 use nimiq_primitives::{
     account::{AccountError, AccountType, FailReason},
+    coin::Coin,
     key_nibbles::KeyNibbles,
+    policy::Policy,
     trie::{
         error::IncompleteTrie,
         trie_chunk::{TrieChunk, TrieChunkPushResult},
@@ -25,7 +29,8 @@ use crate::{
     Account, AccountInherentInteraction, AccountPruningInteraction, AccountReceipt,
     AccountTransactionInteraction, AccountsError, BlockLogger, BlockState, DataStore,
     InherentLogger, InherentOperationReceipt, OperationReceipt, Receipts, ReservedBalance,
-    RevertInfo, TransactionLog, TransactionOperationReceipt, TransactionReceipt,
+    RevertInfo, StakingContractStoreWrite, TransactionLog, TransactionOperationReceipt,
+    TransactionReceipt,
 };
 
 declare_table!(AccountsTrieTable, "AccountsTrie", KeyNibbles => TrieNode);
@@ -350,21 +355,126 @@ impl Accounts {
             receipts.transactions.push(receipt);
         }
 
+        // The stakers' shares of the rewards are distributed for all validators at once, so that
+        // the stakers only need to be iterated over once per block.
+        let mut staker_reward_receipts: Option<VecDeque<InherentOperationReceipt>> = None;
         for inherent in inherents {
-            let receipt = self
-                .commit_inherent(
+            let receipt = if Self::staker_reward(inherent, block_state).is_some() {
+                staker_reward_receipts
+                    .get_or_insert_with(|| {
+                        self.commit_staker_rewards(
+                            txn,
+                            inherents,
+                            block_state,
+                            &mut block_logger.inherent_logger(),
+                        )
+                    })
+                    .pop_front()
+                    .expect("There must be a receipt for every staker reward")
+            } else {
+                self.commit_inherent(
                     txn,
                     inherent,
                     block_state,
                     &mut block_logger.inherent_logger(),
                 )
-                .map_err(|error| AccountsError::InvalidInherent(error, inherent.clone()))?;
+                .map_err(|error| AccountsError::InvalidInherent(error, inherent.clone()))?
+            };
             receipts.inherents.push(receipt);
         }
 
         Ok(receipts)
     }
 
+    /// Returns the validator address and value of a reward inherent whose value is distributed to
+    /// the stakers of the validator.
+    fn staker_reward<'a>(
+        inherent: &'a Inherent,
+        block_state: &BlockState,
+    ) -> Option<(&'a Address, Coin)> {
+        match inherent {
+            Inherent::Reward {
+                validator_address,
+                target,
+                value,
+            } if *target == Policy::STAKING_CONTRACT_ADDRESS
+                && block_state.number >= Policy::commission_fork_block_number() =>
+            {
+                Some((validator_address, *value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Commits all staker rewards of the given inherents in a single pass over the stakers.
+    /// Returns the receipts in the order of the inherents.
+    fn commit_staker_rewards(
+        &self,
+        txn: &mut WriteTransactionProxy,
+        inherents: &[Inherent],
+        block_state: &BlockState,
+        inherent_logger: &mut InherentLogger,
+    ) -> VecDeque<InherentOperationReceipt> {
+        let rewards: Vec<(Address, Coin)> = inherents
+            .iter()
+            .filter_map(|inherent| Self::staker_reward(inherent, block_state))
+            .map(|(validator_address, value)| (validator_address.clone(), value))
+            .collect();
+
+        let address = &Policy::STAKING_CONTRACT_ADDRESS;
+        let store = DataStore::new(&self.tree, address);
+        let mut account = self.get_complete(address, Some(txn));
+
+        let results = match account {
+            Account::Staking(ref mut staking_contract) => {
+                let mut data_store = store.write(txn);
+                staking_contract.distribute_staker_rewards(
+                    &mut StakingContractStoreWrite::new(&mut data_store),
+                    &rewards,
+                    inherent_logger,
+                )
+            }
+            _ => rewards
+                .iter()
+                .map(|_| Err(AccountError::InvalidForTarget))
+                .collect(),
+        };
+        self.put(txn, address, account);
+
+        results
+            .into_iter()
+            .zip(&rewards)
+            .map(|(result, (validator_address, value))| match result {
+                Ok(receipt) => InherentOperationReceipt::Ok(Some(receipt.into())),
+                Err(e) => {
+                    // Like a reward that can't be accepted by its recipient, a reward that can't
+                    // be distributed to the stakers is burned.
+                    let receipt = self.commit_inherent(
+                        txn,
+                        &Self::burned_staker_reward(validator_address, *value),
+                        block_state,
+                        inherent_logger,
+                    );
+                    assert!(
+                        matches!(receipt, Ok(OperationReceipt::Ok(None))),
+                        "The burn address must accept rewards"
+                    );
+                    InherentOperationReceipt::Err(None, e.into())
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the inherent that burns a staker reward of the given validator that couldn't be
+    /// distributed.
+    fn burned_staker_reward(validator_address: &Address, value: Coin) -> Inherent {
+        Inherent::Reward {
+            validator_address: validator_address.clone(),
+            target: Address::burn_address(),
+            value,
+        }
+    }
+
     fn commit_transaction(
         &self,
         txn: &mut WriteTransactionProxy,
@@ -778,10 +888,22 @@ impl Accounts {
         receipt: InherentOperationReceipt,
         inherent_logger: &mut InherentLogger,
     ) -> Result<(), AccountError> {
-        // If the inherent operation failed, there is nothing to revert.
+        // If the inherent operation failed, there is nothing to revert, except for the burn of a
+        // staker reward that couldn't be distributed.
         let receipt = match receipt {
             OperationReceipt::Ok(receipt) => receipt,
-            OperationReceipt::Err(..) => return Ok(()),
+            OperationReceipt::Err(..) => {
+                return match Self::staker_reward(inherent, block_state) {
+                    Some((validator_address, value)) => self.revert_inherent(
+                        txn,
+                        &Self::burned_staker_reward(validator_address, value),
+                        block_state,
+                        OperationReceipt::Ok(None),
+                        inherent_logger,
+                    ),
+                    None => Ok(()),
+                };
+            }
         };
 
         let address = inherent.target();
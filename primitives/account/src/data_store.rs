@@ -94,6 +94,19 @@ impl<'store, 'tree, 'txn, 'txni, 'env> DataStoreWrite<'store, 'tree, 'txn, 'txni
     pub fn remove(&mut self, key: &KeyNibbles) {
         self.store.remove(self.txn, key)
     }
+
+    /// Returns an iterator over all items within a given range (inclusive).
+    pub fn iter<T: Deserialize>(
+        &self,
+        start_key: &KeyNibbles,
+        end_key: &KeyNibbles,
+    ) -> TrieNodeIter<'_, AccountsTrieTable, T> {
+        self.store.tree.iter_nodes(
+            self.txn,
+            &(&self.store.prefix + start_key),
+            &(&self.store.prefix + end_key),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -170,6 +170,15 @@ pub enum Log {
     #[serde(rename_all = "camelCase")]
     PayoutReward { to: Address, value: Coin },
 
+    /// The staker with the given address received its share of the reward of the validator it
+    /// delegates to. The reward is added to the staker's active balance.
+    #[serde(rename_all = "camelCase")]
+    PayoutStakerReward {
+        staker_address: Address,
+        validator_address: Address,
+        value: Coin,
+    },
+
     #[serde(rename_all = "camelCase")]
     Penalize {
         validator_address: Address,
@@ -331,6 +340,11 @@ impl Log {
                         .unwrap_or(false)
             }
             Log::PayoutReward { to, .. } => to == address,
+            Log::PayoutStakerReward {
+                staker_address,
+                validator_address,
+                ..
+            } => staker_address == address || validator_address == address,
             Log::Penalize {
                 validator_address, ..
             }
@@ -1081,3 +1081,52 @@ fn can_revert_inherents() {
         [OperationReceipt::Err(..)]
     ));
 }
+
+#[test]
+fn failing_staker_rewards_are_burned() {
+    let accounts = TestCommitRevert::new();
+    let mut generator = TransactionsGenerator::new(
+        Accounts::new(accounts.env().clone()),
+        NetworkId::UnitAlbatross,
+        test_rng(false),
+    );
+
+    let block_state = BlockState::new(
+        Policy::blocks_per_epoch() + Policy::blocks_per_batch() + 1,
+        10,
+    );
+
+    let (validator_key_pair, _, _) =
+        generator.create_validator_and_staker(ValidatorState::Active, false, false);
+
+    // The reward of the validator is distributed to its staker, while the reward of an unknown
+    // validator is burned.
+    let inherents = [
+        Inherent::Reward {
+            validator_address: Address::from(&validator_key_pair),
+            target: Policy::STAKING_CONTRACT_ADDRESS,
+            value: Coin::from_u64_unchecked(10),
+        },
+        Inherent::Reward {
+            validator_address: Address::burn_address(),
+            target: Policy::STAKING_CONTRACT_ADDRESS,
+            value: Coin::from_u64_unchecked(20),
+        },
+    ];
+
+    let receipts = accounts
+        .commit_and_test(&[], &inherents, &block_state, &mut BlockLogger::empty())
+        .expect("Failed to commit inherents");
+    assert!(matches!(
+        receipts.inherents[..],
+        [OperationReceipt::Ok(_), OperationReceipt::Err(..)]
+    ));
+
+    let accounts = Accounts::new(accounts.env().clone());
+    assert_eq!(
+        accounts
+            .get_complete(&Address::burn_address(), None)
+            .balance(),
+        Coin::from_u64_unchecked(20)
+    );
+}
@@ -173,6 +173,15 @@ fn it_can_serialize_a_htlc() {
     assert_eq!(hex::encode(bytes2), HTLC);
 }
 
+#[test]
+fn it_expires_after_the_timeout() {
+    let (htlc, ..) = prepare_outgoing_transaction();
+
+    assert!(!htlc.is_expired(99));
+    assert!(!htlc.is_expired(100));
+    assert!(htlc.is_expired(101));
+}
+
 #[test]
 fn it_can_create_contract_from_transaction() {
     // Create contract creation transaction.
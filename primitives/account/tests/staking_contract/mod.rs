@@ -182,6 +182,7 @@ fn make_sample_contract(
             voting_key,
             validator_address.clone(),
             None,
+            Policy::MAX_COMMISSION,
             Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT),
             None,
             None,
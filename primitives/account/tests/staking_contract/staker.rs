@@ -109,6 +109,7 @@ fn prepare_second_validator_for_redelegation(
             voting_key,
             validator_address2.clone(),
             None,
+            Policy::MAX_COMMISSION,
             Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT),
             None,
             None,
@@ -1049,6 +1050,54 @@ fn retire_stake_does_not_violate_jail_or_inactive_releases() {
     assert_eq!(receipt_1, Some(expected_receipt.into()));
 }
 
+#[test]
+fn inactive_release_accounts_for_jail() {
+    let staker_setup = StakerSetup::setup_staker_with_inactive_retired_balance(
+        ValidatorState::Jailed,
+        0,
+        Policy::MINIMUM_STAKE,
+        0,
+    );
+    let data_store = staker_setup
+        .accounts
+        .data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+    let db_txn = staker_setup.env.read_transaction();
+    let data_store = data_store.read(&db_txn);
+
+    let staker = staker_setup
+        .staking_contract
+        .get_staker(&data_store, &staker_setup.staker_address)
+        .expect("Staker should exist");
+    let validator = staker_setup
+        .staking_contract
+        .get_validator(&data_store, &staker_setup.validator_address);
+    let release_block = staker_setup
+        .validator_state_release
+        .unwrap()
+        .max(staker_setup.release_block_state.number);
+
+    assert_eq!(
+        staker.inactive_release_block(validator.as_ref()),
+        Some(release_block)
+    );
+    assert_eq!(
+        staker.retirable_balance(validator.as_ref(), release_block - 1),
+        Coin::ZERO
+    );
+    assert!(!staker.can_update_delegation(validator.as_ref(), release_block - 1));
+    assert_eq!(
+        staker.retirable_balance(validator.as_ref(), release_block),
+        staker_setup.inactive_stake
+    );
+    assert!(staker.can_update_delegation(validator.as_ref(), release_block));
+
+    // Without the validator, only the lock-up period applies.
+    assert_eq!(
+        staker.inactive_release_block(None),
+        Some(staker_setup.release_block_state.number)
+    );
+}
+
 #[test]
 fn retire_stake_does_not_violate_minimum_active_stake() {
     // -----------------------------------
@@ -1545,6 +1594,7 @@ fn update_staker_with_stake_reactivation_works() {
             voting_key,
             validator_address2.clone(),
             None,
+            Policy::MAX_COMMISSION,
             Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT),
             None,
             None,
@@ -212,6 +212,7 @@ fn create_validator_works() {
                 .compress(),
             reward_address: reward_address.clone(),
             signal_data: None,
+            commission: 1_000,
             proof: SignatureProof::default(),
         },
         Policy::VALIDATOR_DEPOSIT,
@@ -246,10 +247,26 @@ fn create_validator_works() {
     assert_eq!(validator.voting_key, voting_key);
     assert_eq!(validator.reward_address, Address::from([3u8; 20]));
     assert_eq!(validator.signal_data, None);
+    assert_eq!(validator.commission, 1_000);
     assert_eq!(
         validator.total_stake,
         Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT)
     );
+
+    // The commission is stored separately, the serialization of the validator is unchanged.
+    let commission_key = StakingContractStore::commission_key(&validator_address);
+    assert_eq!(
+        data_store.read(&db_txn).get::<u16>(&commission_key),
+        Some(1_000)
+    );
+    assert_eq!(
+        validator.serialize_to_vec(),
+        Validator {
+            commission: Policy::MAX_COMMISSION,
+            ..validator.clone()
+        }
+        .serialize_to_vec()
+    );
     assert_eq!(validator.num_stakers, 0);
     assert_eq!(validator.inactive_from, None);
 
@@ -302,6 +319,7 @@ fn create_validator_works() {
     );
 
     assert_eq!(staking_contract.balance, Coin::ZERO);
+    assert_eq!(data_store.read(&db_txn).get::<u16>(&commission_key), None);
 
     assert_eq!(
         staking_contract.active_validators.get(&validator_address),
@@ -337,6 +355,7 @@ fn update_validator_works() {
             new_voting_key: Some(new_voting_keypair.public_key.compress()),
             new_reward_address: new_reward_address.clone(),
             new_signal_data: Some(Some(Blake2bHash::default())),
+            new_commission: Some(500),
             new_proof_of_knowledge: Some(
                 new_voting_keypair
                     .sign(&new_voting_keypair.public_key.serialize_to_vec())
@@ -368,6 +387,7 @@ fn update_validator_works() {
         old_voting_key: old_voting_key.clone(),
         old_reward_address: old_reward_address.clone(),
         old_signal_data: None,
+        old_commission: Policy::MAX_COMMISSION,
     };
     assert_eq!(receipt, Some(expected_receipt.into()));
 
@@ -396,6 +416,7 @@ fn update_validator_works() {
     );
     assert_eq!(validator.reward_address, Address::from([77u8; 20]));
     assert_eq!(validator.signal_data, Some(Blake2bHash::default()));
+    assert_eq!(validator.commission, 500);
     assert_eq!(
         validator.total_stake,
         Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 150_000_000)
@@ -438,6 +459,15 @@ fn update_validator_works() {
     assert_eq!(validator.voting_key, old_voting_key);
     assert_eq!(validator.reward_address, old_reward_address);
     assert_eq!(validator.signal_data, None);
+    assert_eq!(validator.commission, Policy::MAX_COMMISSION);
+    assert_eq!(
+        data_store
+            .read(&db_txn)
+            .get::<u16>(&StakingContractStore::commission_key(
+                &validator_setup.validator_address
+            )),
+        None
+    );
     assert_eq!(
         validator.total_stake,
         Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 150_000_000)
@@ -454,6 +484,7 @@ fn update_validator_works() {
             new_voting_key: Some(new_voting_keypair.public_key.compress()),
             new_reward_address: Some(Address::from([77u8; 20])),
             new_signal_data: Some(Some(Blake2bHash::default())),
+            new_commission: None,
             new_proof_of_knowledge: Some(
                 new_voting_keypair
                     .sign(&new_voting_keypair.public_key.serialize_to_vec())
@@ -942,6 +973,7 @@ fn delete_validator_works() {
         voting_key: voting_key.clone(),
         reward_address: reward_address.clone(),
         signal_data: None,
+        commission: Policy::MAX_COMMISSION,
         inactive_from: effective_deactivation_block,
         jailed_from: None,
     };
@@ -1061,11 +1093,138 @@ fn delete_validator_works() {
 }
 
 #[test]
-fn reward_inherents_not_allowed() {
+fn reward_inherents_are_distributed_to_stakers() {
     let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
     let accounts = Accounts::new(env.clone());
     let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
-    let block_state = BlockState::new(2, 2);
+    let block_state = BlockState::new(Policy::commission_fork_block_number(), 2);
+    let mut db_txn = env.write_transaction();
+    let mut db_txn = (&mut db_txn).into();
+
+    let (validator_address, staker_address, mut staking_contract) =
+        make_sample_contract(data_store.write(&mut db_txn), Some(150_000_000));
+    let staker_address = staker_address.unwrap();
+
+    // Add a second staker with a smaller stake.
+    let staker_address2 = Address::from([5u8; 20]);
+    let mut data_store_write = data_store.write(&mut db_txn);
+    let mut store = StakingContractStoreWrite::new(&mut data_store_write);
+    staking_contract
+        .create_staker(
+            &mut store,
+            &staker_address2,
+            Coin::from_u64_unchecked(50_000_000),
+            Some(validator_address.clone()),
+            Coin::ZERO,
+            None,
+            &mut TransactionLog::empty(),
+        )
+        .unwrap();
+
+    let inherent = Inherent::Reward {
+        validator_address: validator_address.clone(),
+        target: Policy::STAKING_CONTRACT_ADDRESS,
+        value: Coin::from_u64_unchecked(1_001),
+    };
+
+    // The reward is split proportionally, the remainder goes to the biggest staker.
+    let mut logs = vec![];
+    let mut inherent_logger = InherentLogger::new(&mut logs);
+    let receipt = staking_contract
+        .commit_inherent(
+            &inherent,
+            &block_state,
+            data_store.write(&mut db_txn),
+            &mut inherent_logger,
+        )
+        .expect("Failed to commit inherent");
+
+    let expected_receipt = StakerRewardReceipt {
+        staker_rewards: vec![
+            (staker_address2.clone(), Coin::from_u64_unchecked(250)),
+            (staker_address.clone(), Coin::from_u64_unchecked(751)),
+        ],
+    };
+    assert_eq!(receipt, Some(expected_receipt.into()));
+
+    assert_eq!(
+        logs,
+        vec![
+            Log::PayoutStakerReward {
+                staker_address: staker_address2.clone(),
+                validator_address: validator_address.clone(),
+                value: Coin::from_u64_unchecked(250),
+            },
+            Log::PayoutStakerReward {
+                staker_address: staker_address.clone(),
+                validator_address: validator_address.clone(),
+                value: Coin::from_u64_unchecked(751),
+            },
+        ]
+    );
+
+    let staker = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address)
+        .unwrap();
+    assert_eq!(staker.active_balance, Coin::from_u64_unchecked(150_000_751));
+
+    let staker2 = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address2)
+        .unwrap();
+    assert_eq!(staker2.active_balance, Coin::from_u64_unchecked(50_000_250));
+
+    let validator = staking_contract
+        .get_validator(&data_store.read(&db_txn), &validator_address)
+        .unwrap();
+    assert_eq!(
+        validator.total_stake,
+        Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 200_001_001)
+    );
+    assert_eq!(
+        staking_contract.balance,
+        Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 200_001_001)
+    );
+
+    // Revert the inherent.
+    staking_contract
+        .revert_inherent(
+            &inherent,
+            &block_state,
+            receipt,
+            data_store.write(&mut db_txn),
+            &mut InherentLogger::empty(),
+        )
+        .expect("Failed to revert inherent");
+
+    let staker = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address)
+        .unwrap();
+    assert_eq!(staker.active_balance, Coin::from_u64_unchecked(150_000_000));
+
+    let staker2 = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address2)
+        .unwrap();
+    assert_eq!(staker2.active_balance, Coin::from_u64_unchecked(50_000_000));
+
+    let validator = staking_contract
+        .get_validator(&data_store.read(&db_txn), &validator_address)
+        .unwrap();
+    assert_eq!(
+        validator.total_stake,
+        Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 200_000_000)
+    );
+    assert_eq!(
+        staking_contract.balance,
+        Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT + 200_000_000)
+    );
+}
+
+#[test]
+fn reward_inherents_need_delegated_stake() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let accounts = Accounts::new(env.clone());
+    let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+    let block_state = BlockState::new(Policy::commission_fork_block_number(), 2);
     let mut db_txn = env.write_transaction();
     let mut db_txn = (&mut db_txn).into();
 
@@ -1073,9 +1232,9 @@ fn reward_inherents_not_allowed() {
         make_sample_contract(data_store.write(&mut db_txn), None);
 
     let inherent = Inherent::Reward {
-        validator_address: Address::burn_address(),
-        target: validator_address,
-        value: Coin::ZERO,
+        validator_address,
+        target: Policy::STAKING_CONTRACT_ADDRESS,
+        value: Coin::from_u64_unchecked(1_000),
     };
 
     assert_eq!(
@@ -1089,6 +1248,192 @@ fn reward_inherents_not_allowed() {
     );
 }
 
+#[test]
+fn reward_inherents_are_rejected_before_the_commission_fork() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let accounts = Accounts::new(env.clone());
+    let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+    let block_state = BlockState::new(Policy::commission_fork_block_number() - 1, 2);
+    let mut db_txn = env.write_transaction();
+    let mut db_txn = (&mut db_txn).into();
+
+    let (validator_address, _, mut staking_contract) =
+        make_sample_contract(data_store.write(&mut db_txn), Some(150_000_000));
+
+    let inherent = Inherent::Reward {
+        validator_address,
+        target: Policy::STAKING_CONTRACT_ADDRESS,
+        value: Coin::from_u64_unchecked(1_000),
+    };
+
+    assert_eq!(
+        staking_contract.commit_inherent(
+            &inherent,
+            &block_state,
+            data_store.write(&mut db_txn),
+            &mut InherentLogger::empty()
+        ),
+        Err(AccountError::InvalidForTarget)
+    );
+}
+
+#[test]
+fn staker_rewards_of_several_validators_are_distributed_at_once() {
+    let env = MdbxDatabase::new_volatile(Default::default()).unwrap();
+    let accounts = Accounts::new(env.clone());
+    let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+    let mut db_txn = env.write_transaction();
+    let mut db_txn = (&mut db_txn).into();
+
+    let (validator_address, staker_address, mut staking_contract) =
+        make_sample_contract(data_store.write(&mut db_txn), Some(150_000_000));
+    let staker_address = staker_address.unwrap();
+
+    // Add a second validator with a staker of its own.
+    let validator_address2 = Address::from([6u8; 20]);
+    let staker_address2 = Address::from([5u8; 20]);
+    let mut data_store_write = data_store.write(&mut db_txn);
+    let mut store = StakingContractStoreWrite::new(&mut data_store_write);
+    staking_contract
+        .create_validator(
+            &mut store,
+            &validator_address2,
+            ed25519_public_key(VALIDATOR_SIGNING_KEY),
+            bls_public_key(VALIDATOR_VOTING_KEY),
+            validator_address2.clone(),
+            None,
+            500,
+            Coin::from_u64_unchecked(Policy::VALIDATOR_DEPOSIT),
+            None,
+            None,
+            false,
+            &mut TransactionLog::empty(),
+        )
+        .unwrap();
+    staking_contract
+        .create_staker(
+            &mut store,
+            &staker_address2,
+            Coin::from_u64_unchecked(50_000_000),
+            Some(validator_address2.clone()),
+            Coin::ZERO,
+            None,
+            &mut TransactionLog::empty(),
+        )
+        .unwrap();
+
+    // Every reward gets its own result, in the order of the rewards.
+    let non_existent_address = Address::from([7u8; 20]);
+    let results = staking_contract.distribute_staker_rewards(
+        &mut store,
+        &[
+            (validator_address.clone(), Coin::from_u64_unchecked(1_000)),
+            (non_existent_address.clone(), Coin::from_u64_unchecked(100)),
+            (validator_address2.clone(), Coin::from_u64_unchecked(501)),
+        ],
+        &mut InherentLogger::empty(),
+    );
+    assert_eq!(
+        results,
+        vec![
+            Ok(StakerRewardReceipt {
+                staker_rewards: vec![(staker_address.clone(), Coin::from_u64_unchecked(1_000))],
+            }),
+            Err(AccountError::NonExistentAddress {
+                address: non_existent_address,
+            }),
+            Ok(StakerRewardReceipt {
+                staker_rewards: vec![(staker_address2.clone(), Coin::from_u64_unchecked(501))],
+            }),
+        ]
+    );
+
+    let staker = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address)
+        .unwrap();
+    assert_eq!(staker.active_balance, Coin::from_u64_unchecked(150_001_000));
+
+    let staker2 = staking_contract
+        .get_staker(&data_store.read(&db_txn), &staker_address2)
+        .unwrap();
+    assert_eq!(staker2.active_balance, Coin::from_u64_unchecked(50_000_501));
+
+    assert_eq!(
+        staking_contract.balance,
+        Coin::from_u64_unchecked(2 * Policy::VALIDATOR_DEPOSIT + 200_001_501)
+    );
+}
+
+#[test]
+fn validator_receipts_keep_their_pre_fork_serialization() {
+    let signing_key = ed25519_public_key(VALIDATOR_SIGNING_KEY);
+    let voting_key = bls_public_key(VALIDATOR_VOTING_KEY);
+
+    // Receipts created before the commission fork decode with the default commission.
+    let pre_fork_bytes = hex::decode(format!(
+        "{VALIDATOR_SIGNING_KEY}{VALIDATOR_VOTING_KEY}{}00",
+        "03".repeat(20)
+    ))
+    .unwrap();
+    let receipt = UpdateValidatorReceipt {
+        old_signing_key: signing_key,
+        old_voting_key: voting_key.clone(),
+        old_reward_address: Address::from([3u8; 20]),
+        old_signal_data: None,
+        old_commission: Policy::MAX_COMMISSION,
+    };
+    assert_eq!(
+        UpdateValidatorReceipt::try_from(AccountReceipt(pre_fork_bytes.clone())),
+        Ok(receipt.clone())
+    );
+    assert_eq!(AccountReceipt::from(receipt.clone()).0, pre_fork_bytes);
+
+    // Other commissions are appended.
+    let receipt = UpdateValidatorReceipt {
+        old_commission: 1_000,
+        ..receipt
+    };
+    let account_receipt = AccountReceipt::from(receipt.clone());
+    assert_eq!(
+        account_receipt.0[..pre_fork_bytes.len()],
+        pre_fork_bytes[..]
+    );
+    assert_eq!(account_receipt.0.len(), pre_fork_bytes.len() + 2);
+    assert_eq!(
+        UpdateValidatorReceipt::try_from(account_receipt),
+        Ok(receipt)
+    );
+
+    let pre_fork_bytes = hex::decode(format!(
+        "{VALIDATOR_SIGNING_KEY}{VALIDATOR_VOTING_KEY}{}000500",
+        "03".repeat(20)
+    ))
+    .unwrap();
+    let receipt = DeleteValidatorReceipt {
+        signing_key,
+        voting_key,
+        reward_address: Address::from([3u8; 20]),
+        signal_data: None,
+        inactive_from: 5,
+        jailed_from: None,
+        commission: Policy::MAX_COMMISSION,
+    };
+    assert_eq!(
+        DeleteValidatorReceipt::try_from(AccountReceipt(pre_fork_bytes.clone())),
+        Ok(receipt.clone())
+    );
+    assert_eq!(AccountReceipt::from(receipt.clone()).0, pre_fork_bytes);
+
+    let receipt = DeleteValidatorReceipt {
+        commission: 1_000,
+        ..receipt
+    };
+    assert_eq!(
+        DeleteValidatorReceipt::try_from(AccountReceipt::from(receipt.clone())),
+        Ok(receipt)
+    );
+}
+
 #[test]
 fn jail_inherents_work() {
     let genesis_block_number = Policy::genesis_block_number();
@@ -3040,6 +3385,7 @@ fn commit_failed_delete_validator_works() {
         voting_key: voting_key.clone(),
         reward_address: reward_address.clone(),
         signal_data: None,
+        commission: Policy::MAX_COMMISSION,
         inactive_from: effective_deactivation_block,
         jailed_from: None,
     };
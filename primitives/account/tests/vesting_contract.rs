@@ -105,6 +105,70 @@ fn it_can_serialize_a_vesting_contract() {
     assert_eq!(hex::encode(bytes2), CONTRACT);
 }
 
+#[test]
+fn it_computes_the_release_schedule() {
+    let (_, mut vesting_contract, _, _) = init_tree();
+
+    assert_eq!(vesting_contract.vested_amount(0), Coin::ZERO);
+    assert_eq!(vesting_contract.available_balance(0), Coin::ZERO);
+    assert_eq!(vesting_contract.next_release(0), Some(100));
+    assert_eq!(
+        vesting_contract.next_release_amount(0),
+        Some(Coin::from_u64_unchecked(100))
+    );
+
+    assert_eq!(
+        vesting_contract.vested_amount(250),
+        Coin::from_u64_unchecked(200)
+    );
+    assert_eq!(
+        vesting_contract.available_balance(250),
+        Coin::from_u64_unchecked(200)
+    );
+    assert_eq!(vesting_contract.next_release(250), Some(300));
+
+    assert_eq!(vesting_contract.next_release(999), Some(1000));
+    assert_eq!(
+        vesting_contract.vested_amount(1000),
+        vesting_contract.total_amount
+    );
+    assert_eq!(vesting_contract.next_release(1000), None);
+    assert_eq!(vesting_contract.next_release_amount(1000), None);
+
+    // Withdrawn coins are still vested, but no longer available.
+    vesting_contract.balance = Coin::from_u64_unchecked(900);
+    assert_eq!(
+        vesting_contract.vested_amount(250),
+        Coin::from_u64_unchecked(200)
+    );
+    assert_eq!(
+        vesting_contract.available_balance(250),
+        Coin::from_u64_unchecked(100)
+    );
+}
+
+#[test]
+fn it_computes_the_release_schedule_before_the_start_time() {
+    let (_, mut vesting_contract, _, _) = init_tree();
+    vesting_contract.start_time = 1000;
+
+    assert_eq!(vesting_contract.vested_amount(700), Coin::ZERO);
+    assert_eq!(vesting_contract.available_balance(700), Coin::ZERO);
+    assert_eq!(vesting_contract.next_release(700), Some(1100));
+    assert_eq!(
+        vesting_contract.next_release_amount(700),
+        Some(Coin::from_u64_unchecked(100))
+    );
+
+    assert_eq!(vesting_contract.next_release(1050), Some(1100));
+    assert_eq!(
+        vesting_contract.next_release_amount(1050),
+        Some(Coin::from_u64_unchecked(100))
+    );
+    assert_eq!(vesting_contract.next_release(1999), Some(2000));
+    assert_eq!(vesting_contract.next_release(2000), None);
+}
+
 #[test]
 #[allow(unused_must_use)]
 fn it_can_create_contract_from_transaction() {
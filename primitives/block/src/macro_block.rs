@@ -129,6 +129,52 @@ impl MacroBlock {
         Ok(())
     }
 
+    /// Verifies the justifications of a sequence of consecutive macro blocks in a single batch.
+    /// The first block must belong to the epoch started by the election block with hash
+    /// `election_hash` and the given `validators`. Whenever the sequence contains an election
+    /// block, its validators are used for the blocks following it. For this to be sound, every
+    /// block must reference the election block that provides its validators as its parent
+    /// election block.
+    pub fn verify_validators_batch<'a, I>(
+        blocks: I,
+        election_hash: &Blake2bHash,
+        validators: &'a Validators,
+    ) -> Result<(), BlockError>
+    where
+        I: IntoIterator<Item = &'a MacroBlock>,
+    {
+        let mut current_election_hash = election_hash.clone();
+        let mut current_validators = validators;
+        let mut entries = vec![];
+
+        for block in blocks {
+            if block.header.parent_election_hash != current_election_hash {
+                return Err(BlockError::InvalidParentElectionHash);
+            }
+
+            entries.push((block, current_validators));
+
+            if block.is_election() {
+                current_election_hash = block.hash();
+                current_validators = block
+                    .header
+                    .validators
+                    .as_ref()
+                    .ok_or(BlockError::InvalidValidators)?;
+            }
+        }
+
+        if !TendermintProof::verify_batch(entries) {
+            warn!(
+                reason = "Batch of macro blocks with bad justification",
+                "Rejecting blocks"
+            );
+            return Err(BlockError::InvalidJustification);
+        }
+
+        Ok(())
+    }
+
     /// Creates a default block that has body and justification.
     pub fn non_empty_default() -> Self {
         let mut validators = ValidatorsBuilder::new();
@@ -1,5 +1,5 @@
 use log::error;
-use nimiq_bls::AggregatePublicKey;
+use nimiq_bls::{AggregatePublicKey, BatchVerifier};
 use nimiq_primitives::{
     policy::Policy, slots_allocation::Validators, TendermintIdentifier, TendermintStep,
     TendermintVote,
@@ -26,11 +26,51 @@ impl TendermintProof {
     /// Verifies the proof. This only checks that the proof is valid for this block, not that the
     /// block itself is valid.
     pub fn verify(block: &MacroBlock, current_validators: &Validators) -> bool {
+        let (agg_pk, message, justification) =
+            match Self::prepare_verification(block, current_validators) {
+                Some(x) => x,
+                None => return false,
+            };
+
+        // Verify the aggregated signature against our aggregated public key.
+        agg_pk.verify(&message, &justification.sig.signature)
+    }
+
+    /// Verifies the proofs of many blocks at once using BLS batch verification. Each block is
+    /// paired with the validators that were active for it. This only checks that the proofs are
+    /// valid for their blocks, not that the blocks themselves are valid.
+    ///
+    /// Returns true only if all proofs are valid. If this returns false, it is not known which
+    /// of the proofs is invalid, so callers should fall back to `verify` to identify it.
+    pub fn verify_batch<'a, I>(blocks: I) -> bool
+    where
+        I: IntoIterator<Item = (&'a MacroBlock, &'a Validators)>,
+    {
+        let mut batch = BatchVerifier::new();
+
+        for (block, validators) in blocks {
+            let (agg_pk, message, justification) =
+                match Self::prepare_verification(block, validators) {
+                    Some(x) => x,
+                    None => return false,
+                };
+            batch.push_aggregate(&agg_pk, &message, &justification.sig.signature);
+        }
+
+        batch.verify()
+    }
+
+    /// Performs all checks of the proof that don't require a pairing and returns the aggregated
+    /// public key of the signers together with the message they should have signed.
+    fn prepare_verification<'a>(
+        block: &'a MacroBlock,
+        current_validators: &Validators,
+    ) -> Option<(AggregatePublicKey, TendermintVote, &'a TendermintProof)> {
         // If there's no justification then the proof is false evidently.
         let justification = match &block.justification {
             None => {
                 error!("Invalid justification - macro block has no justification!");
-                return false;
+                return None;
             }
             Some(x) => x,
         };
@@ -38,7 +78,7 @@ impl TendermintProof {
         // Check if there are enough votes.
         if justification.votes() < Policy::TWO_F_PLUS_ONE {
             error!("Invalid justification - not enough votes!");
-            return false;
+            return None;
         }
 
         // Calculate the `block_hash` as blake2s.
@@ -65,7 +105,6 @@ impl TendermintProof {
             }
         }
 
-        // Verify the aggregated signature against our aggregated public key.
-        agg_pk.verify(&message, &justification.sig.signature)
+        Some((agg_pk, message, justification))
     }
 }
@@ -27,6 +27,9 @@ pub struct Policy {
     /// Genesis block number
     #[cfg_attr(feature = "ts-types", wasm_bindgen(skip))]
     pub genesis_block_number: u32,
+    /// Block number from which on validators can charge a commission
+    #[cfg_attr(feature = "ts-types", wasm_bindgen(skip))]
+    pub commission_fork_block_number: u32,
 }
 
 impl Policy {
@@ -55,7 +58,11 @@ impl Policy {
     /// Maximum size for the recipient data field of the transactions.
     /// This is used by the HTLC, Vesting and staking contract transactions. Basic transactions are allowed to use it as well.
     /// The biggest transactions are the create validator and update validator with a web auth signature.
+    /// From the commission fork on, the larger [`Policy::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK`] applies.
     pub const MAX_TX_RECIPIENT_DATA_SIZE: usize = 2112;
+    /// Maximum size for the recipient data field of the transactions from the commission fork on,
+    /// where the create validator and update validator transactions can additionally set a commission.
+    pub const MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK: usize = 2116;
     /// Maximum length for basic transaction recipient_data used during intrinsic transaction verification.
     pub const MAX_BASIC_TX_RECIPIENT_DATA_SIZE: usize = 64;
     /// Maximum size for transaction's merkle path proofs.
@@ -134,6 +141,11 @@ impl Policy {
     /// The number of epochs a validator is put in jail for. The jailing only happens for severe offenses.
     pub const JAIL_EPOCHS: u32 = 8;
 
+    /// The maximum commission a validator can charge, in basis points (1/10,000) of its rewards.
+    /// A validator charging the maximum commission keeps all of its rewards, while the rewards
+    /// of a validator charging less are partially distributed to the stakers delegating to it.
+    pub const MAX_COMMISSION: u16 = 10_000;
+
     /// Total supply in units.
     pub const TOTAL_SUPPLY: u64 = 2_100_000_000_000_000;
 
@@ -220,6 +232,28 @@ impl Policy {
             .genesis_block_number
     }
 
+    /// Block number from which on validators can charge a commission. Before it, transactions
+    /// setting a commission are invalid and validators receive all of their rewards.
+    #[inline]
+    #[cfg_attr(feature = "ts-types", wasm_bindgen(getter = COMMISSION_FORK_BLOCK_NUMBER))]
+    pub fn commission_fork_block_number() -> u32 {
+        GLOBAL_POLICY
+            .get_or_init(Self::default)
+            .commission_fork_block_number
+    }
+
+    /// Returns the maximum size for the recipient data field of the transactions at a given block
+    /// number (height).
+    #[inline]
+    #[cfg_attr(feature = "ts-types", wasm_bindgen(js_name = maxTxRecipientDataSize))]
+    pub fn max_tx_recipient_data_size(block_number: u32) -> usize {
+        if block_number >= Self::commission_fork_block_number() {
+            Self::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK
+        } else {
+            Self::MAX_TX_RECIPIENT_DATA_SIZE
+        }
+    }
+
     /// Maximum size of accounts trie chunks.
     #[inline]
     #[cfg_attr(feature = "ts-types", wasm_bindgen(getter = STATE_CHUNKS_MAX_SIZE))]
@@ -622,6 +656,12 @@ impl Policy {
         Self::JAIL_EPOCHS
     }
 
+    /// The maximum commission a validator can charge, in basis points (1/10,000) of its rewards.
+    #[cfg_attr(feature = "ts-types", wasm_bindgen(getter = MAX_COMMISSION))]
+    pub fn wasm_max_commission() -> u16 {
+        Self::MAX_COMMISSION
+    }
+
     /// Total supply in units.
     #[cfg_attr(feature = "ts-types", wasm_bindgen(getter = TOTAL_SUPPLY))]
     pub fn wasm_total_supply() -> u64 {
@@ -650,6 +690,8 @@ impl Default for Policy {
             state_chunks_max_size: 1000,
             transaction_validity_window: 120,
             genesis_block_number: 0,
+            // The commission fork is not scheduled yet.
+            commission_fork_block_number: u32::MAX,
         }
     }
 }
@@ -660,6 +702,7 @@ pub const TEST_POLICY: Policy = Policy {
     state_chunks_max_size: 3,
     transaction_validity_window: 2,
     genesis_block_number: 0,
+    commission_fork_block_number: 0,
 };
 
 #[cfg(test)]
@@ -1,7 +1,11 @@
-use std::{cmp::Ordering, collections::BTreeMap};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
 
 use log::error;
 use nimiq_hash::{Blake2bHash, Hash};
+use nimiq_keys::Address;
 use nimiq_serde::{Deserialize, Serialize};
 
 use crate::{key_nibbles::KeyNibbles, trie::trie_proof_node::TrieProofNode};
@@ -205,6 +209,28 @@ impl TrieProof {
         }
         Ok(result)
     }
+
+    /// Verifies the proof against the given state root and returns the serialized accounts stored
+    /// at the given addresses, in the same order. Addresses without an account map to `None`.
+    /// Fails if the proof is invalid or doesn't prove exactly the given addresses.
+    pub fn verify_accounts(
+        self,
+        state_root: &Blake2bHash,
+        addresses: &[Address],
+    ) -> Result<Vec<(Address, Option<Vec<u8>>)>, Error> {
+        let keys: BTreeSet<_> = addresses.iter().map(KeyNibbles::from).collect();
+        let values = self.verify_values(state_root, &keys.iter().collect::<Vec<_>>())?;
+
+        addresses
+            .iter()
+            .map(|address| {
+                values
+                    .get(&KeyNibbles::from(address))
+                    .map(|value| (address.clone(), value.clone()))
+                    .ok_or(Error("requested account missing"))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -436,4 +462,46 @@ mod tests {
         assert!(!proof3.verify(&root_hash));
         assert!(!proof3.verify(&wrong_root_hash));
     }
+
+    #[test]
+    fn verify_accounts_works() {
+        let address_1 = Address::from([0x11; Address::SIZE]);
+        let address_2 = Address::from([0x22; Address::SIZE]);
+        let address_3 = Address::from([0x33; Address::SIZE]);
+
+        let key_1 = KeyNibbles::from(&address_1);
+        let l1 = TrieNode::new_leaf(key_1.clone(), vec![1]);
+
+        let key_2 = KeyNibbles::from(&address_2);
+        let l2 = TrieNode::new_leaf(key_2.clone(), vec![2]);
+
+        let mut r = TrieNode::new_empty(KeyNibbles::ROOT);
+        r.put_child(&key_1, l1.hash_assert()).unwrap();
+        r.put_child(&key_2, l2.hash_assert()).unwrap();
+        let root_hash = r.hash_assert();
+
+        // The root proves that there is no account at the third address.
+        let mut missing_proven_by = BTreeMap::new();
+        missing_proven_by.insert(KeyNibbles::from(&address_3), KeyNibbles::ROOT);
+        let proof = TrieProof::new(vec![l1.into(), r.into()], missing_proven_by);
+
+        assert_eq!(
+            proof
+                .clone()
+                .verify_accounts(&root_hash, &[address_3.clone(), address_1.clone()])
+                .unwrap(),
+            vec![(address_3, None), (address_1.clone(), Some(vec![1]))]
+        );
+
+        // The proof doesn't cover the second address.
+        assert!(proof
+            .clone()
+            .verify_accounts(&root_hash, &[address_1.clone(), address_2])
+            .is_err());
+
+        // The proof doesn't verify against a different state root.
+        assert!(proof
+            .verify_accounts(&":-E".hash::<Blake2bHash>(), &[address_1])
+            .is_err());
+    }
 }
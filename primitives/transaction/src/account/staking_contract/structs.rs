@@ -30,7 +30,15 @@ use crate::{SignatureProof, Transaction, TransactionError};
 /// It is important to note that all `signature` fields contain the signature
 /// over the complete transaction with the `signature` field set to `Default::default()`.
 /// The field is populated only after computing the signature.
+///
+/// Transactions setting a validator commission are only valid from
+/// [`Policy::commission_fork_block_number`] on. All other transactions keep the serialization they
+/// had before the fork.
 #[derive(Clone, Debug, Serialize, Deserialize, SerializedMaxSize)]
+#[serde(
+    try_from = "IncomingStakingTransactionDataRepr",
+    into = "IncomingStakingTransactionDataRepr"
+)]
 #[repr(u8)]
 pub enum IncomingStakingTransactionData {
     CreateValidator {
@@ -38,6 +46,8 @@ pub enum IncomingStakingTransactionData {
         voting_key: BlsPublicKey,
         reward_address: Address,
         signal_data: Option<Blake2bHash>,
+        // The share of the rewards the validator keeps, in basis points.
+        commission: u16,
         proof_of_knowledge: BlsSignature,
         // This proof is signed with the validator cold key, which will become the validator address.
         proof: SignatureProof,
@@ -47,6 +57,7 @@ pub enum IncomingStakingTransactionData {
         new_voting_key: Option<BlsPublicKey>,
         new_reward_address: Option<Address>,
         new_signal_data: Option<Option<Blake2bHash>>,
+        new_commission: Option<u16>,
         new_proof_of_knowledge: Option<BlsSignature>,
         // This proof is signed with the validator cold key.
         proof: SignatureProof,
@@ -87,7 +98,335 @@ pub enum IncomingStakingTransactionData {
     },
 }
 
+/// The serialized form of [`IncomingStakingTransactionData`]. The variants setting a validator
+/// commission were appended after the commission fork, so that the serialization of all other
+/// transactions didn't change. A commission of [`Policy::MAX_COMMISSION`] is the default and thus
+/// never serialized explicitly.
+#[derive(Serialize, Deserialize)]
+#[repr(u8)]
+enum IncomingStakingTransactionDataRepr {
+    CreateValidator {
+        signing_key: SchnorrPublicKey,
+        voting_key: BlsPublicKey,
+        reward_address: Address,
+        signal_data: Option<Blake2bHash>,
+        proof_of_knowledge: BlsSignature,
+        proof: SignatureProof,
+    },
+    UpdateValidator {
+        new_signing_key: Option<SchnorrPublicKey>,
+        new_voting_key: Option<BlsPublicKey>,
+        new_reward_address: Option<Address>,
+        new_signal_data: Option<Option<Blake2bHash>>,
+        new_proof_of_knowledge: Option<BlsSignature>,
+        proof: SignatureProof,
+    },
+    DeactivateValidator {
+        validator_address: Address,
+        proof: SignatureProof,
+    },
+    ReactivateValidator {
+        validator_address: Address,
+        proof: SignatureProof,
+    },
+    RetireValidator {
+        proof: SignatureProof,
+    },
+    CreateStaker {
+        delegation: Option<Address>,
+        proof: SignatureProof,
+    },
+    AddStake {
+        staker_address: Address,
+    },
+    UpdateStaker {
+        new_delegation: Option<Address>,
+        reactivate_all_stake: bool,
+        proof: SignatureProof,
+    },
+    SetActiveStake {
+        new_active_balance: Coin,
+        proof: SignatureProof,
+    },
+    RetireStake {
+        retire_stake: Coin,
+        proof: SignatureProof,
+    },
+    CreateValidatorWithCommission {
+        signing_key: SchnorrPublicKey,
+        voting_key: BlsPublicKey,
+        reward_address: Address,
+        signal_data: Option<Blake2bHash>,
+        commission: u16,
+        proof_of_knowledge: BlsSignature,
+        proof: SignatureProof,
+    },
+    UpdateValidatorWithCommission {
+        new_signing_key: Option<SchnorrPublicKey>,
+        new_voting_key: Option<BlsPublicKey>,
+        new_reward_address: Option<Address>,
+        new_signal_data: Option<Option<Blake2bHash>>,
+        new_commission: u16,
+        new_proof_of_knowledge: Option<BlsSignature>,
+        proof: SignatureProof,
+    },
+}
+
+impl From<IncomingStakingTransactionData> for IncomingStakingTransactionDataRepr {
+    fn from(data: IncomingStakingTransactionData) -> Self {
+        match data {
+            IncomingStakingTransactionData::CreateValidator {
+                signing_key,
+                voting_key,
+                reward_address,
+                signal_data,
+                commission,
+                proof_of_knowledge,
+                proof,
+            } => {
+                if commission == Policy::MAX_COMMISSION {
+                    IncomingStakingTransactionDataRepr::CreateValidator {
+                        signing_key,
+                        voting_key,
+                        reward_address,
+                        signal_data,
+                        proof_of_knowledge,
+                        proof,
+                    }
+                } else {
+                    IncomingStakingTransactionDataRepr::CreateValidatorWithCommission {
+                        signing_key,
+                        voting_key,
+                        reward_address,
+                        signal_data,
+                        commission,
+                        proof_of_knowledge,
+                        proof,
+                    }
+                }
+            }
+            IncomingStakingTransactionData::UpdateValidator {
+                new_signing_key,
+                new_voting_key,
+                new_reward_address,
+                new_signal_data,
+                new_commission,
+                new_proof_of_knowledge,
+                proof,
+            } => match new_commission {
+                None => IncomingStakingTransactionDataRepr::UpdateValidator {
+                    new_signing_key,
+                    new_voting_key,
+                    new_reward_address,
+                    new_signal_data,
+                    new_proof_of_knowledge,
+                    proof,
+                },
+                Some(new_commission) => {
+                    IncomingStakingTransactionDataRepr::UpdateValidatorWithCommission {
+                        new_signing_key,
+                        new_voting_key,
+                        new_reward_address,
+                        new_signal_data,
+                        new_commission,
+                        new_proof_of_knowledge,
+                        proof,
+                    }
+                }
+            },
+            IncomingStakingTransactionData::DeactivateValidator {
+                validator_address,
+                proof,
+            } => IncomingStakingTransactionDataRepr::DeactivateValidator {
+                validator_address,
+                proof,
+            },
+            IncomingStakingTransactionData::ReactivateValidator {
+                validator_address,
+                proof,
+            } => IncomingStakingTransactionDataRepr::ReactivateValidator {
+                validator_address,
+                proof,
+            },
+            IncomingStakingTransactionData::RetireValidator { proof } => {
+                IncomingStakingTransactionDataRepr::RetireValidator { proof }
+            }
+            IncomingStakingTransactionData::CreateStaker { delegation, proof } => {
+                IncomingStakingTransactionDataRepr::CreateStaker { delegation, proof }
+            }
+            IncomingStakingTransactionData::AddStake { staker_address } => {
+                IncomingStakingTransactionDataRepr::AddStake { staker_address }
+            }
+            IncomingStakingTransactionData::UpdateStaker {
+                new_delegation,
+                reactivate_all_stake,
+                proof,
+            } => IncomingStakingTransactionDataRepr::UpdateStaker {
+                new_delegation,
+                reactivate_all_stake,
+                proof,
+            },
+            IncomingStakingTransactionData::SetActiveStake {
+                new_active_balance,
+                proof,
+            } => IncomingStakingTransactionDataRepr::SetActiveStake {
+                new_active_balance,
+                proof,
+            },
+            IncomingStakingTransactionData::RetireStake {
+                retire_stake,
+                proof,
+            } => IncomingStakingTransactionDataRepr::RetireStake {
+                retire_stake,
+                proof,
+            },
+        }
+    }
+}
+
+impl TryFrom<IncomingStakingTransactionDataRepr> for IncomingStakingTransactionData {
+    type Error = &'static str;
+
+    fn try_from(data: IncomingStakingTransactionDataRepr) -> Result<Self, Self::Error> {
+        Ok(match data {
+            IncomingStakingTransactionDataRepr::CreateValidator {
+                signing_key,
+                voting_key,
+                reward_address,
+                signal_data,
+                proof_of_knowledge,
+                proof,
+            } => IncomingStakingTransactionData::CreateValidator {
+                signing_key,
+                voting_key,
+                reward_address,
+                signal_data,
+                commission: Policy::MAX_COMMISSION,
+                proof_of_knowledge,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::CreateValidatorWithCommission {
+                signing_key,
+                voting_key,
+                reward_address,
+                signal_data,
+                commission,
+                proof_of_knowledge,
+                proof,
+            } => {
+                // The default commission must use the old serialization, otherwise the same
+                // transaction would have two different serializations.
+                if commission == Policy::MAX_COMMISSION {
+                    return Err("default commission must not be serialized");
+                }
+                IncomingStakingTransactionData::CreateValidator {
+                    signing_key,
+                    voting_key,
+                    reward_address,
+                    signal_data,
+                    commission,
+                    proof_of_knowledge,
+                    proof,
+                }
+            }
+            IncomingStakingTransactionDataRepr::UpdateValidator {
+                new_signing_key,
+                new_voting_key,
+                new_reward_address,
+                new_signal_data,
+                new_proof_of_knowledge,
+                proof,
+            } => IncomingStakingTransactionData::UpdateValidator {
+                new_signing_key,
+                new_voting_key,
+                new_reward_address,
+                new_signal_data,
+                new_commission: None,
+                new_proof_of_knowledge,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::UpdateValidatorWithCommission {
+                new_signing_key,
+                new_voting_key,
+                new_reward_address,
+                new_signal_data,
+                new_commission,
+                new_proof_of_knowledge,
+                proof,
+            } => IncomingStakingTransactionData::UpdateValidator {
+                new_signing_key,
+                new_voting_key,
+                new_reward_address,
+                new_signal_data,
+                new_commission: Some(new_commission),
+                new_proof_of_knowledge,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::DeactivateValidator {
+                validator_address,
+                proof,
+            } => IncomingStakingTransactionData::DeactivateValidator {
+                validator_address,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::ReactivateValidator {
+                validator_address,
+                proof,
+            } => IncomingStakingTransactionData::ReactivateValidator {
+                validator_address,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::RetireValidator { proof } => {
+                IncomingStakingTransactionData::RetireValidator { proof }
+            }
+            IncomingStakingTransactionDataRepr::CreateStaker { delegation, proof } => {
+                IncomingStakingTransactionData::CreateStaker { delegation, proof }
+            }
+            IncomingStakingTransactionDataRepr::AddStake { staker_address } => {
+                IncomingStakingTransactionData::AddStake { staker_address }
+            }
+            IncomingStakingTransactionDataRepr::UpdateStaker {
+                new_delegation,
+                reactivate_all_stake,
+                proof,
+            } => IncomingStakingTransactionData::UpdateStaker {
+                new_delegation,
+                reactivate_all_stake,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::SetActiveStake {
+                new_active_balance,
+                proof,
+            } => IncomingStakingTransactionData::SetActiveStake {
+                new_active_balance,
+                proof,
+            },
+            IncomingStakingTransactionDataRepr::RetireStake {
+                retire_stake,
+                proof,
+            } => IncomingStakingTransactionData::RetireStake {
+                retire_stake,
+                proof,
+            },
+        })
+    }
+}
+
 impl IncomingStakingTransactionData {
+    /// Returns whether this transaction sets a validator commission. Such transactions are only
+    /// valid from [`Policy::commission_fork_block_number`] on.
+    pub fn sets_commission(&self) -> bool {
+        match self {
+            IncomingStakingTransactionData::CreateValidator { commission, .. } => {
+                *commission != Policy::MAX_COMMISSION
+            }
+            IncomingStakingTransactionData::UpdateValidator { new_commission, .. } => {
+                new_commission.is_some()
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_signaling(&self) -> bool {
         matches!(
             self,
@@ -109,6 +448,7 @@ impl IncomingStakingTransactionData {
         match self {
             IncomingStakingTransactionData::CreateValidator {
                 voting_key,
+                commission,
                 proof_of_knowledge,
                 proof,
                 ..
@@ -119,6 +459,9 @@ impl IncomingStakingTransactionData {
                     return Err(TransactionError::InvalidValue);
                 }
 
+                // Check that the commission is within bounds.
+                verify_commission(*commission)?;
+
                 // Check proof of knowledge.
                 verify_proof_of_knowledge(voting_key, proof_of_knowledge)?;
 
@@ -130,6 +473,7 @@ impl IncomingStakingTransactionData {
                 new_voting_key,
                 new_reward_address,
                 new_signal_data,
+                new_commission,
                 new_proof_of_knowledge,
                 proof,
             } => {
@@ -138,11 +482,17 @@ impl IncomingStakingTransactionData {
                     && new_voting_key.is_none()
                     && new_reward_address.is_none()
                     && new_signal_data.is_none()
+                    && new_commission.is_none()
                 {
                     warn!("Signaling update transactions must actually update something. The offending transaction is the following:\n{:?}", transaction);
                     return Err(TransactionError::InvalidData);
                 }
 
+                // Check that the new commission is within bounds, if necessary.
+                if let Some(new_commission) = new_commission {
+                    verify_commission(*new_commission)?;
+                }
+
                 // Check proof of knowledge, if necessary.
                 if let (Some(new_voting_key), Some(new_proof_of_knowledge)) =
                     (new_voting_key, new_proof_of_knowledge)
@@ -281,6 +631,20 @@ pub fn verify_transaction_signature(
     Ok(())
 }
 
+/// Checks that a validator commission does not exceed [`Policy::MAX_COMMISSION`].
+pub fn verify_commission(commission: u16) -> Result<(), TransactionError> {
+    if commission > Policy::MAX_COMMISSION {
+        warn!(
+            "Validator commission of {} exceeds the maximum commission of {}",
+            commission,
+            Policy::MAX_COMMISSION
+        );
+        return Err(TransactionError::InvalidData);
+    }
+
+    Ok(())
+}
+
 /// Important: Currently, the proof of knowledge of the secret key is a signature of the public key.
 /// If an attacker A ever tricks a validator B into signing a message with content `pk_A - pk_B`,
 /// where `pk_X` is X's BLS public key, A will be able to sign aggregate messages that are valid for
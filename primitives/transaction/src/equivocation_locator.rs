@@ -1,3 +1,4 @@
+use nimiq_database_value_derive::DbSerializable;
 use nimiq_hash_derive::SerializeContent;
 use nimiq_keys::Address;
 use nimiq_primitives::TendermintStep;
@@ -9,7 +10,9 @@ use nimiq_serde::{Deserialize, Serialize};
 /// E.g. since we don't want to record two fork proofs for the same block
 /// height from the same validator, a fork locator consists of a validator
 /// address and a block height.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, SerializeContent)]
+#[derive(
+    Clone, Debug, DbSerializable, Deserialize, Eq, Hash, PartialEq, Serialize, SerializeContent,
+)]
 pub enum EquivocationLocator {
     /// Fork equivocation.
     Fork(ForkLocator),
@@ -19,6 +22,17 @@ pub enum EquivocationLocator {
     DoubleVote(DoubleVoteLocator),
 }
 
+impl EquivocationLocator {
+    /// Returns the block height at which the equivocation occurred.
+    pub fn block_number(&self) -> u32 {
+        match self {
+            EquivocationLocator::Fork(locator) => locator.block_number,
+            EquivocationLocator::DoubleProposal(locator) => locator.block_number,
+            EquivocationLocator::DoubleVote(locator) => locator.block_number,
+        }
+    }
+}
+
 /// Describes the location of a single fork equivocation.
 ///
 /// See [`EquivocationLocator`] for more details.
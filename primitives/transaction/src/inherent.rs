@@ -19,7 +19,8 @@ pub enum Inherent {
     Reward {
         /// The validator address of the rewarded validator.
         validator_address: Address,
-        /// The address the reward goes to.
+        /// The address the reward goes to. If this is the staking contract, the reward is
+        /// distributed to the stakers delegating to the validator.
         target: Address,
         /// The reward amount.
         value: Coin,
@@ -378,6 +378,9 @@ impl Transaction {
         Ok(())
     }
 
+    /// Checks that the transaction can be included in a block at the given height. Besides being
+    /// within its validity window, transactions setting a validator commission or exceeding the
+    /// recipient data size before the fork are only valid from the commission fork on.
     pub fn is_valid_at(&self, block_height: u32) -> bool {
         let window = Policy::transaction_validity_window_blocks();
         block_height
@@ -385,6 +388,17 @@ impl Transaction {
                 .validity_start_height
                 .saturating_sub(Policy::blocks_per_batch())
             && block_height < self.validity_start_height + window
+            && self.recipient_data.len() <= Policy::max_tx_recipient_data_size(block_height)
+            && (block_height >= Policy::commission_fork_block_number()
+                || !self.sets_validator_commission())
+    }
+
+    /// Returns whether this transaction sets the commission of a validator.
+    pub fn sets_validator_commission(&self) -> bool {
+        self.recipient_type == AccountType::Staking
+            && IncomingStakingTransactionData::parse(self)
+                .map(|data| data.sets_commission())
+                .unwrap_or(false)
     }
 
     pub fn contract_creation_address(&self) -> Address {
@@ -918,14 +932,16 @@ mod serde_derive {
                 .next_element()?
                 .ok_or_else(|| Error::invalid_length(4, &self))?;
 
-            // Enforce maximum recipient data length.
+            // Enforce maximum recipient data length. The block height isn't known here, so the
+            // limit of the commission fork applies. The limit before the fork is enforced when
+            // checking the validity at a block height.
             let recipient_data: Vec<u8> = seq
                 .next_element()?
                 .ok_or_else(|| Error::invalid_length(5, &self))?;
-            if recipient_data.len() > Policy::MAX_TX_RECIPIENT_DATA_SIZE {
+            if recipient_data.len() > Policy::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK {
                 return Err(Error::custom(format!(
                     "recipient data length exceeds the maximum allowed length of {}",
-                    Policy::MAX_TX_RECIPIENT_DATA_SIZE
+                    Policy::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK
                 )));
             }
 
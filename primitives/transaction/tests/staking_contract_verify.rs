@@ -87,6 +87,7 @@ fn create_validator() {
                 .compress(),
             reward_address: Address::from([3u8; 20]),
             signal_data: Some(Blake2bHash::default()),
+            commission: Policy::MAX_COMMISSION,
             proof: SignatureProof::default(),
         },
         Policy::VALIDATOR_DEPOSIT,
@@ -123,6 +124,29 @@ fn create_validator() {
         Err(TransactionError::InvalidValue)
     );
 
+    // Commission too high.
+    let tx = make_signed_incoming_tx(
+        IncomingStakingTransactionData::CreateValidator {
+            signing_key,
+            voting_key: voting_key.clone(),
+            proof_of_knowledge: voting_keypair
+                .sign(&voting_key.serialize_to_vec())
+                .compress(),
+            reward_address: Address::from([3u8; 20]),
+            signal_data: None,
+            commission: Policy::MAX_COMMISSION + 1,
+            proof: SignatureProof::default(),
+        },
+        Policy::VALIDATOR_DEPOSIT,
+        &cold_keypair,
+        None,
+    );
+
+    assert_eq!(
+        AccountType::verify_incoming_transaction(&tx),
+        Err(TransactionError::InvalidData)
+    );
+
     // Invalid proof of knowledge.
     let other_pair = BlsKeyPair::generate(&mut rng);
     let invalid_pok = other_pair.sign(&voting_key);
@@ -134,6 +158,7 @@ fn create_validator() {
             proof_of_knowledge: invalid_pok.compress(),
             reward_address: Address::from([3u8; 20]),
             signal_data: None,
+            commission: Policy::MAX_COMMISSION,
             proof: SignatureProof::default(),
         },
         Policy::VALIDATOR_DEPOSIT,
@@ -158,6 +183,7 @@ fn create_validator() {
                 .compress(),
             reward_address: Address::from([3u8; 20]),
             signal_data: None,
+            commission: Policy::MAX_COMMISSION,
             proof: SignatureProof::default(),
         },
         Policy::VALIDATOR_DEPOSIT,
@@ -197,6 +223,7 @@ fn update_validator() {
             ),
             new_reward_address: Some(Address::from([3u8; 20])),
             new_signal_data: Some(Some(Blake2bHash::default())),
+            new_commission: None,
             proof: SignatureProof::default(),
         },
         0,
@@ -234,6 +261,7 @@ fn update_validator() {
             new_proof_of_knowledge: None,
             new_reward_address: None,
             new_signal_data: None,
+            new_commission: None,
             proof: SignatureProof::default(),
         },
         0,
@@ -257,6 +285,7 @@ fn update_validator() {
             new_proof_of_knowledge: Some(invalid_pok.compress()),
             new_reward_address: Some(Address::from([3u8; 20])),
             new_signal_data: Some(Some(Blake2bHash::default())),
+            new_commission: None,
             proof: SignatureProof::default(),
         },
         0,
@@ -283,6 +312,7 @@ fn update_validator() {
             ),
             new_reward_address: Some(Address::from([3u8; 20])),
             new_signal_data: Some(Some(Blake2bHash::default())),
+            new_commission: None,
             proof: SignatureProof::default(),
         },
         0,
@@ -296,6 +326,110 @@ fn update_validator() {
     );
 }
 
+#[test]
+fn validator_commission() {
+    let cold_keypair = ed25519_key_pair(VALIDATOR_PRIVATE_KEY);
+    let voting_keypair = bls_key_pair(VALIDATOR_VOTING_SECRET_KEY);
+    let voting_key = voting_keypair.public_key.compress();
+
+    let create_validator = |commission| IncomingStakingTransactionData::CreateValidator {
+        signing_key: cold_keypair.public,
+        voting_key: voting_key.clone(),
+        proof_of_knowledge: voting_keypair
+            .sign(&voting_key.serialize_to_vec())
+            .compress(),
+        reward_address: Address::from([3u8; 20]),
+        signal_data: None,
+        commission,
+        proof: SignatureProof::default(),
+    };
+    let update_validator = |new_commission| IncomingStakingTransactionData::UpdateValidator {
+        new_signing_key: None,
+        new_voting_key: None,
+        new_proof_of_knowledge: None,
+        new_reward_address: None,
+        new_signal_data: None,
+        new_commission,
+        proof: SignatureProof::default(),
+    };
+
+    // Transactions setting a commission are serialized with variants that were appended at the
+    // commission fork.
+    let data = create_validator(1_000);
+    let serialized = data.serialize_to_vec();
+    assert_eq!(serialized[0], 10);
+    assert_eq!(
+        IncomingStakingTransactionData::deserialize_from_vec(&serialized)
+            .unwrap()
+            .serialize_to_vec(),
+        serialized
+    );
+    assert!(data.sets_commission());
+
+    let data = update_validator(Some(1_000));
+    let serialized = data.serialize_to_vec();
+    assert_eq!(serialized[0], 11);
+    assert_eq!(
+        IncomingStakingTransactionData::deserialize_from_vec(&serialized)
+            .unwrap()
+            .serialize_to_vec(),
+        serialized
+    );
+    assert!(data.sets_commission());
+
+    // The default commission is serialized like before the fork.
+    assert_eq!(
+        create_validator(Policy::MAX_COMMISSION).serialize_to_vec()[0],
+        0
+    );
+    assert!(!create_validator(Policy::MAX_COMMISSION).sets_commission());
+    assert_eq!(update_validator(None).serialize_to_vec()[0], 1);
+    assert!(!update_validator(None).sets_commission());
+
+    // The default commission must not be serialized with the appended variant.
+    let mut serialized = create_validator(1_000).serialize_to_vec();
+    let position = serialized
+        .iter()
+        .zip(create_validator(1_001).serialize_to_vec())
+        .position(|(a, b)| *a != b)
+        .unwrap();
+    // The varint encoding of 10_000.
+    serialized[position..position + 2].copy_from_slice(&[0x90, 0x4e]);
+    assert!(IncomingStakingTransactionData::deserialize_from_vec(&serialized).is_err());
+
+    // Transactions setting a commission are only valid from the fork on.
+    let fork = Policy::commission_fork_block_number();
+    let mut tx = make_signed_incoming_tx(
+        create_validator(1_000),
+        Policy::VALIDATOR_DEPOSIT,
+        &cold_keypair,
+        None,
+    );
+    assert_eq!(AccountType::verify_incoming_transaction(&tx), Ok(()));
+    assert!(tx.sets_validator_commission());
+
+    tx.validity_start_height = fork;
+    assert!(!tx.is_valid_at(fork - 1));
+    assert!(tx.is_valid_at(fork));
+
+    let mut tx = make_incoming_tx(update_validator(Some(1_000)), 0);
+    tx.validity_start_height = fork;
+    assert!(!tx.is_valid_at(fork - 1));
+    assert!(tx.is_valid_at(fork));
+
+    let mut tx = make_incoming_tx(update_validator(None), 0);
+    tx.validity_start_height = fork;
+    assert!(!tx.sets_validator_commission());
+    assert!(tx.is_valid_at(fork - 1));
+
+    // The larger recipient data is only valid from the fork on as well.
+    tx.recipient_data = vec![0; Policy::MAX_TX_RECIPIENT_DATA_SIZE];
+    assert!(tx.is_valid_at(fork - 1));
+    tx.recipient_data = vec![0; Policy::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK];
+    assert!(!tx.is_valid_at(fork - 1));
+    assert!(tx.is_valid_at(fork));
+}
+
 #[test]
 fn deactivate_validator() {
     let mut rng = test_rng(false);
@@ -683,7 +817,10 @@ fn outgoing_transaction_data_is_below_max_transaction_sender() {
 
 #[test]
 fn incoming_transaction_data_is_below_max_transaction_sender() {
-    assert!(Policy::MAX_TX_RECIPIENT_DATA_SIZE >= IncomingStakingTransactionData::MAX_SIZE);
+    assert!(
+        Policy::MAX_TX_RECIPIENT_DATA_SIZE_COMMISSION_FORK
+            >= IncomingStakingTransactionData::MAX_SIZE
+    );
 }
 
 fn make_incoming_tx(data: IncomingStakingTransactionData, value: u64) -> Transaction {
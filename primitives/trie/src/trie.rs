@@ -108,9 +108,12 @@ impl<T: TrieTable> MerkleRadixTrie<T> {
 
         db.create_regular_table(&tree.table);
 
-        let mut txn = db.write_transaction();
-        tree.init_root(&mut (&mut txn).into(), incomplete);
-        txn.commit();
+        // The root of a read-only database is read as it is.
+        if !db.is_read_only() {
+            let mut txn = db.write_transaction();
+            tree.init_root(&mut (&mut txn).into(), incomplete);
+            txn.commit();
+        }
 
         tree
     }
@@ -62,6 +62,10 @@ enum Command {
     /// Shows the zkp information.
     #[clap(flatten)]
     Zkp(ZKPComponentCommand),
+
+    /// Computes and sends the reward payouts of staking pools.
+    #[clap(flatten)]
+    Payout(PayoutCommand),
 }
 
 impl Command {
@@ -75,6 +79,7 @@ impl Command {
             Command::Mempool(command) => command.handle_subcommand(client).await,
             Command::Validator(command) => command.handle_subcommand(client).await,
             Command::Zkp(command) => command.handle_subcommand(client).await,
+            Command::Payout(command) => command.handle_subcommand(client).await,
         }
     }
 }
@@ -1,8 +1,14 @@
+use std::{fs, path::PathBuf};
+
 use anyhow::Error;
 use async_trait::async_trait;
 use clap::Parser;
 use nimiq_keys::{Address, Ed25519PublicKey, Ed25519Signature};
-use nimiq_rpc_interface::{blockchain::BlockchainInterface, wallet::WalletInterface};
+use nimiq_primitives::coin::Coin;
+use nimiq_rpc_interface::{
+    blockchain::BlockchainInterface, types::AccountType, wallet::WalletInterface,
+};
+use url::Url;
 
 use crate::Client;
 
@@ -52,10 +58,49 @@ pub enum AccountCommand {
         #[clap(short = 'P', long)]
         password: Option<String>,
 
+        /// Locks the account again after it hasn't been used for this many seconds.
+        /// Defaults to the idle timeout configured for the node.
+        #[clap(short, long)]
+        duration: Option<u64>,
+
         /// The account's address.
         address: Address,
     },
 
+    /// Changes the passphrase of the wallet and re-encrypts all accounts with it.
+    ChangePassphrase {
+        /// The current passphrase.
+        #[clap(short = 'P', long)]
+        password: Option<String>,
+
+        /// The new passphrase.
+        new_password: String,
+    },
+
+    /// Exports all accounts of the wallet into an encrypted backup file.
+    ExportBackup {
+        /// The passphrase of the wallet. The backup remains encrypted with it.
+        #[clap(short = 'P', long)]
+        password: Option<String>,
+
+        /// The file to write the backup to.
+        file: PathBuf,
+    },
+
+    /// Imports all accounts from an encrypted backup file.
+    ImportBackup {
+        /// The passphrase the backup was encrypted with.
+        #[clap(short = 'B', long)]
+        backup_password: Option<String>,
+
+        /// The passphrase of the wallet.
+        #[clap(short = 'P', long)]
+        password: Option<String>,
+
+        /// The file to read the backup from.
+        file: PathBuf,
+    },
+
     /// Checks if account is unlocked.
     IsUnlocked {
         /// The account's address.
@@ -92,14 +137,43 @@ pub enum AccountCommand {
         is_hex: bool,
     },
 
-    /// Queries all accounts in the accounts tree
-    GetAll {},
+    /// Queries all accounts, validators and stakers in the accounts tree. They are fetched page by
+    /// page.
+    GetAll {
+        /// Only lists accounts, validators or stakers of the given types. If empty it does not
+        /// filter by type.
+        #[clap(short = 't', long, value_enum)]
+        account_types: Vec<AccountType>,
+
+        /// Only lists accounts with at least this balance (in NIM).
+        #[clap(short, long)]
+        min_balance: Option<Coin>,
+    },
 
     /// Queries the account state (e.g. account balance for basic accounts).
     Get {
         /// The account's address.
         address: Address,
     },
+
+    /// Queries what can currently be done with the account, e.g. the withdrawable balance of a
+    /// vesting contract or the release of the inactive stake of the staker at this address.
+    Introspect {
+        /// The account's address.
+        address: Address,
+    },
+
+    /// Queries the accounts state together with a Merkle proof of their inclusion in the accounts
+    /// tree at the current head.
+    GetWithProof {
+        /// The accounts' addresses.
+        #[clap(required = true)]
+        addresses: Vec<Address>,
+
+        /// Verifies the proof against the head block fetched from the node at this URL.
+        #[clap(short, long)]
+        verify_url: Option<Url>,
+    },
 }
 
 #[async_trait]
@@ -134,17 +208,49 @@ impl HandleSubcommand for AccountCommand {
                 client.wallet.lock_account(address).await?;
             }
             AccountCommand::Unlock {
-                address, password, ..
+                address,
+                password,
+                duration,
             } => {
-                // TODO: Duration
                 println!(
                     "{:#?}",
                     client
                         .wallet
-                        .unlock_account(address, password, None)
+                        .unlock_account(address, password, duration)
                         .await?
                 );
             }
+            AccountCommand::ChangePassphrase {
+                password,
+                new_password,
+            } => {
+                let num_accounts = client
+                    .wallet
+                    .change_passphrase(password, new_password)
+                    .await?
+                    .data;
+                println!("Re-encrypted {num_accounts} accounts");
+            }
+            AccountCommand::ExportBackup { password, file } => {
+                let backup = client.wallet.export_backup(password).await?.data;
+                fs::write(&file, hex::decode(backup)?)?;
+                println!("Wrote backup to {}", file.display());
+            }
+            AccountCommand::ImportBackup {
+                backup_password,
+                password,
+                file,
+            } => {
+                let backup = hex::encode(fs::read(&file)?);
+                let addresses = client
+                    .wallet
+                    .import_backup(backup, backup_password, password)
+                    .await?
+                    .data;
+                for address in &addresses {
+                    println!("{}", address.to_user_friendly_address());
+                }
+            }
             AccountCommand::IsUnlocked { address } => {
                 println!("{:#?}", client.wallet.is_account_unlocked(address).await?);
             }
@@ -178,9 +284,65 @@ impl HandleSubcommand for AccountCommand {
                     client.blockchain.get_account_by_address(address).await?
                 );
             }
+            AccountCommand::Introspect { address } => {
+                println!(
+                    "{:#?}",
+                    client
+                        .blockchain
+                        .get_account_introspection_by_address(address)
+                        .await?
+                );
+            }
 
-            AccountCommand::GetAll {} => {
-                println!("{:#?}", client.blockchain.get_accounts().await?);
+            AccountCommand::GetWithProof {
+                addresses,
+                verify_url,
+            } => {
+                let accounts = client.blockchain.get_accounts_with_proof(addresses).await?;
+                println!("{accounts:#?}");
+
+                if let Some(verify_url) = verify_url {
+                    let mut verify_client = Client::new(verify_url.clone(), None).await?;
+                    let block = verify_client
+                        .blockchain
+                        .get_block_by_hash(accounts.data.block_hash.clone(), Some(false))
+                        .await;
+                    verify_client.close().await;
+
+                    accounts.data.verify(&block?.data)?;
+                    println!(
+                        "Proof verified against block #{} from {}",
+                        accounts.data.block_number, verify_url
+                    );
+                }
+            }
+
+            AccountCommand::GetAll {
+                account_types,
+                min_balance,
+            } => {
+                let mut start_key = None;
+                loop {
+                    let page = client
+                        .blockchain
+                        .get_account_page(start_key, None, Some(account_types.clone()), min_balance)
+                        .await?
+                        .data;
+                    for account in page.accounts {
+                        println!("{account:#?}");
+                    }
+                    for validator in page.validators {
+                        println!("{validator:#?}");
+                    }
+                    for staker in page.stakers {
+                        println!("{staker:#?}");
+                    }
+
+                    start_key = page.next_key;
+                    if start_key.is_none() {
+                        break;
+                    }
+                }
             }
         }
 
@@ -41,6 +41,20 @@ pub enum BlockchainCommand {
         include_body: bool,
     },
 
+    /// Query the first block with a timestamp at or after the given timestamp.
+    BlockAtTimestamp {
+        /// The timestamp in milliseconds.
+        timestamp: u64,
+
+        /// Only returns the block number. Block numbers after the head block are estimated.
+        #[clap(short, long)]
+        estimate: bool,
+
+        /// Whether to include the block body
+        #[clap(short = 'b', long, conflicts_with = "estimate")]
+        include_body: bool,
+    },
+
     /// Query a transaction from the blockchain.
     Transaction {
         /// The transaction hash.
@@ -143,6 +157,21 @@ pub enum BlockchainCommand {
     /// Lists the current stakes from the staking contract.
     Stakes {},
 
+    /// Returns the money supply broken down into the balance of the burn address, coins in vesting
+    /// and HTLC contracts and staked coins.
+    /// IMPORTANT: This is a very expensive operation, iterating over all existing accounts.
+    Supply {},
+
+    /// Returns the block reward of the given batch, not including the transaction fees.
+    BatchReward {
+        /// The batch to retrieve the reward of.
+        batch_number: u32,
+    },
+
+    /// Returns the signal data of the current validators weighted by their slots, together with the
+    /// progress of each signal toward the configured thresholds.
+    SignalTally {},
+
     /// Follow the head of the blockchain.
     FollowHead {
         /// Show the full block instead of only the hash.
@@ -156,6 +185,9 @@ pub enum BlockchainCommand {
         address: Address,
     },
 
+    /// Follow validator signals reaching one of the configured thresholds at election blocks.
+    FollowSignalThresholds {},
+
     /// Follow the logs associated with the specified addresses and of any of the log types given.
     /// If no addresses or no log types are provided it fetches all logs.
     FollowLogsOfAddressesAndTypes {
@@ -193,6 +225,29 @@ impl HandleSubcommand for BlockchainCommand {
                 }?;
                 println!("{block:#?}")
             }
+            BlockchainCommand::BlockAtTimestamp {
+                timestamp,
+                estimate,
+                include_body,
+            } => {
+                if estimate {
+                    println!(
+                        "{:#?}",
+                        client
+                            .blockchain
+                            .estimate_block_at_timestamp(timestamp)
+                            .await?
+                    );
+                } else {
+                    println!(
+                        "{:#?}",
+                        client
+                            .blockchain
+                            .get_block_at_timestamp(timestamp, Some(include_body))
+                            .await?
+                    );
+                }
+            }
             BlockchainCommand::BlockNumber {} => {
                 println!("{:#?}", client.blockchain.get_block_number().await?)
             }
@@ -323,6 +378,18 @@ impl HandleSubcommand for BlockchainCommand {
             BlockchainCommand::Stakes {} => {
                 println!("{:#?}", client.blockchain.get_active_validators().await?);
             }
+            BlockchainCommand::Supply {} => {
+                println!("{:#?}", client.blockchain.get_supply().await?);
+            }
+            BlockchainCommand::BatchReward { batch_number } => {
+                println!(
+                    "{:#?}",
+                    client.blockchain.get_batch_reward(batch_number).await?
+                );
+            }
+            BlockchainCommand::SignalTally {} => {
+                println!("{:#?}", client.blockchain.get_signal_tally().await?);
+            }
 
             BlockchainCommand::FollowHead { block: show_block } => {
                 if show_block {
@@ -351,6 +418,12 @@ impl HandleSubcommand for BlockchainCommand {
                     println!("{validator:#?}");
                 }
             }
+            BlockchainCommand::FollowSignalThresholds {} => {
+                let mut stream = client.blockchain.subscribe_for_signal_thresholds().await?;
+                while let Some(event) = stream.next().await {
+                    println!("{event:#?}");
+                }
+            }
             BlockchainCommand::FollowLogsOfAddressesAndTypes {
                 addresses,
                 log_types,
@@ -2,6 +2,7 @@ pub use accounts_subcommands::{AccountCommand, HandleSubcommand};
 pub use blockchain_subcommands::BlockchainCommand;
 pub use mempool_subcommands::MempoolCommand;
 pub use network_subcommands::NetworkCommand;
+pub use payout_subcommands::PayoutCommand;
 pub use policy_subcommands::PolicyCommand;
 pub use transactions_subcommands::TransactionCommand;
 pub use validator_subcommands::ValidatorCommand;
@@ -11,6 +12,7 @@ mod accounts_subcommands;
 mod blockchain_subcommands;
 mod mempool_subcommands;
 mod network_subcommands;
+mod payout_subcommands;
 mod policy_subcommands;
 mod transactions_subcommands;
 mod validator_subcommands;
@@ -17,6 +17,43 @@ pub enum NetworkCommand {
         #[clap(short, long)]
         count: bool,
     },
+
+    /// Returns details about a connected peer or, if no peer ID is given, about all of them.
+    PeerDetails {
+        /// The peer ID of the peer.
+        peer_id: Option<String>,
+    },
+
+    /// Dials the given multiaddress.
+    Dial {
+        /// The multiaddress to dial.
+        address: String,
+    },
+
+    /// Disconnects from the given peer.
+    Disconnect {
+        /// The peer ID of the peer.
+        peer_id: String,
+    },
+
+    /// Bans a peer ID or IP address and closes all connections to it.
+    Ban {
+        /// The peer ID or IP address to ban.
+        target: String,
+
+        /// The duration of the ban in seconds. The ban does not expire if omitted.
+        #[clap(long)]
+        ttl: Option<u64>,
+    },
+
+    /// Removes the ban of a peer ID or IP address.
+    Unban {
+        /// The peer ID or IP address to unban.
+        target: String,
+    },
+
+    /// Lists all manual bans.
+    Bans {},
 }
 
 #[async_trait]
@@ -33,6 +70,29 @@ impl HandleSubcommand for NetworkCommand {
                     println!("{:#?}", client.network.get_peer_list().await?);
                 }
             }
+            NetworkCommand::PeerDetails { peer_id } => match peer_id {
+                Some(peer_id) => {
+                    println!("{:#?}", client.network.get_peer_details(peer_id).await?);
+                }
+                None => {
+                    println!("{:#?}", client.network.get_peers_details().await?);
+                }
+            },
+            NetworkCommand::Dial { address } => {
+                println!("{:#?}", client.network.dial_address(address).await?);
+            }
+            NetworkCommand::Disconnect { peer_id } => {
+                println!("{:#?}", client.network.disconnect_peer(peer_id).await?);
+            }
+            NetworkCommand::Ban { target, ttl } => {
+                println!("{:#?}", client.network.ban(target, ttl).await?);
+            }
+            NetworkCommand::Unban { target } => {
+                println!("{:#?}", client.network.unban(target).await?);
+            }
+            NetworkCommand::Bans {} => {
+                println!("{:#?}", client.network.get_bans().await?);
+            }
         }
         Ok(client)
     }
@@ -0,0 +1,313 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Error};
+use async_trait::async_trait;
+use clap::{Parser, ValueEnum};
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, policy::Policy};
+use nimiq_rpc_interface::{
+    blockchain::BlockchainInterface,
+    consensus::ConsensusInterface,
+    mempool::MempoolInterface,
+    policy::PolicyInterface,
+    types::{Inherent, ValidityStartHeight},
+};
+use nimiq_serde::{Deserialize, Serialize};
+use nimiq_transaction::Transaction;
+use nimiq_transaction_builder::payout::{EpochRewards, PayoutPlan, StakerBalance};
+
+use super::{accounts_subcommands::HandleSubcommand, transactions_subcommands::TxCommon};
+use crate::Client;
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum PlanFormat {
+    Csv,
+    Json,
+}
+
+/// The smallest fee per byte, in Luna, the payout transactions pay.
+const MIN_FEE_PER_BYTE: f64 = 1.0;
+
+#[derive(Debug, Parser)]
+pub enum PayoutCommand {
+    /// Computes how the rewards of a staking pool's validator in a range of epochs are split
+    /// between the pool and its stakers, and optionally pays them out. The stake of each staker is
+    /// taken from the election block preceding the epoch, as persisted by the node: history nodes
+    /// keep it for all election blocks they pushed, other full nodes only for the last two.
+    /// Epochs whose stake isn't available are rejected before any payout is made.
+    PoolPayout {
+        /// The address of the pool's validator.
+        validator_address: Address,
+
+        /// The first epoch to pay out.
+        #[clap(long)]
+        from_epoch: u32,
+
+        /// The last epoch to pay out. If absent only `from_epoch` is paid out.
+        #[clap(long)]
+        to_epoch: Option<u32>,
+
+        /// The commission the pool keeps, in basis points of the rewards.
+        #[clap(long)]
+        commission: u16,
+
+        /// The format of the payout plan.
+        #[clap(long, value_enum, default_value = "csv")]
+        format: PlanFormat,
+
+        /// Writes the payout plan to this file instead of printing it.
+        #[clap(short, long)]
+        output: Option<PathBuf>,
+
+        /// Pays out the stakers from this address. This wallet must be already unlocked. The
+        /// transactions of the whole plan are created and signed by the node before the first one
+        /// is sent. Every transaction pays at least one Luna per byte, or the minimum fee per byte
+        /// of the node's mempool if that is higher, unless the given fee is even higher.
+        /// If absent only the payout plan is created.
+        #[clap(long)]
+        sender_wallet: Option<Address>,
+
+        #[clap(flatten)]
+        tx_commons: TxCommon,
+    },
+}
+
+impl PayoutCommand {
+    /// Returns the election block that selected the validators of the given epoch.
+    async fn election_block_of(client: &mut Client, epoch: u32) -> Result<u32, Error> {
+        if epoch == 0 {
+            bail!("There are no rewards for epoch 0");
+        }
+        Ok(client.policy.get_election_block_of(epoch - 1).await?.data)
+    }
+
+    /// Collects the active balances of the validator's stakers at the election block that
+    /// selected the validators of the given epoch.
+    async fn epoch_stakers(
+        client: &mut Client,
+        validator_address: &Address,
+        epoch: u32,
+    ) -> Result<Vec<StakerBalance>, Error> {
+        let election_block_number = Self::election_block_of(client, epoch).await?;
+        let shares = match client
+            .blockchain
+            .get_staker_shares_by_validator_address(
+                validator_address.clone(),
+                Some(election_block_number),
+            )
+            .await
+        {
+            Ok(shares) => shares.data,
+            Err(error) => bail!(
+                "The stake of epoch {epoch} at election block {election_block_number} is not \
+                 available: {error}"
+            ),
+        };
+
+        Ok(shares
+            .shares
+            .into_iter()
+            .map(|share| StakerBalance {
+                address: share.address,
+                active_balance: share.active_balance,
+            })
+            .collect())
+    }
+
+    /// Collects the rewards the validator received for the given epoch.
+    async fn epoch_rewards(
+        client: &mut Client,
+        validator_address: &Address,
+        epoch: u32,
+        stakers: Vec<StakerBalance>,
+    ) -> Result<EpochRewards, Error> {
+        let election_block_number = Self::election_block_of(client, epoch).await?;
+        let first_block = client.policy.get_first_block_of(epoch).await?.data;
+        let first_batch = client.policy.get_batch_at(first_block).await?.data;
+        let last_block = client.policy.get_election_block_of(epoch).await?.data;
+        let last_batch = client.policy.get_batch_at(last_block).await?.data;
+
+        // The rewards of a batch are paid out in the macro block of the following batch.
+        let payout_block = client.policy.get_macro_block_of(last_batch + 1).await?.data;
+        if client.blockchain.get_block_number().await?.data < payout_block {
+            bail!("The rewards of epoch {epoch} were not paid out yet");
+        }
+
+        let mut reward = Coin::ZERO;
+        for batch_number in first_batch + 1..=last_batch + 1 {
+            let inherents = client
+                .blockchain
+                .get_inherents_by_batch_number(batch_number)
+                .await?
+                .data;
+            for inherent in inherents {
+                // Rewards paid to the staking contract were already distributed on-chain.
+                if let Inherent::Reward {
+                    validator_address: address,
+                    target,
+                    value,
+                    ..
+                } = inherent
+                {
+                    if address == *validator_address && target != Policy::STAKING_CONTRACT_ADDRESS {
+                        reward += value;
+                    }
+                }
+            }
+        }
+
+        Ok(EpochRewards {
+            epoch,
+            election_block_number,
+            reward,
+            stakers,
+        })
+    }
+
+    /// Has the node create and sign the payout transactions of the whole plan from the unlocked
+    /// `sender_wallet`. The fee of every transaction covers the larger of [`MIN_FEE_PER_BYTE`] and
+    /// the minimum fee per byte of the node's mempool, unless `fee` is higher.
+    async fn payout_transactions(
+        client: &mut Client,
+        plan: &PayoutPlan,
+        sender_wallet: &Address,
+        fee: Coin,
+        validity_start_height: u32,
+    ) -> Result<Vec<Transaction>, Error> {
+        let transactions =
+            Self::create_transactions(client, plan, sender_wallet, fee, validity_start_height)
+                .await?;
+        let Some(size) = transactions.iter().map(|tx| tx.serialized_size()).max() else {
+            return Ok(transactions);
+        };
+
+        let fee_per_byte = client
+            .mempool
+            .get_min_fee_per_byte()
+            .await?
+            .data
+            .max(MIN_FEE_PER_BYTE);
+        let min_fee = Coin::try_from((size as f64 * fee_per_byte).ceil() as u64)?;
+        if fee >= min_fee {
+            return Ok(transactions);
+        }
+
+        Self::create_transactions(client, plan, sender_wallet, min_fee, validity_start_height).await
+    }
+
+    /// Has the node create and sign a basic transaction for each payout of the plan.
+    async fn create_transactions(
+        client: &mut Client,
+        plan: &PayoutPlan,
+        sender_wallet: &Address,
+        fee: Coin,
+        validity_start_height: u32,
+    ) -> Result<Vec<Transaction>, Error> {
+        let mut transactions = Vec::with_capacity(plan.payouts.len());
+        for payout in &plan.payouts {
+            let raw_tx = client
+                .consensus
+                .create_basic_transaction(
+                    sender_wallet.clone(),
+                    payout.recipient.clone(),
+                    payout.value,
+                    fee,
+                    ValidityStartHeight::Absolute(validity_start_height),
+                )
+                .await?
+                .data;
+            transactions.push(Transaction::deserialize_from_vec(&hex::decode(raw_tx)?)?);
+        }
+        Ok(transactions)
+    }
+}
+
+#[async_trait]
+impl HandleSubcommand for PayoutCommand {
+    async fn handle_subcommand(self, mut client: Client) -> Result<Client, Error> {
+        match self {
+            PayoutCommand::PoolPayout {
+                validator_address,
+                from_epoch,
+                to_epoch,
+                commission,
+                format,
+                output,
+                sender_wallet,
+                tx_commons,
+            } => {
+                let to_epoch = to_epoch.unwrap_or(from_epoch);
+                if to_epoch < from_epoch {
+                    bail!("The last epoch must not be before the first epoch");
+                }
+
+                // Reject epochs whose stake isn't available before collecting any rewards.
+                let mut stakers = vec![];
+                for epoch in from_epoch..=to_epoch {
+                    stakers
+                        .push(Self::epoch_stakers(&mut client, &validator_address, epoch).await?);
+                }
+
+                let mut epochs = vec![];
+                for (epoch, stakers) in (from_epoch..=to_epoch).zip(stakers) {
+                    epochs.push(
+                        Self::epoch_rewards(&mut client, &validator_address, epoch, stakers)
+                            .await?,
+                    );
+                }
+                let plan = PayoutPlan::compute(validator_address, commission, epochs)?;
+
+                let plan_output = match format {
+                    PlanFormat::Csv => plan.to_csv(),
+                    PlanFormat::Json => serde_json::to_string_pretty(&plan)?,
+                };
+                match output {
+                    Some(path) => fs::write(path, plan_output)?,
+                    None => print!("{plan_output}"),
+                }
+
+                let Some(sender_wallet) = sender_wallet else {
+                    return Ok(client);
+                };
+
+                // All transactions of the plan share the same validity start height.
+                let block_number = client.blockchain.get_block_number().await?.data;
+                let transactions = Self::payout_transactions(
+                    &mut client,
+                    &plan,
+                    &sender_wallet,
+                    tx_commons.fee,
+                    tx_commons.validity_start_height.block_number(block_number),
+                )
+                .await?;
+
+                // Make sure that the whole plan can be paid before sending the first transaction.
+                let balance = client
+                    .blockchain
+                    .get_account_by_address(sender_wallet.clone())
+                    .await?
+                    .data
+                    .balance;
+                let total = transactions
+                    .iter()
+                    .fold(Coin::ZERO, |sum, tx| sum + tx.value + tx.fee);
+                if balance < total {
+                    bail!(
+                        "The balance of {sender_wallet} ({balance}) doesn't cover the payouts and their fees ({total})"
+                    );
+                }
+
+                for tx in transactions {
+                    let raw_tx = hex::encode(tx.serialize_to_vec());
+                    if tx_commons.dry {
+                        println!("{raw_tx}");
+                    } else {
+                        let txid = client.consensus.send_raw_transaction(raw_tx).await?;
+                        println!("{txid:#?}");
+                    }
+                }
+            }
+        }
+        Ok(client)
+    }
+}
@@ -57,6 +57,11 @@ pub enum ValidatorCommand {
         /// The signal data showed by the validator.
         signal_data: String,
 
+        /// The share of the rewards kept by the validator, in basis points. The rest is
+        /// distributed to the stakers delegating to it. Defaults to keeping all rewards.
+        #[clap(long)]
+        commission: Option<u16>,
+
         #[clap(flatten)]
         tx_commons: TxCommon,
     },
@@ -88,6 +93,25 @@ pub enum ValidatorCommand {
         #[clap(short = 'd', long)]
         new_signal_data: Option<String>,
 
+        /// The new share of the rewards kept by the validator, in basis points.
+        #[clap(long)]
+        new_commission: Option<u16>,
+
+        #[clap(flatten)]
+        tx_commons: TxCommon,
+    },
+
+    /// Sends a transaction to the network to change the signal data of this validator, e.g. to
+    /// signal readiness for a coordinated upgrade. This is a shorthand for `update-validator` that
+    /// leaves all other fields unchanged. Use the empty string to stop signaling.
+    /// The sender wallet must be unlocked prior to this command.
+    Signal {
+        /// The fee will be paid from this address. This wallet must be already unlocked.
+        sender_wallet: Address,
+
+        /// The signal data showed by the validator. The empty string removes the signal data.
+        signal_data: String,
+
         #[clap(flatten)]
         tx_commons: TxCommon,
     },
@@ -159,6 +183,7 @@ impl HandleSubcommand for ValidatorCommand {
                 voting_secret_key,
                 reward_address,
                 signal_data,
+                commission,
                 tx_commons,
             } => {
                 if tx_commons.dry {
@@ -173,6 +198,7 @@ impl HandleSubcommand for ValidatorCommand {
                             signal_data,
                             tx_commons.fee,
                             tx_commons.validity_start_height,
+                            commission,
                         )
                         .await?;
                     println!("{tx:#?}");
@@ -188,6 +214,7 @@ impl HandleSubcommand for ValidatorCommand {
                             signal_data,
                             tx_commons.fee,
                             tx_commons.validity_start_height,
+                            commission,
                         )
                         .await?;
                     println!("{txid:#?}");
@@ -200,6 +227,7 @@ impl HandleSubcommand for ValidatorCommand {
                 new_voting_secret_key,
                 new_reward_address,
                 new_signal_data,
+                new_commission,
                 tx_commons,
             } => {
                 let validator_address = client.validator.get_address().await?.data;
@@ -215,6 +243,7 @@ impl HandleSubcommand for ValidatorCommand {
                             new_signal_data,
                             tx_commons.fee,
                             tx_commons.validity_start_height,
+                            new_commission,
                         )
                         .await?;
                     println!("{tx:#?}");
@@ -230,6 +259,48 @@ impl HandleSubcommand for ValidatorCommand {
                             new_signal_data,
                             tx_commons.fee,
                             tx_commons.validity_start_height,
+                            new_commission,
+                        )
+                        .await?;
+                    println!("{txid:#?}");
+                }
+            }
+
+            ValidatorCommand::Signal {
+                sender_wallet,
+                signal_data,
+                tx_commons,
+            } => {
+                let validator_address = client.validator.get_address().await?.data;
+                if tx_commons.dry {
+                    let tx = client
+                        .consensus
+                        .create_update_validator_transaction(
+                            sender_wallet,
+                            validator_address,
+                            None,
+                            None,
+                            None,
+                            Some(signal_data),
+                            tx_commons.fee,
+                            tx_commons.validity_start_height,
+                            None,
+                        )
+                        .await?;
+                    println!("{tx:#?}");
+                } else {
+                    let txid = client
+                        .consensus
+                        .send_update_validator_transaction(
+                            sender_wallet,
+                            validator_address,
+                            None,
+                            None,
+                            None,
+                            Some(signal_data),
+                            tx_commons.fee,
+                            tx_commons.validity_start_height,
+                            None,
                         )
                         .await?;
                     println!("{txid:#?}");
@@ -2,10 +2,13 @@ use async_trait::async_trait;
 use futures::stream::BoxStream;
 use nimiq_hash::Blake2bHash;
 use nimiq_keys::Address;
+use nimiq_primitives::coin::Coin;
 
 use crate::types::{
-    Account, Block, BlockLog, BlockchainState, ExecutedTransaction, Inherent, LogType,
-    PenalizedSlots, RPCData, RPCResult, Slot, Staker, Validator,
+    Account, AccountIntrospection, AccountPage, AccountType, AccountsWithProof, BatchReward, Block,
+    BlockLog, BlockchainState, ChainEvent, Equivocation, ExecutedTransaction, Inherent, LogType,
+    PenalizedSlots, RPCData, RPCResult, SignalTally, SignalThresholdCrossed, Slot, Staker,
+    StakerEvent, StakerOrder, StakerPage, StakerShares, Supply, Validator,
 };
 
 #[nimiq_jsonrpc_derive::proxy(name = "BlockchainProxy", rename_all = "camelCase")]
@@ -46,6 +49,27 @@ pub trait BlockchainInterface {
         include_body: Option<bool>,
     ) -> RPCResult<Block, (), Self::Error>;
 
+    /// Returns the first block on the main chain with a timestamp (in milliseconds) at or after
+    /// the given timestamp. Nodes only know the blocks they stored themselves, e.g. not the blocks
+    /// before the macro block they synced to with a zero-knowledge proof. Full nodes prune older
+    /// micro blocks and history nodes only know the transactions of history synced micro blocks,
+    /// but `estimateBlockAtTimestamp` still returns their numbers as long as the timestamps of
+    /// the preceding blocks are known.
+    async fn get_block_at_timestamp(
+        &mut self,
+        timestamp: u64,
+        include_body: Option<bool>,
+    ) -> RPCResult<Block, (), Self::Error>;
+
+    /// Returns the number of the first block with a timestamp (in milliseconds) at or after the
+    /// given timestamp. Up to the timestamp of the head block, the block number is the same as
+    /// the one of `getBlockAtTimestamp`. For later timestamps it is estimated, assuming that the
+    /// following blocks are produced every `BLOCK_SEPARATION_TIME` milliseconds.
+    async fn estimate_block_at_timestamp(
+        &mut self,
+        timestamp: u64,
+    ) -> RPCResult<u32, BlockchainState, Self::Error>;
+
     /// Returns information about the proposer slot at the given block height and offset. The
     /// offset is optional, it will default to getting the offset for the existing block
     /// at the given height.
@@ -90,6 +114,15 @@ pub trait BlockchainInterface {
         batch_number: u32,
     ) -> RPCResult<Vec<Inherent>, (), Self::Error>;
 
+    /// Returns all equivocations whose proofs were included in the given epoch, together with the
+    /// jail and penalize inherents that resulted from them. Equivocations of the given epoch whose
+    /// proofs were observed by this node but haven't been included are returned as observed. Note
+    /// that this only considers blocks in the main chain and requires the history index.
+    async fn get_equivocations_by_epoch_number(
+        &mut self,
+        epoch_number: u32,
+    ) -> RPCResult<Vec<Equivocation>, (), Self::Error>;
+
     /// Returns the hashes for the latest transactions for a given address. All the transactions
     /// where the given address is listed as a recipient or as a sender are considered. Reward
     /// transactions are also returned. It has an option to specify the maximum number of hashes to
@@ -116,11 +149,58 @@ pub trait BlockchainInterface {
         address: Address,
     ) -> RPCResult<Account, BlockchainState, Self::Error>;
 
+    /// Returns what the consensus rules derive from the state of the account at the given
+    /// address for the next block: the vested and withdrawable amounts of vesting contracts, the
+    /// ways an HTLC can currently be resolved and, if there is a staker at the address, the
+    /// release of its inactive balance and the staking operations it can currently perform.
+    async fn get_account_introspection_by_address(
+        &mut self,
+        address: Address,
+    ) -> RPCResult<AccountIntrospection, BlockchainState, Self::Error>;
+
+    /// Fetches the accounts at the given addresses together with a Merkle proof of their inclusion
+    /// in the accounts trie at the current head. The proof can be verified against the state root
+    /// of the head block obtained from a different node. At most 100 addresses can be requested
+    /// at once.
+    async fn get_accounts_with_proof(
+        &mut self,
+        addresses: Vec<Address>,
+    ) -> RPCResult<AccountsWithProof, BlockchainState, Self::Error>;
+
+    /// Returns a page of the items in the accounts tree, starting at the given key of the
+    /// accounts tree or at the beginning of the tree if none is given. Besides the accounts, the
+    /// page contains the validators and stakers of the staking contract. Items can be filtered by
+    /// their type and a minimum balance, which is compared to the total stake of validators and
+    /// the active balance of stakers. The page size defaults to 500 and can be at most 1000.
+    /// A page can contain fewer items even if there are more items to iterate; the iteration
+    /// is only complete once the returned `nextKey` is `null`.
+    async fn get_account_page(
+        &mut self,
+        start_key: Option<String>,
+        limit: Option<u32>,
+        account_types: Option<Vec<AccountType>>,
+        min_balance: Option<Coin>,
+    ) -> RPCResult<AccountPage, BlockchainState, Self::Error>;
+
     /// Fetches all accounts in the accounts tree.
     /// IMPORTANT: This operation iterates over all accounts in the accounts tree
     /// and thus is extremely computationally expensive.
     async fn get_accounts(&mut self) -> RPCResult<Vec<Account>, BlockchainState, Self::Error>;
 
+    /// Returns the money supply at the current head broken down into the balance of the burn
+    /// address, coins in vesting and HTLC contracts and staked coins.
+    /// IMPORTANT: This operation iterates over all accounts in the accounts tree as well as all
+    /// validators and stakers of the staking contract and thus is extremely computationally
+    /// expensive. The result is cached until the next block.
+    async fn get_supply(&mut self) -> RPCResult<Supply, BlockchainState, Self::Error>;
+
+    /// Returns the block reward for the given batch, broken down into the maximum reward and the
+    /// penalty for producing the batch late. The transaction fees are not included.
+    async fn get_batch_reward(
+        &mut self,
+        batch_number: u32,
+    ) -> RPCResult<BatchReward, BlockchainState, Self::Error>;
+
     /// Returns a collection of the currently active validator's addresses and balances.
     async fn get_active_validators(
         &mut self,
@@ -157,12 +237,43 @@ pub trait BlockchainInterface {
         address: Address,
     ) -> RPCResult<Vec<Staker>, BlockchainState, Self::Error>;
 
+    /// Returns a page of the stakers delegating to a given validator, together with the total
+    /// active, inactive and retired balance of all its stakers. It has options to specify the
+    /// offset of the page and the maximum number of stakers to return, which default to 0 and 500,
+    /// as well as the order of the stakers, which defaults to ordering them by address.
+    /// IMPORTANT: This operation iterates over all stakers of the staking contract
+    /// and thus is extremely computationally expensive.
+    async fn get_staker_page_by_validator_address(
+        &mut self,
+        address: Address,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        order: Option<StakerOrder>,
+    ) -> RPCResult<StakerPage, BlockchainState, Self::Error>;
+
+    /// Returns the share of each staker in the stake of a given validator. If an election block
+    /// number is given, the shares at that election block are returned. These are persisted when
+    /// the election block is pushed: history nodes keep them for all election blocks they pushed,
+    /// other full nodes only for the last two election blocks.
+    /// IMPORTANT: Without an election block number, this operation iterates over all stakers of
+    /// the staking contract and thus is extremely computationally expensive.
+    async fn get_staker_shares_by_validator_address(
+        &mut self,
+        address: Address,
+        election_block_number: Option<u32>,
+    ) -> RPCResult<StakerShares, BlockchainState, Self::Error>;
+
     /// Tries to fetch a staker information given its address.
     async fn get_staker_by_address(
         &mut self,
         address: Address,
     ) -> RPCResult<Staker, BlockchainState, Self::Error>;
 
+    /// Returns the `signal_data` values of the current validators weighted by their slots in the
+    /// current election, together with the progress of each signal toward the configured
+    /// thresholds.
+    async fn get_signal_tally(&mut self) -> RPCResult<SignalTally, BlockchainState, Self::Error>;
+
     /// Subscribes to new block events (retrieves the full block).
     #[stream]
     async fn subscribe_for_head_block(
@@ -176,6 +287,15 @@ pub trait BlockchainInterface {
         &mut self,
     ) -> Result<BoxStream<'static, RPCData<Blake2bHash, ()>>, Self::Error>;
 
+    /// Subscribes to changes of the main chain. Unlike the head block subscriptions, this also
+    /// reports which blocks and transactions were reverted when the main chain switches to a
+    /// different branch or adopts a diverging history, as well as the finalization of batches and
+    /// epochs.
+    #[stream]
+    async fn subscribe_for_chain_events(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<ChainEvent, ()>>, Self::Error>;
+
     /// Subscribes to pre epoch validators events.
     #[stream]
     async fn subscribe_for_validator_election_by_address(
@@ -183,9 +303,34 @@ pub trait BlockchainInterface {
         address: Address,
     ) -> Result<BoxStream<'static, RPCData<Validator, BlockchainState>>, Self::Error>;
 
+    /// Subscribes to equivocation events. This includes equivocation proofs newly observed by this
+    /// node (the proofs added to the equivocation proof pool of a validator, or the fork proofs
+    /// detected by other nodes) as well as equivocation proofs included in new blocks of the main
+    /// chain.
+    #[stream]
+    async fn subscribe_for_equivocations(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<Equivocation, ()>>, Self::Error>;
+
+    /// Subscribes to stakers joining, leaving or changing their delegation to a given validator.
+    #[stream]
+    async fn subscribe_for_staker_events_by_validator_address(
+        &mut self,
+        address: Address,
+    ) -> Result<BoxStream<'static, RPCData<StakerEvent, BlockchainState>>, Self::Error>;
+
+    /// Subscribes to validator signals reaching one of the configured thresholds. Thresholds are
+    /// only checked at election blocks.
+    #[stream]
+    async fn subscribe_for_signal_thresholds(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<SignalThresholdCrossed, ()>>, Self::Error>;
+
     /// Subscribes to log events related to a given list of addresses and of any of the log types provided.
     /// If addresses is empty it does not filter by address. If log_types is empty it won't filter by log types.
     /// Thus the behavior is to assume all addresses or log_types are to be provided if the corresponding vec is empty.
+    /// When blocks are reverted, e.g. due to a fork, a `reverted-block` event with the logs of the
+    /// reverted block is emitted, so previously reported logs can be rolled back.
     #[stream]
     async fn subscribe_for_logs_by_addresses_and_types(
         &mut self,
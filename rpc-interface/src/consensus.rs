@@ -386,6 +386,9 @@ pub trait ConsensusInterface {
     /// have a double Option. So we use the following work-around for the signal data:
     /// "" = Set the signal data field to None.
     /// "0x29a4b..." = Set the signal data field to Some(0x29a4b...).
+    /// The optional commission is the share of the rewards the validator keeps, in basis points.
+    /// The rest is distributed to the stakers delegating to it. By default, the validator keeps
+    /// all rewards.
     async fn create_new_validator_transaction(
         &mut self,
         sender_wallet: Address,
@@ -396,6 +399,7 @@ pub trait ConsensusInterface {
         signal_data: String,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        commission: Option<u16>,
     ) -> RPCResult<String, (), Self::Error>;
 
     /// Sends a `new_validator` transaction to the network. You need to provide the address of a basic
@@ -404,6 +408,9 @@ pub trait ConsensusInterface {
     /// have a double Option. So we use the following work-around for the signal data:
     /// "" = Set the signal data field to None.
     /// "0x29a4b..." = Set the signal data field to Some(0x29a4b...).
+    /// The optional commission is the share of the rewards the validator keeps, in basis points.
+    /// The rest is distributed to the stakers delegating to it. By default, the validator keeps
+    /// all rewards.
     async fn send_new_validator_transaction(
         &mut self,
         sender_wallet: Address,
@@ -414,6 +421,7 @@ pub trait ConsensusInterface {
         signal_data: String,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        commission: Option<u16>,
     ) -> RPCResult<Blake2bHash, (), Self::Error>;
 
     /// Returns a serialized `update_validator` transaction. You need to provide the address of a basic
@@ -423,6 +431,7 @@ pub trait ConsensusInterface {
     /// null = No change in the signal data field.
     /// "" = Change the signal data field to None.
     /// "0x29a4b..." = Change the signal data field to Some(0x29a4b...).
+    /// The optional new commission leaves the commission unchanged if omitted.
     async fn create_update_validator_transaction(
         &mut self,
         sender_wallet: Address,
@@ -433,6 +442,7 @@ pub trait ConsensusInterface {
         new_signal_data: Option<String>,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        new_commission: Option<u16>,
     ) -> RPCResult<String, (), Self::Error>;
 
     /// Sends a `update_validator` transaction to the network. You need to provide the address of a basic
@@ -442,6 +452,7 @@ pub trait ConsensusInterface {
     /// null = No change in the signal data field.
     /// "" = Change the signal data field to None.
     /// "0x29a4b..." = Change the signal data field to Some(0x29a4b...).
+    /// The optional new commission leaves the commission unchanged if omitted.
     async fn send_update_validator_transaction(
         &mut self,
         sender_wallet: Address,
@@ -452,6 +463,7 @@ pub trait ConsensusInterface {
         new_signal_data: Option<String>,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        new_commission: Option<u16>,
     ) -> RPCResult<Blake2bHash, (), Self::Error>;
 
     /// Returns a serialized `deactivate_validator` transaction. You need to provide the address of a basic
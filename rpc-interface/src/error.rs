@@ -16,4 +16,7 @@ pub enum Error {
     // Getting rid of staking contract internal account types like StakingStaker etc makes this obsolete.
     #[error("Unsupported account type")]
     UnsupportedAccountType,
+
+    #[error("Invalid accounts proof: {0}")]
+    InvalidAccountsProof(String),
 }
@@ -1,8 +1,13 @@
 use async_trait::async_trait;
+use futures::stream::BoxStream;
 use nimiq_hash::Blake2bHash;
+use nimiq_keys::Address;
 use nimiq_transaction::Transaction;
 
-use crate::types::{HashOrTx, MempoolInfo, RPCResult};
+use crate::types::{
+    BlockchainState, HashOrTx, MempoolEvent, MempoolInfo, MempoolKind, RPCData, RPCResult,
+    TransactionStatus,
+};
 
 #[nimiq_jsonrpc_derive::proxy(name = "MempoolProxy", rename_all = "camelCase")]
 #[async_trait]
@@ -36,4 +41,35 @@ pub trait MempoolInterface {
         &mut self,
         hash: Blake2bHash,
     ) -> RPCResult<Transaction, (), Self::Error>;
+
+    /// Returns the lifecycle state of the given transaction: whether it is pending in the mempool,
+    /// included in a block, finalized, or was dropped from the mempool. Without a history index,
+    /// included transactions are only found within the validity window.
+    async fn get_transaction_status(
+        &mut self,
+        hash: Blake2bHash,
+    ) -> RPCResult<TransactionStatus, BlockchainState, Self::Error>;
+
+    /// Subscribes to changes of the lifecycle state and the number of confirmations of the given
+    /// transactions. The current status of each transaction is emitted first. A transaction is no
+    /// longer reported once it reached a final state (finalized, expired, evicted, invalidated or
+    /// filtered) and the stream ends once all transactions did. A transaction that is dropped from
+    /// the mempool must be sent again and subscribed to anew.
+    #[stream]
+    async fn subscribe_for_transaction_status(
+        &mut self,
+        hashes: Vec<Blake2bHash>,
+    ) -> Result<BoxStream<'static, RPCData<TransactionStatus, BlockchainState>>, Self::Error>;
+
+    /// Subscribes to transactions being added to and removed from the mempool and to changes of
+    /// the fee threshold. If addresses are given, only transactions sent from or to any of them
+    /// are reported. If a mempool kind is given, only events of that mempool are reported. If the
+    /// subscriber falls behind, a `lagged` event is emitted instead of the missed events and the
+    /// client should resynchronize via `mempoolContent`.
+    #[stream]
+    async fn subscribe_for_mempool_events(
+        &mut self,
+        addresses: Vec<Address>,
+        mempool: Option<MempoolKind>,
+    ) -> Result<BoxStream<'static, RPCData<MempoolEvent, ()>>, Self::Error>;
 }
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 
-use crate::types::RPCResult;
+use crate::types::{Ban, PeerDetails, RPCResult};
 
 #[nimiq_jsonrpc_derive::proxy(name = "NetworkProxy", rename_all = "camelCase")]
 #[async_trait]
@@ -15,4 +15,29 @@ pub trait NetworkInterface {
 
     /// Returns a list with the IDs of all our peers.
     async fn get_peer_list(&mut self) -> RPCResult<Vec<String>, (), Self::Error>;
+
+    /// Returns details about a connected peer, or `None` if we are not connected to the peer.
+    async fn get_peer_details(
+        &mut self,
+        peer_id: String,
+    ) -> RPCResult<Option<PeerDetails>, (), Self::Error>;
+
+    /// Returns details about all connected peers.
+    async fn get_peers_details(&mut self) -> RPCResult<Vec<PeerDetails>, (), Self::Error>;
+
+    /// Dials the given multiaddress.
+    async fn dial_address(&mut self, address: String) -> RPCResult<(), (), Self::Error>;
+
+    /// Disconnects from the given peer.
+    async fn disconnect_peer(&mut self, peer_id: String) -> RPCResult<(), (), Self::Error>;
+
+    /// Bans a peer ID or IP address and closes all connections to it. The ban expires after
+    /// `ttl` seconds or never if no `ttl` is given. Bans persist across restarts.
+    async fn ban(&mut self, target: String, ttl: Option<u64>) -> RPCResult<(), (), Self::Error>;
+
+    /// Removes the ban of a peer ID or IP address. Returns whether it was banned.
+    async fn unban(&mut self, target: String) -> RPCResult<bool, (), Self::Error>;
+
+    /// Returns all manual bans that did not expire yet.
+    async fn get_bans(&mut self) -> RPCResult<Vec<Ban>, (), Self::Error>;
 }
@@ -10,7 +10,7 @@ use std::{
 use clap::ValueEnum;
 use nimiq_account::{BlockLog as BBlockLog, Log, TransactionLog};
 use nimiq_block::{MicroJustification, MultiSignature};
-use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainError};
+use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainError, BlockchainEvent};
 use nimiq_blockchain_proxy::BlockchainReadProxy;
 use nimiq_bls::CompressedPublicKey;
 use nimiq_collections::BitSet;
@@ -18,18 +18,23 @@ use nimiq_hash::{Blake2bHash, Blake2sHash, Hash};
 use nimiq_keys::{Address, Ed25519PublicKey, Ed25519Signature, PrivateKey};
 use nimiq_primitives::{
     coin::Coin, networks::NetworkId, policy::Policy, slots_allocation::Validators,
+    trie::trie_proof::TrieProof,
 };
-use nimiq_serde::Serialize as NimiqSerialize;
+use nimiq_serde::{Deserialize as NimiqDeserialize, Serialize as NimiqSerialize};
 use nimiq_transaction::{
     account::htlc_contract::AnyHash,
     historic_transaction::{
-        HistoricTransaction, HistoricTransactionData, JailEvent, PenalizeEvent, RewardEvent,
+        EquivocationEvent, HistoricTransaction, HistoricTransactionData, JailEvent, PenalizeEvent,
+        RewardEvent,
     },
+    DoubleProposalLocator, DoubleVoteLocator, EquivocationLocator, ForkLocator,
 };
 use nimiq_vrf::VrfSeed;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DeserializeFromStr, SerializeDisplay};
 
+use crate::error::Error;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum HashOrTx {
@@ -100,6 +105,16 @@ pub enum HashAlgorithm {
     Sha512 = 4,
 }
 
+impl From<&AnyHash> for HashAlgorithm {
+    fn from(hash: &AnyHash) -> Self {
+        match hash {
+            AnyHash::Blake2b(_) => HashAlgorithm::Blake2b,
+            AnyHash::Sha256(_) => HashAlgorithm::Sha256,
+            AnyHash::Sha512(_) => HashAlgorithm::Sha512,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Block {
@@ -423,6 +438,94 @@ pub struct PenalizedSlots {
     pub disabled: BitSet,
 }
 
+/// The number of slots of the current validators signaling a value, see `SignalTally`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalSlots {
+    pub signal: Blake2bHash,
+    pub slots: u16,
+    /// The progress toward each of the configured thresholds.
+    pub thresholds: Vec<SignalThresholdProgress>,
+}
+
+/// The progress of a signal toward a threshold.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalThresholdProgress {
+    /// The threshold in slots.
+    pub threshold: u16,
+    /// The number of slots that still need to signal the value to reach the threshold.
+    pub missing_slots: u16,
+    pub reached: bool,
+}
+
+/// The `signal_data` values of the current validators, weighted by their slots in the current
+/// election.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalTally {
+    pub block_number: u32,
+    /// The signals ordered by their number of slots, starting with the leading signal.
+    pub signals: Vec<SignalSlots>,
+    pub unsignaled_slots: u16,
+    pub total_slots: u16,
+}
+
+impl SignalTally {
+    /// Converts the given tally, reporting the progress of each signal toward the given
+    /// thresholds.
+    pub fn from_tally(tally: nimiq_blockchain::signaling::SignalTally, thresholds: &[u16]) -> Self {
+        let mut signals: Vec<_> = tally
+            .signals
+            .into_iter()
+            .map(|(signal, slots)| SignalSlots {
+                signal,
+                slots,
+                thresholds: thresholds
+                    .iter()
+                    .map(|&threshold| SignalThresholdProgress {
+                        threshold,
+                        missing_slots: threshold.saturating_sub(slots),
+                        reached: slots >= threshold,
+                    })
+                    .collect(),
+            })
+            .collect();
+        signals.sort_by(|a, b| b.slots.cmp(&a.slots));
+
+        SignalTally {
+            block_number: tally.block_number,
+            signals,
+            unsignaled_slots: tally.unsignaled_slots,
+            total_slots: Policy::SLOTS,
+        }
+    }
+}
+
+/// Emitted at an election block when a validator signal reached one of the configured
+/// thresholds.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalThresholdCrossed {
+    pub signal: Blake2bHash,
+    pub threshold: u16,
+    pub slots: u16,
+    pub previous_slots: u16,
+    pub block_number: u32,
+}
+
+impl From<nimiq_blockchain::signaling::SignalThresholdCrossed> for SignalThresholdCrossed {
+    fn from(event: nimiq_blockchain::signaling::SignalThresholdCrossed) -> Self {
+        SignalThresholdCrossed {
+            signal: event.signal,
+            threshold: event.threshold,
+            slots: event.slots,
+            previous_slots: event.previous_slots,
+            block_number: event.block_number,
+        }
+    }
+}
+
 /// An equivocation proof proves that a validator misbehaved.
 ///
 /// This can come in several forms, but e.g. producing two blocks in a single slot or voting twice
@@ -762,7 +865,141 @@ impl Inherent {
     }
 }
 
+/// The kind of misbehavior proven by an equivocation proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EquivocationKind {
+    Fork,
+    DoubleProposal,
+    DoubleVote,
+}
+
+/// Whether an equivocation proof was only observed by this node or included in the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EquivocationState {
+    Observed,
+    Included,
+}
+
+/// An equivocation committed by a validator, together with the punishments that resulted from it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Equivocation {
+    pub kind: EquivocationKind,
+    pub state: EquivocationState,
+    /// The address of the offending validator.
+    pub validator_address: Address,
+    /// The block number at which the equivocation occurred.
+    pub offense_event_block: u32,
+    /// The Tendermint round in which the equivocation occurred. Not set for forks.
+    pub round: Option<u32>,
+    /// The block that included the equivocation proof. Not set if the proof was only observed,
+    /// but not (yet) included in the chain.
+    pub reporting_block: Option<u32>,
+    pub reporting_block_time: Option<u64>,
+    /// The jail and penalize inherents caused by this equivocation.
+    pub punishments: Vec<Inherent>,
+}
+
+impl Equivocation {
+    /// Creates an observed equivocation from its locator. Reporting block and punishments are left
+    /// empty.
+    pub fn from_locator(locator: EquivocationLocator) -> Self {
+        let (kind, validator_address, offense_event_block, round) = match locator {
+            EquivocationLocator::Fork(ForkLocator {
+                validator_address,
+                block_number,
+            }) => (
+                EquivocationKind::Fork,
+                validator_address,
+                block_number,
+                None,
+            ),
+            EquivocationLocator::DoubleProposal(DoubleProposalLocator {
+                validator_address,
+                block_number,
+                round,
+            }) => (
+                EquivocationKind::DoubleProposal,
+                validator_address,
+                block_number,
+                Some(round),
+            ),
+            EquivocationLocator::DoubleVote(DoubleVoteLocator {
+                validator_address,
+                block_number,
+                round,
+                step: _,
+            }) => (
+                EquivocationKind::DoubleVote,
+                validator_address,
+                block_number,
+                Some(round),
+            ),
+        };
+
+        Equivocation {
+            kind,
+            state: EquivocationState::Observed,
+            validator_address,
+            offense_event_block,
+            round,
+            reporting_block: None,
+            reporting_block_time: None,
+            punishments: vec![],
+        }
+    }
+
+    /// Groups the given equivocation, jail and penalize events into equivocations. Punishments
+    /// are matched to the equivocation of the same validator and offense event block. Punishments
+    /// without a matching equivocation (e.g. penalties for missed blocks) are ignored.
+    pub fn from_historic_transactions(hist_txs: Vec<HistoricTransaction>) -> Vec<Self> {
+        let mut equivocations = vec![];
+        let mut punishments = vec![];
+
+        for hist_tx in hist_txs {
+            if let HistoricTransactionData::Equivocation(EquivocationEvent { ref locator }) =
+                hist_tx.data
+            {
+                let mut equivocation = Equivocation::from_locator(locator.clone());
+                equivocation.state = EquivocationState::Included;
+                equivocation.reporting_block = Some(hist_tx.block_number);
+                equivocation.reporting_block_time = Some(hist_tx.block_time);
+                equivocations.push(equivocation);
+            } else if let Some(inherent) = Inherent::try_from(hist_tx) {
+                punishments.push(inherent);
+            }
+        }
+
+        for inherent in punishments {
+            let (validator_address, offense_event_block) = match inherent {
+                Inherent::Penalize {
+                    ref validator_address,
+                    offense_event_block,
+                    ..
+                }
+                | Inherent::Jail {
+                    ref validator_address,
+                    offense_event_block,
+                    ..
+                } => (validator_address, offense_event_block),
+                Inherent::Reward { .. } => continue,
+            };
+
+            if let Some(equivocation) = equivocations.iter_mut().find(|equivocation| {
+                &equivocation.validator_address == validator_address
+                    && equivocation.offense_event_block == offense_event_block
+            }) {
+                equivocation.punishments.push(inherent);
+            }
+        }
+
+        equivocations
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Account {
     pub address: Address,
     pub balance: Coin,
@@ -794,7 +1031,7 @@ pub struct ReturnAccount {
 }
 
 #[serde_as]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum AccountAdditionalFields {
     /// Additional account information for basic accounts.
@@ -898,6 +1135,334 @@ impl Account {
     }
 }
 
+/// Derived information about an account that the consensus rules compute from its raw state,
+/// e.g. which part of a contract's balance can currently be spent.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountIntrospection {
+    pub address: Address,
+    pub balance: Coin,
+    /// The block number everything is computed for. This is the block following the head block,
+    /// i.e. the first block a transaction sent now can be included in.
+    pub block_number: u32,
+    /// The time everything is computed for. This is the timestamp of the head block, since the
+    /// timestamp of the following block is not known yet.
+    pub timestamp: u64,
+
+    #[serde(flatten)]
+    pub contract: ContractIntrospection,
+
+    /// The staker at the same address, if there is one.
+    pub staker: Option<StakerIntrospection>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ContractIntrospection {
+    /// Basic accounts have no additional state.
+    #[serde(rename_all = "camelCase")]
+    Basic {},
+
+    /// The state of the release schedule of a vesting contract.
+    #[serde(rename_all = "camelCase")]
+    Vesting {
+        /// The amount released by the schedule so far, including already withdrawn coins.
+        vested_amount: Coin,
+        /// The amount that can currently be withdrawn from the contract.
+        available_balance: Coin,
+        /// The time of the next release, if there are still coins to be released.
+        next_release: Option<u64>,
+        /// The amount that is released at `nextRelease`.
+        next_release_amount: Option<Coin>,
+    },
+
+    /// The state of an HTLC contract.
+    #[serde(rename_all = "camelCase")]
+    Htlc {
+        /// The algorithm used to hash the pre-image.
+        hash_algorithm: HashAlgorithm,
+        /// Whether the timeout of the contract has been reached.
+        timeout_reached: bool,
+        /// Whether the recipient can claim funds with a pre-image.
+        regular_transfer_possible: bool,
+        /// Whether the sender and the recipient together can resolve the contract.
+        early_resolve_possible: bool,
+        /// Whether the sender alone can resolve the contract.
+        timeout_resolve_possible: bool,
+    },
+
+    /// The staking contract itself has no additional state.
+    #[serde(rename_all = "camelCase")]
+    Staking {},
+}
+
+/// The release of a staker's inactive balance and the staking operations it can currently perform.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerIntrospection {
+    pub delegation: Option<Address>,
+    pub active_balance: Coin,
+    pub inactive_balance: Coin,
+    pub retired_balance: Coin,
+    /// The block from which on the inactive balance is released. This is the maximum of the end of
+    /// the lock-up period after `inactiveFrom` and the end of the jail of the delegated validator.
+    /// `None` if the inactive balance is not locked.
+    pub inactive_release_block: Option<u32>,
+    /// Whether the inactive balance is released.
+    pub inactive_balance_released: bool,
+    /// The maximum amount of inactive balance that can be retired.
+    pub retirable_balance: Coin,
+    pub allowed_operations: StakerOperations,
+}
+
+/// The staking operations a staker can perform. Adding stake is always possible.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerOperations {
+    pub set_active_stake: bool,
+    pub update_delegation: bool,
+    pub retire_stake: bool,
+    pub remove_stake: bool,
+}
+
+impl AccountIntrospection {
+    /// Computes the introspection of an account at the given block number and time. The staker at
+    /// the same address needs to be given together with the validator it delegates to, if any.
+    pub fn from_account(
+        address: Address,
+        account: nimiq_account::Account,
+        staker: Option<(nimiq_account::Staker, Option<nimiq_account::Validator>)>,
+        block_number: u32,
+        timestamp: u64,
+    ) -> Self {
+        let balance = account.balance();
+        let contract = match account {
+            nimiq_account::Account::Basic(_) => ContractIntrospection::Basic {},
+            nimiq_account::Account::Vesting(vesting) => ContractIntrospection::Vesting {
+                vested_amount: vesting.vested_amount(timestamp),
+                available_balance: vesting.available_balance(timestamp),
+                next_release: vesting.next_release(timestamp),
+                next_release_amount: vesting.next_release_amount(timestamp),
+            },
+            nimiq_account::Account::HTLC(htlc) => {
+                let expired = htlc.is_expired(timestamp);
+                ContractIntrospection::Htlc {
+                    hash_algorithm: HashAlgorithm::from(&htlc.hash_root),
+                    timeout_reached: expired,
+                    regular_transfer_possible: !expired,
+                    early_resolve_possible: true,
+                    timeout_resolve_possible: expired,
+                }
+            }
+            nimiq_account::Account::Staking(_) => ContractIntrospection::Staking {},
+        };
+
+        AccountIntrospection {
+            address,
+            balance,
+            block_number,
+            timestamp,
+            contract,
+            staker: staker.map(|(staker, validator)| {
+                StakerIntrospection::from_staker(&staker, validator.as_ref(), block_number)
+            }),
+        }
+    }
+}
+
+impl StakerIntrospection {
+    pub fn from_staker(
+        staker: &nimiq_account::Staker,
+        validator: Option<&nimiq_account::Validator>,
+        block_number: u32,
+    ) -> Self {
+        let inactive_release_block = staker.inactive_release_block(validator);
+        let retirable_balance = staker.retirable_balance(validator, block_number);
+
+        StakerIntrospection {
+            delegation: staker.delegation.clone(),
+            active_balance: staker.active_balance,
+            inactive_balance: staker.inactive_balance,
+            retired_balance: staker.retired_balance,
+            inactive_release_block,
+            inactive_balance_released: staker.is_inactive_balance_released(validator, block_number),
+            retirable_balance,
+            allowed_operations: StakerOperations {
+                set_active_stake: !staker.non_retired_balance().is_zero(),
+                update_delegation: staker.can_update_delegation(validator, block_number),
+                retire_stake: !retirable_balance.is_zero(),
+                remove_stake: !staker.retired_balance.is_zero(),
+            },
+        }
+    }
+}
+
+/// The money supply broken down by where the coins are held.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Supply {
+    /// The supply according to the emission schedule at the time of the head block.
+    pub theoretical_supply: Coin,
+    /// The sum of the balances of all accounts.
+    pub total_supply: Coin,
+    /// The balance of the burn address, which is included in the total supply. Besides the
+    /// rewards burned by the protocol, e.g. of penalized slots, it includes any coins sent to
+    /// the burn address, so it is neither exactly the amount burned nor removed from circulation.
+    pub burn_address_balance: Coin,
+    /// The rewards burned by the protocol since genesis, because slots were penalized, because
+    /// their recipients couldn't accept them or because they couldn't be distributed to the
+    /// stakers of a validator. `None` if the node didn't apply all macro blocks since genesis,
+    /// e.g. because it synced the accounts tree from its peers.
+    pub burned: Option<Coin>,
+    /// The part of the burned rewards that was withheld from penalized slots since genesis.
+    /// `None` under the same conditions as `burned`.
+    pub penalized: Option<Coin>,
+    /// The coins in vesting contracts that can already be withdrawn.
+    pub vested: Coin,
+    /// The coins in vesting contracts that are still locked.
+    pub unvested: Coin,
+    /// The coins locked in HTLC contracts.
+    pub htlc: Coin,
+    /// The coins in the staking contract, the sum of the validator deposits and the active,
+    /// inactive and retired stake.
+    pub staking: Coin,
+    pub validator_deposits: Coin,
+    pub active_stake: Coin,
+    pub inactive_stake: Coin,
+    pub retired_stake: Coin,
+}
+
+/// The block reward for a batch, not including the transaction fees.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchReward {
+    pub batch_number: u32,
+    /// The block number of the macro block that finalized the batch.
+    pub block_number: u32,
+    /// The maximum reward for the batch, i.e. the increase of the supply during the batch.
+    pub max_reward: Coin,
+    /// The delay producing the batch (in ms).
+    pub delay: u64,
+    /// The share of the maximum reward that is paid out given the delay, in the range [0, 1].
+    pub delay_penalty: f64,
+    /// The reward that is paid out for the batch.
+    pub reward: Coin,
+}
+
+impl BatchReward {
+    pub fn from_batch_reward(
+        batch_number: u32,
+        block_number: u32,
+        batch_reward: nimiq_blockchain::reward::BatchReward,
+    ) -> Self {
+        BatchReward {
+            batch_number,
+            block_number,
+            max_reward: batch_reward.max_reward,
+            delay: batch_reward.batch_delay,
+            delay_penalty: batch_reward.batch_delay_penalty,
+            reward: batch_reward.reward,
+        }
+    }
+}
+
+/// The type of an item in the accounts tree. Besides the account types, the validators and
+/// stakers stored in the staking contract can be selected.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum AccountType {
+    Basic,
+    Vesting,
+    Htlc,
+    Staking,
+    Validator,
+    Staker,
+}
+
+impl AccountType {
+    /// Returns the account type this type selects, or `None` for validators and stakers.
+    pub fn account_type(&self) -> Option<nimiq_primitives::account::AccountType> {
+        match self {
+            AccountType::Basic => Some(nimiq_primitives::account::AccountType::Basic),
+            AccountType::Vesting => Some(nimiq_primitives::account::AccountType::Vesting),
+            AccountType::Htlc => Some(nimiq_primitives::account::AccountType::HTLC),
+            AccountType::Staking => Some(nimiq_primitives::account::AccountType::Staking),
+            AccountType::Validator | AccountType::Staker => None,
+        }
+    }
+}
+
+/// A page of the items in the accounts tree.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPage {
+    pub accounts: Vec<Account>,
+    /// The validators stored in the staking contract.
+    pub validators: Vec<Validator>,
+    /// The stakers stored in the staking contract.
+    pub stakers: Vec<Staker>,
+    /// The key to continue the iteration from. `None` if all items have been iterated.
+    pub next_key: Option<String>,
+}
+
+/// Accounts together with a Merkle proof of their inclusion in the accounts trie at the given
+/// block. Accounts that don't exist are returned as empty basic accounts and proven to be absent.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsWithProof {
+    pub block_number: u32,
+    pub block_hash: Blake2bHash,
+    /// The state root of the block, which the proof is verified against.
+    pub state_hash: Blake2bHash,
+    pub accounts: Vec<Account>,
+    /// The serialized trie proof.
+    #[serde(with = "crate::serde_helpers::hex")]
+    pub proof: Vec<u8>,
+}
+
+impl AccountsWithProof {
+    /// Verifies the accounts against the state root of the given block. To not trust the node
+    /// that returned the accounts, the block should be fetched from a different source.
+    pub fn verify(&self, block: &Block) -> Result<(), Error> {
+        if block.hash != self.block_hash || block.number != self.block_number {
+            return Err(Error::InvalidAccountsProof(format!(
+                "proof is for block #{} {}, but got block #{} {}",
+                self.block_number, self.block_hash, block.number, block.hash
+            )));
+        }
+
+        let proof = TrieProof::deserialize_from_vec(&self.proof)
+            .map_err(|error| Error::InvalidAccountsProof(error.to_string()))?;
+        let addresses: Vec<_> = self
+            .accounts
+            .iter()
+            .map(|account| account.address.clone())
+            .collect();
+        let values = proof
+            .verify_accounts(&block.state_hash, &addresses)
+            .map_err(|error| Error::InvalidAccountsProof(error.0.to_string()))?;
+
+        for (account, (address, value)) in self.accounts.iter().zip(values) {
+            let proven_account = match value {
+                Some(value) => Account::from_account(
+                    address,
+                    nimiq_account::Account::deserialize_from_vec(&value)
+                        .map_err(|error| Error::InvalidAccountsProof(error.to_string()))?,
+                ),
+                None => Account::empty(address),
+            };
+            if *account != proven_account {
+                return Err(Error::InvalidAccountsProof(format!(
+                    "account {} doesn't match the proof",
+                    account.address
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Staker {
@@ -922,6 +1487,202 @@ impl Staker {
     }
 }
 
+/// The order in which the stakers of a validator are returned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StakerOrder {
+    /// Ascending by staker address.
+    #[default]
+    Address,
+    /// Descending by active balance.
+    ActiveBalance,
+}
+
+/// A page of the stakers delegating to a validator. The totals are computed over all stakers of
+/// the validator, not only the ones on this page.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerPage {
+    pub stakers: Vec<Staker>,
+    pub total_stakers: u64,
+    pub total_active_balance: Coin,
+    pub total_inactive_balance: Coin,
+    pub total_retired_balance: Coin,
+}
+
+impl StakerPage {
+    /// Sorts the given stakers and returns the requested page of them.
+    pub fn from_stakers(
+        mut stakers: Vec<nimiq_account::Staker>,
+        order: StakerOrder,
+        offset: usize,
+        limit: usize,
+    ) -> Self {
+        let mut page = StakerPage {
+            stakers: vec![],
+            total_stakers: stakers.len() as u64,
+            total_active_balance: Coin::ZERO,
+            total_inactive_balance: Coin::ZERO,
+            total_retired_balance: Coin::ZERO,
+        };
+
+        for staker in stakers.iter() {
+            page.total_active_balance += staker.active_balance;
+            page.total_inactive_balance += staker.inactive_balance;
+            page.total_retired_balance += staker.retired_balance;
+        }
+
+        match order {
+            StakerOrder::Address => stakers.sort_by(|a, b| a.address.cmp(&b.address)),
+            StakerOrder::ActiveBalance => stakers.sort_by(|a, b| {
+                b.active_balance
+                    .cmp(&a.active_balance)
+                    .then_with(|| a.address.cmp(&b.address))
+            }),
+        }
+
+        page.stakers = stakers
+            .iter()
+            .skip(offset)
+            .take(limit)
+            .map(Staker::from_staker)
+            .collect();
+        page
+    }
+}
+
+/// The share of a single staker in the stake of a validator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerShare {
+    pub address: Address,
+    pub active_balance: Coin,
+    /// The active balance of the staker divided by the total stake of the validator, in parts per
+    /// billion and rounded down.
+    pub share_ppb: u32,
+}
+
+impl StakerShare {
+    /// The denominator of [`share_ppb`](Self::share_ppb).
+    pub const PPB: u64 = 1_000_000_000;
+}
+
+/// The stake of a validator broken down by its stakers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerShares {
+    pub validator_address: Address,
+    /// The total stake of the validator, including its deposit.
+    pub total_stake: Coin,
+    pub deposit: Coin,
+    pub shares: Vec<StakerShare>,
+}
+
+impl StakerShares {
+    /// Computes the shares of the given stakers in the stake of the given validator.
+    pub fn from_stakers(
+        validator: &nimiq_account::Validator,
+        stakers: &[nimiq_account::Staker],
+    ) -> Self {
+        let total_stake = u64::from(validator.total_stake);
+        let shares = stakers
+            .iter()
+            .filter(|staker| !staker.active_balance.is_zero())
+            .map(|staker| StakerShare {
+                address: staker.address.clone(),
+                active_balance: staker.active_balance,
+                share_ppb: if total_stake == 0 {
+                    0
+                } else {
+                    (u128::from(u64::from(staker.active_balance)) * u128::from(StakerShare::PPB)
+                        / u128::from(total_stake)) as u32
+                },
+            })
+            .collect();
+
+        StakerShares {
+            validator_address: validator.address.clone(),
+            total_stake: validator.total_stake,
+            deposit: validator.deposit,
+            shares,
+        }
+    }
+}
+
+/// The kind of change to the delegation of a staker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StakerEventKind {
+    /// The staker started delegating to the validator.
+    Joined,
+    /// The staker stopped delegating to the validator.
+    Left,
+    /// The staker changed its active balance while delegating to the validator.
+    Updated,
+    /// The staker removed stake while delegating to the validator.
+    StakeRemoved,
+}
+
+/// A change to the delegation of a staker to a validator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakerEvent {
+    pub kind: StakerEventKind,
+    pub staker_address: Address,
+    /// The active balance of the staker after the change, or the removed value for
+    /// `stakeRemoved` events.
+    pub value: Coin,
+    /// Whether the block containing the change was reverted.
+    pub reverted: bool,
+}
+
+impl StakerEvent {
+    /// Returns the event described by the given log with respect to the given validator, if any.
+    pub fn from_log(log: &Log, validator_address: &Address, reverted: bool) -> Option<Self> {
+        let is_validator = |address: &Option<Address>| address.as_ref() == Some(validator_address);
+
+        let (kind, staker_address, value) = match log {
+            Log::CreateStaker {
+                staker_address,
+                validator_address,
+                value,
+            } if is_validator(validator_address) => {
+                (StakerEventKind::Joined, staker_address, *value)
+            }
+            Log::UpdateStaker {
+                staker_address,
+                old_validator_address,
+                new_validator_address,
+                active_balance,
+                ..
+            } => match (
+                is_validator(old_validator_address),
+                is_validator(new_validator_address),
+            ) {
+                (false, true) => (StakerEventKind::Joined, staker_address, *active_balance),
+                (true, false) => (StakerEventKind::Left, staker_address, Coin::ZERO),
+                (true, true) => (StakerEventKind::Updated, staker_address, *active_balance),
+                (false, false) => return None,
+            },
+            Log::RemoveStake {
+                staker_address,
+                validator_address,
+                value,
+            } if is_validator(validator_address) => {
+                (StakerEventKind::StakeRemoved, staker_address, *value)
+            }
+            _ => return None,
+        };
+
+        Some(StakerEvent {
+            kind,
+            staker_address: staker_address.clone(),
+            value,
+            reverted,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Validator {
@@ -930,6 +1691,7 @@ pub struct Validator {
     pub voting_key: CompressedPublicKey,
     pub reward_address: Address,
     pub signal_data: Option<Blake2bHash>,
+    pub commission: u16,
     pub balance: Coin,
     pub num_stakers: u64,
     pub inactivity_flag: Option<u32>,
@@ -945,6 +1707,7 @@ impl Validator {
             voting_key: validator.voting_key.clone(),
             reward_address: validator.reward_address.clone(),
             signal_data: validator.signal_data.clone(),
+            commission: validator.commission,
             balance: validator.total_stake,
             num_stakers: validator.num_stakers,
             inactivity_flag: validator.inactive_from,
@@ -1076,6 +1839,7 @@ pub enum LogType {
     DeleteStaker,
     StakerFeeDeduction,
     PayoutReward,
+    PayoutStakerReward,
     Penalize,
     Jail,
     RevertContract,
@@ -1103,6 +1867,92 @@ pub enum BlockLog {
     },
 }
 
+/// A change of the main chain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum ChainEvent {
+    /// The main chain was extended by the given block.
+    #[serde(rename_all = "camelCase")]
+    Extended { block_hash: Blake2bHash },
+
+    /// The main chain switched to a different branch. The transactions of the reverted blocks
+    /// are no longer part of the main chain, unless they are included in the adopted blocks as
+    /// well. Blocks are listed in ascending order.
+    #[serde(rename_all = "camelCase")]
+    Rebranched {
+        reverted_blocks: Vec<Blake2bHash>,
+        adopted_blocks: Vec<Blake2bHash>,
+        reverted_transactions: Vec<Blake2bHash>,
+        adopted_transactions: Vec<Blake2bHash>,
+    },
+
+    /// The main chain adopted the history of the given macro block during history sync, which
+    /// replaced the reverted blocks. The transactions of the reverted blocks are no longer part of
+    /// the main chain, unless they are included in the adopted history as well. Blocks are listed
+    /// in ascending order. If no blocks were reverted, `Extended` is emitted instead.
+    #[serde(rename_all = "camelCase")]
+    HistoryAdopted {
+        block_hash: Blake2bHash,
+        reverted_blocks: Vec<Blake2bHash>,
+        reverted_transactions: Vec<Blake2bHash>,
+    },
+
+    /// The given macro block was added to the main chain, finalizing the batch.
+    #[serde(rename_all = "camelCase")]
+    Finalized { block_hash: Blake2bHash },
+
+    /// The given election block was added to the main chain, finalizing the epoch.
+    #[serde(rename_all = "camelCase")]
+    EpochFinalized { block_hash: Blake2bHash },
+}
+
+impl ChainEvent {
+    /// Returns the chain event corresponding to the given blockchain event, if any.
+    pub fn from_blockchain_event(event: BlockchainEvent) -> Option<Self> {
+        let transaction_hashes =
+            |blocks: &[(Blake2bHash, nimiq_block::Block)]| -> Vec<Blake2bHash> {
+                blocks
+                    .iter()
+                    .filter_map(|(_, block)| block.transactions())
+                    .flatten()
+                    .map(|transaction| transaction.get_raw_transaction().hash::<Blake2bHash>())
+                    .collect()
+            };
+        let block_hashes = |blocks: Vec<(Blake2bHash, nimiq_block::Block)>| -> Vec<Blake2bHash> {
+            blocks.into_iter().map(|(hash, _)| hash).collect()
+        };
+
+        let event = match event {
+            BlockchainEvent::Extended(block_hash) => ChainEvent::Extended { block_hash },
+            BlockchainEvent::HistoryAdopted(block_hash, reverted_blocks) => {
+                if reverted_blocks.is_empty() {
+                    ChainEvent::Extended { block_hash }
+                } else {
+                    ChainEvent::HistoryAdopted {
+                        block_hash,
+                        reverted_transactions: transaction_hashes(&reverted_blocks),
+                        reverted_blocks: block_hashes(reverted_blocks),
+                    }
+                }
+            }
+            BlockchainEvent::Rebranched(reverted_blocks, adopted_blocks) => {
+                ChainEvent::Rebranched {
+                    reverted_transactions: transaction_hashes(&reverted_blocks),
+                    adopted_transactions: transaction_hashes(&adopted_blocks),
+                    reverted_blocks: block_hashes(reverted_blocks),
+                    adopted_blocks: block_hashes(adopted_blocks),
+                }
+            }
+            BlockchainEvent::Finalized(block_hash) => ChainEvent::Finalized { block_hash },
+            BlockchainEvent::EpochFinalized(block_hash) => {
+                ChainEvent::EpochFinalized { block_hash }
+            }
+            BlockchainEvent::Stored(_) => return None,
+        };
+        Some(event)
+    }
+}
+
 impl LogType {
     pub fn from_log(log: &Log) -> Self {
         match log {
@@ -1128,6 +1978,7 @@ impl LogType {
             Log::RetireStake { .. } => Self::RetireStake,
             Log::DeleteStaker { .. } => Self::DeleteStaker,
             Log::PayoutReward { .. } => Self::PayoutReward,
+            Log::PayoutStakerReward { .. } => Self::PayoutStakerReward,
             Log::Penalize { .. } => Self::Penalize,
             Log::Jail { .. } => Self::Jail,
             Log::RevertContract { .. } => Self::RevertContract,
@@ -1178,6 +2029,127 @@ impl ZKPState {
     }
 }
 
+/// The state of a transaction in its lifecycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionState {
+    /// The transaction is not known to this node.
+    Unknown,
+    /// The transaction is waiting in the mempool to be included in a block.
+    Pending,
+    /// The transaction is included in a block of a batch that is not finalized yet.
+    Included,
+    /// The transaction is included in a batch that was finalized by a macro block.
+    Finalized,
+    /// The transaction expired in the mempool without being included in a block.
+    Expired,
+    /// The transaction was evicted from the mempool because the mempool is full.
+    Evicted,
+    /// The transaction was removed from the mempool because it became invalid, e.g. because the
+    /// sender can no longer pay for it.
+    Invalidated,
+    /// The transaction was rejected by the mempool filter.
+    Filtered,
+}
+
+impl TransactionState {
+    /// Returns true if the state of the transaction can't change anymore. Transactions that were
+    /// filtered, evicted or invalidated are not tracked by the mempool anymore, so they are
+    /// considered final even though they could be sent again.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            TransactionState::Finalized
+                | TransactionState::Expired
+                | TransactionState::Filtered
+                | TransactionState::Evicted
+                | TransactionState::Invalidated
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionStatus {
+    pub hash: Blake2bHash,
+    pub state: TransactionState,
+    /// The number of the block that includes the transaction, if known.
+    pub block_number: Option<u32>,
+    /// The number of blocks on top of and including the block that includes the transaction.
+    pub confirmations: Option<u32>,
+}
+
+/// The mempool container a transaction is stored in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MempoolKind {
+    /// Regular transactions.
+    Regular,
+    /// Control transactions, i.e. transactions sent from or to the staking contract.
+    Control,
+}
+
+/// The reason why a transaction was removed from the mempool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RemovalReason {
+    /// The transaction was included in a block.
+    Included,
+    /// The transaction was handed out to be included in a block produced by this node.
+    BlockBuilding,
+    /// The transaction was evicted because the mempool reached its size limit.
+    Evicted,
+    /// The transaction is no longer valid at the current block height.
+    Expired,
+    /// The transaction was invalidated by a block, e.g. because the sender can no longer pay for it.
+    Invalidated,
+}
+
+/// A change of the mempool.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "type")]
+pub enum MempoolEvent {
+    /// The transaction was added to the mempool.
+    #[serde(rename_all = "camelCase")]
+    TransactionAdded {
+        transaction: Transaction,
+        mempool: MempoolKind,
+    },
+
+    /// The transaction was removed from the mempool.
+    #[serde(rename_all = "camelCase")]
+    TransactionRemoved {
+        transaction: Transaction,
+        mempool: MempoolKind,
+        reason: RemovalReason,
+    },
+
+    /// The minimum fee per byte a transaction needs to pay to be accepted into the mempool
+    /// changed. This is raised above the configured minimum when the mempool is almost full.
+    #[serde(rename_all = "camelCase")]
+    FeeThresholdChanged {
+        mempool: MempoolKind,
+        fee_per_byte: f64,
+    },
+
+    /// The subscriber didn't keep up and missed the given number of events. The mempool content
+    /// should be fetched again to resynchronize.
+    #[serde(rename_all = "camelCase")]
+    Lagged { missed_events: u64 },
+}
+
+impl MempoolEvent {
+    /// Returns the mempool container the event refers to, or `None` if it refers to all of them.
+    pub fn mempool(&self) -> Option<MempoolKind> {
+        match self {
+            MempoolEvent::TransactionAdded { mempool, .. }
+            | MempoolEvent::TransactionRemoved { mempool, .. }
+            | MempoolEvent::FeeThresholdChanged { mempool, .. } => Some(*mempool),
+            MempoolEvent::Lagged { .. } => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MempoolInfo {
@@ -1356,3 +2328,33 @@ impl MempoolInfo {
         info
     }
 }
+
+/// Details about a connected peer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDetails {
+    pub peer_id: String,
+    /// The address of the connection to the peer.
+    pub address: String,
+    /// The addresses advertised by the peer.
+    pub advertised_addresses: Vec<String>,
+    /// The services provided by the peer, as a bit set.
+    pub services: u32,
+    /// The score of the peer in gossipsub, if it is known.
+    pub gossipsub_score: Option<f64>,
+    /// Whether the connection was established by us.
+    pub outbound: bool,
+    /// The time since the connection to the peer was established, in seconds.
+    pub connection_age: u64,
+}
+
+/// A manual ban of a peer ID or IP address.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ban {
+    /// The banned peer ID or IP address.
+    pub target: String,
+    /// The time at which the ban expires in seconds since the unix epoch, or `None` if the ban
+    /// does not expire.
+    pub expires_at: Option<u64>,
+}
@@ -32,7 +32,9 @@ pub trait WalletInterface {
         passphrase: Option<String>,
     ) -> RPCResult<ReturnAccount, (), Self::Error>;
 
-    /// Unlocks the account.
+    /// Unlocks the account. The account is locked again automatically after it hasn't been used
+    /// for `duration` seconds. If no duration is given, the idle timeout configured for the node
+    /// applies.
     async fn unlock_account(
         &mut self,
         address: Address,
@@ -53,6 +55,31 @@ pub trait WalletInterface {
         is_hex: bool,
     ) -> RPCResult<ReturnSignature, (), Self::Error>;
 
+    /// Re-encrypts all accounts of the wallet with a new passphrase. This fails without changing
+    /// anything if any account can't be decrypted with the old passphrase. Returns the number of
+    /// re-encrypted accounts.
+    async fn change_passphrase(
+        &mut self,
+        old_passphrase: Option<String>,
+        new_passphrase: String,
+    ) -> RPCResult<usize, (), Self::Error>;
+
+    /// Exports all accounts of the wallet as an encrypted backup, in hexadecimal format.
+    /// The accounts remain encrypted with the current wallet passphrase.
+    async fn export_backup(
+        &mut self,
+        passphrase: Option<String>,
+    ) -> RPCResult<String, (), Self::Error>;
+
+    /// Imports all accounts of an encrypted backup, in hexadecimal format. The accounts are
+    /// decrypted with the passphrase of the backup and re-encrypted with the wallet passphrase.
+    async fn import_backup(
+        &mut self,
+        backup: String,
+        backup_passphrase: Option<String>,
+        passphrase: Option<String>,
+    ) -> RPCResult<Vec<Address>, (), Self::Error>;
+
     /// Verifies the signature based on the provided public key and message.
     async fn verify_signature(
         &mut self,
@@ -1,5 +1,10 @@
-use nimiq_hash::Blake2bHash;
-use nimiq_rpc_interface::types::Block;
+use nimiq_block::{Block as BBlock, MicroBlock, MicroBody, MicroHeader};
+use nimiq_blockchain_interface::BlockchainEvent;
+use nimiq_hash::{Blake2bHash, Hash};
+use nimiq_keys::Address;
+use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use nimiq_rpc_interface::types::{Block, ChainEvent};
+use nimiq_transaction::{ExecutedTransaction, Transaction};
 
 #[test]
 fn it_can_deserialize_result_blocks() {
@@ -7,3 +12,143 @@ fn it_can_deserialize_result_blocks() {
     let value = serde_json::from_str(data).unwrap();
     let _result: Result<Block, Blake2bHash> = serde_json::from_value(value).unwrap();
 }
+
+fn transaction(value: u64) -> Transaction {
+    Transaction::new_basic(
+        Address::from([1; Address::SIZE]),
+        Address::from([2; Address::SIZE]),
+        Coin::from_u64_unchecked(value),
+        Coin::ZERO,
+        1,
+        NetworkId::UnitAlbatross,
+    )
+}
+
+fn micro_block(block_number: u32, transactions: Vec<Transaction>) -> (Blake2bHash, BBlock) {
+    let block = BBlock::Micro(MicroBlock {
+        header: MicroHeader {
+            block_number,
+            ..Default::default()
+        },
+        justification: None,
+        body: Some(MicroBody {
+            equivocation_proofs: vec![],
+            transactions: transactions
+                .into_iter()
+                .map(ExecutedTransaction::Ok)
+                .collect(),
+        }),
+    });
+    (block.hash(), block)
+}
+
+#[test]
+fn it_converts_extensions_and_finalizations_to_chain_events() {
+    let hash = Blake2bHash::default();
+
+    assert!(matches!(
+        ChainEvent::from_blockchain_event(BlockchainEvent::Extended(hash.clone())),
+        Some(ChainEvent::Extended { block_hash }) if block_hash == hash
+    ));
+    // Adopting a history without reverting any blocks just extends the main chain.
+    assert!(matches!(
+        ChainEvent::from_blockchain_event(BlockchainEvent::HistoryAdopted(hash.clone(), vec![])),
+        Some(ChainEvent::Extended { block_hash }) if block_hash == hash
+    ));
+    assert!(matches!(
+        ChainEvent::from_blockchain_event(BlockchainEvent::Finalized(hash.clone())),
+        Some(ChainEvent::Finalized { block_hash }) if block_hash == hash
+    ));
+    assert!(matches!(
+        ChainEvent::from_blockchain_event(BlockchainEvent::EpochFinalized(hash.clone())),
+        Some(ChainEvent::EpochFinalized { block_hash }) if block_hash == hash
+    ));
+
+    let (_, block) = micro_block(1, vec![]);
+    assert!(ChainEvent::from_blockchain_event(BlockchainEvent::Stored(block)).is_none());
+}
+
+#[test]
+fn it_reports_reverted_and_adopted_transactions_of_rebranches() {
+    let reverted = vec![
+        micro_block(1, vec![transaction(1), transaction(2)]),
+        micro_block(2, vec![]),
+    ];
+    let adopted = vec![
+        micro_block(1, vec![transaction(2)]),
+        micro_block(2, vec![transaction(3)]),
+    ];
+
+    let Some(ChainEvent::Rebranched {
+        reverted_blocks,
+        adopted_blocks,
+        reverted_transactions,
+        adopted_transactions,
+    }) = ChainEvent::from_blockchain_event(BlockchainEvent::Rebranched(
+        reverted.clone(),
+        adopted.clone(),
+    ))
+    else {
+        panic!("Expected a rebranch");
+    };
+
+    assert_eq!(
+        reverted_blocks,
+        reverted
+            .iter()
+            .map(|(hash, _)| hash.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        adopted_blocks,
+        adopted
+            .iter()
+            .map(|(hash, _)| hash.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        reverted_transactions,
+        vec![
+            transaction(1).hash::<Blake2bHash>(),
+            transaction(2).hash::<Blake2bHash>()
+        ]
+    );
+    assert_eq!(
+        adopted_transactions,
+        vec![
+            transaction(2).hash::<Blake2bHash>(),
+            transaction(3).hash::<Blake2bHash>()
+        ]
+    );
+}
+
+#[test]
+fn it_reports_blocks_reverted_by_adopted_histories() {
+    let macro_hash = Blake2bHash::default();
+    let reverted = vec![micro_block(1, vec![transaction(1)]), micro_block(2, vec![])];
+
+    let Some(ChainEvent::HistoryAdopted {
+        block_hash,
+        reverted_blocks,
+        reverted_transactions,
+    }) = ChainEvent::from_blockchain_event(BlockchainEvent::HistoryAdopted(
+        macro_hash.clone(),
+        reverted.clone(),
+    ))
+    else {
+        panic!("Expected an adopted history");
+    };
+
+    assert_eq!(block_hash, macro_hash);
+    assert_eq!(
+        reverted_blocks,
+        reverted
+            .iter()
+            .map(|(hash, _)| hash.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        reverted_transactions,
+        vec![transaction(1).hash::<Blake2bHash>()]
+    );
+}
@@ -1,34 +1,131 @@
+use std::{collections::BTreeSet, str::FromStr, sync::Arc};
+
 use async_trait::async_trait;
-use futures::{future, stream::BoxStream, StreamExt};
-use nimiq_account::{BlockLog as BBlockLog, TransactionLog};
-use nimiq_blockchain::interface::{HistoryIndexInterface, HistoryInterface};
-use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainEvent};
+use futures::{future, stream, stream::BoxStream, StreamExt};
+use nimiq_account::{Account as BAccount, Accounts, BlockLog as BBlockLog, TransactionLog};
+use nimiq_block::Block as BBlock;
+use nimiq_blockchain::{
+    interface::{HistoryIndexInterface, HistoryInterface},
+    reward::batch_reward,
+    AccountsTreeItem,
+};
+use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainEvent, ForkEvent};
 use nimiq_blockchain_proxy::{BlockchainProxy, BlockchainReadProxy};
+use nimiq_database::{mdbx::MdbxReadTransaction, traits::Database};
 use nimiq_hash::Blake2bHash;
 use nimiq_keys::Address;
-use nimiq_primitives::{key_nibbles::KeyNibbles, policy::Policy};
+use nimiq_primitives::{coin::Coin, key_nibbles::KeyNibbles, policy::Policy};
 use nimiq_rpc_interface::{
     blockchain::BlockchainInterface,
     types::{
-        is_of_log_type_and_related_to_addresses, Account, Block, BlockLog, BlockchainState,
-        ExecutedTransaction, Inherent, LogType, PenalizedSlots, RPCData, RPCResult, Slot, Staker,
-        Validator,
+        is_of_log_type_and_related_to_addresses, Account, AccountIntrospection, AccountPage,
+        AccountType, AccountsWithProof, BatchReward, Block, BlockLog, BlockchainState, ChainEvent,
+        Equivocation, EquivocationState, ExecutedTransaction, Inherent, LogType, PenalizedSlots,
+        RPCData, RPCResult, SignalTally, SignalThresholdCrossed, Slot, Staker, StakerEvent,
+        StakerOrder, StakerPage, StakerShares, Supply, Validator,
     },
 };
+use nimiq_serde::{Deserialize, Serialize};
+use nimiq_validator::validator::ValidatorProxy;
 use tokio_stream::wrappers::BroadcastStream;
 
 use crate::error::Error;
 
+/// The maximum number of accounts returned in a single page.
+const MAX_ACCOUNT_PAGE_SIZE: u32 = 1000;
+
+/// The maximum number of items of the accounts tree scanned for a single page.
+const MAX_ACCOUNTS_SCANNED_PER_PAGE: usize = 10_000;
+
+/// The maximum number of accounts that can be proven in a single request.
+const MAX_ACCOUNTS_WITH_PROOF: usize = 100;
+
 pub struct BlockchainDispatcher {
     blockchain: BlockchainProxy,
+    /// A separate handle to the accounts tree of a full blockchain, which allows reading the
+    /// accounts without holding the blockchain lock.
+    accounts: Option<Arc<Accounts>>,
+    /// The supply computed for the block with the given hash.
+    supply: Option<(Blake2bHash, Supply)>,
+    /// The validator of this node, if any.
+    validator: Option<ValidatorProxy>,
 }
 
 impl BlockchainDispatcher {
-    pub fn new(blockchain: BlockchainProxy) -> Self {
-        Self { blockchain }
+    pub fn new(blockchain: BlockchainProxy, validator: Option<ValidatorProxy>) -> Self {
+        let accounts = if let BlockchainProxy::Full(ref blockchain) = blockchain {
+            // The accounts tree must be opened without holding the lock, since it needs a write
+            // transaction.
+            let env = blockchain.read().state.accounts.env.clone();
+            Some(Arc::new(Accounts::new(env)))
+        } else {
+            None
+        };
+
+        Self {
+            blockchain,
+            accounts,
+            supply: None,
+            validator,
+        }
     }
 }
 
+/// Computes the supply held by the accounts as seen by the given transaction, except for the
+/// theoretical supply. Returns `None` if the accounts tree is incomplete.
+fn get_supply(accounts: &Accounts, db_txn: &MdbxReadTransaction, timestamp: u64) -> Option<Supply> {
+    if !accounts.is_complete(Some(db_txn)) {
+        return None;
+    }
+
+    let mut supply = Supply::default();
+    let mut start = Some(KeyNibbles::default());
+    while let Some(start_key) = start {
+        let chunk = accounts.get_chunk(start_key, 1000, Some(db_txn));
+        start = chunk.end_key;
+        for item in chunk.items {
+            // Skip the entries of the staking contract.
+            let Some(address) = item.key.to_address() else {
+                continue;
+            };
+            let account = BAccount::deserialize_from_vec(&item.value).ok()?;
+            supply.total_supply += account.balance();
+            match account {
+                BAccount::Basic(basic) if address == Address::burn_address() => {
+                    supply.burn_address_balance += basic.balance;
+                }
+                BAccount::Basic(_) => {}
+                BAccount::Vesting(vesting) => {
+                    let unvested = vesting.min_cap(timestamp).min(vesting.balance);
+                    supply.unvested += unvested;
+                    supply.vested += vesting.balance - unvested;
+                }
+                BAccount::HTLC(htlc) => supply.htlc += htlc.balance,
+                BAccount::Staking(staking) => supply.staking += staking.balance,
+            }
+        }
+    }
+
+    let BAccount::Staking(staking_contract) = accounts
+        .get(&Policy::STAKING_CONTRACT_ADDRESS, Some(db_txn))
+        .ok()?
+    else {
+        return None;
+    };
+    let data_store = accounts.data_store(&Policy::STAKING_CONTRACT_ADDRESS);
+    let data_store = data_store.read(db_txn);
+    for validator in staking_contract.get_validators(&data_store) {
+        supply.validator_deposits += validator.deposit;
+    }
+    for staker in staking_contract.get_stakers(&data_store) {
+        supply.active_stake += staker.active_balance;
+        supply.inactive_stake += staker.inactive_balance;
+        supply.retired_stake += staker.retired_balance;
+    }
+
+    Some(supply)
+}
+
 /// Tries to fetch a block given its hash. It has an option to include the transactions in the
 /// block, which defaults to false.
 /// This function requires the read lock acquisition prior to its execution
@@ -46,6 +143,48 @@ fn get_block_by_hash(
         .map(|block| block.into())
 }
 
+/// Returns the equivocations whose proofs are included in the given block. If the history index is
+/// available, the punishments resulting from the equivocations are included as well.
+/// This function requires the read lock acquisition prior to its execution.
+fn get_equivocations_of_block(
+    blockchain_proxy: &BlockchainReadProxy,
+    block: &BBlock,
+) -> Vec<Equivocation> {
+    let proofs = match block {
+        BBlock::Micro(micro_block) => match micro_block.body {
+            Some(ref body) => &body.equivocation_proofs,
+            None => return vec![],
+        },
+        BBlock::Macro(_) => return vec![],
+    };
+
+    if proofs.is_empty() {
+        return vec![];
+    }
+
+    if let BlockchainReadProxy::Full(blockchain) = blockchain_proxy {
+        if let Some(history_index) = blockchain.history_store.history_index() {
+            let hist_txs = history_index
+                .get_equivocation_events(block.epoch_number(), None)
+                .into_iter()
+                .filter(|hist_tx| hist_tx.block_number == block.block_number())
+                .collect();
+            return Equivocation::from_historic_transactions(hist_txs);
+        }
+    }
+
+    proofs
+        .iter()
+        .map(|proof| {
+            let mut equivocation = Equivocation::from_locator(proof.locator());
+            equivocation.state = EquivocationState::Included;
+            equivocation.reporting_block = Some(block.block_number());
+            equivocation.reporting_block_time = Some(block.timestamp());
+            equivocation
+        })
+        .collect()
+}
+
 /// Tries to fetch a validator information given its address.
 /// This function requires the read lock acquisition prior to its execution.
 fn get_validator_by_address(
@@ -129,6 +268,53 @@ impl BlockchainInterface for BlockchainDispatcher {
         )
     }
 
+    async fn get_block_at_timestamp(
+        &mut self,
+        timestamp: u64,
+        include_body: Option<bool>,
+    ) -> RPCResult<Block, (), Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let block_number = blockchain
+                .get_block_number_at_timestamp(timestamp, None)
+                .ok_or(Error::NoBlockAtTimestamp(timestamp))?;
+
+            let include_body = include_body.unwrap_or(true);
+            let block = blockchain
+                .get_block_at(block_number, include_body, None)
+                .map_err(|_| Error::BlockNotFound(block_number))?;
+
+            Ok(Block::from_block(&blockchain_proxy, block, include_body)
+                .map_err(|_| Error::BlockNotFound(block_number))?
+                .into())
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
+    async fn estimate_block_at_timestamp(
+        &mut self,
+        timestamp: u64,
+    ) -> RPCResult<u32, BlockchainState, Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+        let head = blockchain_proxy.head();
+
+        let block_number = if timestamp > head.timestamp() {
+            // Assume that the following blocks are produced at the optimal rate.
+            let blocks = (timestamp - head.timestamp()).div_ceil(Policy::BLOCK_SEPARATION_TIME);
+            head.block_number()
+                .saturating_add(u32::try_from(blocks).unwrap_or(u32::MAX))
+        } else if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            blockchain
+                .get_block_number_at_timestamp(timestamp, None)
+                .ok_or(Error::NoBlockAtTimestamp(timestamp))?
+        } else {
+            return Err(Error::NotSupportedForLightBlockchain);
+        };
+
+        Ok(RPCData::with_blockchain(block_number, &blockchain_proxy))
+    }
+
     async fn get_slot_at(
         &mut self,
         block_number: u32,
@@ -303,6 +489,38 @@ impl BlockchainInterface for BlockchainDispatcher {
         }
     }
 
+    async fn get_equivocations_by_epoch_number(
+        &mut self,
+        epoch_number: u32,
+    ) -> RPCResult<Vec<Equivocation>, (), Self::Error> {
+        if let BlockchainReadProxy::Full(blockchain) = self.blockchain.read() {
+            let history_index = blockchain
+                .history_store
+                .history_index()
+                .ok_or(Error::RequiresHistoryIndex)?;
+            let hist_txs = history_index.get_equivocation_events(epoch_number, None);
+            let mut equivocations = Equivocation::from_historic_transactions(hist_txs);
+
+            // Observed proofs that were included in the chain are already listed in the epoch that
+            // included them.
+            equivocations.extend(
+                history_index
+                    .get_observed_equivocations(epoch_number, None)
+                    .into_iter()
+                    .filter(|locator| {
+                        !blockchain
+                            .history_store
+                            .has_equivocation_proof(locator.clone(), None)
+                    })
+                    .map(Equivocation::from_locator),
+            );
+
+            Ok(equivocations.into())
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     async fn get_transaction_hashes_by_address(
         &mut self,
         address: Address,
@@ -381,6 +599,171 @@ impl BlockchainInterface for BlockchainDispatcher {
         }
     }
 
+    async fn get_account_introspection_by_address(
+        &mut self,
+        address: Address,
+    ) -> RPCResult<AccountIntrospection, BlockchainState, Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let db_txn = blockchain.read_transaction();
+            let account = blockchain
+                .get_account_if_complete(&address)
+                .ok_or(Error::NoConsensus)?;
+            let staking_contract = blockchain
+                .get_staking_contract_if_complete(Some(&db_txn))
+                .ok_or(Error::NoConsensus)?;
+
+            let data_store = blockchain.get_staking_contract_store();
+            let data_store = data_store.read(&db_txn);
+            let staker = staking_contract
+                .get_staker(&data_store, &address)
+                .map(|staker| {
+                    let validator = staker.delegation.as_ref().and_then(|validator_address| {
+                        staking_contract.get_validator(&data_store, validator_address)
+                    });
+                    (staker, validator)
+                });
+
+            Ok(RPCData::with_blockchain(
+                AccountIntrospection::from_account(
+                    address,
+                    account,
+                    staker,
+                    blockchain.block_number() + 1,
+                    blockchain.timestamp(),
+                ),
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
+    async fn get_accounts_with_proof(
+        &mut self,
+        addresses: Vec<Address>,
+    ) -> RPCResult<AccountsWithProof, BlockchainState, Self::Error> {
+        if addresses.is_empty() || addresses.len() > MAX_ACCOUNTS_WITH_PROOF {
+            return Err(Error::InvalidArgument(format!(
+                "Between 1 and {MAX_ACCOUNTS_WITH_PROOF} addresses are required"
+            )));
+        }
+
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let keys: BTreeSet<_> = addresses.iter().map(KeyNibbles::from).collect();
+            let proof = blockchain
+                .get_accounts_proof(keys.iter().collect())
+                .map_err(|_| Error::NoConsensus)?;
+
+            let mut accounts = Vec::with_capacity(addresses.len());
+            for address in addresses {
+                let account = blockchain
+                    .get_account_if_complete(&address)
+                    .ok_or(Error::NoConsensus)?;
+                accounts.push(Account::from_account(address, account));
+            }
+
+            let head = blockchain.head();
+            Ok(RPCData::with_blockchain(
+                AccountsWithProof {
+                    block_number: head.block_number(),
+                    block_hash: blockchain.head_hash(),
+                    state_hash: head.state_root().clone(),
+                    accounts,
+                    proof: proof.serialize_to_vec(),
+                },
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
+    async fn get_account_page(
+        &mut self,
+        start_key: Option<String>,
+        limit: Option<u32>,
+        account_types: Option<Vec<AccountType>>,
+        min_balance: Option<Coin>,
+    ) -> RPCResult<AccountPage, BlockchainState, Self::Error> {
+        let start = match start_key {
+            Some(start_key) => KeyNibbles::from_str(&start_key)
+                .map_err(|error| Error::InvalidArgument(error.to_string()))?,
+            None => KeyNibbles::default(),
+        };
+        let limit = limit.unwrap_or(500);
+        if limit == 0 || limit > MAX_ACCOUNT_PAGE_SIZE {
+            return Err(Error::InvalidArgument(format!(
+                "Limit must be between 1 and {MAX_ACCOUNT_PAGE_SIZE}"
+            )));
+        }
+        let account_types = account_types.unwrap_or_default();
+        let min_balance = min_balance.unwrap_or(Coin::ZERO);
+
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            if !blockchain.accounts_complete() {
+                return Err(Error::NoConsensus);
+            }
+
+            // All accounts of the page are read from the same transaction.
+            let db_txn = blockchain.read_transaction();
+            let page = blockchain.get_accounts_page(
+                &db_txn,
+                start,
+                limit as usize,
+                MAX_ACCOUNTS_SCANNED_PER_PAGE,
+                |item| {
+                    let (is_of_type, balance) = match item {
+                        AccountsTreeItem::Account(_, account) => (
+                            account_types.iter().any(|account_type| {
+                                account_type.account_type() == Some(account.account_type())
+                            }),
+                            account.balance(),
+                        ),
+                        AccountsTreeItem::Validator(validator) => (
+                            account_types.contains(&AccountType::Validator),
+                            validator.total_stake,
+                        ),
+                        AccountsTreeItem::Staker(staker) => (
+                            account_types.contains(&AccountType::Staker),
+                            staker.active_balance,
+                        ),
+                    };
+                    (account_types.is_empty() || is_of_type) && balance >= min_balance
+                },
+            );
+
+            let mut accounts = vec![];
+            let mut validators = vec![];
+            let mut stakers = vec![];
+            for item in page.items {
+                match item {
+                    AccountsTreeItem::Account(address, account) => {
+                        accounts.push(Account::from_account(address, account))
+                    }
+                    AccountsTreeItem::Validator(validator) => {
+                        validators.push(Validator::from_validator(&validator))
+                    }
+                    AccountsTreeItem::Staker(staker) => stakers.push(Staker::from_staker(&staker)),
+                }
+            }
+
+            Ok(RPCData::with_blockchain(
+                AccountPage {
+                    accounts,
+                    validators,
+                    stakers,
+                    next_key: page.end_key.map(|key| key.to_string()),
+                },
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     async fn get_accounts(&mut self) -> RPCResult<Vec<Account>, BlockchainState, Self::Error> {
         let blockchain_proxy = self.blockchain.read();
         if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
@@ -400,6 +783,86 @@ impl BlockchainInterface for BlockchainDispatcher {
         }
     }
 
+    async fn get_supply(&mut self) -> RPCResult<Supply, BlockchainState, Self::Error> {
+        let (BlockchainProxy::Full(blockchain), Some(accounts)) =
+            (&self.blockchain, &self.accounts)
+        else {
+            return Err(Error::NotSupportedForLightBlockchain);
+        };
+
+        let blockchain_rg = blockchain.read();
+        let state = BlockchainState::new(blockchain_rg.block_number(), blockchain_rg.head_hash());
+        if let Some((ref block_hash, ref supply)) = self.supply {
+            if *block_hash == state.block_hash {
+                return Ok(RPCData::new(supply.clone(), state));
+            }
+        }
+
+        let (genesis_supply, genesis_timestamp) = blockchain_rg.get_genesis_parameters();
+        let timestamp = blockchain_rg.timestamp();
+        // Coins are only burned by macro blocks.
+        let burned_supply =
+            blockchain_rg.get_burned_supply(blockchain_rg.macro_head().block_number());
+        // The read transaction keeps seeing the state at the head block after the lock is
+        // released, so the accounts tree is iterated without blocking the blockchain.
+        let db_txn = accounts.env.read_transaction();
+        drop(blockchain_rg);
+
+        let mut supply = get_supply(accounts, &db_txn, timestamp).ok_or(Error::NoConsensus)?;
+        supply.theoretical_supply = Coin::from_u64_unchecked(Policy::supply_at(
+            u64::from(genesis_supply),
+            genesis_timestamp,
+            timestamp,
+        ));
+        supply.burned = burned_supply
+            .as_ref()
+            .map(|burned_supply| burned_supply.burned);
+        supply.penalized = burned_supply.map(|burned_supply| burned_supply.penalized);
+
+        self.supply = Some((state.block_hash.clone(), supply.clone()));
+        Ok(RPCData::new(supply, state))
+    }
+
+    async fn get_batch_reward(
+        &mut self,
+        batch_number: u32,
+    ) -> RPCResult<BatchReward, BlockchainState, Self::Error> {
+        if batch_number == 0 {
+            return Err(Error::InvalidArgument(
+                "The genesis batch has no reward".to_string(),
+            ));
+        }
+        let block_number = Policy::macro_block_of(batch_number).ok_or_else(|| {
+            Error::InvalidArgument(format!("Invalid batch number {batch_number}"))
+        })?;
+        let previous_block_number = Policy::macro_block_of(batch_number - 1).unwrap();
+
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let block = blockchain
+                .get_block_at(block_number, false)
+                .map_err(|_| Error::BlockNotFound(block_number))?;
+            let previous_block = blockchain
+                .get_block_at(previous_block_number, false)
+                .map_err(|_| Error::BlockNotFound(previous_block_number))?;
+
+            let (genesis_supply, genesis_timestamp) = blockchain.get_genesis_parameters();
+            let batch_reward = batch_reward(
+                &block.unwrap_macro_ref().header,
+                &previous_block.unwrap_macro_ref().header,
+                genesis_supply,
+                genesis_timestamp,
+            );
+
+            Ok(RPCData::with_blockchain(
+                BatchReward::from_batch_reward(batch_number, block_number, batch_reward),
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     async fn get_active_validators(
         &mut self,
     ) -> RPCResult<Vec<Validator>, BlockchainState, Self::Error> {
@@ -525,6 +988,93 @@ impl BlockchainInterface for BlockchainDispatcher {
         }
     }
 
+    async fn get_staker_page_by_validator_address(
+        &mut self,
+        address: Address,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        order: Option<StakerOrder>,
+    ) -> RPCResult<StakerPage, BlockchainState, Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let staking_contract = blockchain
+                .get_staking_contract_if_complete(None)
+                .ok_or(Error::NoConsensus)?;
+            let data_store = blockchain.get_staking_contract_store();
+            let db_txn = blockchain.read_transaction();
+            let stakers =
+                staking_contract.get_stakers_for_validator(&data_store.read(&db_txn), &address);
+
+            Ok(RPCData::with_blockchain(
+                StakerPage::from_stakers(
+                    stakers,
+                    order.unwrap_or_default(),
+                    offset.unwrap_or(0) as usize,
+                    limit.unwrap_or(500) as usize,
+                ),
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
+    async fn get_staker_shares_by_validator_address(
+        &mut self,
+        address: Address,
+        election_block_number: Option<u32>,
+    ) -> RPCResult<StakerShares, BlockchainState, Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+
+        if let (Some(election_block_number), BlockchainReadProxy::Full(ref blockchain)) =
+            (election_block_number, &blockchain_proxy)
+        {
+            if !Policy::is_election_block_at(election_block_number) {
+                return Err(Error::InvalidArgument(format!(
+                    "Block {election_block_number} is not an election block"
+                )));
+            }
+            if !blockchain.has_stake_snapshots(election_block_number) {
+                return Err(Error::InvalidArgument(format!(
+                    "Staker shares are not available for election block {election_block_number}"
+                )));
+            }
+
+            let block = blockchain
+                .get_block_at(election_block_number, false, None)
+                .map_err(|_| Error::BlockNotFound(election_block_number))?;
+            let snapshot = blockchain
+                .get_stake_snapshot(election_block_number, &address)
+                .ok_or(Error::ValidatorNotFound(address))?;
+
+            return Ok(RPCData::new(
+                StakerShares::from_stakers(&snapshot.validator, &snapshot.stakers),
+                BlockchainState::new(election_block_number, block.hash()),
+            ));
+        }
+
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let staking_contract = blockchain
+                .get_staking_contract_if_complete(None)
+                .ok_or(Error::NoConsensus)?;
+            let data_store = blockchain.get_staking_contract_store();
+            let db_txn = blockchain.read_transaction();
+            let data_store = data_store.read(&db_txn);
+            let validator = staking_contract
+                .get_validator(&data_store, &address)
+                .ok_or_else(|| Error::ValidatorNotFound(address.clone()))?;
+            let stakers = staking_contract.get_stakers_for_validator(&data_store, &address);
+
+            Ok(RPCData::with_blockchain(
+                StakerShares::from_stakers(&validator, &stakers),
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     async fn get_staker_by_address(
         &mut self,
         address: Address,
@@ -549,6 +1099,20 @@ impl BlockchainInterface for BlockchainDispatcher {
         }
     }
 
+    async fn get_signal_tally(&mut self) -> RPCResult<SignalTally, BlockchainState, Self::Error> {
+        let blockchain_proxy = self.blockchain.read();
+        if let BlockchainReadProxy::Full(ref blockchain) = blockchain_proxy {
+            let tally = blockchain.tally_signals().ok_or(Error::NoConsensus)?;
+
+            Ok(RPCData::with_blockchain(
+                SignalTally::from_tally(tally, &blockchain.config.signal_thresholds),
+                &blockchain_proxy,
+            ))
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     #[stream]
     async fn subscribe_for_head_block(
         &mut self,
@@ -582,7 +1146,7 @@ impl BlockchainInterface for BlockchainDispatcher {
             .filter_map(|event| {
                 let result = match event {
                     BlockchainEvent::Extended(hash) => Some(hash.into()),
-                    BlockchainEvent::HistoryAdopted(hash) => Some(hash.into()),
+                    BlockchainEvent::HistoryAdopted(hash, _) => Some(hash.into()),
                     BlockchainEvent::Finalized(_) | BlockchainEvent::EpochFinalized(_) => None,
                     BlockchainEvent::Rebranched(_, new_branch) => {
                         Some(new_branch.into_iter().last().unwrap().0.into())
@@ -594,6 +1158,18 @@ impl BlockchainInterface for BlockchainDispatcher {
             .boxed())
     }
 
+    #[stream]
+    async fn subscribe_for_chain_events(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<ChainEvent, ()>>, Self::Error> {
+        let stream = self.blockchain.read().notifier_as_stream();
+        Ok(stream
+            .filter_map(|event| {
+                future::ready(ChainEvent::from_blockchain_event(event).map(RPCData::from))
+            })
+            .boxed())
+    }
+
     #[stream]
     async fn subscribe_for_validator_election_by_address(
         &mut self,
@@ -616,6 +1192,123 @@ impl BlockchainInterface for BlockchainDispatcher {
             .boxed())
     }
 
+    #[stream]
+    async fn subscribe_for_equivocations(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<Equivocation, ()>>, Self::Error> {
+        let blockchain = self.blockchain.clone();
+        let blockchain_rg = self.blockchain.read();
+
+        // Equivocation proofs are reported as soon as they are observed by this node. Validators
+        // report the proofs newly added to their equivocation proof pool, other nodes report the
+        // fork proofs detected by the blockchain.
+        let observed = match self.validator {
+            Some(ref validator) => validator
+                .consensus_state
+                .read()
+                .subscribe_equivocation_proofs()
+                .filter_map(|proof| future::ready(proof.ok()))
+                .map(|proof| vec![Equivocation::from_locator(proof.locator())])
+                .boxed(),
+            None => blockchain_rg
+                .fork_notifier_as_stream()
+                .map(|event| match event {
+                    ForkEvent::Detected(proof) => {
+                        vec![Equivocation::from_locator(proof.locator().into())]
+                    }
+                })
+                .boxed(),
+        };
+
+        // All kinds of equivocation proofs are reported once they are included in the main chain.
+        let included = blockchain_rg.notifier_as_stream().map(move |event| {
+            let hashes = match event {
+                BlockchainEvent::Extended(hash) => vec![hash],
+                BlockchainEvent::Rebranched(_, new_branch) => {
+                    new_branch.into_iter().map(|(hash, _)| hash).collect()
+                }
+                _ => vec![],
+            };
+
+            let blockchain_rg = blockchain.read();
+            hashes
+                .iter()
+                .filter_map(|hash| blockchain_rg.get_block(hash, true).ok())
+                .flat_map(|block| get_equivocations_of_block(&blockchain_rg, &block))
+                .collect()
+        });
+
+        Ok(stream::select(observed, included)
+            .flat_map(|equivocations: Vec<Equivocation>| {
+                stream::iter(equivocations.into_iter().map(RPCData::from))
+            })
+            .boxed())
+    }
+
+    #[stream]
+    async fn subscribe_for_staker_events_by_validator_address(
+        &mut self,
+        address: Address,
+    ) -> Result<BoxStream<'static, RPCData<StakerEvent, BlockchainState>>, Self::Error> {
+        if let BlockchainReadProxy::Full(blockchain) = self.blockchain.read() {
+            let stream = BroadcastStream::new(blockchain.log_notifier.subscribe());
+
+            Ok(stream
+                .flat_map(move |event| {
+                    let (tx_logs, reverted, state) = match event {
+                        Ok(BBlockLog::AppliedBlock {
+                            tx_logs,
+                            block_hash,
+                            block_number,
+                            ..
+                        }) => (
+                            tx_logs,
+                            false,
+                            BlockchainState::new(block_number, block_hash),
+                        ),
+                        Ok(BBlockLog::RevertedBlock {
+                            tx_logs,
+                            block_hash,
+                            block_number,
+                            ..
+                        }) => (
+                            tx_logs,
+                            true,
+                            BlockchainState::new(block_number, block_hash),
+                        ),
+                        Err(_) => return stream::iter(vec![]),
+                    };
+
+                    let events: Vec<_> = tx_logs
+                        .iter()
+                        .flat_map(|tx_log| tx_log.logs.iter())
+                        .filter_map(|log| StakerEvent::from_log(log, &address, reverted))
+                        .map(|event| RPCData::new(event, state.clone()))
+                        .collect();
+                    stream::iter(events)
+                })
+                .boxed())
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
+    #[stream]
+    async fn subscribe_for_signal_thresholds(
+        &mut self,
+    ) -> Result<BoxStream<'static, RPCData<SignalThresholdCrossed, ()>>, Self::Error> {
+        if let BlockchainReadProxy::Full(blockchain) = self.blockchain.read() {
+            let stream = BroadcastStream::new(blockchain.signal_notifier.subscribe());
+
+            Ok(stream
+                .filter_map(|event| future::ready(event.ok().map(SignalThresholdCrossed::from)))
+                .map(RPCData::from)
+                .boxed())
+        } else {
+            Err(Error::NotSupportedForLightBlockchain)
+        }
+    }
+
     #[stream]
     async fn subscribe_for_logs_by_addresses_and_types(
         &mut self,
@@ -8,7 +8,7 @@ use nimiq_consensus::ConsensusProxy;
 use nimiq_hash::{Blake2bHash, Hash};
 use nimiq_keys::{Address, Ed25519PublicKey, KeyPair, PrivateKey};
 use nimiq_network_libp2p::Network;
-use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
 use nimiq_rpc_interface::{
     consensus::ConsensusInterface,
     types::{RPCResult, Transaction as RPCTransaction, ValidityStartHeight},
@@ -793,6 +793,7 @@ impl ConsensusInterface for ConsensusDispatcher {
         signal_data: String,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        commission: Option<u16>,
     ) -> RPCResult<String, (), Self::Error> {
         let voting_secret_key =
             BlsSecretKey::deserialize_from_vec(&hex::decode(voting_secret_key)?)
@@ -826,6 +827,7 @@ impl ConsensusInterface for ConsensusDispatcher {
             &hot_keypair,
             reward_address,
             signal_data,
+            commission.unwrap_or(Policy::MAX_COMMISSION),
             fee,
             self.validity_start_height(validity_start_height),
             self.get_network_id(),
@@ -844,6 +846,7 @@ impl ConsensusInterface for ConsensusDispatcher {
         signal_data: String,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        commission: Option<u16>,
     ) -> RPCResult<Blake2bHash, (), Self::Error> {
         let raw_tx = self
             .create_new_validator_transaction(
@@ -855,6 +858,7 @@ impl ConsensusInterface for ConsensusDispatcher {
                 signal_data,
                 fee,
                 validity_start_height,
+                commission,
             )
             .await?
             .data;
@@ -871,6 +875,7 @@ impl ConsensusInterface for ConsensusDispatcher {
         new_signal_data: Option<String>,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        new_commission: Option<u16>,
     ) -> RPCResult<String, (), Self::Error> {
         let new_voting_keypair = match new_voting_secret_key {
             Some(key) => {
@@ -917,6 +922,7 @@ impl ConsensusInterface for ConsensusDispatcher {
             new_voting_keypair.as_ref(),
             new_reward_address,
             new_signal_data,
+            new_commission,
             fee,
             self.validity_start_height(validity_start_height),
             self.get_network_id(),
@@ -935,6 +941,7 @@ impl ConsensusInterface for ConsensusDispatcher {
         new_signal_data: Option<String>,
         fee: Coin,
         validity_start_height: ValidityStartHeight,
+        new_commission: Option<u16>,
     ) -> RPCResult<Blake2bHash, (), Self::Error> {
         let raw_tx = self
             .create_update_validator_transaction(
@@ -946,6 +953,7 @@ impl ConsensusInterface for ConsensusDispatcher {
                 new_signal_data,
                 fee,
                 validity_start_height,
+                new_commission,
             )
             .await?
             .data;
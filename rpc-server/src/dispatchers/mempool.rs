@@ -1,28 +1,116 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
+use futures::{
+    future,
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use nimiq_blockchain::Blockchain;
+use nimiq_blockchain_interface::AbstractBlockchain;
 use nimiq_hash::{Blake2bHash, Hash};
-use nimiq_mempool::{mempool::Mempool, mempool_transactions::TxPriority};
+use nimiq_keys::Address;
+use nimiq_mempool::{
+    events::{EvictionReason, MempoolEvent as MMempoolEvent, MempoolKind as MMempoolKind},
+    mempool::Mempool,
+    mempool_transactions::TxPriority,
+};
 use nimiq_rpc_interface::{
     mempool::MempoolInterface,
-    types::{HashOrTx, MempoolInfo, RPCResult},
+    types::{
+        BlockchainState, HashOrTx, MempoolEvent, MempoolInfo, MempoolKind, RPCData, RPCResult,
+        RemovalReason, Transaction as RPCTransaction, TransactionState, TransactionStatus,
+    },
 };
 use nimiq_serde::Deserialize;
 use nimiq_transaction::Transaction;
+use parking_lot::RwLock;
 
 use crate::error::Error;
 
-#[allow(dead_code)]
 pub struct MempoolDispatcher {
     mempool: Arc<Mempool>,
+    blockchain: Arc<RwLock<Blockchain>>,
 }
 
 impl MempoolDispatcher {
-    pub fn new(mempool: Arc<Mempool>) -> Self {
-        MempoolDispatcher { mempool }
+    pub fn new(mempool: Arc<Mempool>, blockchain: Arc<RwLock<Blockchain>>) -> Self {
+        MempoolDispatcher {
+            mempool,
+            blockchain,
+        }
+    }
+}
+
+/// Determines the lifecycle state of the transaction with the given hash.
+fn get_transaction_status(
+    blockchain: &Blockchain,
+    mempool: &Mempool,
+    hash: &Blake2bHash,
+) -> TransactionStatus {
+    let head_height = blockchain.block_number();
+
+    // Check whether the transaction is included in the chain. Without a history index, only
+    // transactions within the validity window can be found.
+    let block_number = blockchain
+        .history_store
+        .history_index()
+        .and_then(|index| index.get_hist_tx_by_hash(hash, None))
+        .map(|hist_tx| hist_tx.block_number)
+        .or_else(|| blockchain.get_tx_block_number_in_validity_window(&hash.clone().into(), None));
+    let (state, block_number) = if let Some(block_number) = block_number {
+        if block_number <= blockchain.macro_head().block_number() {
+            (TransactionState::Finalized, Some(block_number))
+        } else {
+            (TransactionState::Included, Some(block_number))
+        }
+    } else if mempool.contains_transaction_by_hash(hash) {
+        (TransactionState::Pending, None)
+    } else if mempool.is_filtered(hash) {
+        (TransactionState::Filtered, None)
+    } else {
+        let state = match mempool.get_removal_reason(hash) {
+            // The transaction is about to be included in a block produced by this node.
+            Some(EvictionReason::BlockBuilding) => TransactionState::Pending,
+            Some(EvictionReason::Expired) => TransactionState::Expired,
+            Some(EvictionReason::Invalid) => TransactionState::Invalidated,
+            Some(EvictionReason::TooFull) => TransactionState::Evicted,
+            // The block that included the transaction was reverted since.
+            Some(EvictionReason::AlreadyIncluded) | None => TransactionState::Unknown,
+        };
+        (state, None)
+    };
+
+    TransactionStatus {
+        hash: hash.clone(),
+        state,
+        block_number,
+        confirmations: block_number.map(|block| head_height.saturating_sub(block) + 1),
+    }
+}
+
+fn to_rpc_kind(kind: MMempoolKind) -> MempoolKind {
+    match kind {
+        MMempoolKind::Regular => MempoolKind::Regular,
+        MMempoolKind::Control => MempoolKind::Control,
+    }
+}
+
+fn to_rpc_reason(reason: EvictionReason) -> RemovalReason {
+    match reason {
+        EvictionReason::BlockBuilding => RemovalReason::BlockBuilding,
+        EvictionReason::Expired => RemovalReason::Expired,
+        EvictionReason::AlreadyIncluded => RemovalReason::Included,
+        EvictionReason::Invalid => RemovalReason::Invalidated,
+        EvictionReason::TooFull => RemovalReason::Evicted,
     }
 }
 
+/// Returns true if no addresses are given or the transaction is sent from or to any of them.
+fn is_related_to_addresses(tx: &Transaction, addresses: &[Address]) -> bool {
+    addresses.is_empty() || addresses.contains(&tx.sender) || addresses.contains(&tx.recipient)
+}
+
 #[nimiq_jsonrpc_derive::service(rename_all = "camelCase")]
 #[async_trait]
 impl MempoolInterface for MempoolDispatcher {
@@ -94,4 +182,179 @@ impl MempoolInterface for MempoolDispatcher {
             return Err(Error::TransactionNotFound(hash));
         }
     }
+
+    async fn get_transaction_status(
+        &mut self,
+        hash: Blake2bHash,
+    ) -> RPCResult<TransactionStatus, BlockchainState, Self::Error> {
+        let blockchain = self.blockchain.read();
+        let status = get_transaction_status(&blockchain, &self.mempool, &hash);
+        Ok(RPCData::new(
+            status,
+            BlockchainState::new(blockchain.block_number(), blockchain.head_hash()),
+        ))
+    }
+
+    #[stream]
+    async fn subscribe_for_transaction_status(
+        &mut self,
+        hashes: Vec<Blake2bHash>,
+    ) -> Result<BoxStream<'static, RPCData<TransactionStatus, BlockchainState>>, Self::Error> {
+        let blockchain = Arc::clone(&self.blockchain);
+        let mempool = Arc::clone(&self.mempool);
+
+        // Changes of the mempool only affect the transactions involved, while changes of the
+        // main chain might include, revert or finalize any of the transactions and change the
+        // number of confirmations. If mempool events were missed, all transactions are checked
+        // again as well.
+        let mempool_updates = self.mempool.subscribe_events().filter_map(|event| {
+            future::ready(match event {
+                MMempoolEvent::TransactionAdded(tx) | MMempoolEvent::TransactionRemoved(tx, _) => {
+                    Some(Some(tx.hash::<Blake2bHash>()))
+                }
+                MMempoolEvent::TransactionFiltered(hash) => Some(Some(hash)),
+                MMempoolEvent::Lagged(_) => Some(None),
+                MMempoolEvent::FeeThresholdChanged(..) => None,
+            })
+        });
+        let blockchain_rg = self.blockchain.read();
+        let chain_updates = blockchain_rg.notifier_as_stream().map(|_| None);
+
+        // Emit the current status of all transactions first.
+        let state = BlockchainState::new(blockchain_rg.block_number(), blockchain_rg.head_hash());
+        let mut tracked = HashMap::new();
+        let mut initial = vec![];
+        for hash in hashes {
+            let status = get_transaction_status(&blockchain_rg, &self.mempool, &hash);
+            initial.push(RPCData::new(status.clone(), state.clone()));
+            if !status.state.is_final() {
+                tracked.insert(hash, status);
+            }
+        }
+        drop(blockchain_rg);
+
+        // The stream ends as soon as all transactions reached a final state.
+        let updates = stream::unfold(
+            (stream::select(mempool_updates, chain_updates), tracked),
+            move |(mut updates, mut tracked)| {
+                let blockchain = Arc::clone(&blockchain);
+                let mempool = Arc::clone(&mempool);
+                async move {
+                    if tracked.is_empty() {
+                        return None;
+                    }
+
+                    let hashes: Vec<Blake2bHash> = match updates.next().await? {
+                        Some(hash) if tracked.contains_key(&hash) => vec![hash],
+                        Some(_) => vec![],
+                        None => tracked.keys().cloned().collect(),
+                    };
+
+                    let mut changes = vec![];
+                    if !hashes.is_empty() {
+                        let blockchain = blockchain.read();
+                        let state =
+                            BlockchainState::new(blockchain.block_number(), blockchain.head_hash());
+                        for hash in hashes {
+                            let status = get_transaction_status(&blockchain, &mempool, &hash);
+                            if tracked.get(&hash) == Some(&status) {
+                                continue;
+                            }
+
+                            changes.push(RPCData::new(status.clone(), state.clone()));
+                            if status.state.is_final() {
+                                tracked.remove(&hash);
+                            } else {
+                                tracked.insert(hash, status);
+                            }
+                        }
+                    }
+                    Some((changes, (updates, tracked)))
+                }
+            },
+        )
+        .flat_map(stream::iter);
+
+        Ok(stream::iter(initial).chain(updates).boxed())
+    }
+
+    #[stream]
+    async fn subscribe_for_mempool_events(
+        &mut self,
+        addresses: Vec<Address>,
+        mempool: Option<MempoolKind>,
+    ) -> Result<BoxStream<'static, RPCData<MempoolEvent, ()>>, Self::Error> {
+        let mempool_rc = Arc::clone(&self.mempool);
+
+        // The reported fee threshold is never below the minimum fee per byte of the mempool
+        // rules, so changes of the mempool threshold below that minimum are not reported.
+        let effective_fee_threshold =
+            move |fee_per_byte: f64| fee_per_byte.max(mempool_rc.get_rules().tx_fee_per_byte);
+        let mut fee_thresholds: HashMap<MempoolKind, f64> =
+            [MMempoolKind::Regular, MMempoolKind::Control]
+                .into_iter()
+                .map(|kind| {
+                    (
+                        to_rpc_kind(kind),
+                        effective_fee_threshold(self.mempool.get_fee_threshold(kind)),
+                    )
+                })
+                .collect();
+
+        Ok(self
+            .mempool
+            .subscribe_events()
+            .filter_map(move |event| {
+                let event = match event {
+                    MMempoolEvent::TransactionAdded(tx)
+                        if is_related_to_addresses(&tx, &addresses) =>
+                    {
+                        Some(MempoolEvent::TransactionAdded {
+                            mempool: to_rpc_kind(MMempoolKind::of(&tx)),
+                            transaction: RPCTransaction::from_transaction(tx),
+                        })
+                    }
+                    MMempoolEvent::TransactionRemoved(tx, reason)
+                        if is_related_to_addresses(&tx, &addresses) =>
+                    {
+                        Some(MempoolEvent::TransactionRemoved {
+                            mempool: to_rpc_kind(MMempoolKind::of(&tx)),
+                            transaction: RPCTransaction::from_transaction(tx),
+                            reason: to_rpc_reason(reason),
+                        })
+                    }
+                    MMempoolEvent::FeeThresholdChanged(kind, fee_per_byte) => {
+                        let fee_per_byte = effective_fee_threshold(fee_per_byte);
+                        let kind = to_rpc_kind(kind);
+                        if fee_thresholds.insert(kind, fee_per_byte) == Some(fee_per_byte) {
+                            None
+                        } else {
+                            Some(MempoolEvent::FeeThresholdChanged {
+                                mempool: kind,
+                                fee_per_byte,
+                            })
+                        }
+                    }
+                    MMempoolEvent::Lagged(missed_events) => {
+                        // Threshold changes might have been missed as well, so the next one is
+                        // reported in any case.
+                        fee_thresholds.clear();
+                        Some(MempoolEvent::Lagged { missed_events })
+                    }
+                    _ => None,
+                };
+
+                future::ready(
+                    event
+                        .filter(|event| {
+                            event
+                                .mempool()
+                                .zip(mempool)
+                                .map_or(true, |(event_kind, kind)| event_kind == kind)
+                        })
+                        .map(RPCData::from),
+                )
+            })
+            .boxed())
+    }
 }
@@ -1,19 +1,88 @@
-use std::sync::Arc;
+use std::{net::IpAddr, str::FromStr, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
-use nimiq_network_interface::network::Network as InterfaceNetwork;
-use nimiq_network_libp2p::Network;
-use nimiq_rpc_interface::{network::NetworkInterface, types::RPCResult};
+use nimiq_network_interface::{
+    network::{CloseReason, Network as InterfaceNetwork},
+    Multiaddr,
+};
+use nimiq_network_libp2p::{
+    Ban as NetworkBan, BanTarget, Network, PeerDetails as NetworkPeerDetails, PeerId,
+};
+use nimiq_rpc_interface::{
+    network::NetworkInterface,
+    types::{Ban, PeerDetails, RPCResult},
+};
+use nimiq_utils::file_store::FileStore;
 
 use crate::error::Error;
 
 pub struct NetworkDispatcher {
     network: Arc<Network>,
+    /// File store in which the manual bans are persisted, if any.
+    ban_list_store: Option<Arc<FileStore>>,
 }
 
 impl NetworkDispatcher {
-    pub fn new(network: Arc<Network>) -> Self {
-        NetworkDispatcher { network }
+    pub fn new(network: Arc<Network>, ban_list_store: Option<Arc<FileStore>>) -> Self {
+        NetworkDispatcher {
+            network,
+            ban_list_store,
+        }
+    }
+
+    /// Writes the current manual bans to the ban list file, if there is one.
+    async fn persist_bans(&self) -> Result<(), Error> {
+        if let Some(ban_list_store) = &self.ban_list_store {
+            let bans = self.network.get_bans().await?;
+            if let Err(error) = ban_list_store.store(&bans) {
+                log::warn!(%error, "Failed to persist the ban list");
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_peer_id(peer_id: &str) -> Result<PeerId, Error> {
+    PeerId::from_str(peer_id).map_err(|_| Error::InvalidArgument("Peer ID".to_string()))
+}
+
+/// Parses a ban target, which is either an IP address or a peer ID.
+fn parse_ban_target(target: &str) -> Result<BanTarget, Error> {
+    if let Ok(ip) = IpAddr::from_str(target) {
+        return Ok(BanTarget::Ip(ip));
+    }
+    PeerId::from_str(target)
+        .map(BanTarget::Peer)
+        .map_err(|_| Error::InvalidArgument("Ban target".to_string()))
+}
+
+fn ban_target_to_string(target: &BanTarget) -> String {
+    match target {
+        BanTarget::Peer(peer_id) => peer_id.to_string(),
+        BanTarget::Ip(ip) => ip.to_string(),
+    }
+}
+
+fn peer_details_from(details: NetworkPeerDetails) -> PeerDetails {
+    PeerDetails {
+        peer_id: details.peer_id.to_string(),
+        address: details.address.to_string(),
+        advertised_addresses: details
+            .advertised_addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect(),
+        services: details.services.bits(),
+        gossipsub_score: details.gossipsub_score,
+        outbound: details.outbound,
+        connection_age: details.connection_age.as_secs(),
+    }
+}
+
+fn ban_from(ban: NetworkBan) -> Ban {
+    Ban {
+        target: ban_target_to_string(&ban.target),
+        expires_at: ban.expires_at,
     }
 }
 
@@ -39,4 +108,70 @@ impl NetworkInterface for NetworkDispatcher {
             .collect::<Vec<_>>()
             .into())
     }
+
+    async fn get_peer_details(
+        &mut self,
+        peer_id: String,
+    ) -> RPCResult<Option<PeerDetails>, (), Self::Error> {
+        let peer_id = parse_peer_id(&peer_id)?;
+        Ok(self
+            .network
+            .get_peer_details(peer_id)
+            .await?
+            .map(peer_details_from)
+            .into())
+    }
+
+    async fn get_peers_details(&mut self) -> RPCResult<Vec<PeerDetails>, (), Self::Error> {
+        let mut peers = vec![];
+        for peer_id in self.network.get_peers() {
+            // The peer might have disconnected in the meantime.
+            if let Some(details) = self.network.get_peer_details(peer_id).await? {
+                peers.push(peer_details_from(details));
+            }
+        }
+        Ok(peers.into())
+    }
+
+    async fn dial_address(&mut self, address: String) -> RPCResult<(), (), Self::Error> {
+        let address = Multiaddr::from_str(&address)
+            .map_err(|_| Error::InvalidArgument("Address".to_string()))?;
+        self.network.dial_address(address).await?;
+        Ok(().into())
+    }
+
+    async fn disconnect_peer(&mut self, peer_id: String) -> RPCResult<(), (), Self::Error> {
+        let peer_id = parse_peer_id(&peer_id)?;
+        self.network
+            .disconnect_peer(peer_id, CloseReason::Other)
+            .await;
+        Ok(().into())
+    }
+
+    async fn ban(&mut self, target: String, ttl: Option<u64>) -> RPCResult<(), (), Self::Error> {
+        let target = parse_ban_target(&target)?;
+        self.network
+            .ban(target, ttl.map(Duration::from_secs))
+            .await?;
+        self.persist_bans().await?;
+        Ok(().into())
+    }
+
+    async fn unban(&mut self, target: String) -> RPCResult<bool, (), Self::Error> {
+        let target = parse_ban_target(&target)?;
+        let was_banned = self.network.unban(target).await?;
+        self.persist_bans().await?;
+        Ok(was_banned.into())
+    }
+
+    async fn get_bans(&mut self) -> RPCResult<Vec<Ban>, (), Self::Error> {
+        Ok(self
+            .network
+            .get_bans()
+            .await?
+            .into_iter()
+            .map(ban_from)
+            .collect::<Vec<_>>()
+            .into())
+    }
 }
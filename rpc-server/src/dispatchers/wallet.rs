@@ -1,15 +1,16 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
-use nimiq_database::traits::WriteTransaction;
+use futures::StreamExt;
 use nimiq_keys::{Address, Ed25519PublicKey, Ed25519Signature, KeyPair, PrivateKey};
 use nimiq_rpc_interface::{
     types::{RPCResult, ReturnAccount, ReturnSignature},
     wallet::WalletInterface,
 };
-use nimiq_serde::Deserialize;
-use nimiq_utils::otp::Locked;
-use nimiq_wallet::{WalletAccount, WalletStore};
+use nimiq_serde::{Deserialize, Serialize};
+use nimiq_time::interval;
+use nimiq_utils::spawn;
+use nimiq_wallet::{WalletAccount, WalletBackup, WalletStore};
 use parking_lot::RwLock;
 
 use crate::{error::Error, wallets::UnlockedWallets};
@@ -25,14 +26,49 @@ fn message_from_maybe_hex(s: String, is_hex: bool) -> Result<Vec<u8>, Error> {
 pub struct WalletDispatcher {
     wallet_store: Arc<WalletStore>,
     pub unlocked_wallets: Arc<RwLock<UnlockedWallets>>,
+    idle_timeout: Option<Duration>,
+    auto_lock_spawned: bool,
 }
 
 impl WalletDispatcher {
-    pub fn new(wallet_store: Arc<WalletStore>) -> Self {
-        Self {
+    /// Interval in which unlocked accounts are checked for having exceeded their idle timeout.
+    const AUTO_LOCK_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// Creates a new wallet dispatcher. Unlocked accounts are locked automatically after not being
+    /// used for `idle_timeout`, unless a different duration is given when unlocking them.
+    pub fn new(wallet_store: Arc<WalletStore>, idle_timeout: Option<Duration>) -> Self {
+        let mut dispatcher = Self {
             wallet_store,
             unlocked_wallets: Arc::new(RwLock::new(UnlockedWallets::default())),
+            idle_timeout,
+            auto_lock_spawned: false,
+        };
+
+        if idle_timeout.is_some() {
+            dispatcher.spawn_auto_lock();
         }
+
+        dispatcher
+    }
+
+    /// Periodically removes the private keys of idle accounts from memory. The task is only
+    /// spawned once an idle timeout is in use, either by default or for an unlocked account.
+    fn spawn_auto_lock(&mut self) {
+        if self.auto_lock_spawned {
+            return;
+        }
+        self.auto_lock_spawned = true;
+
+        let unlocked_wallets = Arc::downgrade(&self.unlocked_wallets);
+        spawn(async move {
+            let mut interval = interval(Self::AUTO_LOCK_INTERVAL);
+            while interval.next().await.is_some() {
+                match unlocked_wallets.upgrade() {
+                    Some(unlocked_wallets) => unlocked_wallets.write().remove_expired(),
+                    None => break,
+                }
+            }
+        });
     }
 }
 
@@ -52,13 +88,9 @@ impl WalletInterface for WalletDispatcher {
 
         let wallet_account = WalletAccount::from(KeyPair::from(private_key));
 
-        let address = wallet_account.address.clone();
-
-        let wallet_account = Locked::with_defaults(wallet_account, passphrase.as_bytes())?;
-
-        let mut txn = self.wallet_store.create_write_transaction();
-        self.wallet_store.put(&address, &wallet_account, &mut txn);
-        txn.commit();
+        let address = self
+            .wallet_store
+            .insert(wallet_account, passphrase.as_bytes())?;
 
         Ok(address.into())
     }
@@ -84,12 +116,9 @@ impl WalletInterface for WalletDispatcher {
     ) -> RPCResult<ReturnAccount, (), Self::Error> {
         let passphrase = passphrase.unwrap_or_default();
         let account = WalletAccount::generate();
-        let address = account.address.clone();
-        let locked_account = Locked::with_defaults(account.clone(), passphrase.as_bytes())?;
-
-        let mut txn = self.wallet_store.create_write_transaction();
-        self.wallet_store.put(&address, &locked_account, &mut txn);
-        txn.commit();
+        let address = self
+            .wallet_store
+            .insert(account.clone(), passphrase.as_bytes())?;
 
         Ok(ReturnAccount {
             address,
@@ -99,12 +128,11 @@ impl WalletInterface for WalletDispatcher {
         .into())
     }
 
-    // # TODO The duration parameter is ignored.
     async fn unlock_account(
         &mut self,
         address: Address,
         passphrase: Option<String>,
-        _duration: Option<u64>,
+        duration: Option<u64>,
     ) -> RPCResult<bool, (), Self::Error> {
         let passphrase = passphrase.unwrap_or_default();
         let account = self
@@ -116,14 +144,20 @@ impl WalletInterface for WalletDispatcher {
             .unlock(passphrase.as_bytes())
             .map_err(|_locked| Error::WrongPassphrase)?;
 
-        self.unlocked_wallets.write().insert(unlocked_account);
+        let idle_timeout = duration.map(Duration::from_secs).or(self.idle_timeout);
+        if idle_timeout.is_some() {
+            self.spawn_auto_lock();
+        }
+        self.unlocked_wallets
+            .write()
+            .insert(unlocked_account, idle_timeout);
 
         Ok(true.into())
     }
 
     async fn is_account_unlocked(&mut self, address: Address) -> RPCResult<bool, (), Self::Error> {
         let unlocked_wallets = self.unlocked_wallets.read();
-        let is_unlocked = unlocked_wallets.get(&address).is_some();
+        let is_unlocked = unlocked_wallets.is_unlocked(&address);
 
         Ok(is_unlocked.into())
     }
@@ -166,6 +200,50 @@ impl WalletInterface for WalletDispatcher {
         .into())
     }
 
+    async fn change_passphrase(
+        &mut self,
+        old_passphrase: Option<String>,
+        new_passphrase: String,
+    ) -> RPCResult<usize, (), Self::Error> {
+        let old_passphrase = old_passphrase.unwrap_or_default();
+
+        let num_accounts = self
+            .wallet_store
+            .change_passphrase(old_passphrase.as_bytes(), new_passphrase.as_bytes())?;
+
+        Ok(num_accounts.into())
+    }
+
+    async fn export_backup(
+        &mut self,
+        passphrase: Option<String>,
+    ) -> RPCResult<String, (), Self::Error> {
+        let passphrase = passphrase.unwrap_or_default();
+
+        let backup = self.wallet_store.export_backup(passphrase.as_bytes())?;
+
+        Ok(hex::encode(backup.serialize_to_vec()).into())
+    }
+
+    async fn import_backup(
+        &mut self,
+        backup: String,
+        backup_passphrase: Option<String>,
+        passphrase: Option<String>,
+    ) -> RPCResult<Vec<Address>, (), Self::Error> {
+        let backup_passphrase = backup_passphrase.unwrap_or_default();
+        let passphrase = passphrase.unwrap_or_default();
+
+        let backup = WalletBackup::from_bytes(&hex::decode(backup)?)?;
+        let addresses = self.wallet_store.import_backup(
+            backup,
+            backup_passphrase.as_bytes(),
+            passphrase.as_bytes(),
+        )?;
+
+        Ok(addresses.into())
+    }
+
     async fn verify_signature(
         &mut self,
         message: String,
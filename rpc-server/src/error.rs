@@ -24,6 +24,9 @@ pub enum Error {
     #[error("Block number cannot be smaller than genesis block")]
     BlockNumberBeforeGenesis,
 
+    #[error("No known block at or after timestamp: {0}")]
+    NoBlockAtTimestamp(u64),
+
     #[error("Unexpected macro block: {0}")]
     UnexpectedMacroBlock(u32),
 
@@ -63,6 +66,9 @@ pub enum Error {
     #[error("Wrong passphrase")]
     WrongPassphrase,
 
+    #[error("{0}")]
+    WalletStore(#[from] nimiq_wallet::WalletStoreError),
+
     #[error("No unlocked wallet with address: {0}")]
     UnlockedWalletNotFound(Address),
 
@@ -1,28 +1,130 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
 
 use nimiq_keys::Address;
 use nimiq_utils::otp::Unlocked;
 use nimiq_wallet::WalletAccount;
+use parking_lot::Mutex;
+
+/// An unlocked wallet together with the information needed to lock it automatically once it
+/// has not been used for a while.
+pub struct UnlockedWallet {
+    wallet: Unlocked<WalletAccount>,
+    idle_timeout: Option<Duration>,
+    last_used: Mutex<Instant>,
+}
+
+impl UnlockedWallet {
+    fn is_expired(&self, now: Instant) -> bool {
+        match self.idle_timeout {
+            Some(idle_timeout) => {
+                now.saturating_duration_since(*self.last_used.lock()) >= idle_timeout
+            }
+            None => false,
+        }
+    }
+}
 
 #[derive(Default)]
 pub struct UnlockedWallets {
-    pub unlocked_wallets: HashMap<Address, Unlocked<WalletAccount>>,
+    pub unlocked_wallets: HashMap<Address, UnlockedWallet>,
 }
 
 impl UnlockedWallets {
-    pub fn insert(&mut self, wallet: Unlocked<WalletAccount>) {
+    /// Inserts an unlocked wallet. If an idle timeout is given, the wallet is considered locked
+    /// once it has not been accessed for that long.
+    pub fn insert(&mut self, wallet: Unlocked<WalletAccount>, idle_timeout: Option<Duration>) {
         log::info!("Unlocking {:?}", &wallet.address);
-        self.unlocked_wallets.insert(wallet.address.clone(), wallet);
+        self.unlocked_wallets.insert(
+            wallet.address.clone(),
+            UnlockedWallet {
+                wallet,
+                idle_timeout,
+                last_used: Mutex::new(Instant::now()),
+            },
+        );
     }
 
     pub fn get(&self, address: &Address) -> Option<&WalletAccount> {
         log::info!("Accessing {:?}", address);
+        let now = Instant::now();
+        let unlocked_wallet = self.unlocked_wallets.get(address)?;
+        if unlocked_wallet.is_expired(now) {
+            return None;
+        }
+        *unlocked_wallet.last_used.lock() = now;
+        Some(Unlocked::unlocked_data(&unlocked_wallet.wallet))
+    }
+
+    /// Returns whether the wallet is unlocked. Unlike [`get`](Self::get), this doesn't count as a
+    /// use of the wallet, so it doesn't delay locking it automatically.
+    pub fn is_unlocked(&self, address: &Address) -> bool {
         self.unlocked_wallets
             .get(address)
-            .map(Unlocked::unlocked_data)
+            .is_some_and(|unlocked_wallet| !unlocked_wallet.is_expired(Instant::now()))
     }
 
     pub fn remove(&mut self, address: &Address) -> Option<Unlocked<WalletAccount>> {
-        self.unlocked_wallets.remove(address)
+        self.unlocked_wallets
+            .remove(address)
+            .map(|unlocked_wallet| unlocked_wallet.wallet)
+    }
+
+    /// Removes all unlocked wallets whose idle timeout has passed. This drops (and thereby
+    /// clears) their private keys from memory.
+    pub fn remove_expired(&mut self) {
+        let now = Instant::now();
+        self.unlocked_wallets.retain(|address, unlocked_wallet| {
+            let expired = unlocked_wallet.is_expired(now);
+            if expired {
+                log::info!("Locking {:?} after being idle", address);
+            }
+            !expired
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlocked_wallets(idle_timeout: Duration) -> (UnlockedWallets, Address) {
+        let wallet = Unlocked::with_defaults(WalletAccount::generate(), b"password").unwrap();
+        let address = wallet.address.clone();
+        let mut unlocked_wallets = UnlockedWallets::default();
+        unlocked_wallets.insert(wallet, Some(idle_timeout));
+        (unlocked_wallets, address)
+    }
+
+    fn set_idle(unlocked_wallets: &UnlockedWallets, address: &Address, idle: Duration) {
+        *unlocked_wallets.unlocked_wallets[address].last_used.lock() = Instant::now() - idle;
+    }
+
+    #[test]
+    fn checking_the_lock_status_does_not_count_as_use() {
+        let (unlocked_wallets, address) = unlocked_wallets(Duration::from_secs(60));
+        set_idle(&unlocked_wallets, &address, Duration::from_secs(30));
+
+        assert!(unlocked_wallets.is_unlocked(&address));
+        let last_used = *unlocked_wallets.unlocked_wallets[&address].last_used.lock();
+        assert!(Instant::now().duration_since(last_used) >= Duration::from_secs(30));
+
+        assert!(unlocked_wallets.get(&address).is_some());
+        let last_used = *unlocked_wallets.unlocked_wallets[&address].last_used.lock();
+        assert!(Instant::now().duration_since(last_used) < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn idle_wallets_are_locked() {
+        let (mut unlocked_wallets, address) = unlocked_wallets(Duration::from_secs(60));
+        set_idle(&unlocked_wallets, &address, Duration::from_secs(61));
+
+        assert!(!unlocked_wallets.is_unlocked(&address));
+        assert!(unlocked_wallets.get(&address).is_none());
+
+        unlocked_wallets.remove_expired();
+        assert!(unlocked_wallets.unlocked_wallets.is_empty());
     }
 }
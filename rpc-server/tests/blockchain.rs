@@ -0,0 +1,405 @@
+use std::{str::FromStr, sync::Arc};
+
+use nimiq_account::Staker as BStaker;
+use nimiq_blockchain_proxy::BlockchainProxy;
+use nimiq_hash::Blake2bHash;
+use nimiq_keys::{Address, KeyPair, PrivateKey};
+use nimiq_primitives::{coin::Coin, policy::Policy};
+use nimiq_rpc_interface::{
+    blockchain::BlockchainInterface,
+    types::{Account, AccountType, StakerOrder, StakerPage, StakerShare},
+};
+use nimiq_rpc_server::{dispatchers::BlockchainDispatcher, Error};
+use nimiq_test_log::test;
+use nimiq_test_utils::{block_production::TemporaryBlockProducer, blockchain::REWARD_KEY};
+
+/// The validator and its staker in `genesis/src/genesis/unit-albatross.toml`.
+const VALIDATOR_ADDRESS: &str = "NQ20 TSB0 DFSM UH9C 15GQ GAGJ TTE4 D3MA 859E";
+const STAKER_ADDRESS: &str = "NQ39 VBTN P2HX Q3MF KHF1 CCLA G6FS 9B8S VY28";
+
+fn validator_address() -> Address {
+    Address::from_user_friendly_address(VALIDATOR_ADDRESS).unwrap()
+}
+
+fn dispatcher(producer: &TemporaryBlockProducer) -> BlockchainDispatcher {
+    BlockchainDispatcher::new(
+        BlockchainProxy::from(Arc::clone(&producer.blockchain)),
+        None,
+    )
+}
+
+/// Produces blocks up to and including the next macro block.
+fn produce_batch(producer: &TemporaryBlockProducer) {
+    while !producer.next_block(vec![], false).is_macro() {}
+}
+
+/// Produces blocks up to and including the next election block and returns its block number.
+fn produce_epoch(producer: &TemporaryBlockProducer) -> u32 {
+    loop {
+        let block = producer.next_block(vec![], false);
+        if block.is_election() {
+            return block.block_number();
+        }
+    }
+}
+
+fn staker(id: u8, active_balance: u64) -> BStaker {
+    BStaker {
+        address: Address::from([id; Address::SIZE]),
+        active_balance: Coin::from_u64_unchecked(active_balance),
+        inactive_balance: Coin::from_u64_unchecked(1),
+        inactive_from: Some(Policy::genesis_block_number()),
+        retired_balance: Coin::ZERO,
+        delegation: Some(validator_address()),
+    }
+}
+
+#[test]
+fn it_sorts_and_pages_stakers() {
+    let stakers = vec![
+        staker(1, 300),
+        staker(2, 500),
+        staker(3, 100),
+        staker(4, 400),
+        staker(5, 200),
+    ];
+
+    let page = StakerPage::from_stakers(stakers.clone(), StakerOrder::Address, 1, 2);
+    let addresses: Vec<_> = page.stakers.iter().map(|s| s.address.clone()).collect();
+    assert_eq!(
+        addresses,
+        vec![
+            Address::from([2; Address::SIZE]),
+            Address::from([3; Address::SIZE])
+        ]
+    );
+    // The totals cover all stakers, not only the ones on the page.
+    assert_eq!(page.total_stakers, 5);
+    assert_eq!(page.total_active_balance, Coin::from_u64_unchecked(1500));
+    assert_eq!(page.total_inactive_balance, Coin::from_u64_unchecked(5));
+
+    let page = StakerPage::from_stakers(stakers.clone(), StakerOrder::ActiveBalance, 0, 3);
+    let balances: Vec<_> = page.stakers.iter().map(|s| u64::from(s.balance)).collect();
+    assert_eq!(balances, vec![500, 400, 300]);
+
+    let page = StakerPage::from_stakers(stakers, StakerOrder::Address, 5, 10);
+    assert!(page.stakers.is_empty());
+    assert_eq!(page.total_stakers, 5);
+}
+
+#[test(tokio::test)]
+async fn it_pages_the_stakers_of_a_validator() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let page = dispatcher
+        .get_staker_page_by_validator_address(validator_address(), None, None, None)
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(page.total_stakers, 1);
+    assert_eq!(
+        page.stakers[0].address,
+        Address::from_user_friendly_address(STAKER_ADDRESS).unwrap()
+    );
+
+    let page = dispatcher
+        .get_staker_page_by_validator_address(validator_address(), Some(1), Some(10), None)
+        .await
+        .unwrap()
+        .data;
+    assert!(page.stakers.is_empty());
+    assert_eq!(page.total_stakers, 1);
+}
+
+#[test(tokio::test)]
+async fn it_computes_staker_shares_in_parts_per_billion() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let shares = dispatcher
+        .get_staker_shares_by_validator_address(validator_address(), None)
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(shares.validator_address, validator_address());
+    assert_eq!(shares.shares.len(), 1);
+
+    let share = &shares.shares[0];
+    assert_eq!(
+        share.address,
+        Address::from_user_friendly_address(STAKER_ADDRESS).unwrap()
+    );
+    assert_eq!(shares.total_stake, shares.deposit + share.active_balance);
+    assert_eq!(
+        u64::from(share.share_ppb),
+        u64::from(share.active_balance) * StakerShare::PPB / u64::from(shares.total_stake)
+    );
+
+    assert!(matches!(
+        dispatcher
+            .get_staker_shares_by_validator_address(Address::from([1; Address::SIZE]), None)
+            .await,
+        Err(Error::ValidatorNotFound(_))
+    ));
+}
+
+#[test(tokio::test)]
+async fn it_keeps_staker_shares_of_past_election_blocks() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    assert!(matches!(
+        dispatcher
+            .get_staker_shares_by_validator_address(
+                validator_address(),
+                Some(Policy::genesis_block_number() + 1)
+            )
+            .await,
+        Err(Error::InvalidArgument(_))
+    ));
+
+    let mut election_blocks = vec![];
+    for _ in 0..3 {
+        election_blocks.push(produce_epoch(&producer));
+    }
+
+    // The producer keeps the full history, so the shares of all election blocks are kept.
+    for &election_block in &election_blocks {
+        let snapshot = dispatcher
+            .get_staker_shares_by_validator_address(validator_address(), Some(election_block))
+            .await
+            .unwrap();
+        assert_eq!(snapshot.metadata.block_number, election_block);
+        assert_eq!(snapshot.data.shares.len(), 1);
+    }
+
+    // The head is still the last election block, so its snapshot matches the current shares.
+    let current = dispatcher
+        .get_staker_shares_by_validator_address(validator_address(), None)
+        .await
+        .unwrap()
+        .data;
+    let snapshot = dispatcher
+        .get_staker_shares_by_validator_address(validator_address(), Some(election_blocks[2]))
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(snapshot.total_stake, current.total_stake);
+    assert_eq!(snapshot.shares[0].share_ppb, current.shares[0].share_ppb);
+}
+
+#[test(tokio::test)]
+async fn it_keeps_staker_shares_after_the_head_moved_past_the_election_block() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let election_block = produce_epoch(&producer);
+    let current = dispatcher
+        .get_staker_shares_by_validator_address(validator_address(), None)
+        .await
+        .unwrap()
+        .data;
+    producer.next_block(vec![], false);
+
+    let snapshot = dispatcher
+        .get_staker_shares_by_validator_address(validator_address(), Some(election_block))
+        .await
+        .unwrap();
+    assert_eq!(snapshot.metadata.block_number, election_block);
+    assert_eq!(snapshot.data.total_stake, current.total_stake);
+    assert_eq!(snapshot.data.shares.len(), current.shares.len());
+
+    // Shares aren't available for election blocks that haven't been pushed yet.
+    assert!(matches!(
+        dispatcher
+            .get_staker_shares_by_validator_address(
+                validator_address(),
+                Some(Policy::election_block_after(election_block + 1))
+            )
+            .await,
+        Err(Error::InvalidArgument(_))
+    ));
+}
+
+#[test(tokio::test)]
+async fn it_pages_validators_and_stakers_of_the_staking_contract() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let page = dispatcher
+        .get_account_page(
+            None,
+            None,
+            Some(vec![AccountType::Validator, AccountType::Staker]),
+            None,
+        )
+        .await
+        .unwrap()
+        .data;
+    assert!(page.accounts.is_empty());
+    assert_eq!(page.validators.len(), 1);
+    assert_eq!(page.validators[0].address, validator_address());
+    assert_eq!(page.stakers.len(), 1);
+    assert_eq!(
+        page.stakers[0].address,
+        Address::from_user_friendly_address(STAKER_ADDRESS).unwrap()
+    );
+    assert_eq!(page.next_key, None);
+
+    // The staker's active balance is compared to the minimum balance.
+    let min_balance = page.stakers[0].balance + Coin::from_u64_unchecked(1);
+    let page = dispatcher
+        .get_account_page(
+            None,
+            None,
+            Some(vec![AccountType::Staker]),
+            Some(min_balance),
+        )
+        .await
+        .unwrap()
+        .data;
+    assert!(page.stakers.is_empty());
+
+    let page = dispatcher
+        .get_account_page(None, None, Some(vec![AccountType::Staking]), None)
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(page.accounts.len(), 1);
+    assert_eq!(page.accounts[0].address, Policy::STAKING_CONTRACT_ADDRESS);
+    assert!(page.validators.is_empty() && page.stakers.is_empty());
+}
+
+#[test(tokio::test)]
+async fn it_computes_the_supply_of_the_head_block() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let supply = dispatcher.get_supply().await.unwrap();
+    assert_eq!(
+        supply.metadata.block_number,
+        producer.blockchain.read().block_number()
+    );
+    let supply = supply.data;
+    assert_eq!(
+        supply.staking,
+        supply.validator_deposits
+            + supply.active_stake
+            + supply.inactive_stake
+            + supply.retired_stake
+    );
+    let staker = dispatcher
+        .get_staker_by_address(Address::from_user_friendly_address(STAKER_ADDRESS).unwrap())
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(supply.active_stake, staker.balance);
+    assert!(supply.total_supply >= supply.staking + supply.vested + supply.unvested + supply.htlc);
+
+    // The supply is computed again for a new head block, which mints the batch reward.
+    let block_number = produce_epoch(&producer);
+    let supply_after_election = dispatcher.get_supply().await.unwrap();
+    assert_eq!(supply_after_election.metadata.block_number, block_number);
+    assert!(supply_after_election.data.total_supply > supply.total_supply);
+    assert!(supply_after_election.data.theoretical_supply > supply.theoretical_supply);
+}
+
+#[test(tokio::test)]
+async fn it_returns_accounts_with_a_verifiable_proof() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+    producer.next_block(vec![], false);
+
+    let reward_address = Address::from(&KeyPair::from(PrivateKey::from_str(REWARD_KEY).unwrap()));
+    let unknown_address = Address::from([7; Address::SIZE]);
+    let accounts = dispatcher
+        .get_accounts_with_proof(vec![reward_address, unknown_address.clone()])
+        .await
+        .unwrap()
+        .data;
+    let block = dispatcher.get_latest_block(None).await.unwrap().data;
+
+    assert_eq!(accounts.block_hash, block.hash);
+    assert!(accounts.accounts[0].balance > Coin::ZERO);
+    assert_eq!(accounts.accounts[1], Account::empty(unknown_address));
+    accounts.verify(&block).unwrap();
+}
+
+#[test(tokio::test)]
+async fn it_rejects_tampered_accounts_proofs() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+    producer.next_block(vec![], false);
+
+    let reward_address = Address::from(&KeyPair::from(PrivateKey::from_str(REWARD_KEY).unwrap()));
+    let accounts = dispatcher
+        .get_accounts_with_proof(vec![reward_address])
+        .await
+        .unwrap()
+        .data;
+    let block = dispatcher.get_latest_block(None).await.unwrap().data;
+
+    let mut tampered = accounts.clone();
+    tampered.accounts[0].balance += Coin::from_u64_unchecked(1);
+    assert!(tampered.verify(&block).is_err());
+
+    let mut tampered = accounts.clone();
+    tampered.accounts[0].address = Address::from([7; Address::SIZE]);
+    assert!(tampered.verify(&block).is_err());
+
+    let mut tampered = accounts.clone();
+    tampered.proof.truncate(tampered.proof.len() / 2);
+    assert!(tampered.verify(&block).is_err());
+
+    let mut other_block = block.clone();
+    other_block.state_hash = Blake2bHash::default();
+    assert!(accounts.verify(&other_block).is_err());
+
+    let previous_block = dispatcher
+        .get_block_by_number(block.number - 1, None)
+        .await
+        .unwrap()
+        .data;
+    assert!(accounts.verify(&previous_block).is_err());
+}
+
+#[test(tokio::test)]
+async fn it_limits_the_number_of_accounts_with_proof() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    assert!(matches!(
+        dispatcher.get_accounts_with_proof(vec![]).await,
+        Err(Error::InvalidArgument(_))
+    ));
+
+    let addresses = (0..=100)
+        .map(|i: u8| Address::from([i; Address::SIZE]))
+        .collect();
+    assert!(matches!(
+        dispatcher.get_accounts_with_proof(addresses).await,
+        Err(Error::InvalidArgument(_))
+    ));
+}
+
+#[test(tokio::test)]
+async fn it_accumulates_the_burned_supply() {
+    let producer = TemporaryBlockProducer::new();
+    let mut dispatcher = dispatcher(&producer);
+
+    let supply = dispatcher.get_supply().await.unwrap().data;
+    assert_eq!(supply.burned, Some(Coin::ZERO));
+    assert_eq!(supply.penalized, Some(Coin::ZERO));
+
+    // Penalize a slot. Its reward is burned once the batch is rewarded by the macro block of the
+    // following batch.
+    producer.next_block(vec![], true);
+    produce_batch(&producer);
+    produce_batch(&producer);
+
+    let supply = dispatcher.get_supply().await.unwrap().data;
+    let penalized = supply.penalized.unwrap();
+    assert!(penalized > Coin::ZERO);
+    assert_eq!(supply.burned, Some(penalized));
+}
@@ -0,0 +1,338 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use futures::{stream::BoxStream, StreamExt};
+use nimiq_blockchain::{Blockchain, BlockchainConfig};
+use nimiq_blockchain_interface::AbstractBlockchain;
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_hash::{Blake2bHash, Hash};
+use nimiq_keys::{Address, KeyPair, PrivateKey};
+use nimiq_mempool::{
+    config::MempoolConfig, filter::MempoolRules, mempool::Mempool, verify::VerifyErr,
+};
+use nimiq_primitives::{coin::Coin, networks::NetworkId};
+use nimiq_rpc_interface::{
+    mempool::MempoolInterface,
+    types::{BlockchainState, MempoolEvent, RPCData, TransactionState, TransactionStatus},
+};
+use nimiq_rpc_server::dispatchers::MempoolDispatcher;
+use nimiq_serde::Serialize;
+use nimiq_test_log::test;
+use nimiq_test_utils::{block_production::TemporaryBlockProducer, blockchain::REWARD_KEY};
+use nimiq_time::timeout;
+use nimiq_transaction::Transaction;
+use nimiq_transaction_builder::TransactionBuilder;
+use nimiq_utils::time::OffsetTime;
+use parking_lot::RwLock;
+
+fn dispatcher(producer: &TemporaryBlockProducer) -> (MempoolDispatcher, Arc<Mempool>) {
+    dispatcher_with_config(producer, MempoolConfig::default())
+}
+
+fn dispatcher_with_config(
+    producer: &TemporaryBlockProducer,
+    config: MempoolConfig,
+) -> (MempoolDispatcher, Arc<Mempool>) {
+    let mempool = Arc::new(Mempool::new(Arc::clone(&producer.blockchain), config));
+    let dispatcher = MempoolDispatcher::new(Arc::clone(&mempool), Arc::clone(&producer.blockchain));
+    (dispatcher, mempool)
+}
+
+/// Creates a transaction sent from the funded basic account of the unit genesis.
+fn transaction(producer: &TemporaryBlockProducer) -> Transaction {
+    transaction_with(producer, Coin::from_u64_unchecked(10), Coin::ZERO)
+}
+
+fn transaction_with(producer: &TemporaryBlockProducer, value: Coin, fee: Coin) -> Transaction {
+    let key_pair = KeyPair::from(PrivateKey::from_str(REWARD_KEY).unwrap());
+    TransactionBuilder::new_basic(
+        &key_pair,
+        Address::from([1; Address::SIZE]),
+        value,
+        fee,
+        producer.blockchain.read().block_number(),
+        NetworkId::UnitAlbatross,
+    )
+    .unwrap()
+}
+
+/// Produces blocks up to and including the next macro block.
+fn produce_batch(producer: &TemporaryBlockProducer) {
+    while !producer.next_block(vec![], false).is_macro() {}
+}
+
+async fn status(dispatcher: &mut MempoolDispatcher, hash: &Blake2bHash) -> TransactionStatus {
+    dispatcher
+        .get_transaction_status(hash.clone())
+        .await
+        .unwrap()
+        .data
+}
+
+#[test(tokio::test)]
+async fn it_reports_the_transaction_lifecycle() {
+    let producer = TemporaryBlockProducer::new();
+    let (mut dispatcher, mempool) = dispatcher(&producer);
+    let tx = transaction(&producer);
+    let hash: Blake2bHash = tx.hash();
+
+    let status_before = status(&mut dispatcher, &hash).await;
+    assert_eq!(status_before.state, TransactionState::Unknown);
+    assert_eq!(status_before.confirmations, None);
+
+    mempool.add_transaction(tx.clone(), None).unwrap();
+    assert_eq!(
+        status(&mut dispatcher, &hash).await.state,
+        TransactionState::Pending
+    );
+
+    let block = producer.next_block_with_txs(vec![], false, vec![tx]);
+    let included = status(&mut dispatcher, &hash).await;
+    assert_eq!(included.state, TransactionState::Included);
+    assert_eq!(included.block_number, Some(block.block_number()));
+    assert_eq!(included.confirmations, Some(1));
+
+    producer.next_block(vec![], false);
+    assert_eq!(status(&mut dispatcher, &hash).await.confirmations, Some(2));
+
+    produce_batch(&producer);
+    let finalized = status(&mut dispatcher, &hash).await;
+    assert_eq!(finalized.state, TransactionState::Finalized);
+    assert_eq!(finalized.block_number, Some(block.block_number()));
+}
+
+#[test(tokio::test)]
+async fn it_finds_confirmations_without_history_index() {
+    let blockchain = Blockchain::new(
+        MdbxDatabase::new_volatile(Default::default()).unwrap(),
+        BlockchainConfig {
+            index_history: false,
+            ..Default::default()
+        },
+        NetworkId::UnitAlbatross,
+        Arc::new(OffsetTime::new()),
+    )
+    .unwrap();
+    let producer = TemporaryBlockProducer {
+        blockchain: Arc::new(RwLock::new(blockchain)),
+        producer: TemporaryBlockProducer::new().producer,
+    };
+    let (mut dispatcher, _) = dispatcher(&producer);
+    let tx = transaction(&producer);
+    let hash: Blake2bHash = tx.hash();
+
+    let block = producer.next_block_with_txs(vec![], false, vec![tx]);
+    producer.next_block(vec![], false);
+
+    let included = status(&mut dispatcher, &hash).await;
+    assert_eq!(included.state, TransactionState::Included);
+    assert_eq!(included.block_number, Some(block.block_number()));
+    assert_eq!(included.confirmations, Some(2));
+}
+
+#[test(tokio::test)]
+async fn it_ends_the_status_subscription_once_all_transactions_are_final() {
+    let producer = TemporaryBlockProducer::new();
+    let (mut dispatcher, mempool) = dispatcher(&producer);
+    let tx = transaction(&producer);
+    let hash: Blake2bHash = tx.hash();
+    mempool.add_transaction(tx.clone(), None).unwrap();
+
+    let mut stream = dispatcher
+        .subscribe_for_transaction_status(vec![hash.clone()])
+        .await
+        .unwrap();
+    let initial = stream.next().await.unwrap().data;
+    assert_eq!(initial.state, TransactionState::Pending);
+
+    producer.next_block_with_txs(vec![], false, vec![tx]);
+    let included = stream.next().await.unwrap().data;
+    assert_eq!(included.state, TransactionState::Included);
+    assert_eq!(included.confirmations, Some(1));
+
+    // The status is only reported again once it changed, the stream ends after finalization.
+    produce_batch(&producer);
+    let statuses: Vec<TransactionStatus> = timeout(
+        Duration::from_secs(10),
+        stream.map(|status| status.data).collect::<Vec<_>>(),
+    )
+    .await
+    .expect("The stream should end once the transaction is finalized");
+    assert_eq!(
+        statuses.last().map(|status| status.state),
+        Some(TransactionState::Finalized)
+    );
+}
+
+#[test(tokio::test)]
+async fn it_ends_the_status_subscription_immediately_for_final_transactions() {
+    let producer = TemporaryBlockProducer::new();
+    let (mut dispatcher, _) = dispatcher(&producer);
+    let tx = transaction(&producer);
+    let hash: Blake2bHash = tx.hash();
+
+    producer.next_block_with_txs(vec![], false, vec![tx]);
+    produce_batch(&producer);
+
+    let statuses: Vec<_> = dispatcher
+        .subscribe_for_transaction_status(vec![hash])
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(statuses.len(), 1);
+    assert_eq!(statuses[0].data.state, TransactionState::Finalized);
+}
+
+/// Collects the remaining statuses of a subscription, which must end in time.
+async fn remaining_states(
+    stream: BoxStream<'static, RPCData<TransactionStatus, BlockchainState>>,
+) -> Vec<TransactionState> {
+    timeout(
+        Duration::from_secs(10),
+        stream.map(|status| status.data.state).collect::<Vec<_>>(),
+    )
+    .await
+    .expect("The stream should end once the transaction is final")
+}
+
+#[test(tokio::test)]
+async fn it_ends_the_status_subscription_once_a_transaction_is_filtered() {
+    let producer = TemporaryBlockProducer::new();
+    let config = MempoolConfig {
+        filter_rules: MempoolRules {
+            tx_value: Coin::from_u64_unchecked(100),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let (mut dispatcher, mempool) = dispatcher_with_config(&producer, config);
+    let tx = transaction(&producer);
+
+    let mut stream = dispatcher
+        .subscribe_for_transaction_status(vec![tx.hash()])
+        .await
+        .unwrap();
+    assert_eq!(
+        stream.next().await.unwrap().data.state,
+        TransactionState::Unknown
+    );
+
+    assert_eq!(mempool.add_transaction(tx, None), Err(VerifyErr::Filtered));
+    assert_eq!(
+        remaining_states(stream).await,
+        vec![TransactionState::Filtered]
+    );
+}
+
+#[test(tokio::test)]
+async fn it_ends_the_status_subscription_once_a_transaction_is_evicted() {
+    let producer = TemporaryBlockProducer::new();
+    let tx = transaction(&producer);
+    let better_tx = transaction_with(
+        &producer,
+        Coin::from_u64_unchecked(10),
+        Coin::from_u64_unchecked(1000),
+    );
+
+    // Only one of the two transactions fits into the mempool.
+    let config = MempoolConfig {
+        size_limit: tx.serialized_size() + better_tx.serialized_size() - 1,
+        ..Default::default()
+    };
+    let (mut dispatcher, mempool) = dispatcher_with_config(&producer, config);
+    mempool.add_transaction(tx.clone(), None).unwrap();
+
+    let mut stream = dispatcher
+        .subscribe_for_transaction_status(vec![tx.hash()])
+        .await
+        .unwrap();
+    assert_eq!(
+        stream.next().await.unwrap().data.state,
+        TransactionState::Pending
+    );
+
+    mempool.add_transaction(better_tx, None).unwrap();
+    assert_eq!(
+        remaining_states(stream).await,
+        vec![TransactionState::Evicted]
+    );
+}
+
+#[test(tokio::test)]
+async fn it_ends_the_status_subscription_once_a_transaction_is_invalidated() {
+    let producer = TemporaryBlockProducer::new();
+    let (mut dispatcher, mempool) = dispatcher(&producer);
+    let sender = Address::from(&KeyPair::from(PrivateKey::from_str(REWARD_KEY).unwrap()));
+    let balance = producer
+        .blockchain
+        .read()
+        .get_account_if_complete(&sender)
+        .unwrap()
+        .balance();
+
+    // The pending transaction spends almost all of the balance, which another transaction of the
+    // same sender included in a block uses up.
+    let tx = transaction_with(
+        &producer,
+        balance - Coin::from_u64_unchecked(10),
+        Coin::ZERO,
+    );
+    mempool.add_transaction(tx.clone(), None).unwrap();
+
+    let mut stream = dispatcher
+        .subscribe_for_transaction_status(vec![tx.hash()])
+        .await
+        .unwrap();
+    assert_eq!(
+        stream.next().await.unwrap().data.state,
+        TransactionState::Pending
+    );
+
+    let block = producer.next_block_with_txs(
+        vec![],
+        false,
+        vec![transaction_with(
+            &producer,
+            Coin::from_u64_unchecked(20),
+            Coin::ZERO,
+        )],
+    );
+    mempool.update(&[(block.hash(), block)], &[]);
+    assert_eq!(
+        remaining_states(stream).await.last(),
+        Some(&TransactionState::Invalidated)
+    );
+}
+
+#[test(tokio::test)]
+async fn it_reports_missed_mempool_events() {
+    let producer = TemporaryBlockProducer::new();
+    let config = MempoolConfig {
+        filter_rules: MempoolRules {
+            tx_value: Coin::from_u64_unchecked(10_000),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let (mut dispatcher, mempool) = dispatcher_with_config(&producer, config);
+    let mut stream = dispatcher
+        .subscribe_for_mempool_events(vec![], None)
+        .await
+        .unwrap();
+
+    // Every filtered transaction raises an event, more than the subscriber can buffer.
+    for value in 1..=1100 {
+        let tx = transaction_with(&producer, Coin::from_u64_unchecked(value), Coin::ZERO);
+        assert_eq!(mempool.add_transaction(tx, None), Err(VerifyErr::Filtered));
+    }
+
+    let event = timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("The lag should be reported")
+        .unwrap()
+        .data;
+    assert!(matches!(
+        event,
+        MempoolEvent::Lagged { missed_events } if missed_events > 0
+    ));
+}
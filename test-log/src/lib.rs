@@ -29,6 +29,10 @@ pub fn initialize() {
         let mut policy_config = TEST_POLICY;
         // The genesis block number must be set accordingly
         policy_config.genesis_block_number = genesis_block.block_number();
+        // The commission fork happens after the first epoch, so that tests can cover the chain
+        // before and after it
+        policy_config.commission_fork_block_number = genesis_block.block_number()
+            + policy_config.blocks_per_batch * policy_config.batches_per_epoch as u32;
 
         let _ = Policy::get_or_init(policy_config);
     });
@@ -599,6 +599,7 @@ impl<R: Rng + CryptoRng> TransactionsGenerator<R> {
                         voting_key: validator_voting_key_compressed.clone(),
                         reward_address: Address(self.rng.gen()),
                         signal_data: None,
+                        commission: Policy::MAX_COMMISSION,
                         proof_of_knowledge: validator_voting_key_pair
                             .sign(&validator_voting_key_compressed.serialize_to_vec())
                             .compress(),
@@ -628,6 +629,7 @@ impl<R: Rng + CryptoRng> TransactionsGenerator<R> {
                         new_voting_key: Some(new_validator_voting_key_compressed.clone()),
                         new_reward_address: Some(Address(self.rng.gen())),
                         new_signal_data: None,
+                        new_commission: None,
                         new_proof_of_knowledge: Some(
                             new_validator_voting_key_pair
                                 .sign(&new_validator_voting_key_compressed.serialize_to_vec())
@@ -832,6 +834,7 @@ impl<R: Rng + CryptoRng> TransactionsGenerator<R> {
                 validator_voting_key_pair.public_key.compress(),
                 Address::from(&validator_key_pair),
                 None,
+                Policy::MAX_COMMISSION,
                 deposit,
                 None,
                 None,
@@ -1,8 +1,9 @@
 use std::{error::Error, process};
 
 use clap::{Arg, Command};
-use nimiq_keys::{Address, Ed25519PublicKey, PrivateKey, SecureGenerate};
+use nimiq_keys::{Address, Ed25519PublicKey, PrivateKey};
 use nimiq_serde::Deserialize;
+use nimiq_tools::keys::{generate_key_pair, private_key_hex};
 
 fn parse_private_key(s: &str) -> Result<PrivateKey, Box<dyn Error>> {
     Ok(PrivateKey::deserialize_from_vec(&hex::decode(s)?)?)
@@ -23,7 +24,7 @@ fn main() {
             }
         }
     } else {
-        PrivateKey::generate_default_csprng()
+        generate_key_pair().private
     };
     let public_key = Ed25519PublicKey::from(&private_key);
     let address = Address::from(&public_key);
@@ -31,5 +32,5 @@ fn main() {
     println!("Address:       {}", address.to_user_friendly_address());
     println!("Address (raw): {}", address.to_hex());
     println!("Public Key:    {}", public_key.to_hex());
-    println!("Private Key:   {}", hex::encode(private_key.as_bytes()));
+    println!("Private Key:   {}", private_key_hex(&private_key));
 }
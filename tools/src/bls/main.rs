@@ -1,21 +1,19 @@
-use nimiq_bls::{PublicKey, SecretKey};
-use nimiq_serde::Serialize;
-use nimiq_utils::key_rng::SecureGenerate;
+use nimiq_tools::keys::{
+    bls_proof_of_knowledge_hex, bls_public_key_hex, bls_secret_key_hex, generate_bls_key_pair,
+};
 
 fn main() {
-    let secret_key = SecretKey::generate_default_csprng();
-    let public_key = PublicKey::from_secret(&secret_key);
+    let key_pair = generate_bls_key_pair();
 
     println!("# Public Key:");
     println!();
-    let x: Vec<u8> = public_key.serialize_to_vec(); // need to apply a little bit of force to make it a slice
-    println!("{}", hex::encode(x));
+    println!("{}", bls_public_key_hex(&key_pair.public_key));
     println!();
     println!("# Secret Key:");
     println!();
-    println!("{}", hex::encode(secret_key.serialize_to_vec()));
+    println!("{}", bls_secret_key_hex(&key_pair.secret_key));
     println!();
     println!("# Proof Of Knowledge:");
     println!();
-    println!("{}", hex::encode(secret_key.sign(&public_key).compress()));
+    println!("{}", bls_proof_of_knowledge_hex(&key_pair));
 }
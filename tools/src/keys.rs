@@ -0,0 +1,34 @@
+use nimiq_bls::{KeyPair as BlsKeyPair, PublicKey as BlsPublicKey, SecretKey as BlsSecretKey};
+use nimiq_keys::{KeyPair, PrivateKey};
+use nimiq_serde::Serialize;
+use nimiq_utils::key_rng::SecureGenerate;
+
+/// Generates a new Schnorr key pair, e.g. for an address or a signing key.
+pub fn generate_key_pair() -> KeyPair {
+    KeyPair::from(PrivateKey::generate_default_csprng())
+}
+
+/// Encodes a Schnorr private key as hex, the format used by the client config.
+pub fn private_key_hex(private_key: &PrivateKey) -> String {
+    hex::encode(private_key.as_bytes())
+}
+
+/// Generates a new BLS key pair, e.g. for a voting key.
+pub fn generate_bls_key_pair() -> BlsKeyPair {
+    BlsKeyPair::from(BlsSecretKey::generate_default_csprng())
+}
+
+/// Encodes a BLS secret key as hex, the format used by the client config.
+pub fn bls_secret_key_hex(secret_key: &BlsSecretKey) -> String {
+    hex::encode(secret_key.serialize_to_vec())
+}
+
+/// Encodes a BLS public key as hex.
+pub fn bls_public_key_hex(public_key: &BlsPublicKey) -> String {
+    hex::encode(public_key.serialize_to_vec())
+}
+
+/// Computes the proof of knowledge of the secret key of a BLS key pair, encoded as hex.
+pub fn bls_proof_of_knowledge_hex(key_pair: &BlsKeyPair) -> String {
+    hex::encode(key_pair.secret_key.sign(&key_pair.public_key).compress())
+}
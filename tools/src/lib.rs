@@ -0,0 +1,4 @@
+//! Key generation shared by the command line tools and other crates that need keys in the same
+//! format, e.g. the devnet launcher.
+
+pub mod keys;
@@ -10,6 +10,7 @@ use thiserror::Error;
 
 pub use crate::{proof::TransactionProofBuilder, recipient::Recipient, sender::Sender};
 
+pub mod payout;
 pub mod proof;
 pub mod recipient;
 pub mod sender;
@@ -1222,6 +1223,8 @@ impl TransactionBuilder {
     ///  - `voting_key_pair`:       The BLS key pair used by the validator.
     ///  - `reward_address`:        The address to which the staking rewards are sent.
     ///  - `signal_data`:           The signal data showed by the validator.
+    ///  - `commission`:            The share of the rewards kept by the validator, in basis points.
+    ///                             The rest is distributed to the stakers delegating to it.
     ///  - `fee`:                   Transaction fee.
     ///  - `validity_start_height`: Block height from which this transaction is valid.
     ///  - `network_id`:            ID of network for which the transaction is meant.
@@ -1237,12 +1240,19 @@ impl TransactionBuilder {
         voting_key_pair: &BlsKeyPair,
         reward_address: Address,
         signal_data: Option<Blake2bHash>,
+        commission: u16,
         fee: Coin,
         validity_start_height: u32,
         network_id: NetworkId,
     ) -> Result<Transaction, TransactionBuilderError> {
         let mut recipient = Recipient::new_staking_builder();
-        recipient.create_validator(signing_key, voting_key_pair, reward_address, signal_data);
+        recipient.create_validator(
+            signing_key,
+            voting_key_pair,
+            reward_address,
+            signal_data,
+            commission,
+        );
 
         let mut builder = Self::new();
         builder
@@ -1276,6 +1286,7 @@ impl TransactionBuilder {
     ///  - `new_signing_key`:          The new Schnorr signing key used by the validator.
     ///  - `new_reward_address`:       The new address to which the staking reward is sent.
     ///  - `new_signal_data`:          The new signal data showed by the validator.
+    ///  - `new_commission`:           The new share of the rewards kept by the validator.
     ///  - `new_voting_key_pair`:      The new validator BLS key pair used by the validator.
     ///  - `fee`:                      Transaction fee.
     ///  - `validity_start_height`:    Block height from which this transaction is valid.
@@ -1296,6 +1307,7 @@ impl TransactionBuilder {
         new_voting_key_pair: Option<&BlsKeyPair>,
         new_reward_address: Option<Address>,
         new_signal_data: Option<Option<Blake2bHash>>,
+        new_commission: Option<u16>,
         fee: Coin,
         validity_start_height: u32,
         network_id: NetworkId,
@@ -1306,6 +1318,7 @@ impl TransactionBuilder {
             new_voting_key_pair,
             new_reward_address,
             new_signal_data,
+            new_commission,
         );
 
         let mut builder = Self::new();
@@ -0,0 +1,257 @@
+//! Payout plans for staking pools that redistribute their validator's rewards off-chain.
+//!
+//! A [`PayoutPlan`] is computed from the rewards a validator received in a range of epochs and
+//! the active balances of the stakers delegating to it at the election blocks that selected the
+//! validator sets of those epochs. The plan can be reviewed as CSV or JSON before the payout
+//! transactions are built from it.
+
+use std::collections::BTreeMap;
+
+use nimiq_keys::{Address, KeyPair};
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
+use nimiq_transaction::Transaction;
+use thiserror::Error;
+
+use crate::{TransactionBuilder, TransactionBuilderError};
+
+/// Computing a payout plan can fail if the inputs are inconsistent.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PayoutError {
+    /// The commission is given in basis points and can't exceed [`Policy::MAX_COMMISSION`].
+    #[error("Invalid commission {0}, the maximum is {max}", max = Policy::MAX_COMMISSION)]
+    InvalidCommission(u16),
+    /// The rewards of an epoch were given more than once.
+    #[error("Duplicate rewards for epoch {0}")]
+    DuplicateEpoch(u32),
+}
+
+/// The active balance a staker had delegated to the pool's validator at an election block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-derive",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct StakerBalance {
+    pub address: Address,
+    pub active_balance: Coin,
+}
+
+/// The rewards the pool's validator received for its slots in an epoch, together with the stake
+/// delegated to it at the election block that selected the validators of the epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EpochRewards {
+    pub epoch: u32,
+    pub election_block_number: u32,
+    /// The rewards paid to the validator's reward address. Rewards the staking contract already
+    /// distributed to the stakers on-chain must not be included.
+    pub reward: Coin,
+    pub stakers: Vec<StakerBalance>,
+}
+
+/// The payout of a single staker for a single epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-derive",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct StakerPayout {
+    pub address: Address,
+    pub active_balance: Coin,
+    pub value: Coin,
+}
+
+/// The split of the rewards of a single epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-derive",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct EpochPayout {
+    pub epoch: u32,
+    pub election_block_number: u32,
+    pub reward: Coin,
+    /// The part of the reward the pool keeps. This includes the rounding remainder of the
+    /// stakers' payouts.
+    pub commission: Coin,
+    pub stakers: Vec<StakerPayout>,
+}
+
+/// A payment of the plan. Payouts of a staker are summed up over all epochs, so that every staker
+/// receives a single transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-derive",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct Payout {
+    pub recipient: Address,
+    pub value: Coin,
+}
+
+/// The rewards of a validator over a range of epochs, split between the pool and its stakers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-derive",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct PayoutPlan {
+    pub validator_address: Address,
+    /// The commission of the pool in basis points.
+    pub commission: u16,
+    pub total_reward: Coin,
+    pub total_commission: Coin,
+    /// The epochs of the plan, ordered by epoch number.
+    pub epochs: Vec<EpochPayout>,
+    /// The payouts to the stakers, ordered by recipient address.
+    pub payouts: Vec<Payout>,
+}
+
+impl PayoutPlan {
+    /// Computes the payout plan of a validator. In every epoch, the pool keeps `commission` basis
+    /// points of the reward and the remainder is split between the stakers proportionally to
+    /// their active balances. Amounts that can't be split evenly are kept by the pool.
+    pub fn compute(
+        validator_address: Address,
+        commission: u16,
+        mut epochs: Vec<EpochRewards>,
+    ) -> Result<Self, PayoutError> {
+        if commission > Policy::MAX_COMMISSION {
+            return Err(PayoutError::InvalidCommission(commission));
+        }
+
+        epochs.sort_by_key(|epoch| epoch.epoch);
+        if let Some(window) = epochs.windows(2).find(|w| w[0].epoch == w[1].epoch) {
+            return Err(PayoutError::DuplicateEpoch(window[0].epoch));
+        }
+
+        let mut total_reward = Coin::ZERO;
+        let mut total_commission = Coin::ZERO;
+        let mut payouts: BTreeMap<Address, Coin> = BTreeMap::new();
+
+        let epochs = epochs
+            .into_iter()
+            .map(|epoch| {
+                let distributable = epoch.reward
+                    - share_of(
+                        epoch.reward,
+                        commission.into(),
+                        Policy::MAX_COMMISSION.into(),
+                    );
+                let total_balance = epoch
+                    .stakers
+                    .iter()
+                    .fold(Coin::ZERO, |sum, staker| sum + staker.active_balance);
+
+                let stakers: Vec<StakerPayout> = if total_balance.is_zero() {
+                    vec![]
+                } else {
+                    epoch
+                        .stakers
+                        .into_iter()
+                        .map(|staker| StakerPayout {
+                            value: share_of(
+                                distributable,
+                                u64::from(staker.active_balance),
+                                u64::from(total_balance),
+                            ),
+                            address: staker.address,
+                            active_balance: staker.active_balance,
+                        })
+                        .filter(|payout| !payout.value.is_zero())
+                        .collect()
+                };
+
+                let distributed = stakers
+                    .iter()
+                    .fold(Coin::ZERO, |sum, payout| sum + payout.value);
+                for payout in &stakers {
+                    *payouts.entry(payout.address.clone()).or_default() += payout.value;
+                }
+
+                total_reward += epoch.reward;
+                total_commission += epoch.reward - distributed;
+
+                EpochPayout {
+                    epoch: epoch.epoch,
+                    election_block_number: epoch.election_block_number,
+                    reward: epoch.reward,
+                    commission: epoch.reward - distributed,
+                    stakers,
+                }
+            })
+            .collect();
+
+        Ok(PayoutPlan {
+            validator_address,
+            commission,
+            total_reward,
+            total_commission,
+            epochs,
+            payouts: payouts
+                .into_iter()
+                .map(|(recipient, value)| Payout { recipient, value })
+                .collect(),
+        })
+    }
+
+    /// The sum of all payouts to the stakers.
+    pub fn total_payout(&self) -> Coin {
+        self.payouts
+            .iter()
+            .fold(Coin::ZERO, |sum, payout| sum + payout.value)
+    }
+
+    /// Returns the per-epoch breakdown of the plan as CSV, with one line per staker and epoch.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("epoch,election_block_number,staker_address,active_balance,value\n");
+        for epoch in &self.epochs {
+            for staker in &epoch.stakers {
+                csv.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    epoch.epoch,
+                    epoch.election_block_number,
+                    staker.address.to_user_friendly_address(),
+                    u64::from(staker.active_balance),
+                    u64::from(staker.value),
+                ));
+            }
+        }
+        csv
+    }
+
+    /// Builds and signs a basic transaction for each payout of the plan. The `fee` of every
+    /// transaction is paid on top of the payout by the account of `key_pair`.
+    pub fn build_transactions(
+        &self,
+        key_pair: &KeyPair,
+        fee: Coin,
+        validity_start_height: u32,
+        network_id: NetworkId,
+    ) -> Result<Vec<Transaction>, TransactionBuilderError> {
+        self.payouts
+            .iter()
+            .map(|payout| {
+                TransactionBuilder::new_basic(
+                    key_pair,
+                    payout.recipient.clone(),
+                    payout.value,
+                    fee,
+                    validity_start_height,
+                    network_id,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Computes `value * numerator / denominator`, rounded down.
+fn share_of(value: Coin, numerator: u64, denominator: u64) -> Coin {
+    let share = u128::from(u64::from(value)) * u128::from(numerator) / u128::from(denominator);
+    Coin::from_u64_unchecked(share as u64)
+}
@@ -219,7 +219,7 @@ impl TransactionProofBuilder {
     ///
     /// let sender = Sender::new_basic(Address::from(&cold_key_pair.public));
     /// let mut recipient = Recipient::new_staking_builder();
-    /// recipient.update_validator(Some(signing_key_pair.public), Some(&bls_key_pair), None, None);
+    /// recipient.update_validator(Some(signing_key_pair.public), Some(&bls_key_pair), None, None, None);
     ///
     /// let tx_builder = TransactionBuilder::with_required(
     ///     sender,
@@ -39,7 +39,8 @@ impl StakingRecipientBuilder {
     }
 
     /// This method allows to create a new validator entry using two addresses and a BLS key pair.
-    /// All rewards for this validator will be paid out to its `reward_address`.
+    /// The validator's `commission` share of its rewards will be paid out to its `reward_address`,
+    /// the rest is distributed to the stakers delegating to it.
     /// The proof needs to be signed by the cold keypair, which is the key pair that determines the
     /// validator address, and is not an input to this function.
     pub fn create_validator(
@@ -48,6 +49,7 @@ impl StakingRecipientBuilder {
         voting_key_pair: &BlsKeyPair,
         reward_address: Address,
         signal_data: Option<Blake2bHash>,
+        commission: u16,
     ) -> &mut Self {
         self.data = Some(IncomingStakingTransactionData::CreateValidator {
             signing_key,
@@ -57,6 +59,7 @@ impl StakingRecipientBuilder {
             ),
             reward_address,
             signal_data,
+            commission,
             proof: Default::default(),
         });
         self
@@ -70,6 +73,7 @@ impl StakingRecipientBuilder {
         new_key_pair: Option<&BlsKeyPair>,
         new_reward_address: Option<Address>,
         new_signal_data: Option<Option<Blake2bHash>>,
+        new_commission: Option<u16>,
     ) -> &mut Self {
         self.data = Some(IncomingStakingTransactionData::UpdateValidator {
             new_signing_key,
@@ -78,6 +82,7 @@ impl StakingRecipientBuilder {
                 .map(StakingRecipientBuilder::generate_proof_of_knowledge),
             new_reward_address,
             new_signal_data,
+            new_commission,
             proof: Default::default(),
         });
         self
@@ -195,7 +200,7 @@ impl StakingRecipientBuilder {
     /// let reward_address = Address::from_any_str("NQ46 MNYU LQ93 GYYS P5DC YA51 L5JP UPUT KR62").unwrap();
     ///
     /// let mut recipient_builder = Recipient::new_staking_builder();
-    /// recipient_builder.create_validator(signing_key_pair.public, &voting_key_pair, reward_address, None);
+    /// recipient_builder.create_validator(signing_key_pair.public, &voting_key_pair, reward_address, None, 1_000);
     /// let recipient = recipient_builder.generate();
     /// assert!(recipient.is_some());
     /// ```
@@ -1,3 +1,4 @@
 mod htlc_contract;
+mod payout;
 mod staking_contract;
 mod vesting_contract;
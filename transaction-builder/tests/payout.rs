@@ -0,0 +1,145 @@
+use nimiq_keys::{Address, KeyPair, PrivateKey};
+use nimiq_primitives::{coin::Coin, networks::NetworkId, policy::Policy};
+use nimiq_serde::Deserialize;
+use nimiq_test_log::test;
+use nimiq_transaction_builder::payout::{
+    EpochRewards, Payout, PayoutError, PayoutPlan, StakerBalance,
+};
+
+fn staker(byte: u8, active_balance: u64) -> StakerBalance {
+    StakerBalance {
+        address: Address::from([byte; 20]),
+        active_balance: Coin::from_u64_unchecked(active_balance),
+    }
+}
+
+fn epoch(epoch: u32, reward: u64, stakers: Vec<StakerBalance>) -> EpochRewards {
+    EpochRewards {
+        epoch,
+        election_block_number: epoch * 100,
+        reward: Coin::from_u64_unchecked(reward),
+        stakers,
+    }
+}
+
+#[test]
+fn it_splits_rewards_between_pool_and_stakers() {
+    let validator_address = Address::from([9u8; 20]);
+    let plan = PayoutPlan::compute(
+        validator_address.clone(),
+        1_000,
+        vec![
+            epoch(2, 10_000, vec![staker(1, 300), staker(2, 100)]),
+            epoch(1, 1_001, vec![staker(1, 100), staker(2, 200)]),
+        ],
+    )
+    .unwrap();
+
+    assert_eq!(plan.validator_address, validator_address);
+    assert_eq!(plan.epochs[0].epoch, 1);
+    assert_eq!(plan.epochs[1].epoch, 2);
+
+    // Epoch 1: 1_001 - 100 commission = 901 to distribute, 300 and 600 paid out.
+    assert_eq!(
+        plan.epochs[0].stakers[0].value,
+        Coin::from_u64_unchecked(300)
+    );
+    assert_eq!(
+        plan.epochs[0].stakers[1].value,
+        Coin::from_u64_unchecked(600)
+    );
+    assert_eq!(plan.epochs[0].commission, Coin::from_u64_unchecked(101));
+
+    // Epoch 2: 10_000 - 1_000 commission = 9_000 to distribute.
+    assert_eq!(
+        plan.epochs[1].stakers[0].value,
+        Coin::from_u64_unchecked(6_750)
+    );
+    assert_eq!(
+        plan.epochs[1].stakers[1].value,
+        Coin::from_u64_unchecked(2_250)
+    );
+    assert_eq!(plan.epochs[1].commission, Coin::from_u64_unchecked(1_000));
+
+    assert_eq!(
+        plan.payouts,
+        vec![
+            Payout {
+                recipient: Address::from([1u8; 20]),
+                value: Coin::from_u64_unchecked(7_050),
+            },
+            Payout {
+                recipient: Address::from([2u8; 20]),
+                value: Coin::from_u64_unchecked(2_850),
+            },
+        ]
+    );
+    assert_eq!(plan.total_reward, Coin::from_u64_unchecked(11_001));
+    assert_eq!(plan.total_commission, Coin::from_u64_unchecked(1_101));
+    assert_eq!(
+        plan.total_payout() + plan.total_commission,
+        plan.total_reward
+    );
+
+    let csv = plan.to_csv();
+    assert_eq!(csv.lines().count(), 5);
+    assert_eq!(
+        csv.lines().nth(1).unwrap(),
+        format!(
+            "1,100,{},100,300",
+            Address::from([1u8; 20]).to_user_friendly_address()
+        )
+    );
+}
+
+#[test]
+fn it_rejects_invalid_inputs() {
+    assert_eq!(
+        PayoutPlan::compute(Address::default(), Policy::MAX_COMMISSION + 1, vec![]),
+        Err(PayoutError::InvalidCommission(Policy::MAX_COMMISSION + 1))
+    );
+    assert_eq!(
+        PayoutPlan::compute(
+            Address::default(),
+            0,
+            vec![epoch(1, 100, vec![]), epoch(1, 100, vec![])]
+        ),
+        Err(PayoutError::DuplicateEpoch(1))
+    );
+
+    // Without stakers, the pool keeps the whole reward.
+    let plan = PayoutPlan::compute(Address::default(), 0, vec![epoch(1, 100, vec![])]).unwrap();
+    assert!(plan.payouts.is_empty());
+    assert_eq!(plan.total_commission, Coin::from_u64_unchecked(100));
+}
+
+#[test]
+fn it_can_build_payout_transactions() {
+    let key_pair = KeyPair::from(
+        PrivateKey::deserialize_from_vec(
+            &hex::decode("9d5bd02379e7e45cf515c788048f5cf3c454ffabd3e83bd1d7667716c325c3c0")
+                .unwrap(),
+        )
+        .unwrap(),
+    );
+    let plan = PayoutPlan::compute(
+        Address::default(),
+        0,
+        vec![epoch(1, 1_000, vec![staker(1, 100), staker(2, 300)])],
+    )
+    .unwrap();
+
+    let fee = Coin::from_u64_unchecked(138);
+    let txs = plan
+        .build_transactions(&key_pair, fee, 1, NetworkId::UnitAlbatross)
+        .unwrap();
+
+    assert_eq!(txs.len(), 2);
+    for (tx, payout) in txs.iter().zip(&plan.payouts) {
+        assert_eq!(tx.sender, Address::from(&key_pair));
+        assert_eq!(tx.recipient, payout.recipient);
+        assert_eq!(tx.value, payout.value);
+        assert_eq!(tx.fee, fee);
+        assert!(tx.verify(NetworkId::UnitAlbatross).is_ok());
+    }
+}
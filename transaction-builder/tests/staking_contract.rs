@@ -157,6 +157,7 @@ fn it_can_create_validator_transactions() {
             proof_of_knowledge: bls_pair.sign(&bls_pair.public_key).compress(),
             reward_address: address.clone(),
             signal_data: Some(Blake2bHash::default()),
+            commission: 1_000,
             proof: Default::default(),
         },
         Policy::VALIDATOR_DEPOSIT,
@@ -170,6 +171,7 @@ fn it_can_create_validator_transactions() {
         &bls_pair,
         address.clone(),
         Some(Blake2bHash::default()),
+        1_000,
         100.try_into().unwrap(),
         1,
         NetworkId::UnitAlbatross,
@@ -186,6 +188,7 @@ fn it_can_create_validator_transactions() {
             new_proof_of_knowledge: None,
             new_reward_address: Some(address.clone()),
             new_signal_data: None,
+            new_commission: Some(1_000),
             proof: Default::default(),
         },
         0,
@@ -199,6 +202,7 @@ fn it_can_create_validator_transactions() {
         None,
         Some(address.clone()),
         None,
+        Some(1_000),
         100.try_into().unwrap(),
         1,
         NetworkId::UnitAlbatross,
@@ -1,6 +1,15 @@
+use nimiq_network_interface::request::request_type_name;
+
+use self::tendermint::proposal::RequestProposal;
+
 pub mod registry;
 pub mod skip_block;
 pub mod tendermint;
 /// Implementation of signature aggregation protocols (skip block and pBFT prepare/commit) using
 /// the Handel protocol. The Handel protocol itself is implemented in the nimiq-handel crate.
 mod verifier;
+
+/// Returns the names of the request types served by the validator.
+pub fn request_type_names() -> Vec<&'static str> {
+    vec![request_type_name::<RequestProposal>()]
+}
@@ -33,6 +33,8 @@ use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
 use super::{registry::ValidatorRegistry, verifier::MultithreadedVerifier};
+#[cfg(feature = "metrics")]
+use crate::validator_metrics::{AggregationKind, AggregationMonitor, ValidatorMetrics};
 
 enum SkipBlockResult {
     SkipBlock(SignedSkipBlockMessage),
@@ -247,10 +249,20 @@ impl SkipBlockAggregation {
         validator_id: u16,
         active_validators: Validators,
         network: Arc<N>,
+        #[cfg(feature = "metrics")] metrics: Arc<ValidatorMetrics>,
     ) -> (SkipBlockInfo, SkipBlockProof) {
         // TODO expose this somewehere else so we don't need to clone here.
         let weights = Arc::new(ValidatorRegistry::new(active_validators.clone()));
 
+        #[cfg(feature = "metrics")]
+        let mut monitor = AggregationMonitor::new(
+            metrics,
+            AggregationKind::SkipBlock,
+            Arc::clone(&weights),
+            validator_id as usize,
+            policy::Policy::TWO_F_PLUS_ONE as usize,
+        );
+
         let slots = active_validators.validators[validator_id as usize]
             .slots
             .clone();
@@ -305,6 +317,9 @@ impl SkipBlockAggregation {
         while let Some(msg) = stream.next().await {
             match msg {
                 SkipBlockResult::SkipBlock(sb_msg) => {
+                    #[cfg(feature = "metrics")]
+                    monitor.note_aggregate(&sb_msg);
+
                     if let Some(aggregate_weight) = weights.signature_weight(&sb_msg) {
                         info!(
                             aggregate_weight,
@@ -2,22 +2,49 @@ use std::collections::HashSet;
 
 use nimiq_block::{Block, EquivocationProof, MacroBlock, MacroHeader, MicroBlock};
 use nimiq_serde::Serialize;
+use tokio::sync::broadcast::{
+    channel as broadcast, Receiver as BroadcastReceiver, Sender as BroadcastSender,
+};
 
 /// Pool for holding distinct equivocation proofs that haven't been seen in blocks yet.
-#[derive(Default)]
 pub struct EquivocationProofPool {
     equivocation_proofs: HashSet<EquivocationProof>,
+    /// Notifies about equivocation proofs newly added to the pool.
+    notifier: BroadcastSender<EquivocationProof>,
+}
+
+impl Default for EquivocationProofPool {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EquivocationProofPool {
+    const NOTIFIER_CAPACITY: usize = 64;
+
     pub fn new() -> Self {
-        Self::default()
+        let (notifier, _rx) = broadcast(Self::NOTIFIER_CAPACITY);
+        Self {
+            equivocation_proofs: HashSet::new(),
+            notifier,
+        }
     }
 
     /// Adds an equivocation proof if it is not yet part of the pool.
     /// Returns whether it has been added.
     pub fn insert(&mut self, equivocation_proof: EquivocationProof) -> bool {
-        self.equivocation_proofs.insert(equivocation_proof)
+        let is_new = self.equivocation_proofs.insert(equivocation_proof.clone());
+        if is_new {
+            // An error only means that there are no subscribers.
+            let _ = self.notifier.send(equivocation_proof);
+        }
+        is_new
+    }
+
+    /// Subscribes to equivocation proofs newly added to the pool. Proofs that are re-added when a
+    /// block is reverted are not reported again.
+    pub fn subscribe(&self) -> BroadcastReceiver<EquivocationProof> {
+        self.notifier.subscribe()
     }
 
     /// Applies a block to the pool, removing processed equivocation proofs.
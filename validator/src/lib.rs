@@ -8,3 +8,6 @@ mod micro;
 mod proposal_buffer;
 pub mod tendermint;
 pub mod validator;
+/// Validator metrics
+#[cfg(feature = "metrics")]
+pub mod validator_metrics;
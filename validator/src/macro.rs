@@ -18,6 +18,8 @@ use nimiq_tendermint::{Return as TendermintReturn, SignedProposalMessage, Tender
 use nimiq_validator_network::{PubsubId, ValidatorNetwork};
 use parking_lot::RwLock;
 
+#[cfg(feature = "metrics")]
+use crate::validator_metrics::{TendermintMonitor, ValidatorMetrics};
 use crate::{
     aggregation::tendermint::{
         proposal::{Header, SignedProposal},
@@ -84,6 +86,7 @@ where
             'static,
             SignedProposalMessage<Header<PubsubId<TValidatorNetwork>>, (SchnorrSignature, u16)>,
         >,
+        #[cfg(feature = "metrics")] metrics: Arc<ValidatorMetrics>,
     ) -> Self {
         let input = network
             .receive::<TendermintUpdate>()
@@ -108,8 +111,13 @@ where
             validator_slot_band,
             network_id,
             block_height,
+            #[cfg(feature = "metrics")]
+            Arc::clone(&metrics),
         );
 
+        #[cfg(feature = "metrics")]
+        let mut monitor = TendermintMonitor::new(metrics);
+
         // create the Tendermint instance, which implements Stream
         let tendermint = Tendermint::new(
             dependencies,
@@ -119,8 +127,16 @@ where
         )
         // and map the return value such that a state update can be persisted.
         .map(move |item| match item {
-            TendermintReturn::Decision(decision) => MappedReturn::Decision(decision),
+            TendermintReturn::Decision(decision) => {
+                #[cfg(feature = "metrics")]
+                if let Some(justification) = &decision.justification {
+                    monitor.note_decision(justification.round);
+                }
+                MappedReturn::Decision(decision)
+            }
             TendermintReturn::Update(state) => {
+                #[cfg(feature = "metrics")]
+                monitor.note_state(state.current_round, state.current_step);
                 MappedReturn::Update(MacroState::from_tendermint_state(block_height, state))
             }
             TendermintReturn::ProposalAccepted(proposal) => {
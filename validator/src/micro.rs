@@ -19,6 +19,8 @@ use nimiq_vrf::VrfSeed;
 use parking_lot::RwLock;
 
 use crate::aggregation::skip_block::SkipBlockAggregation;
+#[cfg(feature = "metrics")]
+use crate::validator_metrics::ValidatorMetrics;
 
 // Ignoring this clippy warning since size difference is not that much (320
 // bytes) and we probably don't want the performance penalty of the allocation.
@@ -39,6 +41,8 @@ struct NextProduceMicroBlockEvent<TValidatorNetwork> {
     block_number: u32,
     producer_timeout: Duration,
     block_separation_time: Duration,
+    #[cfg(feature = "metrics")]
+    metrics: Arc<ValidatorMetrics>,
 }
 
 impl<TValidatorNetwork: ValidatorNetwork + 'static> NextProduceMicroBlockEvent<TValidatorNetwork> {
@@ -56,6 +60,7 @@ impl<TValidatorNetwork: ValidatorNetwork + 'static> NextProduceMicroBlockEvent<T
         block_number: u32,
         producer_timeout: Duration,
         block_separation_time: Duration,
+        #[cfg(feature = "metrics")] metrics: Arc<ValidatorMetrics>,
     ) -> Self {
         Self {
             blockchain,
@@ -68,6 +73,8 @@ impl<TValidatorNetwork: ValidatorNetwork + 'static> NextProduceMicroBlockEvent<T
             block_number,
             producer_timeout,
             block_separation_time,
+            #[cfg(feature = "metrics")]
+            metrics,
         }
     }
 
@@ -241,6 +248,8 @@ impl<TValidatorNetwork: ValidatorNetwork + 'static> NextProduceMicroBlockEvent<T
             self.validator_slot_band,
             active_validators.unwrap(),
             Arc::clone(&self.network),
+            #[cfg(feature = "metrics")]
+            Arc::clone(&self.metrics),
         )
         .await;
 
@@ -397,6 +406,7 @@ impl<TValidatorNetwork: ValidatorNetwork + 'static> ProduceMicroBlock<TValidator
         block_number: u32,
         producer_timeout: Duration,
         block_separation_time: Duration,
+        #[cfg(feature = "metrics")] metrics: Arc<ValidatorMetrics>,
     ) -> Self {
         let next_event = NextProduceMicroBlockEvent::new(
             blockchain,
@@ -409,6 +419,8 @@ impl<TValidatorNetwork: ValidatorNetwork + 'static> ProduceMicroBlock<TValidator
             block_number,
             producer_timeout,
             block_separation_time,
+            #[cfg(feature = "metrics")]
+            metrics,
         )
         .next()
         .boxed();
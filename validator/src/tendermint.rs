@@ -31,6 +31,8 @@ use nimiq_validator_network::{
 };
 use parking_lot::RwLock;
 
+#[cfg(feature = "metrics")]
+use crate::validator_metrics::{AggregationKind, AggregationMonitor, ValidatorMetrics};
 use crate::{
     aggregation::{
         registry::ValidatorRegistry,
@@ -118,6 +120,9 @@ pub struct TendermintProtocol<TValidatorNetwork: ValidatorNetwork> {
     blockchain: Arc<RwLock<Blockchain>>,
     // Validator registry on the heap for easy cloning into handel protocol.
     validator_registry: Arc<ValidatorRegistry>,
+    // Metrics of the validator, used to observe the vote aggregations.
+    #[cfg(feature = "metrics")]
+    metrics: Arc<ValidatorMetrics>,
 }
 
 impl<TValidatorNetwork: ValidatorNetwork> Clone for TendermintProtocol<TValidatorNetwork> {
@@ -131,6 +136,8 @@ impl<TValidatorNetwork: ValidatorNetwork> Clone for TendermintProtocol<TValidato
             current_validators: self.current_validators.clone(),
             blockchain: Arc::clone(&self.blockchain),
             validator_registry: Arc::clone(&self.validator_registry),
+            #[cfg(feature = "metrics")]
+            metrics: Arc::clone(&self.metrics),
         }
     }
 }
@@ -147,6 +154,7 @@ where
         validator_slot_band: u16,
         network_id: NetworkId,
         block_height: u32,
+        #[cfg(feature = "metrics")] metrics: Arc<ValidatorMetrics>,
     ) -> Self {
         Self {
             block_producer,
@@ -157,6 +165,8 @@ where
             validator_registry: Arc::new(ValidatorRegistry::new(current_validators.clone())),
             current_validators,
             network,
+            #[cfg(feature = "metrics")]
+            metrics,
         }
     }
 }
@@ -383,6 +393,12 @@ where
             _ => panic!("Step must be either prevote or precommit."),
         };
 
+        #[cfg(feature = "metrics")]
+        let aggregation_kind = match step {
+            TendermintStep::PreVote => AggregationKind::Prevote,
+            _ => AggregationKind::Precommit,
+        };
+
         let id = TendermintIdentifier {
             network: self.network_id,
             block_number: self.block_height,
@@ -408,14 +424,29 @@ where
             id,
         );
 
-        Aggregation::new(
+        let aggregation = Aggregation::new(
             protocol,
             nimiq_handel::config::Config::default(),
             own_contribution,
             update_stream.map(|item| item.0).boxed(),
             network,
-        )
-        .boxed()
+        );
+
+        // Observe the aggregates as they are produced. The monitor records its results once the
+        // aggregation stream is dropped.
+        #[cfg(feature = "metrics")]
+        let aggregation = {
+            let mut monitor = AggregationMonitor::new(
+                Arc::clone(&self.metrics),
+                aggregation_kind,
+                Arc::clone(&self.validator_registry),
+                self.validator_slot_band as usize,
+                Self::TWO_F_PLUS_ONE,
+            );
+            aggregation.inspect(move |aggregate| monitor.note_aggregate(aggregate))
+        };
+
+        aggregation.boxed()
     }
 
     fn verify_aggregation_message(
@@ -13,7 +13,10 @@ use std::{
 use futures::stream::StreamExt;
 use nimiq_account::Validator as ValidatorAccount;
 use nimiq_block::{Block, BlockType, EquivocationProof};
-use nimiq_blockchain::{interface::HistoryInterface, BlockProducer, Blockchain};
+use nimiq_blockchain::{
+    interface::{HistoryIndexInterface, HistoryInterface},
+    BlockProducer, Blockchain,
+};
 use nimiq_blockchain_interface::{AbstractBlockchain, BlockchainEvent, ForkEvent, PushResult};
 use nimiq_bls::{lazy::LazyPublicKey, KeyPair as BlsKeyPair};
 use nimiq_consensus::{
@@ -42,6 +45,8 @@ use parking_lot::RwLock;
 use tokio_metrics::TaskMonitor;
 use tokio_stream::wrappers::BroadcastStream;
 
+#[cfg(feature = "metrics")]
+use crate::validator_metrics::ValidatorMetrics;
 use crate::{
     aggregation::tendermint::{proposal::RequestProposal, state::MacroState},
     jail::EquivocationProofPool,
@@ -61,6 +66,13 @@ pub struct ConsensusState {
     equivocation_proofs: EquivocationProofPool,
 }
 
+impl ConsensusState {
+    /// Subscribes to equivocation proofs newly added to the equivocation proof pool.
+    pub fn subscribe_equivocation_proofs(&self) -> BroadcastStream<EquivocationProof> {
+        BroadcastStream::new(self.equivocation_proofs.subscribe())
+    }
+}
+
 /// Validator inactivity
 struct InactivityState {
     inactive_tx_hash: Blake2bHash,
@@ -125,6 +137,9 @@ where
 
     micro_producer: Option<ProduceMicroBlock<TValidatorNetwork>>,
 
+    #[cfg(feature = "metrics")]
+    metrics: Arc<ValidatorMetrics>,
+
     pub mempool_task: MempoolTask<TValidatorNetwork::NetworkType>,
 }
 
@@ -228,6 +243,9 @@ where
 
             micro_producer: None,
 
+            #[cfg(feature = "metrics")]
+            metrics: Arc::new(ValidatorMetrics::default()),
+
             mempool_task: mempool,
         }
     }
@@ -422,6 +440,8 @@ where
                     next_block_number,
                     self.macro_state.read().clone(),
                     proposal_stream,
+                    #[cfg(feature = "metrics")]
+                    Arc::clone(&self.metrics),
                 ));
             }
             BlockType::Micro => {
@@ -443,6 +463,8 @@ where
                     next_block_number,
                     Self::compute_micro_block_producer_timeout(head, &blockchain),
                     Self::BLOCK_SEPARATION_TIME,
+                    #[cfg(feature = "metrics")]
+                    Arc::clone(&self.metrics),
                 ));
             }
         }
@@ -469,7 +491,7 @@ where
             BlockchainEvent::Rebranched(ref old_chain, ref new_chain) => {
                 self.on_blockchain_rebranched(old_chain, new_chain)
             }
-            BlockchainEvent::HistoryAdopted(_) | BlockchainEvent::Stored(_) => {
+            BlockchainEvent::HistoryAdopted(..) | BlockchainEvent::Stored(_) => {
                 // Nothing to do here for now. Forks are already reported on `fork_event_rx`
                 // and inferior chain blocks are irrelevant here.
             }
@@ -489,6 +511,9 @@ where
             .equivocation_proofs
             .apply_block(&block);
 
+        #[cfg(feature = "metrics")]
+        self.note_missed_block(hash, &block);
+
         self.check_reactivate(block.block_number());
         self.init_block_producer(Some(hash));
     }
@@ -508,10 +533,33 @@ where
         }
         drop(consensus_state);
 
+        #[cfg(feature = "metrics")]
+        for (hash, block) in new_chain.iter() {
+            self.note_missed_block(hash, block);
+        }
+
         let head_hash = &new_chain.last().expect("new_chain must not be empty").0;
         self.init_block_producer(Some(head_hash));
     }
 
+    /// Counts skip blocks that replaced a micro block that we were supposed to produce.
+    #[cfg(feature = "metrics")]
+    fn note_missed_block(&self, hash: &Blake2bHash, block: &Block) {
+        if !block.is_skip() {
+            return;
+        }
+        let Some(slot_band) = *self.slot_band.read() else {
+            return;
+        };
+        match self.blockchain.read().get_proposer_of(hash, None) {
+            Ok(slot) if slot.band == slot_band => {
+                self.metrics.note_micro_block_missed(block.block_number())
+            }
+            Ok(_) => {}
+            Err(error) => debug!(%error, "Failed to find proposer of skip block"),
+        }
+    }
+
     fn on_fork_event(&mut self, event: ForkEvent) {
         match event {
             ForkEvent::Detected(fork_proof) => self.on_equivocation_proof(fork_proof.into()),
@@ -521,16 +569,27 @@ where
     fn on_equivocation_proof(&mut self, proof: EquivocationProof) {
         // Keep the lock until the proof is added to the proof pool.
         let blockchain = self.blockchain.read();
+        let locator = proof.locator();
         if blockchain
             .history_store
-            .has_equivocation_proof(proof.locator(), None)
+            .has_equivocation_proof(locator.clone(), None)
         {
             return;
         }
-        self.consensus_state
+        let is_new = self
+            .consensus_state
             .write()
             .equivocation_proofs
             .insert(proof);
+
+        // Record the observed proof, such that it can be looked up even if it never gets included.
+        if is_new {
+            if let Some(history_index) = blockchain.history_store.history_index() {
+                let mut txn = blockchain.write_transaction();
+                history_index.put_observed_equivocation(&mut txn, &locator);
+                txn.commit();
+            }
+        }
     }
 
     fn poll_macro(&mut self, cx: &mut Context<'_>) {
@@ -638,6 +697,10 @@ where
             match event {
                 ProduceMicroBlockEvent::MicroBlock(block, result) => {
                     if result == PushResult::Extended || result == PushResult::Rebranched {
+                        #[cfg(feature = "metrics")]
+                        if !block.is_skip_block() {
+                            self.metrics.note_micro_block_produced();
+                        }
                         self.publish_block(Block::Micro(block));
                     }
                 }
@@ -793,6 +856,11 @@ where
     pub fn get_control_mempool_monitor(&self) -> TaskMonitor {
         self.mempool_task.get_control_mempool_monitor()
     }
+
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> Arc<ValidatorMetrics> {
+        Arc::clone(&self.metrics)
+    }
 }
 
 impl<TValidatorNetwork: ValidatorNetwork> Future for Validator<TValidatorNetwork>
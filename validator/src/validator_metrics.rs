@@ -0,0 +1,626 @@
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use instant::Instant;
+use nimiq_handel::{
+    contribution::AggregatableContribution,
+    identity::IdentityRegistry,
+    partitioner::{BinomialPartitioner, Partitioner},
+};
+use nimiq_primitives::policy::Policy;
+use nimiq_tendermint::Step;
+use parking_lot::Mutex;
+use prometheus_client::{
+    encoding::{EncodeLabelSet, EncodeLabelValue},
+    metrics::{counter::Counter, family::Family, histogram::Histogram},
+    registry::Registry,
+};
+
+use crate::aggregation::registry::ValidatorRegistry;
+
+type HistogramFamily<S> = Family<S, Histogram, fn() -> Histogram>;
+
+pub struct ValidatorMetrics {
+    micro_blocks: Family<MicroBlockLabels, Counter>,
+    aggregations: Family<AggregationLabels, Counter>,
+    aggregation_updates: Family<AggregationKindLabels, Counter>,
+    aggregation_levels: HistogramFamily<AggregationKindLabels>,
+    aggregation_contributors: HistogramFamily<AggregationKindLabels>,
+    aggregation_time_to_threshold: HistogramFamily<AggregationKindLabels>,
+    tendermint_steps: Family<TendermintStepLabels, Counter>,
+    tendermint_rounds: Histogram,
+    tendermint_durations: Histogram,
+    tendermint_round_durations: Histogram,
+    tendermint_step_durations: HistogramFamily<TendermintStepLabels>,
+    /// Heights of the skip blocks already counted as missed, such that a skip block that is
+    /// reverted and re-applied in a rebranch is only counted once.
+    missed_block_heights: Mutex<BTreeSet<u32>>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct MicroBlockLabels {
+    result: MicroBlockResult,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+enum MicroBlockResult {
+    Produced,
+    Missed,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct AggregationLabels {
+    kind: AggregationKind,
+    state: AggregationState,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct AggregationKindLabels {
+    kind: AggregationKind,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+pub(crate) enum AggregationKind {
+    SkipBlock,
+    Prevote,
+    Precommit,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+enum AggregationState {
+    Started,
+    Completed,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct TendermintStepLabels {
+    step: TendermintStep,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelValue)]
+enum TendermintStep {
+    Propose,
+    Prevote,
+    Precommit,
+}
+
+impl From<Step> for TendermintStep {
+    fn from(step: Step) -> Self {
+        match step {
+            Step::Propose => TendermintStep::Propose,
+            Step::Prevote => TendermintStep::Prevote,
+            Step::Precommit => TendermintStep::Precommit,
+        }
+    }
+}
+
+fn level_histogram() -> Histogram {
+    Histogram::new((0..12).map(|level| level as f64))
+}
+
+fn contributors_histogram() -> Histogram {
+    Histogram::new([1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 512.0].into_iter())
+}
+
+fn time_to_threshold_histogram() -> Histogram {
+    Histogram::new([0.05, 0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0].into_iter())
+}
+
+fn tendermint_step_duration_histogram() -> Histogram {
+    Histogram::new([0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0].into_iter())
+}
+
+impl Default for ValidatorMetrics {
+    fn default() -> Self {
+        ValidatorMetrics {
+            micro_blocks: Default::default(),
+            aggregations: Default::default(),
+            aggregation_updates: Default::default(),
+            aggregation_levels: Family::new_with_constructor(level_histogram),
+            aggregation_contributors: Family::new_with_constructor(contributors_histogram),
+            aggregation_time_to_threshold: Family::new_with_constructor(
+                time_to_threshold_histogram,
+            ),
+            tendermint_steps: Default::default(),
+            tendermint_rounds: Histogram::new([1.0, 2.0, 3.0, 5.0, 10.0].into_iter()),
+            tendermint_durations: Histogram::new(
+                [0.5, 1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0].into_iter(),
+            ),
+            tendermint_round_durations: Histogram::new(
+                [0.5, 1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0].into_iter(),
+            ),
+            tendermint_step_durations: Family::new_with_constructor(
+                tendermint_step_duration_histogram,
+            ),
+            missed_block_heights: Default::default(),
+        }
+    }
+}
+
+impl ValidatorMetrics {
+    pub fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "micro_blocks",
+            "Count of micro blocks produced and missed by this validator",
+            self.micro_blocks.clone(),
+        );
+
+        registry.register(
+            "aggregations",
+            "Count of signature aggregations started and completed",
+            self.aggregations.clone(),
+        );
+
+        registry.register(
+            "aggregation_updates",
+            "Count of improved aggregates received during signature aggregations",
+            self.aggregation_updates.clone(),
+        );
+
+        registry.register(
+            "aggregation_levels",
+            "Highest Handel level completed per signature aggregation",
+            self.aggregation_levels.clone(),
+        );
+
+        registry.register(
+            "aggregation_contributors",
+            "Number of contributing validators per signature aggregation",
+            self.aggregation_contributors.clone(),
+        );
+
+        registry.register(
+            "aggregation_time_to_threshold",
+            "Time from the start of a signature aggregation until 2f+1 slots contributed",
+            self.aggregation_time_to_threshold.clone(),
+        );
+
+        registry.register(
+            "tendermint_steps",
+            "Count of Tendermint steps entered",
+            self.tendermint_steps.clone(),
+        );
+
+        registry.register(
+            "tendermint_rounds",
+            "Number of Tendermint rounds needed per macro block",
+            self.tendermint_rounds.clone(),
+        );
+
+        registry.register(
+            "tendermint_durations",
+            "Time from starting Tendermint until a macro block was decided",
+            self.tendermint_durations.clone(),
+        );
+
+        registry.register(
+            "tendermint_round_durations",
+            "Time spent in a single Tendermint round",
+            self.tendermint_round_durations.clone(),
+        );
+
+        registry.register(
+            "tendermint_step_durations",
+            "Time spent in a single Tendermint step",
+            self.tendermint_step_durations.clone(),
+        );
+    }
+
+    pub(crate) fn note_micro_block_produced(&self) {
+        self.micro_blocks
+            .get_or_create(&MicroBlockLabels {
+                result: MicroBlockResult::Produced,
+            })
+            .inc();
+    }
+
+    /// Counts the micro block at the given height as missed, unless it was already counted.
+    pub(crate) fn note_micro_block_missed(&self, block_number: u32) {
+        let mut missed_block_heights = self.missed_block_heights.lock();
+        // Blocks before the last macro block can't be reverted anymore, so their heights don't
+        // need to be remembered.
+        let last_macro_block = Policy::last_macro_block(block_number);
+        missed_block_heights.retain(|&height| height > last_macro_block);
+        if !missed_block_heights.insert(block_number) {
+            return;
+        }
+
+        self.micro_blocks
+            .get_or_create(&MicroBlockLabels {
+                result: MicroBlockResult::Missed,
+            })
+            .inc();
+    }
+
+    fn note_aggregation(&self, kind: AggregationKind, state: AggregationState) {
+        self.aggregations
+            .get_or_create(&AggregationLabels { kind, state })
+            .inc();
+    }
+
+    fn note_tendermint_step(&self, step: Step) {
+        self.tendermint_steps
+            .get_or_create(&TendermintStepLabels { step: step.into() })
+            .inc();
+    }
+
+    fn note_tendermint_decision(&self, rounds: u32, duration: Duration) {
+        self.tendermint_rounds.observe(rounds as f64);
+        self.tendermint_durations.observe(duration.as_secs_f64());
+    }
+
+    fn note_tendermint_round_duration(&self, duration: Duration) {
+        self.tendermint_round_durations
+            .observe(duration.as_secs_f64());
+    }
+
+    fn note_tendermint_step_duration(&self, step: Step, duration: Duration) {
+        self.tendermint_step_durations
+            .get_or_create(&TendermintStepLabels { step: step.into() })
+            .observe(duration.as_secs_f64());
+    }
+}
+
+/// Observes a single Handel aggregation. The level reached and the number of contributors are
+/// recorded once the monitor is dropped, i.e. when the aggregation finished or was aborted.
+pub(crate) struct AggregationMonitor {
+    metrics: Arc<ValidatorMetrics>,
+    kind: AggregationKind,
+    registry: Arc<ValidatorRegistry>,
+    partitioner: BinomialPartitioner,
+    threshold: usize,
+    started: Instant,
+    level: usize,
+    contributors: usize,
+    threshold_reached: bool,
+}
+
+impl AggregationMonitor {
+    pub fn new(
+        metrics: Arc<ValidatorMetrics>,
+        kind: AggregationKind,
+        registry: Arc<ValidatorRegistry>,
+        node_id: usize,
+        threshold: usize,
+    ) -> Self {
+        metrics.note_aggregation(kind, AggregationState::Started);
+        let partitioner = BinomialPartitioner::new(node_id, registry.len());
+        AggregationMonitor {
+            metrics,
+            kind,
+            registry,
+            partitioner,
+            threshold,
+            started: Instant::now(),
+            level: 0,
+            contributors: 1,
+            threshold_reached: false,
+        }
+    }
+
+    /// Records an aggregate produced by the aggregation.
+    pub fn note_aggregate<C: AggregatableContribution>(&mut self, aggregate: &C) {
+        self.metrics
+            .aggregation_updates
+            .get_or_create(&AggregationKindLabels { kind: self.kind })
+            .inc();
+
+        let slots = aggregate.contributors();
+        let identity = self.registry.signers_identity(&slots);
+        let validators: BTreeSet<usize> = identity
+            .as_vec()
+            .into_iter()
+            .map(|id| id as usize)
+            .collect();
+
+        self.contributors = self.contributors.max(validators.len());
+        self.level = self.level.max(self.completed_level(&validators));
+
+        if !self.threshold_reached && slots.len() >= self.threshold {
+            self.threshold_reached = true;
+            self.metrics
+                .note_aggregation(self.kind, AggregationState::Completed);
+            self.metrics
+                .aggregation_time_to_threshold
+                .get_or_create(&AggregationKindLabels { kind: self.kind })
+                .observe(self.started.elapsed().as_secs_f64());
+        }
+    }
+
+    /// Returns the highest level for which the aggregate contains the contributions of all
+    /// validators on that level and all levels below it.
+    fn completed_level(&self, validators: &BTreeSet<usize>) -> usize {
+        let mut completed = 0;
+        for level in 1..self.partitioner.levels() {
+            // Empty levels are trivially complete.
+            if let Ok(mut range) = self.partitioner.range(level) {
+                if !range.all(|id| validators.contains(&id)) {
+                    break;
+                }
+            }
+            completed = level;
+        }
+        completed
+    }
+}
+
+impl Drop for AggregationMonitor {
+    fn drop(&mut self) {
+        let labels = AggregationKindLabels { kind: self.kind };
+        self.metrics
+            .aggregation_levels
+            .get_or_create(&labels)
+            .observe(self.level as f64);
+        self.metrics
+            .aggregation_contributors
+            .get_or_create(&labels)
+            .observe(self.contributors as f64);
+    }
+}
+
+/// Observes the Tendermint instance of a single macro block.
+pub(crate) struct TendermintMonitor {
+    metrics: Arc<ValidatorMetrics>,
+    started: Instant,
+    round_started: Instant,
+    step_started: Instant,
+    round: Option<u32>,
+    step: Option<Step>,
+}
+
+impl TendermintMonitor {
+    pub fn new(metrics: Arc<ValidatorMetrics>) -> Self {
+        let started = Instant::now();
+        TendermintMonitor {
+            metrics,
+            started,
+            round_started: started,
+            step_started: started,
+            round: None,
+            step: None,
+        }
+    }
+
+    /// Records a state update of Tendermint. The duration of the previous step and round is
+    /// recorded once Tendermint moved on from it.
+    pub fn note_state(&mut self, round: u32, step: Step) {
+        if self.round == Some(round) && self.step == Some(step) {
+            return;
+        }
+
+        let now = Instant::now();
+        if let Some(previous_step) = self.step {
+            self.metrics
+                .note_tendermint_step_duration(previous_step, now - self.step_started);
+        }
+        self.step_started = now;
+
+        if self
+            .round
+            .is_some_and(|previous_round| previous_round != round)
+        {
+            self.metrics
+                .note_tendermint_round_duration(now - self.round_started);
+            self.round_started = now;
+        }
+
+        self.metrics.note_tendermint_step(step);
+        self.round = Some(round);
+        self.step = Some(step);
+    }
+
+    /// Records the decision of Tendermint in the given round, including the duration of the
+    /// deciding step and round.
+    pub fn note_decision(&self, round: u32) {
+        let now = Instant::now();
+        if let Some(step) = self.step {
+            self.metrics
+                .note_tendermint_step_duration(step, now - self.step_started);
+        }
+        self.metrics
+            .note_tendermint_round_duration(now - self.round_started);
+        self.metrics
+            .note_tendermint_decision(round + 1, now - self.started);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nimiq_block::MultiSignature;
+    use nimiq_bls::{AggregateSignature, KeyPair as BlsKeyPair};
+    use nimiq_collections::BitSet;
+    use nimiq_keys::{Address, KeyPair, SecureGenerate};
+    use nimiq_primitives::slots_allocation::ValidatorsBuilder;
+    use nimiq_test_log::test;
+    use prometheus_client::encoding::text::encode;
+
+    use super::*;
+    use crate::aggregation::skip_block::SignedSkipBlockMessage;
+
+    const NUM_VALIDATORS: usize = 4;
+    const SLOTS_PER_VALIDATOR: usize = 2;
+
+    fn registry() -> Arc<ValidatorRegistry> {
+        let mut builder = ValidatorsBuilder::new();
+        for i in 0..NUM_VALIDATORS {
+            let address = Address::from([i as u8; Address::SIZE]);
+            let voting_key = BlsKeyPair::generate_default_csprng().public_key;
+            let signing_key = KeyPair::generate_default_csprng().public;
+            for _ in 0..SLOTS_PER_VALIDATOR {
+                builder.push(address.clone(), voting_key, signing_key);
+            }
+        }
+        Arc::new(ValidatorRegistry::new(builder.build()))
+    }
+
+    /// Creates an aggregate containing all slots of the given validators.
+    fn aggregate(validators: &[usize]) -> SignedSkipBlockMessage {
+        let mut signers = BitSet::new();
+        for validator in validators {
+            for slot in 0..SLOTS_PER_VALIDATOR {
+                signers.insert(validator * SLOTS_PER_VALIDATOR + slot);
+            }
+        }
+        SignedSkipBlockMessage {
+            proof: MultiSignature::new(AggregateSignature::new(), signers),
+        }
+    }
+
+    fn encoded(metrics: &ValidatorMetrics) -> String {
+        let mut registry = Registry::default();
+        metrics.register(&mut registry);
+        let mut buffer = String::new();
+        encode(&mut buffer, &registry).unwrap();
+        buffer
+    }
+
+    fn aggregations(metrics: &ValidatorMetrics, state: AggregationState) -> u64 {
+        metrics
+            .aggregations
+            .get_or_create(&AggregationLabels {
+                kind: AggregationKind::SkipBlock,
+                state,
+            })
+            .get()
+    }
+
+    fn tendermint_steps(metrics: &ValidatorMetrics, step: Step) -> u64 {
+        metrics
+            .tendermint_steps
+            .get_or_create(&TendermintStepLabels { step: step.into() })
+            .get()
+    }
+
+    #[test]
+    fn it_records_aggregates() {
+        let metrics = Arc::new(ValidatorMetrics::default());
+        let mut monitor = AggregationMonitor::new(
+            Arc::clone(&metrics),
+            AggregationKind::SkipBlock,
+            registry(),
+            0,
+            3 * SLOTS_PER_VALIDATOR,
+        );
+        assert_eq!(aggregations(&metrics, AggregationState::Started), 1);
+        assert_eq!(aggregations(&metrics, AggregationState::Completed), 0);
+
+        // The first level only contains our own contribution and the one of the validator 1.
+        monitor.note_aggregate(&aggregate(&[0, 1]));
+        assert_eq!(monitor.level, 1);
+        assert_eq!(monitor.contributors, 2);
+        assert_eq!(aggregations(&metrics, AggregationState::Completed), 0);
+
+        // An incomplete second level doesn't count as completed, but the threshold is reached.
+        monitor.note_aggregate(&aggregate(&[0, 1, 2]));
+        assert_eq!(monitor.level, 1);
+        assert_eq!(monitor.contributors, 3);
+        assert_eq!(aggregations(&metrics, AggregationState::Completed), 1);
+
+        // Reaching the threshold is only counted once.
+        monitor.note_aggregate(&aggregate(&[0, 1, 2, 3]));
+        assert_eq!(monitor.level, 2);
+        assert_eq!(monitor.contributors, 4);
+        assert_eq!(aggregations(&metrics, AggregationState::Completed), 1);
+
+        // A worse aggregate doesn't lower the recorded level or contributors.
+        monitor.note_aggregate(&aggregate(&[0]));
+        assert_eq!(monitor.level, 2);
+        assert_eq!(monitor.contributors, 4);
+
+        assert_eq!(
+            metrics
+                .aggregation_updates
+                .get_or_create(&AggregationKindLabels {
+                    kind: AggregationKind::SkipBlock
+                })
+                .get(),
+            4
+        );
+
+        // The level and contributors are recorded when the aggregation ends.
+        drop(monitor);
+        let encoded = encoded(&metrics);
+        assert!(encoded.contains("aggregation_levels_sum{kind=\"SkipBlock\"} 2.0"));
+        assert!(encoded.contains("aggregation_levels_count{kind=\"SkipBlock\"} 1"));
+        assert!(encoded.contains("aggregation_contributors_sum{kind=\"SkipBlock\"} 4.0"));
+        assert!(encoded.contains("aggregation_time_to_threshold_count{kind=\"SkipBlock\"} 1"));
+    }
+
+    #[test]
+    fn it_records_tendermint_states() {
+        let metrics = Arc::new(ValidatorMetrics::default());
+        let mut monitor = TendermintMonitor::new(Arc::clone(&metrics));
+
+        monitor.note_state(0, Step::Propose);
+        // Repeated updates of the same state are only counted once.
+        monitor.note_state(0, Step::Propose);
+        monitor.note_state(0, Step::Prevote);
+        monitor.note_state(0, Step::Precommit);
+        monitor.note_state(1, Step::Propose);
+        monitor.note_state(1, Step::Prevote);
+
+        assert_eq!(tendermint_steps(&metrics, Step::Propose), 2);
+        assert_eq!(tendermint_steps(&metrics, Step::Prevote), 2);
+        assert_eq!(tendermint_steps(&metrics, Step::Precommit), 1);
+
+        // Only the steps and rounds that were left have a duration so far.
+        let encoded = encoded(&metrics);
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Propose\"} 2"));
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Prevote\"} 1"));
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Precommit\"} 1"));
+        assert!(encoded.contains("tendermint_round_durations_count 1"));
+        assert!(encoded.contains("tendermint_rounds_count 0"));
+    }
+
+    #[test]
+    fn it_records_tendermint_decisions() {
+        let metrics = Arc::new(ValidatorMetrics::default());
+        let mut monitor = TendermintMonitor::new(Arc::clone(&metrics));
+
+        monitor.note_state(0, Step::Propose);
+        monitor.note_state(1, Step::Propose);
+        monitor.note_state(1, Step::Prevote);
+        monitor.note_state(1, Step::Precommit);
+        monitor.note_decision(1);
+
+        // The deciding step and round are recorded along with the decision.
+        let encoded = encoded(&metrics);
+        assert!(encoded.contains("tendermint_rounds_sum 2.0"));
+        assert!(encoded.contains("tendermint_rounds_count 1"));
+        assert!(encoded.contains("tendermint_durations_count 1"));
+        assert!(encoded.contains("tendermint_round_durations_count 2"));
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Propose\"} 2"));
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Prevote\"} 1"));
+        assert!(encoded.contains("tendermint_step_durations_count{step=\"Precommit\"} 1"));
+    }
+
+    #[test]
+    fn it_counts_missed_blocks_once_per_height() {
+        let metrics = ValidatorMetrics::default();
+        let missed = || {
+            metrics
+                .micro_blocks
+                .get_or_create(&MicroBlockLabels {
+                    result: MicroBlockResult::Missed,
+                })
+                .get()
+        };
+        let block_number = Policy::genesis_block_number() + 1;
+
+        metrics.note_micro_block_missed(block_number);
+        metrics.note_micro_block_missed(block_number + 1);
+        assert_eq!(missed(), 2);
+
+        // A skip block that is re-applied after a rebranch isn't counted again.
+        metrics.note_micro_block_missed(block_number);
+        assert_eq!(missed(), 2);
+
+        // Heights before the last macro block are forgotten.
+        let next_batch_block = block_number + Policy::blocks_per_batch();
+        metrics.note_micro_block_missed(next_batch_block);
+        assert_eq!(missed(), 3);
+        assert_eq!(
+            *metrics.missed_block_heights.lock(),
+            BTreeSet::from([next_batch_block])
+        );
+    }
+}
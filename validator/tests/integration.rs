@@ -47,6 +47,7 @@ async fn validator_update() {
         Some(&new_voting_key),
         None,
         None,
+        None,
         Coin::ZERO,
         blockchain.read().block_number(),
         NetworkId::UnitAlbatross,
@@ -119,6 +119,8 @@ async fn it_verifies_inferior_chain_proposals() {
         0,
         NetworkId::UnitAlbatross,
         blockchain2.read().head().block_number() + 1,
+        #[cfg(feature = "metrics")]
+        Default::default(),
     );
 
     // Make sure the main chain proposal is acceptable.
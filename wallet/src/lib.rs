@@ -1,7 +1,7 @@
 pub use multisig_account::MultiSigAccount;
 pub use wallet_account::WalletAccount;
 #[cfg(feature = "store")]
-pub use wallet_store::WalletStore;
+pub use wallet_store::{WalletBackup, WalletStore, WalletStoreError};
 
 mod multisig_account;
 mod wallet_account;
@@ -3,26 +3,105 @@ use nimiq_database::{
     mdbx::{MdbxDatabase, MdbxReadTransaction, MdbxWriteTransaction, OptionalTransaction},
     traits::{Database, ReadCursor, ReadTransaction, WriteTransaction},
 };
+use nimiq_hash::{argon2kdf::Argon2Error, Blake2bHash, Blake2bHasher, Hasher};
 use nimiq_keys::Address;
-use nimiq_utils::otp::Locked;
+use nimiq_serde::{Deserialize, DeserializeError, Serialize};
+use nimiq_utils::{
+    key_rng::{RngCore, SecureRng},
+    otp::{Locked, Unlocked, Verify},
+};
+use thiserror::Error;
 
 use crate::wallet_account::WalletAccount;
 
 declare_table!(WalletTable, "Wallet", Address => Locked<WalletAccount>);
+declare_table!(WalletPassphraseTable, "WalletPassphrase", () => Locked<PassphraseCheck>);
+
+/// Errors that can occur when managing the accounts of a wallet store.
+#[derive(Debug, Error)]
+pub enum WalletStoreError {
+    #[error("Wrong passphrase")]
+    WrongPassphrase,
+    #[error("Wrong passphrase for account: {0}")]
+    WrongAccountPassphrase(Address),
+    #[error("Invalid wallet backup: {0}")]
+    InvalidBackup(#[from] DeserializeError),
+    #[error("Unsupported wallet backup version: {0}")]
+    UnsupportedBackupVersion(u8),
+    #[error("{0}")]
+    Argon2(#[from] Argon2Error),
+}
+
+/// A random value together with its hash. It is stored encrypted with the master passphrase of
+/// the wallet, such that a passphrase can be checked even if the wallet doesn't contain any
+/// accounts.
+#[derive(Default, Deserialize, Serialize)]
+pub struct PassphraseCheck {
+    nonce: [u8; 32],
+    checksum: Blake2bHash,
+}
+
+impl PassphraseCheck {
+    fn generate() -> Self {
+        let mut nonce = [0u8; 32];
+        SecureRng::default().fill_bytes(&mut nonce);
+        let checksum = Blake2bHasher::default().digest(&nonce);
+        PassphraseCheck { nonce, checksum }
+    }
+}
+
+impl Verify for PassphraseCheck {
+    fn verify(&self) -> bool {
+        Blake2bHasher::default().digest(&self.nonce) == self.checksum
+    }
+}
+
+/// An encrypted backup of all accounts of a wallet store. The accounts remain encrypted with the
+/// passphrase of the wallet at the time of the export.
+#[derive(Deserialize, Serialize)]
+pub struct WalletBackup {
+    version: u8,
+    accounts: Vec<(Address, Locked<WalletAccount>)>,
+}
+
+impl WalletBackup {
+    pub const VERSION: u8 = 1;
+
+    /// Returns the addresses of the accounts contained in this backup.
+    pub fn addresses(&self) -> Vec<Address> {
+        self.accounts
+            .iter()
+            .map(|(address, _)| address.clone())
+            .collect()
+    }
+
+    /// Deserializes a backup and checks that its version is supported.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WalletStoreError> {
+        let backup = WalletBackup::deserialize_from_vec(bytes)?;
+        if backup.version != Self::VERSION {
+            return Err(WalletStoreError::UnsupportedBackupVersion(backup.version));
+        }
+        Ok(backup)
+    }
+}
 
 #[derive(Debug)]
 pub struct WalletStore {
     env: MdbxDatabase,
     table: WalletTable,
+    passphrase_table: WalletPassphraseTable,
 }
 
 impl WalletStore {
     pub fn new(env: MdbxDatabase) -> Self {
         let wallet_table = WalletTable;
+        let passphrase_table = WalletPassphraseTable;
         env.create_regular_table(&wallet_table);
+        env.create_regular_table(&passphrase_table);
         WalletStore {
             env,
             table: wallet_table,
+            passphrase_table,
         }
     }
 
@@ -53,12 +132,159 @@ impl WalletStore {
         txn.get(&self.table, address)
     }
 
-    pub fn put(
+    /// Returns whether a master passphrase has been set for this wallet.
+    /// Wallets created before master passphrases were introduced might use different passphrases
+    /// for each account until `change_passphrase` is called on them.
+    pub fn has_master_passphrase(&self, txn_option: Option<&MdbxReadTransaction>) -> bool {
+        let txn = txn_option.or_new(&self.env);
+        txn.get(&self.passphrase_table, &()).is_some()
+    }
+
+    /// Checks the given passphrase against the master passphrase of the wallet.
+    /// If no master passphrase has been set yet, any passphrase is accepted.
+    pub fn verify_passphrase(
         &self,
-        address: &Address,
-        wallet: &Locked<WalletAccount>,
+        passphrase: &[u8],
+        txn_option: Option<&MdbxReadTransaction>,
+    ) -> bool {
+        let txn = txn_option.or_new(&self.env);
+        match txn.get(&self.passphrase_table, &()) {
+            Some(check) => check.unlock(passphrase).is_ok(),
+            None => true,
+        }
+    }
+
+    /// Encrypts the account with the master passphrase and stores it. If the wallet doesn't have
+    /// a master passphrase yet, the given passphrase becomes the master passphrase.
+    pub fn insert(
+        &self,
+        account: WalletAccount,
+        passphrase: &[u8],
+    ) -> Result<Address, WalletStoreError> {
+        let mut txn = self.create_write_transaction();
+        let address = self.insert_with_txn(account, passphrase, &mut txn)?;
+        txn.commit();
+        Ok(address)
+    }
+
+    fn insert_with_txn(
+        &self,
+        account: WalletAccount,
+        passphrase: &[u8],
         txn: &mut MdbxWriteTransaction,
-    ) {
-        txn.put_reserve(&self.table, address, wallet);
+    ) -> Result<Address, WalletStoreError> {
+        match txn.get(&self.passphrase_table, &()) {
+            Some(check) => {
+                check
+                    .unlock(passphrase)
+                    .map_err(|_| WalletStoreError::WrongPassphrase)?;
+            }
+            None => {
+                let check = Locked::with_defaults(PassphraseCheck::generate(), passphrase)?;
+                txn.put_reserve(&self.passphrase_table, &(), &check);
+            }
+        }
+
+        let address = account.address.clone();
+        let locked = Locked::with_defaults(account, passphrase)?;
+        txn.put_reserve(&self.table, &address, &locked);
+
+        Ok(address)
+    }
+
+    /// Re-encrypts every account of the wallet with a new master passphrase. All accounts must be
+    /// decryptable with the old passphrase, otherwise nothing is changed. The change is applied
+    /// atomically within a single database transaction.
+    /// Returns the number of re-encrypted accounts.
+    pub fn change_passphrase(
+        &self,
+        old_passphrase: &[u8],
+        new_passphrase: &[u8],
+    ) -> Result<usize, WalletStoreError> {
+        let mut txn = self.create_write_transaction();
+
+        if let Some(check) = txn.get(&self.passphrase_table, &()) {
+            check
+                .unlock(old_passphrase)
+                .map_err(|_| WalletStoreError::WrongPassphrase)?;
+        }
+
+        let accounts: Vec<(Address, Locked<WalletAccount>)> =
+            WriteTransaction::cursor(&txn, &self.table)
+                .into_iter_start()
+                .collect();
+
+        let mut relocked = Vec::with_capacity(accounts.len());
+        for (address, locked) in accounts {
+            let unlocked = locked
+                .unlock(old_passphrase)
+                .map_err(|_| WalletStoreError::WrongAccountPassphrase(address.clone()))?;
+            let account = Unlocked::into_unlocked_data(unlocked);
+            relocked.push((address, Locked::with_defaults(account, new_passphrase)?));
+        }
+
+        for (address, locked) in &relocked {
+            txn.put_reserve(&self.table, address, locked);
+        }
+
+        let check = Locked::with_defaults(PassphraseCheck::generate(), new_passphrase)?;
+        txn.put_reserve(&self.passphrase_table, &(), &check);
+
+        txn.commit();
+
+        Ok(relocked.len())
+    }
+
+    /// Exports all accounts of the wallet into an encrypted backup. The passphrase must match the
+    /// master passphrase and decrypt every account, such that the backup can be restored with it.
+    pub fn export_backup(&self, passphrase: &[u8]) -> Result<WalletBackup, WalletStoreError> {
+        let txn = self.create_read_transaction();
+
+        if !self.verify_passphrase(passphrase, Some(&txn)) {
+            return Err(WalletStoreError::WrongPassphrase);
+        }
+
+        // Make sure that the backup can actually be restored using this passphrase.
+        let mut accounts = vec![];
+        for (address, locked) in txn.cursor(&self.table).into_iter_start() {
+            let unlocked = locked
+                .unlock(passphrase)
+                .map_err(|_| WalletStoreError::WrongAccountPassphrase(address.clone()))?;
+            accounts.push((address, Unlocked::lock(unlocked)));
+        }
+
+        Ok(WalletBackup {
+            version: WalletBackup::VERSION,
+            accounts,
+        })
+    }
+
+    /// Imports all accounts of an encrypted backup. The accounts are decrypted with the passphrase
+    /// of the backup and re-encrypted with the master passphrase of this wallet. Either all
+    /// accounts are imported or none.
+    /// Returns the addresses of the imported accounts.
+    pub fn import_backup(
+        &self,
+        backup: WalletBackup,
+        backup_passphrase: &[u8],
+        passphrase: &[u8],
+    ) -> Result<Vec<Address>, WalletStoreError> {
+        let mut txn = self.create_write_transaction();
+
+        let mut addresses = Vec::with_capacity(backup.accounts.len());
+        for (address, locked) in backup.accounts {
+            let unlocked = locked
+                .unlock(backup_passphrase)
+                .map_err(|_| WalletStoreError::WrongAccountPassphrase(address.clone()))?;
+            let account = Unlocked::into_unlocked_data(unlocked);
+            if account.address != address {
+                return Err(WalletStoreError::WrongAccountPassphrase(address));
+            }
+            addresses.push(self.insert_with_txn(account, passphrase, &mut txn)?);
+        }
+
+        txn.commit();
+
+        Ok(addresses)
     }
 }
@@ -0,0 +1,107 @@
+use nimiq_database::mdbx::MdbxDatabase;
+use nimiq_serde::Serialize;
+use nimiq_test_log::test;
+use nimiq_wallet::{WalletAccount, WalletBackup, WalletStore, WalletStoreError};
+
+fn wallet_store() -> WalletStore {
+    WalletStore::new(MdbxDatabase::new_volatile(Default::default()).unwrap())
+}
+
+#[test]
+fn it_enforces_the_master_passphrase() {
+    let store = wallet_store();
+    assert!(!store.has_master_passphrase(None));
+
+    let address = store
+        .insert(WalletAccount::generate(), b"passphrase")
+        .unwrap();
+    assert!(store.has_master_passphrase(None));
+    assert!(store.verify_passphrase(b"passphrase", None));
+    assert!(!store.verify_passphrase(b"wrong", None));
+
+    // Accounts can only be added using the master passphrase.
+    assert!(matches!(
+        store.insert(WalletAccount::generate(), b"wrong"),
+        Err(WalletStoreError::WrongPassphrase)
+    ));
+    assert_eq!(store.list(None), vec![address.clone()]);
+
+    let account = store.get(&address, None).unwrap();
+    assert!(account.unlock(b"passphrase").is_ok());
+}
+
+#[test]
+fn it_changes_the_passphrase_of_all_accounts() {
+    let store = wallet_store();
+    let address1 = store
+        .insert(WalletAccount::generate(), b"passphrase")
+        .unwrap();
+    let address2 = store
+        .insert(WalletAccount::generate(), b"passphrase")
+        .unwrap();
+
+    assert!(matches!(
+        store.change_passphrase(b"wrong", b"new passphrase"),
+        Err(WalletStoreError::WrongPassphrase)
+    ));
+    assert!(store.verify_passphrase(b"passphrase", None));
+
+    assert_eq!(
+        store
+            .change_passphrase(b"passphrase", b"new passphrase")
+            .unwrap(),
+        2
+    );
+    assert!(store.verify_passphrase(b"new passphrase", None));
+    assert!(!store.verify_passphrase(b"passphrase", None));
+
+    for address in [address1, address2] {
+        let account = store.get(&address, None).unwrap();
+        assert!(account.unlock(b"passphrase").is_err());
+        let account = store.get(&address, None).unwrap();
+        assert!(account.unlock(b"new passphrase").is_ok());
+    }
+}
+
+#[test]
+fn it_exports_and_imports_backups() {
+    let store = wallet_store();
+    let address1 = store
+        .insert(WalletAccount::generate(), b"passphrase")
+        .unwrap();
+    let address2 = store
+        .insert(WalletAccount::generate(), b"passphrase")
+        .unwrap();
+
+    assert!(store.export_backup(b"wrong").is_err());
+
+    let backup = store.export_backup(b"passphrase").unwrap();
+    let bytes = backup.serialize_to_vec();
+    let backup = WalletBackup::from_bytes(&bytes).unwrap();
+    assert_eq!(backup.addresses().len(), 2);
+
+    let other_store = wallet_store();
+    other_store
+        .insert(WalletAccount::generate(), b"other passphrase")
+        .unwrap();
+
+    // A wrong backup passphrase doesn't import anything.
+    let backup = WalletBackup::from_bytes(&bytes).unwrap();
+    assert!(other_store
+        .import_backup(backup, b"wrong", b"other passphrase")
+        .is_err());
+    assert_eq!(other_store.list(None).len(), 1);
+
+    let backup = WalletBackup::from_bytes(&bytes).unwrap();
+    let mut addresses = other_store
+        .import_backup(backup, b"passphrase", b"other passphrase")
+        .unwrap();
+    addresses.sort();
+    let mut expected = vec![address1.clone(), address2];
+    expected.sort();
+    assert_eq!(addresses, expected);
+
+    // The imported accounts are encrypted with the passphrase of the importing wallet.
+    let account = other_store.get(&address1, None).unwrap();
+    assert!(account.unlock(b"other passphrase").is_ok());
+}
@@ -187,11 +187,14 @@ pub struct PlainValidator {
     signing_public_key: String,
     /// The voting public key, it is used to vote for skip and macro blocks.
     voting_public_key: String,
-    /// The reward address of the validator. All the block rewards are paid to this address.
+    /// The reward address of the validator. The validator's commission is paid to this address.
     reward_address: String,
     /// Signaling field. Can be used to do chain upgrades or for any other purpose that requires
     /// validators to coordinate among themselves.
     signal_data: Option<String>,
+    /// The share of the rewards the validator keeps, in basis points. The remainder is distributed
+    /// to the stakers delegating to this validator.
+    commission: u16,
     /// The total stake assigned to this validator. It includes the validator deposit as well as the
     /// coins delegated to him by stakers.
     total_stake: u64,
@@ -227,6 +230,7 @@ impl From<&nimiq_account::Validator> for PlainValidator {
             voting_public_key: validator.voting_key.to_hex(),
             reward_address: validator.reward_address.to_user_friendly_address(),
             signal_data: validator.signal_data.as_ref().map(|data| data.to_hex()),
+            commission: validator.commission,
             total_stake: validator.total_stake.into(),
             deposit: validator.deposit.into(),
             num_stakers: validator.num_stakers,
@@ -965,11 +965,16 @@ impl Client {
 
                             (hash, "extended", Array::new(), adopted_blocks)
                         }
-                        Some(BlockchainEvent::HistoryAdopted(hash)) => {
+                        Some(BlockchainEvent::HistoryAdopted(hash, reverted_chain)) => {
+                            let reverted_blocks = Array::new();
+                            for (h, _) in reverted_chain {
+                                reverted_blocks.push(&h.to_hex().into());
+                            }
+
                             let adopted_blocks = Array::new();
                             adopted_blocks.push(&hash.to_hex().into());
 
-                            (hash, "history-adopted", Array::new(), adopted_blocks)
+                            (hash, "history-adopted", reverted_blocks, adopted_blocks)
                         }
                         Some(BlockchainEvent::EpochFinalized(hash)) => {
                             let adopted_blocks = Array::new();
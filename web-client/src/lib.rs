@@ -55,6 +55,7 @@ mod tests {
             &BLSKeyPair::generate(),
             None,
             None,
+            None,
             1,
             5,
         )
@@ -292,6 +292,9 @@ impl TransactionBuilder {
 
     /// Registers a new validator in the staking contract.
     ///
+    /// The `commission` is the share of the rewards the validator keeps, in basis points. The rest
+    /// is distributed to the stakers delegating to it. By default, the validator keeps all rewards.
+    ///
     /// The returned transaction is not yet signed. You can sign it e.g. with `tx.sign(keyPair)`.
     ///
     /// Throws when the fee does not fit within a u64 or the `networkId` is unknown.
@@ -302,6 +305,7 @@ impl TransactionBuilder {
         signing_key: &PublicKey,
         voting_key_pair: &BLSKeyPair,
         signal_data: Option<String>,
+        commission: Option<u16>,
         fee: Option<u64>,
         validity_start_height: u32,
         network_id: u8,
@@ -313,6 +317,7 @@ impl TransactionBuilder {
             voting_key_pair.native_ref(),
             reward_address.native_ref().clone(),
             native_signal_data,
+            commission.unwrap_or(Policy::MAX_COMMISSION),
         );
 
         let mut builder = nimiq_transaction_builder::TransactionBuilder::new();
@@ -341,6 +346,7 @@ impl TransactionBuilder {
         signing_key: Option<PublicKey>,
         voting_key_pair: Option<BLSKeyPair>,
         signal_data: Option<String>,
+        commission: Option<u16>,
         fee: Option<u64>,
         validity_start_height: u32,
         network_id: u8,
@@ -356,6 +362,7 @@ impl TransactionBuilder {
             native_voting_key_pair.as_ref(),
             native_reward_address,
             native_signal_data,
+            commission,
         );
 
         let mut builder = nimiq_transaction_builder::TransactionBuilder::new();
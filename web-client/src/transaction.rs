@@ -504,6 +504,7 @@ impl Transaction {
                             voting_key,
                             reward_address,
                             signal_data,
+                            commission,
                             proof_of_knowledge,
                             proof: _proof,
                         } => PlainTransactionRecipientData::CreateValidator(
@@ -513,6 +514,7 @@ impl Transaction {
                                 voting_key: voting_key.to_hex(),
                                 reward_address: reward_address.to_user_friendly_address(),
                                 signal_data: signal_data.map(hex::encode),
+                                commission,
                                 proof_of_knowledge: proof_of_knowledge.to_hex(),
                             },
                         ),
@@ -521,6 +523,7 @@ impl Transaction {
                             new_voting_key,
                             new_reward_address,
                             new_signal_data,
+                            new_commission,
                             new_proof_of_knowledge,
                             proof: _proof,
                         } => PlainTransactionRecipientData::UpdateValidator(
@@ -535,6 +538,7 @@ impl Transaction {
                                 }),
                                 new_signal_data: new_signal_data
                                     .map(|signal_data| signal_data.map(hex::encode)),
+                                new_commission,
                                 new_proof_of_knowledge: new_proof_of_knowledge
                                     .map(|proof_of_knowledge| proof_of_knowledge.to_hex()),
                             },
@@ -825,6 +829,7 @@ pub struct PlainCreateValidatorData {
     voting_key: String,
     reward_address: String,
     signal_data: Option<String>,
+    commission: u16,
     proof_of_knowledge: String,
 }
 
@@ -837,6 +842,7 @@ pub struct PlainUpdateValidatorData {
     new_voting_key: Option<String>,
     new_reward_address: Option<String>,
     new_signal_data: Option<Option<String>>,
+    new_commission: Option<u16>,
     new_proof_of_knowledge: Option<String>,
 }
 
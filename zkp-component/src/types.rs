@@ -9,7 +9,7 @@ use nimiq_blockchain_proxy::BlockchainProxy;
 use nimiq_database_value_derive::DbSerializable;
 use nimiq_network_interface::{
     network::{Network, Topic},
-    request::{Handle, RequestCommon, RequestError, RequestMarker},
+    request::{request_type_name, Handle, RequestCommon, RequestError, RequestMarker},
 };
 use nimiq_serde::{Deserialize, DeserializeError, Serialize};
 use nimiq_zkp_primitives::NanoZKPError;
@@ -221,6 +221,11 @@ impl RequestCommon for RequestZKP {
     const MAX_REQUESTS: u32 = MAX_REQUEST_RESPONSE_ZKP;
 }
 
+/// Returns the names of the request types served by the ZKP component.
+pub fn request_type_names() -> Vec<&'static str> {
+    vec![request_type_name::<RequestZKP>()]
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum RequestZKPResponse {